@@ -8,10 +8,13 @@ use tokio::net::TcpListener;
 use tokio::sync::RwLock;
 use tracing::{error, info, warn};
 
-use crate::crypto::Hash;
+use crate::consensus::ValidatorSet;
+use crate::contracts::verification_queue::VerificationQueue;
+use crate::crypto::{Hash, PublicKey};
 use crate::mempool::Mempool;
+use crate::metrics::Metrics;
 use crate::state::ChainState;
-use crate::types::Address;
+use crate::types::{Address, JobPacket, SolutionCandidate};
 
 const EXPLORER_HTML: &str = include_str!("explorer.html");
 
@@ -19,6 +22,9 @@ const EXPLORER_HTML: &str = include_str!("explorer.html");
 pub async fn start_api_server(
     state: Arc<RwLock<ChainState>>,
     mempool: Arc<RwLock<Mempool>>,
+    verification_queue: Arc<VerificationQueue>,
+    metrics: Arc<Metrics>,
+    verifier_set: Arc<RwLock<ValidatorSet>>,
     port: u16,
 ) {
     let addr = format!("0.0.0.0:{}", port);
@@ -43,17 +49,25 @@ pub async fn start_api_server(
 
         let state = state.clone();
         let mempool = mempool.clone();
+        let verification_queue = verification_queue.clone();
+        let metrics = metrics.clone();
+        let verifier_set = verifier_set.clone();
 
         tokio::spawn(async move {
-            let mut buf = [0; 4096];
-            let n = match socket.read(&mut buf).await {
-                Ok(0) => return,
-                Ok(n) => n,
-                Err(_) => return,
+            let request = match read_request(&mut socket).await {
+                Some(request) => request,
+                None => return,
             };
 
-            let request = String::from_utf8_lossy(&buf[..n]);
-            let response = handle_request(&request, &state, &mempool).await;
+            let response = handle_request(
+                &request,
+                &state,
+                &mempool,
+                &verification_queue,
+                &metrics,
+                &verifier_set,
+            )
+            .await;
 
             if let Err(e) = socket.write_all(response.as_bytes()).await {
                 warn!("Failed to write API response: {}", e);
@@ -62,16 +76,94 @@ pub async fn start_api_server(
     }
 }
 
+/// Read a full HTTP request off `socket`: the header block, plus the body
+/// once `Content-Length` bytes of it have arrived. A single `read` call
+/// isn't enough for `POST` bodies larger than the OS read buffer, so this
+/// loops until the declared body length is satisfied (or the peer closes
+/// the connection).
+async fn read_request(socket: &mut tokio::net::TcpStream) -> Option<String> {
+    let mut buf = Vec::with_capacity(4096);
+    let mut chunk = [0u8; 4096];
+
+    let header_end = loop {
+        let n = socket.read(&mut chunk).await.ok()?;
+        if n == 0 {
+            return (!buf.is_empty()).then(|| String::from_utf8_lossy(&buf).into_owned());
+        }
+        buf.extend_from_slice(&chunk[..n]);
+
+        if let Some(pos) = buf.windows(4).position(|w| w == b"\r\n\r\n") {
+            break pos + 4;
+        }
+    };
+
+    let content_length = parse_content_length(&buf[..header_end]);
+
+    while buf.len() < header_end + content_length {
+        let n = socket.read(&mut chunk).await.ok()?;
+        if n == 0 {
+            break;
+        }
+        buf.extend_from_slice(&chunk[..n]);
+    }
+
+    Some(String::from_utf8_lossy(&buf).into_owned())
+}
+
+/// Find the `Content-Length` header (case-insensitive) in a raw header
+/// block; defaults to 0 (no body) if absent or unparsable.
+fn parse_content_length(header_bytes: &[u8]) -> usize {
+    String::from_utf8_lossy(header_bytes)
+        .lines()
+        .find_map(|line| {
+            let (name, value) = line.split_once(':')?;
+            name.trim()
+                .eq_ignore_ascii_case("content-length")
+                .then(|| value.trim().parse().ok())
+                .flatten()
+        })
+        .unwrap_or(0)
+}
+
 async fn handle_request(
     req: &str,
     state: &Arc<RwLock<ChainState>>,
     mempool: &Arc<RwLock<Mempool>>,
+    verification_queue: &Arc<VerificationQueue>,
+    metrics: &Arc<Metrics>,
+    verifier_set: &Arc<RwLock<ValidatorSet>>,
 ) -> String {
     let first_line = req.lines().next().unwrap_or("");
     let mut parts = first_line.split_whitespace();
     let method = parts.next().unwrap_or("GET");
     let path = parts.next().unwrap_or("/");
 
+    if method == "POST" {
+        let body = req.split_once("\r\n\r\n").map_or("", |(_, body)| body);
+
+        if path == "/api/submit/job" {
+            return match serde_json::from_str::<JobPacket>(body) {
+                Ok(job) => match mempool.write().await.add_job(job) {
+                    Ok(()) => json_response(json!({ "status": "accepted" })),
+                    Err(e) => json_response(json!({ "error": e.to_string() })),
+                },
+                Err(e) => json_response(json!({ "error": format!("invalid job: {}", e) })),
+            };
+        }
+
+        if path == "/api/submit/solution" {
+            return match serde_json::from_str::<SolutionCandidate>(body) {
+                Ok(solution) => match mempool.write().await.add_solution(solution) {
+                    Ok(()) => json_response(json!({ "status": "accepted" })),
+                    Err(e) => json_response(json!({ "error": e.to_string() })),
+                },
+                Err(e) => json_response(json!({ "error": format!("invalid solution: {}", e) })),
+            };
+        }
+
+        return not_found();
+    }
+
     if method != "GET" {
         return not_found();
     }
@@ -80,13 +172,19 @@ async fn handle_request(
         return html_response(EXPLORER_HTML);
     }
 
+    if path == "/metrics" {
+        return metrics_response(&metrics.render());
+    }
+
     if path == "/api/status" {
-        let (height, chain_id, tip) = {
+        let (height, chain_id, tip, genesis_alloc_count) = {
             let st = state.read().await;
             (
                 st.height(),
                 st.chain_id().map(ToString::to_string),
                 st.tip().map(|b| b.hash.to_string()),
+                st.get_block_at_height(0)
+                    .map_or(0, |b| b.genesis_alloc.len()),
             )
         };
 
@@ -97,7 +195,8 @@ async fn handle_request(
             "chain_id": chain_id,
             "tip": tip,
             "mempool_size": mp_size.jobs + mp_size.solutions,
-            "peer_count": 0
+            "peer_count": 0,
+            "genesis_alloc_count": genesis_alloc_count
         }));
     }
 
@@ -149,6 +248,37 @@ async fn handle_request(
         return json_response(json!({ "error": "Invalid address" }));
     }
 
+    // Explorer endpoint - balance, genesis allocation label, and the jobs
+    // an address has touched (requester, solver, or verifier). Backed by
+    // the address->jobs index `state.apply_block` maintains incrementally,
+    // so this stays O(1) instead of scanning every block per request.
+    if path.starts_with("/api/address/") {
+        let addr_str = path.trim_start_matches("/api/address/");
+        if let Ok(bytes) = hex::decode(addr_str.trim_start_matches("0x")) {
+            if bytes.len() == 20 {
+                let mut arr = [0u8; 20];
+                arr.copy_from_slice(&bytes);
+                let address = Address::from_bytes(arr);
+                let st = state.read().await;
+                let balance = st.balance_of(&address);
+                let allocation_label = st.allocation_label(&address);
+                let jobs: Vec<String> = st
+                    .jobs_for_address(&address)
+                    .into_iter()
+                    .map(|id| id.to_string())
+                    .collect();
+                return json_response(json!({
+                    "address": addr_str,
+                    "balance": balance.whole_hclaw(),
+                    "raw": balance.raw(),
+                    "allocation_label": allocation_label,
+                    "jobs": jobs
+                }));
+            }
+        }
+        return json_response(json!({ "error": "Invalid address" }));
+    }
+
     if path.starts_with("/api/block/") {
         let query = path.trim_start_matches("/api/block/");
 
@@ -174,9 +304,18 @@ async fn handle_request(
     if path.starts_with("/api/job/") {
         let query = path.trim_start_matches("/api/job/");
         if let Ok(hash) = Hash::from_hex(query) {
-            let job = state.read().await.get_job(&hash).cloned();
+            let st = state.read().await;
+            let job = st.get_job(&hash).cloned();
             if let Some(j) = job {
-                return json_response(json!(j));
+                // job-id->block index, maintained incrementally in
+                // `state.apply_block`, so this doesn't scan the chain.
+                let block_hash = st.block_for_job(&hash).map(|h| h.to_string());
+                let status = format!("{:?}", j.status);
+                return json_response(json!({
+                    "job": j,
+                    "status": status,
+                    "block_hash": block_hash
+                }));
             }
         }
         return json_response(json!({ "error": "Job not found" }));
@@ -212,6 +351,43 @@ async fn handle_request(
         };
     }
 
+    // Cross-verifier queue depth - lets operators watch verification backlog
+    if path == "/api/verification" {
+        let info = verification_queue.queue_info();
+        return json_response(json!({
+            "unverified": info.unverified,
+            "verifying": info.verifying,
+            "verified": info.verified,
+            "total_queue_size": info.total_queue_size(),
+            "incomplete_queue_size": info.incomplete_queue_size()
+        }));
+    }
+
+    // Current verifier set and weights, so operators can see who's
+    // eligible to propose the next block and by how much.
+    if path == "/api/verifiers" {
+        let vs = verifier_set.read().await;
+        let members: Vec<_> = vs
+            .validators
+            .iter()
+            .map(|v| {
+                json!({
+                    "public_key": v.public_key.to_hex(),
+                    "weight": v.stake.raw(),
+                    "active": v.stake.raw() > 0
+                })
+            })
+            .collect();
+        let next_proposer = vs.proposer_for_height(state.read().await.height());
+        return json_response(json!({
+            "epoch": vs.epoch,
+            "total_weight": vs.total_stake().raw(),
+            "active_weight": vs.total_active_stake().raw(),
+            "members": members,
+            "next_proposer": next_proposer.map(PublicKey::to_hex)
+        }));
+    }
+
     not_found()
 }
 
@@ -223,6 +399,14 @@ fn html_response(body: &str) -> String {
     )
 }
 
+fn metrics_response(body: &str) -> String {
+    format!(
+        "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nContent-Type: text/plain; version=0.0.4\r\nConnection: close\r\n\r\n{}",
+        body.len(),
+        body
+    )
+}
+
 fn json_response(body: serde_json::Value) -> String {
     let s = body.to_string();
     format!(
@@ -3,10 +3,12 @@
 use std::io::{self, Write};
 
 use hardclaw::{
-    crypto::{Keypair, hash_data},
+    crypto::{hash_data, keypair_from_imported_phrase, mnemonic_to_words, Keypair},
     types::{Address, JobPacket, JobType, HclawAmount, VerificationSpec},
 };
 
+use crate::rpc_client::NodeClient;
+
 fn main() {
     println!("╔════════════════════════════════════════════╗");
     println!("║       HardClaw CLI v{}             ║", hardclaw::VERSION);
@@ -14,9 +16,26 @@ fn main() {
     println!("╚════════════════════════════════════════════╝");
     println!();
 
-    // Generate a keypair for this session
-    let keypair = Keypair::generate();
-    let address = Address::from_public_key(keypair.public_key());
+    // Generate a mnemonic-backed keypair for this session, so it can be
+    // backed up (`mnemonic`) and later restored (`restore <words...>`).
+    let mut mnemonic = hardclaw::crypto::generate_mnemonic();
+    let mut keypair = hardclaw::crypto::keypair_from_mnemonic(&mnemonic, "");
+    let mut address = Address::from_public_key(keypair.public_key());
+
+    let connect_addr = std::env::args().skip_while(|a| a != "--connect").nth(1);
+    let mut node: Option<NodeClient> = connect_addr.and_then(|addr| {
+        println!("Connecting to node at {addr}...");
+        match NodeClient::connect(&addr) {
+            Ok(client) => {
+                println!("Connected (post-quantum encrypted channel established).");
+                Some(client)
+            }
+            Err(e) => {
+                println!("Failed to connect to {addr}: {e}");
+                None
+            }
+        }
+    });
 
     println!("Session address: {}", address);
     println!();
@@ -26,6 +45,8 @@ fn main() {
     println!("  submit <job>    - Submit a job");
     println!("  status <id>     - Check job status");
     println!("  verify <id>     - Verify a solution");
+    println!("  mnemonic        - Show this session's backup phrase");
+    println!("  restore <words> - Replace the session keypair from a backup phrase");
     println!("  help            - Show this help");
     println!("  quit            - Exit");
     println!();
@@ -58,8 +79,16 @@ fn main() {
                     println!("Usage: balance <address>");
                     continue;
                 }
-                // In a full implementation, this would query the node
-                println!("Balance for {}: 0.0 HCLAW (not connected to network)", parts[1]);
+                match (&mut node, Address::from_hex(parts[1])) {
+                    (Some(client), Ok(addr)) => match client.balance(&addr) {
+                        Ok(response) => println!("Balance for {}: {}", parts[1], response),
+                        Err(e) => println!("Balance request failed: {}", e),
+                    },
+                    (Some(_), Err(e)) => println!("Invalid address: {}", e),
+                    (None, _) => {
+                        println!("Balance for {}: 0.0 HCLAW (not connected to network)", parts[1]);
+                    }
+                }
             }
 
             "submit" => {
@@ -122,7 +151,16 @@ fn main() {
                 println!("  Burn Fee: 1 HCLAW");
                 println!("  Expires: {} seconds", 3600);
                 println!();
-                println!("(In a connected network, this would be broadcast to the mempool)");
+
+                match &mut node {
+                    Some(client) => match client.submit_job(&job) {
+                        Ok(response) => println!("Broadcast to mempool: {}", response),
+                        Err(e) => println!("Failed to broadcast job: {}", e),
+                    },
+                    None => {
+                        println!("(In a connected network, this would be broadcast to the mempool)");
+                    }
+                }
             }
 
             "status" => {
@@ -130,7 +168,15 @@ fn main() {
                     println!("Usage: status <job_id>");
                     continue;
                 }
-                println!("Job {} status: Unknown (not connected to network)", parts[1]);
+                match &mut node {
+                    Some(client) => match client.status(parts[1]) {
+                        Ok(response) => println!("Job {} status: {}", parts[1], response),
+                        Err(e) => println!("Status request failed: {}", e),
+                    },
+                    None => {
+                        println!("Job {} status: Unknown (not connected to network)", parts[1]);
+                    }
+                }
             }
 
             "verify" => {
@@ -138,7 +184,41 @@ fn main() {
                     println!("Usage: verify <solution_id>");
                     continue;
                 }
-                println!("Solution {} verification: Not implemented in CLI mode", parts[1]);
+                match &mut node {
+                    Some(client) => match client.verify(parts[1]) {
+                        Ok(response) => println!("Solution {} verification: {}", parts[1], response),
+                        Err(e) => println!("Verify request failed: {}", e),
+                    },
+                    None => {
+                        println!("Solution {} verification: Not implemented in CLI mode", parts[1]);
+                    }
+                }
+            }
+
+            "mnemonic" => {
+                println!("Session backup phrase (write this down, do not share it):");
+                println!("  {}", mnemonic_to_words(&mnemonic).join(" "));
+            }
+
+            "restore" => {
+                if parts.len() < 2 {
+                    println!("Usage: restore <word1> <word2> ... <wordN>");
+                    continue;
+                }
+                let phrase = parts[1..].join(" ");
+                match keypair_from_imported_phrase(&phrase, "") {
+                    Ok(new_keypair) => {
+                        mnemonic = hardclaw::crypto::parse_mnemonic(&phrase)
+                            .expect("keypair_from_imported_phrase already validated this phrase");
+                        keypair = new_keypair;
+                        address = Address::from_public_key(keypair.public_key());
+                        println!("Restored session keypair:");
+                        println!("  Address: {}", address);
+                    }
+                    Err(e) => {
+                        println!("Failed to restore from phrase: {}", e);
+                    }
+                }
             }
 
             "help" => {
@@ -148,6 +228,8 @@ fn main() {
                 println!("  submit          - Submit a job interactively");
                 println!("  status <id>     - Check job status");
                 println!("  verify <id>     - Verify a solution");
+                println!("  mnemonic        - Show this session's backup phrase");
+                println!("  restore <words> - Replace the session keypair from a backup phrase");
                 println!("  help            - Show this help");
                 println!("  quit            - Exit");
             }
@@ -0,0 +1,573 @@
+//! Pluggable consensus engines.
+//!
+//! [`types::block::Block`](crate::types::block::Block) used to call
+//! `crate::CONSENSUS_THRESHOLD` directly, baking proof-of-verification's
+//! 66% attestation rule into the block type itself. [`ConsensusEngine`]
+//! pulls that rule (and the rest of what makes a block valid and final)
+//! out into a trait the block type is handed at call time instead —
+//! generalizing the split the way OpenEthereum separated its common
+//! `EthereumMachine` plumbing from per-chain `Engine` implementations.
+//! [`ProofOfVerification`] is the default mainnet engine; [`BasicAuthority`]
+//! and [`Schelling`] are alternate engines for devnets and
+//! subjective-task-flavored deployments.
+//!
+//! Quorum is stake-weighted: [`ValidatorSet`] carries each validator's
+//! staked [`HclawAmount`], and [`ConsensusEngine::stake_quorum`] expresses
+//! how much of that stake must back a block's attestations, rather than
+//! a bare attestation headcount. Validator sets rotate by epoch, so
+//! [`ValidatorSetHistory`] keeps one snapshot per epoch — again borrowing
+//! OpenEthereum's validator-set + transition-handler split — so that an
+//! attestation on a late or forked block is always checked against the
+//! set that was active when its epoch began.
+
+use std::collections::HashMap;
+
+use crate::crypto::PublicKey;
+use crate::types::block::{Block, GenesisAlloc};
+use crate::types::{Address, HclawAmount};
+
+/// One validator's voting weight within a [`ValidatorSet`] — their
+/// staked [`HclawAmount`] as of the epoch the set was snapshotted.
+#[derive(Clone, Debug)]
+pub struct ValidatorEntry {
+    /// The validator's public key.
+    pub public_key: PublicKey,
+    /// Stake backing this validator's attestations.
+    pub stake: HclawAmount,
+}
+
+/// The stake-weighted validator set active during one epoch.
+///
+/// Membership and stake are both fixed for the lifetime of the epoch;
+/// [`ConsensusEngine::is_epoch_end`] marks the block after which a new
+/// set takes over, and [`ValidatorSetHistory`] is where each set is kept
+/// once snapshotted.
+#[derive(Clone, Debug, Default)]
+pub struct ValidatorSet {
+    /// The epoch this set is active during.
+    pub epoch: u64,
+    /// Validators in the set and their stake.
+    pub validators: Vec<ValidatorEntry>,
+}
+
+impl ValidatorSet {
+    /// Build a set for `epoch` out of `validators`.
+    #[must_use]
+    pub fn new(epoch: u64, validators: Vec<ValidatorEntry>) -> Self {
+        Self { epoch, validators }
+    }
+
+    /// Whether this set has no validators.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.validators.is_empty()
+    }
+
+    /// Sum of every validator's stake in this set.
+    #[must_use]
+    pub fn total_stake(&self) -> HclawAmount {
+        let total: u128 = self.validators.iter().map(|v| v.stake.raw()).sum();
+        HclawAmount::from_raw(total)
+    }
+
+    /// `validator`'s stake in this set, if they're a member.
+    #[must_use]
+    pub fn stake_of(&self, validator: &PublicKey) -> Option<HclawAmount> {
+        self.validators
+            .iter()
+            .find(|v| &v.public_key == validator)
+            .map(|v| v.stake.clone())
+    }
+
+    /// Whether `validator` is a member of this set.
+    #[must_use]
+    pub fn contains(&self, validator: &PublicKey) -> bool {
+        self.validators.iter().any(|v| &v.public_key == validator)
+    }
+
+    /// Members with nonzero stake — validators who've bonded no stake
+    /// have no say in proposing or attesting, the same zero-power
+    /// exclusion proof-of-stake validator sets apply.
+    pub fn active_validators(&self) -> impl Iterator<Item = &ValidatorEntry> {
+        self.validators.iter().filter(|v| v.stake.raw() > 0)
+    }
+
+    /// Whether this set has any validator with nonzero stake.
+    #[must_use]
+    pub fn has_active_members(&self) -> bool {
+        self.active_validators().next().is_some()
+    }
+
+    /// Sum of stake held by [`Self::active_validators`]. Equal to
+    /// [`Self::total_stake`] since zero-stake members contribute nothing
+    /// to either sum; kept as its own method so callers that care about
+    /// active weight specifically (proposer selection, quorum) don't have
+    /// to reason about whether zero-stake members are already excluded.
+    #[must_use]
+    pub fn total_active_stake(&self) -> HclawAmount {
+        let total: u128 = self.active_validators().map(|v| v.stake.raw()).sum();
+        HclawAmount::from_raw(total)
+    }
+
+    /// Deterministically pick the proposer for `height` by weighted
+    /// round-robin over [`Self::active_validators`]: each validator owns a
+    /// `stake`-wide slice of the `0..total_active_stake` range, and
+    /// `height % total_active_stake` selects which slice is "up" this
+    /// height — so heavier-staked validators propose proportionally more
+    /// often. Returns `None` if the set has no active members.
+    #[must_use]
+    pub fn proposer_for_height(&self, height: u64) -> Option<&PublicKey> {
+        let total = self.total_active_stake().raw();
+        if total == 0 {
+            return None;
+        }
+
+        let mut cursor = u128::from(height) % total;
+        for validator in self.active_validators() {
+            let stake = validator.stake.raw();
+            if cursor < stake {
+                return Some(&validator.public_key);
+            }
+            cursor -= stake;
+        }
+        None
+    }
+}
+
+/// Epoch-keyed history of [`ValidatorSet`] snapshots.
+///
+/// Blocks name the epoch their attestations were gathered under
+/// (`BlockHeader::epoch`); looking that epoch up here gives the set
+/// those attestations must be checked against, even after the set has
+/// since rotated — so a late-arriving or forked attestation can't be
+/// validated against the wrong, newer validator set.
+#[derive(Clone, Debug, Default)]
+pub struct ValidatorSetHistory {
+    snapshots: HashMap<u64, ValidatorSet>,
+}
+
+impl ValidatorSetHistory {
+    /// An empty history with no snapshots yet.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record `set` as the validator set active during `set.epoch`,
+    /// replacing any prior snapshot for that epoch.
+    pub fn snapshot(&mut self, set: ValidatorSet) {
+        self.snapshots.insert(set.epoch, set);
+    }
+
+    /// The validator set active during `epoch`, if one has been
+    /// snapshotted.
+    #[must_use]
+    pub fn for_epoch(&self, epoch: u64) -> Option<&ValidatorSet> {
+        self.snapshots.get(&epoch)
+    }
+}
+
+/// A pluggable block-validity and quorum engine.
+///
+/// [`Block`] delegates every consensus-specific decision — family/seal
+/// validity, how big a quorum is, when an epoch ends, and how the fee
+/// pool is split — to whatever engine the deployment runs, rather than
+/// hardcoding any one rule itself.
+pub trait ConsensusEngine {
+    /// Validate `block` against its claimed `parent`: parent hash, height,
+    /// and timestamp monotonicity.
+    ///
+    /// # Errors
+    /// Returns [`ConsensusError::InvalidFamily`] if `block` doesn't
+    /// descend from `parent`.
+    fn verify_block_family(&self, block: &Block, parent: &Block) -> Result<(), ConsensusError>;
+
+    /// Validate the block's seal: whatever makes `block.proposer_signature`
+    /// an authorized claim to have produced this block under this engine
+    /// (a valid signature alone for proof-of-verification; signature *and*
+    /// membership in a fixed authority set for [`BasicAuthority`]).
+    ///
+    /// # Errors
+    /// Returns [`ConsensusError::InvalidSeal`] if the seal is invalid.
+    fn verify_seal(&self, block: &Block) -> Result<(), ConsensusError>;
+
+    /// Stake that must back a block's attestations for it to be final,
+    /// given the current `validator_set`.
+    fn stake_quorum(&self, validator_set: &ValidatorSet) -> HclawAmount;
+
+    /// Whether `block` closes a validator-set epoch (e.g. triggers
+    /// re-election or rotation). Engines without epochs always return
+    /// `false`.
+    fn is_epoch_end(&self, block: &Block) -> bool;
+
+    /// Distribute `fees` collected while producing `block` into
+    /// `GenesisAlloc`-shaped credits (solver/verifier shares, a burn,
+    /// etc). This is a block-level split; per-transaction gas fees are
+    /// still settled by `contracts::processor` as each transaction runs.
+    fn on_close_block(&self, block: &Block, fees: HclawAmount) -> Vec<GenesisAlloc>;
+}
+
+/// Errors produced validating a block against a [`ConsensusEngine`].
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum ConsensusError {
+    /// `block` doesn't descend from the parent it was checked against.
+    #[error("block does not descend from the given parent")]
+    InvalidFamily,
+    /// The block's seal (proposer signature, and/or proposer authorization)
+    /// didn't check out.
+    #[error("block seal is invalid: {0}")]
+    InvalidSeal(String),
+}
+
+/// The default engine: proof-of-verification's attestation quorum. Any
+/// validator in `validator_set` may attest; `stake_quorum` is the same
+/// `ceil(total_stake * CONSENSUS_THRESHOLD)` rule `Block::has_consensus`
+/// always used, just weighted by stake instead of counted by head, and
+/// reached through the engine instead of hardcoded.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ProofOfVerification;
+
+impl ConsensusEngine for ProofOfVerification {
+    fn verify_block_family(&self, block: &Block, parent: &Block) -> Result<(), ConsensusError> {
+        verify_linear_family(block, parent)
+    }
+
+    fn verify_seal(&self, block: &Block) -> Result<(), ConsensusError> {
+        verify_proposer_signature(block)
+    }
+
+    fn stake_quorum(&self, validator_set: &ValidatorSet) -> HclawAmount {
+        let total = validator_set.total_stake().raw();
+        HclawAmount::from_raw((total as f64 * crate::CONSENSUS_THRESHOLD).ceil() as u128)
+    }
+
+    fn is_epoch_end(&self, _block: &Block) -> bool {
+        false
+    }
+
+    fn on_close_block(&self, block: &Block, fees: HclawAmount) -> Vec<GenesisAlloc> {
+        distribute_fees(block, fees)
+    }
+}
+
+/// A fixed-signer-set engine for test/devnets: any block proposed by one
+/// of `authorities` is final on its own seal — there's no attestation
+/// quorum to collect at all.
+#[derive(Clone, Debug)]
+pub struct BasicAuthority {
+    /// The fixed set of keys allowed to propose blocks.
+    pub authorities: Vec<PublicKey>,
+}
+
+impl BasicAuthority {
+    /// Build an engine that trusts exactly `authorities` to seal blocks.
+    #[must_use]
+    pub fn new(authorities: Vec<PublicKey>) -> Self {
+        Self { authorities }
+    }
+}
+
+impl ConsensusEngine for BasicAuthority {
+    fn verify_block_family(&self, block: &Block, parent: &Block) -> Result<(), ConsensusError> {
+        verify_linear_family(block, parent)
+    }
+
+    fn verify_seal(&self, block: &Block) -> Result<(), ConsensusError> {
+        if !self.authorities.contains(&block.header.proposer) {
+            return Err(ConsensusError::InvalidSeal(
+                "proposer is not a recognized authority".to_string(),
+            ));
+        }
+        verify_proposer_signature(block)
+    }
+
+    fn stake_quorum(&self, _validator_set: &ValidatorSet) -> HclawAmount {
+        // The seal alone finalizes the block; no attestations required.
+        HclawAmount::ZERO
+    }
+
+    fn is_epoch_end(&self, _block: &Block) -> bool {
+        false
+    }
+
+    fn on_close_block(&self, block: &Block, fees: HclawAmount) -> Vec<GenesisAlloc> {
+        distribute_fees(block, fees)
+    }
+}
+
+/// An engine sized around `SCHELLING_REDUNDANCY` rather than a percentage
+/// quorum, so block finality tracks the same "enough independent
+/// attesters agree" intuition schelling-point solution verification uses
+/// elsewhere in this protocol. Its stake quorum is `SCHELLING_REDUNDANCY`
+/// validators' worth of the set's average stake (capped at the set's
+/// full stake for small sets), which reduces to the old headcount rule
+/// when every validator is staked equally. Family and seal checks are
+/// otherwise identical to [`ProofOfVerification`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Schelling;
+
+impl ConsensusEngine for Schelling {
+    fn verify_block_family(&self, block: &Block, parent: &Block) -> Result<(), ConsensusError> {
+        verify_linear_family(block, parent)
+    }
+
+    fn verify_seal(&self, block: &Block) -> Result<(), ConsensusError> {
+        verify_proposer_signature(block)
+    }
+
+    fn stake_quorum(&self, validator_set: &ValidatorSet) -> HclawAmount {
+        let validator_count = validator_set.validators.len();
+        if validator_count == 0 {
+            return HclawAmount::ZERO;
+        }
+        let redundancy = crate::SCHELLING_REDUNDANCY.min(validator_count) as u128;
+        let total = validator_set.total_stake().raw();
+        HclawAmount::from_raw(total * redundancy / validator_count as u128)
+    }
+
+    fn is_epoch_end(&self, _block: &Block) -> bool {
+        false
+    }
+
+    fn on_close_block(&self, block: &Block, fees: HclawAmount) -> Vec<GenesisAlloc> {
+        distribute_fees(block, fees)
+    }
+}
+
+/// Shared family check for the linear-chain engines above: `block` must
+/// name `parent` as its parent, sit exactly one height above it, and not
+/// claim a timestamp earlier than its parent's.
+fn verify_linear_family(block: &Block, parent: &Block) -> Result<(), ConsensusError> {
+    if block.header.parent_hash != parent.hash {
+        return Err(ConsensusError::InvalidFamily);
+    }
+    if block.header.height != parent.header.height + 1 {
+        return Err(ConsensusError::InvalidFamily);
+    }
+    if block.header.timestamp < parent.header.timestamp {
+        return Err(ConsensusError::InvalidFamily);
+    }
+    Ok(())
+}
+
+/// Shared seal check: `block.proposer_signature` must verify against
+/// `block.header.proposer` over `block.signing_bytes()`.
+fn verify_proposer_signature(block: &Block) -> Result<(), ConsensusError> {
+    crate::crypto::verify(
+        &block.header.proposer,
+        &block.signing_bytes(),
+        &block.proposer_signature,
+    )
+    .map_err(|e| ConsensusError::InvalidSeal(e.to_string()))
+}
+
+/// Split `fees` per [`crate::fees`]'s solver/verifier/burn percentages,
+/// crediting the combined solver+verifier share to the block's proposer
+/// and sending the burn share to [`Address::ZERO`]. This is a coarse
+/// block-level split — it doesn't track individual solver attribution,
+/// which is instead settled per-transaction by `contracts::processor`'s
+/// gas accounting.
+fn distribute_fees(block: &Block, fees: HclawAmount) -> Vec<GenesisAlloc> {
+    let total = fees.raw();
+    let burn_share = total * u128::from(crate::fees::BURN_SHARE) / 100;
+    let kept_share = total - burn_share;
+
+    let mut allocs = vec![GenesisAlloc {
+        address: Address::from_public_key(&block.header.proposer),
+        amount: HclawAmount::from_raw(kept_share),
+        label: "block-reward".to_string(),
+    }];
+
+    if burn_share > 0 {
+        allocs.push(GenesisAlloc {
+            address: Address::ZERO,
+            amount: HclawAmount::from_raw(burn_share),
+            label: "burn".to_string(),
+        });
+    }
+
+    allocs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto::{Hash, Keypair};
+
+    fn signed_block(height: u64, parent_hash: Hash, proposer_kp: &Keypair) -> Block {
+        let mut block = Block::new(
+            height,
+            0,
+            parent_hash,
+            proposer_kp.public_key().clone(),
+            Vec::new(),
+            Hash::ZERO,
+        );
+        block.proposer_signature = proposer_kp.sign(&block.signing_bytes());
+        block
+    }
+
+    fn equal_stake_set(count: usize, stake: u128) -> ValidatorSet {
+        ValidatorSet::new(
+            0,
+            (0..count)
+                .map(|_| ValidatorEntry {
+                    public_key: Keypair::generate().public_key().clone(),
+                    stake: HclawAmount::from_raw(stake),
+                })
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_proof_of_verification_stake_quorum_is_66_percent_of_total_stake() {
+        let engine = ProofOfVerification;
+        let validator_set = equal_stake_set(10, 100);
+
+        let total = validator_set.total_stake().raw();
+        let expected = (total as f64 * crate::CONSENSUS_THRESHOLD).ceil() as u128;
+        assert_eq!(engine.stake_quorum(&validator_set).raw(), expected);
+    }
+
+    #[test]
+    fn test_proof_of_verification_accepts_valid_seal() {
+        let engine = ProofOfVerification;
+        let proposer = Keypair::generate();
+        let block = signed_block(1, Hash::ZERO, &proposer);
+
+        assert!(engine.verify_seal(&block).is_ok());
+    }
+
+    #[test]
+    fn test_basic_authority_rejects_unknown_proposer() {
+        let authority = Keypair::generate();
+        let impostor = Keypair::generate();
+        let engine = BasicAuthority::new(vec![authority.public_key().clone()]);
+        let block = signed_block(1, Hash::ZERO, &impostor);
+
+        assert!(matches!(
+            engine.verify_seal(&block),
+            Err(ConsensusError::InvalidSeal(_))
+        ));
+    }
+
+    #[test]
+    fn test_basic_authority_accepts_known_proposer_with_no_quorum() {
+        let authority = Keypair::generate();
+        let engine = BasicAuthority::new(vec![authority.public_key().clone()]);
+        let block = signed_block(1, Hash::ZERO, &authority);
+
+        assert!(engine.verify_seal(&block).is_ok());
+        assert_eq!(engine.stake_quorum(&ValidatorSet::default()).raw(), 0);
+    }
+
+    #[test]
+    fn test_schelling_stake_quorum_caps_at_redundancy_worth_of_average_stake() {
+        let engine = Schelling;
+        let small_set = equal_stake_set(2, 10);
+        let large_set = equal_stake_set(100, 10);
+
+        // With fewer validators than SCHELLING_REDUNDANCY, the quorum is
+        // the set's full stake.
+        assert_eq!(
+            engine.stake_quorum(&small_set).raw(),
+            small_set.total_stake().raw()
+        );
+        // With more, the quorum is redundancy-many validators' worth.
+        assert_eq!(
+            engine.stake_quorum(&large_set).raw(),
+            10 * crate::SCHELLING_REDUNDANCY as u128
+        );
+    }
+
+    #[test]
+    fn test_proposer_for_height_excludes_zero_stake_validators() {
+        let silent = Keypair::generate();
+        let active = Keypair::generate();
+        let validator_set = ValidatorSet::new(
+            0,
+            vec![
+                ValidatorEntry {
+                    public_key: silent.public_key().clone(),
+                    stake: HclawAmount::from_raw(0),
+                },
+                ValidatorEntry {
+                    public_key: active.public_key().clone(),
+                    stake: HclawAmount::from_raw(10),
+                },
+            ],
+        );
+
+        assert!(!validator_set.active_validators().any(|v| v.public_key == *silent.public_key()));
+        for height in 0..5 {
+            assert_eq!(
+                validator_set.proposer_for_height(height),
+                Some(active.public_key())
+            );
+        }
+    }
+
+    #[test]
+    fn test_proposer_for_height_is_weighted_by_stake() {
+        let heavy = Keypair::generate();
+        let light = Keypair::generate();
+        let validator_set = ValidatorSet::new(
+            0,
+            vec![
+                ValidatorEntry {
+                    public_key: heavy.public_key().clone(),
+                    stake: HclawAmount::from_raw(9),
+                },
+                ValidatorEntry {
+                    public_key: light.public_key().clone(),
+                    stake: HclawAmount::from_raw(1),
+                },
+            ],
+        );
+
+        let heavy_wins = (0..10)
+            .filter(|&h| validator_set.proposer_for_height(h) == Some(heavy.public_key()))
+            .count();
+        assert_eq!(heavy_wins, 9);
+    }
+
+    #[test]
+    fn test_proposer_for_height_returns_none_with_no_active_members() {
+        let validator_set = ValidatorSet::default();
+        assert_eq!(validator_set.proposer_for_height(0), None);
+    }
+
+    #[test]
+    fn test_verify_block_family_rejects_wrong_parent() {
+        let engine = ProofOfVerification;
+        let proposer = Keypair::generate();
+        let parent = signed_block(1, Hash::ZERO, &proposer);
+        let mut child = signed_block(2, parent.hash, &proposer);
+        child.header.parent_hash = Hash::ZERO;
+
+        assert!(matches!(
+            engine.verify_block_family(&child, &parent),
+            Err(ConsensusError::InvalidFamily)
+        ));
+    }
+
+    #[test]
+    fn test_on_close_block_splits_burn_share_to_zero_address() {
+        let engine = ProofOfVerification;
+        let proposer = Keypair::generate();
+        let block = signed_block(1, Hash::ZERO, &proposer);
+
+        let allocs = engine.on_close_block(&block, HclawAmount::from_raw(1_000));
+
+        let burn = allocs.iter().find(|a| a.address == Address::ZERO).unwrap();
+        assert_eq!(
+            burn.amount.raw(),
+            1_000 * u128::from(crate::fees::BURN_SHARE) / 100
+        );
+        let reward = allocs
+            .iter()
+            .find(|a| a.address == Address::from_public_key(proposer.public_key()))
+            .unwrap();
+        assert_eq!(reward.amount.raw() + burn.amount.raw(), 1_000);
+    }
+}
@@ -0,0 +1,173 @@
+//! Logs bloom filter over `ContractEvent` topics, Ethereum-style.
+//!
+//! Scanning every event to answer "did any result touch topic X?" doesn't
+//! scale. [`LogsBloom`] is a fixed-size, 2048-bit probabilistic filter
+//! accumulated per [`ExecutionResult`](super::ExecutionResult) and OR'd
+//! together per block, so a light client can cheaply rule out "definitely
+//! not present" before paying for a real scan. False positives are
+//! possible; false negatives are not.
+
+use serde::{Deserialize, Serialize};
+
+use super::ContractEvent;
+use crate::crypto::hash_data;
+use crate::types::Id;
+
+/// Number of bits in the filter (2048 bits = 256 bytes), matching the
+/// Ethereum `LOGS_BLOOM` construction.
+const BLOOM_BITS: usize = 2048;
+const BLOOM_BYTES: usize = BLOOM_BITS / 8;
+
+/// 2048-bit logs bloom filter over `(contract_id, topic)` pairs emitted by
+/// contract execution.
+///
+/// Each event contributes two items to the filter — its `contract_id` and
+/// its `topic` — inserted independently so `matches_contract` and
+/// `matches_topic` can each be answered without knowing the other half of
+/// the pair.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LogsBloom {
+    bits: [u8; BLOOM_BYTES],
+}
+
+impl LogsBloom {
+    /// An empty filter that matches nothing.
+    #[must_use]
+    pub const fn empty() -> Self {
+        Self {
+            bits: [0u8; BLOOM_BYTES],
+        }
+    }
+
+    /// Build a filter covering every event in `events`.
+    #[must_use]
+    pub fn from_events(events: &[ContractEvent]) -> Self {
+        let mut bloom = Self::empty();
+        for event in events {
+            bloom.insert_event(event);
+        }
+        bloom
+    }
+
+    /// Fold `event` into this filter.
+    pub fn insert_event(&mut self, event: &ContractEvent) {
+        self.insert(event.contract_id.as_bytes());
+        self.insert(event.topic.as_bytes());
+    }
+
+    /// OR `other` into this filter in place — how a block's bloom is built
+    /// up from each of its `ExecutionResult`s.
+    pub fn merge(&mut self, other: &Self) {
+        for (b, o) in self.bits.iter_mut().zip(other.bits.iter()) {
+            *b |= o;
+        }
+    }
+
+    /// Aggregate every result's filter into one block-level filter.
+    #[must_use]
+    pub fn aggregate<'a>(blooms: impl IntoIterator<Item = &'a Self>) -> Self {
+        let mut bloom = Self::empty();
+        for b in blooms {
+            bloom.merge(b);
+        }
+        bloom
+    }
+
+    /// `true` if some event matching `contract_id` may have been emitted.
+    /// Can false-positive; never false-negatives.
+    #[must_use]
+    pub fn matches_contract(&self, contract_id: &Id) -> bool {
+        self.contains(contract_id.as_bytes())
+    }
+
+    /// `true` if some event matching `topic` may have been emitted.
+    /// Can false-positive; never false-negatives.
+    #[must_use]
+    pub fn matches_topic(&self, topic: &str) -> bool {
+        self.contains(topic.as_bytes())
+    }
+
+    /// Set the three bits `item` hashes to.
+    fn insert(&mut self, item: &[u8]) {
+        for bit in Self::bit_indices(item) {
+            self.bits[bit / 8] |= 1 << (bit % 8);
+        }
+    }
+
+    /// `true` if all three bits `item` hashes to are set.
+    fn contains(&self, item: &[u8]) -> bool {
+        Self::bit_indices(item)
+            .iter()
+            .all(|&bit| self.bits[bit / 8] & (1 << (bit % 8)) != 0)
+    }
+
+    /// Hash `item` and derive the three bit indices it sets/checks: three
+    /// non-overlapping 16-bit pairs from the 32-byte hash, each masked to
+    /// the low 11 bits (`& 0x7FF`) to land in `[0, BLOOM_BITS)`.
+    fn bit_indices(item: &[u8]) -> [usize; 3] {
+        let hash = hash_data(item);
+        let bytes = hash.as_bytes();
+        [
+            (usize::from(bytes[0]) << 8 | usize::from(bytes[1])) & 0x7FF,
+            (usize::from(bytes[2]) << 8 | usize::from(bytes[3])) & 0x7FF,
+            (usize::from(bytes[4]) << 8 | usize::from(bytes[5])) & 0x7FF,
+        ]
+    }
+}
+
+impl Default for LogsBloom {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(contract_id: Id, topic: &str) -> ContractEvent {
+        ContractEvent {
+            contract_id,
+            topic: topic.to_string(),
+            data: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_empty_bloom_matches_nothing() {
+        let bloom = LogsBloom::empty();
+        assert!(!bloom.matches_topic("Transfer"));
+        assert!(!bloom.matches_contract(&Id::ZERO));
+    }
+
+    #[test]
+    fn test_inserted_event_matches_its_own_contract_and_topic() {
+        let contract_id = Id::from_bytes([7u8; 32]);
+        let ev = event(contract_id, "Transfer");
+        let bloom = LogsBloom::from_events(&[ev]);
+
+        assert!(bloom.matches_contract(&contract_id));
+        assert!(bloom.matches_topic("Transfer"));
+    }
+
+    #[test]
+    fn test_unrelated_topic_is_very_likely_absent() {
+        let contract_id = Id::from_bytes([7u8; 32]);
+        let ev = event(contract_id, "Transfer");
+        let bloom = LogsBloom::from_events(&[ev]);
+
+        assert!(!bloom.matches_topic("SomeOtherEventThatWasNeverEmitted"));
+    }
+
+    #[test]
+    fn test_merge_combines_two_blooms() {
+        let a = LogsBloom::from_events(&[event(Id::from_bytes([1u8; 32]), "Joined")]);
+        let b = LogsBloom::from_events(&[event(Id::from_bytes([2u8; 32]), "MisbehaviorReported")]);
+
+        let aggregated = LogsBloom::aggregate([&a, &b]);
+        assert!(aggregated.matches_topic("Joined"));
+        assert!(aggregated.matches_topic("MisbehaviorReported"));
+        assert!(aggregated.matches_contract(&Id::from_bytes([1u8; 32])));
+        assert!(aggregated.matches_contract(&Id::from_bytes([2u8; 32])));
+    }
+}
@@ -0,0 +1,204 @@
+//! Gas cost schedule for contract execution.
+//!
+//! Modeled on EVM's per-operation cost table: [`ContractState`](super::state::ContractState)
+//! charges each metered helper (`storage_read`, `storage_write`, `credit`,
+//! `debit`, `emit_event`) against a running total as it's called, so a
+//! contract that loops over caller-supplied data pays gas proportional to
+//! how much it actually touches instead of a flat per-call fee.
+
+use serde::{Deserialize, Serialize};
+
+/// Per-operation gas costs, analogous to EVM's opcode cost table.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct Schedule {
+    /// Flat cost charged once per transaction, before any metered
+    /// operation, analogous to EVM's 21000 base transaction fee
+    pub base_tx_cost: u64,
+    /// Flat cost of a `storage_read`
+    pub storage_read: u64,
+    /// Flat cost of a `storage_write` or `storage_delete`, before the
+    /// per-byte surcharge below
+    pub storage_write: u64,
+    /// Extra cost per byte of a `storage_write`'s serialized value
+    pub storage_write_byte: u64,
+    /// Flat cost of a `credit`
+    pub credit: u64,
+    /// Flat cost of a `debit`
+    pub debit: u64,
+    /// Flat cost of an `emit_event`
+    pub emit_event: u64,
+    /// Cost per byte of an `ExecutionResult`'s `output`, charged by the
+    /// transaction processor once execution returns
+    pub output_byte: u64,
+    /// Flat cost charged once per `Deploy` transaction, on top of
+    /// `base_tx_cost`, analogous to EVM's `CREATE` cost
+    pub deploy_base_cost: u64,
+    /// Extra cost per byte of a `Deploy` transaction's contract code
+    pub deploy_code_byte: u64,
+}
+
+impl Schedule {
+    /// The default cost table, loosely modeled on EVM SLOAD/SSTORE/LOG costs
+    /// scaled down for `HardClaw`'s simpler account/storage model.
+    #[must_use]
+    pub const fn standard() -> Self {
+        Self {
+            base_tx_cost: 21_000,
+            storage_read: 200,
+            storage_write: 5_000,
+            storage_write_byte: 3,
+            credit: 100,
+            debit: 100,
+            emit_event: 375,
+            output_byte: 8,
+            deploy_base_cost: 32_000,
+            deploy_code_byte: 200,
+        }
+    }
+
+    /// Gas charged for writing a value of `value_len` bytes.
+    #[must_use]
+    pub const fn storage_write_cost(&self, value_len: usize) -> u64 {
+        self.storage_write
+            .saturating_add(self.storage_write_byte.saturating_mul(value_len as u64))
+    }
+
+    /// Gas charged for an `ExecutionResult::output` of `output_len` bytes.
+    #[must_use]
+    pub const fn output_cost(&self, output_len: usize) -> u64 {
+        self.output_byte.saturating_mul(output_len as u64)
+    }
+
+    /// Gas charged for deploying `code_len` bytes of contract code.
+    #[must_use]
+    pub const fn deploy_cost(&self, code_len: usize) -> u64 {
+        self.deploy_base_cost
+            .saturating_add(self.deploy_code_byte.saturating_mul(code_len as u64))
+    }
+}
+
+impl Default for Schedule {
+    fn default() -> Self {
+        Self::standard()
+    }
+}
+
+/// Per-WASM-instruction gas costs, charged by the block-level `gas`
+/// trampolines [`super::wasm::instrument`] injects. Deliberately coarser
+/// than a full per-opcode table (mirroring how [`Schedule`] charges by
+/// operation category rather than by CPU cycle): most instructions fall
+/// under `default`, with a few categories broken out because they're
+/// disproportionately expensive or security-relevant.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct InstructionCosts {
+    /// Cost of an instruction that doesn't match any other category
+    /// (arithmetic, locals, constants, ...)
+    pub default: u64,
+    /// Cost of a `call`/`call_indirect`, on top of the callee's own
+    /// metered cost
+    pub call: u64,
+    /// Cost of a memory load or store
+    pub memory_op: u64,
+    /// Cost of a control-flow instruction (`block`, `loop`, `if`, `else`,
+    /// `br`, `br_if`, `br_table`, `return`)
+    pub control: u64,
+}
+
+impl InstructionCosts {
+    /// Conservative per-instruction costs for production chains.
+    #[must_use]
+    pub const fn standard() -> Self {
+        Self {
+            default: 1,
+            call: 50,
+            memory_op: 5,
+            control: 2,
+        }
+    }
+}
+
+impl Default for InstructionCosts {
+    fn default() -> Self {
+        Self::standard()
+    }
+}
+
+/// Resource limits applied to a WASM contract's module at load time, via
+/// [`super::wasm::instrument`] — HardClaw's equivalent of a runtime
+/// contract schedule, but enforced by instrumenting the bytecode itself
+/// rather than trapping in a host interpreter loop.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ContractSchedule {
+    /// Total gas a single execution may burn before the injected `gas`
+    /// trampoline traps
+    pub max_gas: u64,
+    /// Per-instruction costs charged by the injected `gas` calls
+    pub instruction_costs: InstructionCosts,
+    /// Maximum number of 64KiB memory pages the module's linear memory may
+    /// grow to, regardless of what the module itself declares
+    pub max_memory_pages: u32,
+    /// Maximum call depth before the injected stack-height limiter traps
+    pub max_stack_height: u32,
+    /// Whether the module may import and call a host `debug_print`
+    /// function. Left off on mainnet; turned on for bootstrap/dev chains
+    /// so contract authors can debug without a full tracing setup.
+    pub enable_debug_print: bool,
+}
+
+impl ContractSchedule {
+    /// Conservative limits for production chains: a bounded gas budget,
+    /// 16 pages (1MiB) of memory, and a shallow call stack.
+    #[must_use]
+    pub const fn mainnet() -> Self {
+        Self {
+            max_gas: 10_000_000,
+            instruction_costs: InstructionCosts::standard(),
+            max_memory_pages: 16,
+            max_stack_height: 256,
+            enable_debug_print: false,
+        }
+    }
+
+    /// Relaxed limits for bootstrap/dev chains: a much larger gas budget
+    /// and memory ceiling, plus `debug_print` enabled so contract authors
+    /// can iterate without mainnet's constraints.
+    #[must_use]
+    pub const fn bootstrap() -> Self {
+        Self {
+            max_gas: 1_000_000_000,
+            instruction_costs: InstructionCosts::standard(),
+            max_memory_pages: 256,
+            max_stack_height: 1024,
+            enable_debug_print: true,
+        }
+    }
+}
+
+impl Default for ContractSchedule {
+    fn default() -> Self {
+        Self::mainnet()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn storage_write_cost_scales_with_value_len() {
+        let schedule = Schedule::standard();
+        assert_eq!(schedule.storage_write_cost(0), schedule.storage_write);
+        assert!(schedule.storage_write_cost(100) > schedule.storage_write_cost(10));
+    }
+
+    #[test]
+    fn bootstrap_schedule_is_more_permissive_than_mainnet() {
+        let mainnet = ContractSchedule::mainnet();
+        let bootstrap = ContractSchedule::bootstrap();
+        assert!(bootstrap.max_gas > mainnet.max_gas);
+        assert!(bootstrap.max_memory_pages > mainnet.max_memory_pages);
+        assert!(bootstrap.max_stack_height > mainnet.max_stack_height);
+        assert!(!mainnet.enable_debug_print);
+        assert!(bootstrap.enable_debug_print);
+    }
+}
@@ -15,8 +15,8 @@ use std::collections::HashMap;
 use crate::contracts::state::ContractState;
 use crate::contracts::transaction::ContractTransaction;
 use crate::contracts::{Contract, ContractError, ContractEvent, ContractResult, ExecutionResult};
-use crate::crypto::Hash;
-use crate::types::{Address, GovernanceAction};
+use crate::crypto::{hash_data, Hash};
+use crate::types::{Address, GovernanceAction, HclawAmount, SpendCondition};
 
 /// Governance contract ID (deterministic hash of contract name)
 pub const GOVERNANCE_CONTRACT_ID: Hash = Hash::from_bytes([
@@ -36,7 +36,137 @@ pub const APPROVAL_THRESHOLD_PERCENT: u8 = 66;
 // Storage keys
 const KEY_TOTAL_VOTING_POWER: &[u8] = b"gov:total_voting_power";
 const KEY_PROPOSAL_INDEX: &[u8] = b"gov:proposal_index";
+const KEY_VOTING_CONFIG: &[u8] = b"gov:voting_config";
+const KEY_FUNDING_INDEX: &[u8] = b"gov:funding_index";
+const KEY_SPEND_INDEX: &[u8] = b"gov:spend_index";
 const PROPOSAL_PREFIX: &[u8] = b"gov:proposal:";
+const FUNDING_PREFIX: &[u8] = b"gov:funding:";
+const SPEND_PREFIX: &[u8] = b"gov:spend:";
+const DELEGATE_PREFIX: &[u8] = b"gov:delegate:";
+const DELEGATORS_PREFIX: &[u8] = b"gov:delegators:";
+const KEY_CHAIRPERSON: &[u8] = b"gov:chairperson";
+const REGISTERED_VOTER_PREFIX: &[u8] = b"gov:registered_voter:";
+const KEY_EVENT_LOG_LEN: &[u8] = b"gov:event_log_len";
+const EVENT_LOG_PREFIX: &[u8] = b"gov:event_log:";
+const KEY_TOTAL_PROPOSALS_CREATED: &[u8] = b"gov:tally:proposals_created";
+const KEY_TOTAL_VOTES_CAST: &[u8] = b"gov:tally:votes_cast";
+const KEY_TOTAL_WEIGHT_CAST: &[u8] = b"gov:tally:weight_cast";
+const KEY_LEADING_PROPOSAL: &[u8] = b"gov:tally:leading_proposal";
+
+/// A standing public-goods-funding payment stream, created by a
+/// [`GovernanceAction::ContinuousFunding`] and paid out epoch-by-epoch via
+/// [`GovernanceTransactionKind::DisburseFunding`], rather than as a single
+/// one-shot [`GovernanceAction::TreasurySpend`].
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct FundingStream {
+    /// Stream ID
+    pub id: Hash,
+    /// Recipient of each epoch's disbursement
+    pub recipient: Address,
+    /// Amount paid out per epoch the stream is active
+    pub amount_per_epoch: HclawAmount,
+    /// First epoch (inclusive) the stream pays out for
+    pub start: u64,
+    /// Last epoch (inclusive) the stream pays out for
+    pub end: u64,
+    /// Epoch this stream was most recently disbursed for, if any — guards
+    /// against paying the same epoch twice if `DisburseFunding` is called
+    /// more than once for it
+    pub last_disbursed_epoch: Option<u64>,
+    /// Whether the stream is still active; set to `false` by
+    /// `GovernanceAction::StopFunding` to cancel it early
+    pub active: bool,
+}
+
+/// A treasury spend escrowed by [`GovernanceAction::ConditionalSpend`],
+/// modeled on the Solana budget program's `PaymentPlan`/`Witness` design:
+/// funds leave the treasury as soon as the spend is approved but only reach
+/// `recipient` once every condition is witnessed satisfied.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ConditionalSpend {
+    /// Spend ID
+    pub id: Hash,
+    /// Recipient once all conditions are satisfied
+    pub recipient: Address,
+    /// Amount held in escrow
+    pub amount: HclawAmount,
+    /// Conditions gating release, all of which must be satisfied
+    pub conditions: Vec<SpendCondition>,
+    /// If the conditions aren't all satisfied by this time, the escrow can
+    /// be refunded back to the treasury instead of released
+    pub expires_at: Option<i64>,
+    /// Addresses that have witnessed a `Signature` condition on their own
+    /// behalf so far
+    pub witnessed_signers: Vec<Address>,
+    /// Whether the escrow has released to `recipient`
+    pub released: bool,
+    /// Whether the escrow has been refunded back to the treasury
+    pub cancelled: bool,
+}
+
+impl ConditionalSpend {
+    fn condition_satisfied(&self, condition: &SpendCondition, now: i64) -> bool {
+        match condition {
+            SpendCondition::AfterTimestamp(ts) => now >= *ts,
+            SpendCondition::Signature(addr) => self.witnessed_signers.contains(addr),
+            SpendCondition::Or(a, b) => {
+                self.condition_satisfied(a, now) || self.condition_satisfied(b, now)
+            }
+            SpendCondition::And(a, b) => {
+                self.condition_satisfied(a, now) && self.condition_satisfied(b, now)
+            }
+        }
+    }
+
+    /// Whether every condition is currently satisfied
+    fn all_satisfied(&self, now: i64) -> bool {
+        self.conditions
+            .iter()
+            .all(|c| self.condition_satisfied(c, now))
+    }
+}
+
+/// A witness submitted via `ApplyWitness` toward satisfying one of a
+/// [`ConditionalSpend`]'s conditions
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub enum Witness {
+    /// Attests to a `Signature` condition — the witnessing transaction's
+    /// sender must match `signer` for it to count
+    Signed {
+        /// The address the condition names, expected to equal the
+        /// transaction's sender
+        signer: Address,
+    },
+}
+
+/// Voting rules governing a proposal. Loaded from storage at proposal
+/// creation time and snapshotted onto the [`Proposal`] itself, so a later
+/// [`crate::types::GovernanceAction::ConfigUpdate`] only affects proposals
+/// created afterward — an in-flight proposal keeps the rules voters agreed
+/// to vote under.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct VotingConfig {
+    /// Minimum voting period a proposal must allow (milliseconds)
+    pub min_voting_period: i64,
+    /// Quorum requirement (percent of total voting power)
+    pub quorum_percent: u8,
+    /// Approval threshold (percent of directional votes cast)
+    pub approval_threshold: u8,
+    /// Delay between a proposal passing and becoming eligible to execute
+    /// (milliseconds)
+    pub execution_delay: i64,
+}
+
+impl Default for VotingConfig {
+    fn default() -> Self {
+        Self {
+            min_voting_period: MIN_VOTING_PERIOD,
+            quorum_percent: QUORUM_PERCENT,
+            approval_threshold: APPROVAL_THRESHOLD_PERCENT,
+            execution_delay: 0,
+        }
+    }
+}
 
 /// Actions the governance contract can perform
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -51,21 +181,117 @@ pub enum GovernanceTransactionKind {
         actions: Vec<GovernanceAction>,
         /// Voting period end time
         voting_ends_at: i64,
+        /// When set, the proposal uses commit-reveal voting instead of
+        /// plaintext tallying: votes are cast via `CommitVote` during
+        /// `voting_ends_at` and only tallied once revealed via
+        /// `RevealVote`, which must happen by this timestamp.
+        reveal_ends_at: Option<i64>,
+        /// When set, `CastVote` is rejected until the chain's current block
+        /// height (see [`crate::contracts::state::BlockContext`]) reaches
+        /// this height, even though `voting_ends_at` has not yet passed.
+        /// Ignored when no block context is attached to the executing
+        /// state, since there's no height to compare against.
+        start_block: Option<u64>,
+        /// When set, `CastVote` is rejected once the chain's current block
+        /// height passes this height, ahead of `voting_ends_at`. Like
+        /// `start_block`, ignored without an attached block context.
+        end_block: Option<u64>,
+    },
+    /// Delegate the caller's voting power to `delegate`, or revoke a prior
+    /// delegation by passing `None`. A delegate casts one aggregated `Vote`
+    /// covering itself plus everyone currently delegating to it, transitively
+    /// following the delegation chain (a delegates to b, b delegates to c —
+    /// c's vote covers both); an address that has delegated away its power
+    /// cannot vote directly until it revokes. Rejected if `delegate`'s own
+    /// chain already leads back to the caller, since that would create a
+    /// cycle with no member able to vote.
+    SetDelegate {
+        /// Address to delegate to, or `None` to revoke
+        delegate: Option<Address>,
+    },
+    /// Register `account` with `weight` in the chairperson-curated voter
+    /// set, callable only by the chairperson recorded at deploy time. Only
+    /// registered accounts may `CastVote`.
+    RegisterVoter {
+        /// Account being granted a voting weight
+        account: Address,
+        /// Weight `account` contributes when it casts a vote
+        weight: u128,
+    },
+    /// Remove `account` from the chairperson-curated voter set, callable
+    /// only by the chairperson
+    RevokeVoter {
+        /// Account being deregistered
+        account: Address,
+    },
+    /// Cast a vote using the weight assigned by `RegisterVoter`, rather than
+    /// a caller-supplied amount — rejected for accounts not in the
+    /// chairperson-curated voter set. A separate closed-membership
+    /// complement to `Vote`'s open, caller-attested voting power.
+    CastVote {
+        /// Proposal ID
+        proposal_id: Hash,
+        /// How the voter is voting
+        choice: VoteChoice,
     },
-    /// Cast a vote on a proposal
+    /// Cast a vote on a proposal directly — rejected for a proposal using
+    /// commit-reveal (see `reveal_ends_at`); use `CommitVote`/`RevealVote`
+    /// instead
     Vote {
         /// Proposal ID
         proposal_id: Hash,
-        /// Vote (true = yes, false = no)
-        in_favor: bool,
+        /// How the voter is voting
+        choice: VoteChoice,
+        /// Voting power (based on stake)
+        voting_power: u128,
+    },
+    /// Commit to a vote on a commit-reveal proposal without disclosing it.
+    /// `commitment` must equal `hash_data(choice_byte || voting_power_le
+    /// || salt)` for the `RevealVote` that later opens it.
+    CommitVote {
+        /// Proposal ID
+        proposal_id: Hash,
+        /// Commitment to the voter's choice and voting power
+        commitment: Hash,
+    },
+    /// Open a prior `CommitVote` commitment and tally it
+    RevealVote {
+        /// Proposal ID
+        proposal_id: Hash,
+        /// How the voter voted
+        choice: VoteChoice,
         /// Voting power (based on stake)
         voting_power: u128,
+        /// Salt used when computing the original commitment
+        salt: Vec<u8>,
     },
     /// Execute an approved proposal
     Execute {
         /// Proposal ID
         proposal_id: Hash,
     },
+    /// Pay out this epoch's disbursement for every active continuous
+    /// funding stream whose window covers it. Callable by anyone, each
+    /// epoch — a stream can only be paid once per epoch regardless of how
+    /// many times this is called.
+    DisburseFunding {
+        /// Epoch to disburse funding for
+        now_epoch: u64,
+    },
+    /// Witness one of a `ConditionalSpend` escrow's conditions; releases
+    /// the escrow to its recipient once every condition is satisfied
+    ApplyWitness {
+        /// Escrow to witness
+        spend_id: Hash,
+        /// The condition being attested
+        witness: Witness,
+    },
+    /// Refund an unsatisfied `ConditionalSpend` escrow back to the treasury
+    /// once its expiry has lapsed
+    CancelConditionalSpend {
+        /// Escrow to cancel
+        spend_id: Hash,
+    },
     /// Update total voting power (called when stakes change)
     UpdateVotingPower {
         /// New total voting power
@@ -73,15 +299,47 @@ pub enum GovernanceTransactionKind {
     },
 }
 
+/// How a voter is voting on a proposal. Following the Soroban DAO pattern,
+/// `Abstain` registers attendance toward quorum without pushing the
+/// approval percentage in either direction.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub enum VoteChoice {
+    /// In favor of the proposal
+    Yes,
+    /// Against the proposal
+    No,
+    /// Counts toward quorum, not toward approval
+    Abstain,
+}
+
+impl VoteChoice {
+    /// Stable byte encoding used inside a commit-reveal commitment hash.
+    fn as_byte(self) -> u8 {
+        match self {
+            Self::Yes => 0,
+            Self::No => 1,
+            Self::Abstain => 2,
+        }
+    }
+}
+
 /// Proposal status
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub enum ProposalStatus {
+    /// Has a `start_block` that the chain hasn't reached yet, so voting has
+    /// not opened — distinct from `Active`, which accepts votes now
+    Pending,
     /// Currently accepting votes
     Active,
     /// Voting period ended, awaiting execution
     Passed,
-    /// Voting period ended, did not pass
+    /// Voting period ended, did not pass the approval threshold (quorum was
+    /// met)
     Rejected,
+    /// Voting period ended without `yes_votes + no_votes + abstain_votes`
+    /// reaching quorum against total voting power — distinct from
+    /// `Rejected`, which covers quorum being met but the threshold not
+    QuorumNotMet,
     /// Successfully executed
     Executed,
     /// Failed to execute
@@ -105,16 +363,59 @@ pub struct Proposal {
     pub created_at: i64,
     /// Voting ends at
     pub voting_ends_at: i64,
+    /// When set, this proposal uses commit-reveal voting: votes tallied in
+    /// `voters` only land there once revealed, and must be revealed by
+    /// this timestamp
+    pub reveal_ends_at: Option<i64>,
+    /// When set, `CastVote` rejects votes cast before the chain reaches this
+    /// block height, in addition to the `voting_ends_at` timestamp window.
+    /// Only enforced when the executing state has a block context attached
+    /// (see [`crate::contracts::state::BlockContext`]); `None` by default so
+    /// existing timestamp-only proposals are unaffected.
+    pub start_block: Option<u64>,
+    /// When set, `CastVote` rejects votes cast after the chain passes this
+    /// block height, ahead of `voting_ends_at`. Same block-context caveat as
+    /// `start_block`.
+    pub end_block: Option<u64>,
+    /// Voting rules this proposal was created under, snapshotted from the
+    /// contract's [`VotingConfig`] at creation time
+    pub voting_config: VotingConfig,
     /// Votes in favor (voting power)
     pub yes_votes: u128,
     /// Votes against (voting power)
     pub no_votes: u128,
-    /// Voters (to prevent double voting)
-    pub voters: HashMap<Address, bool>,
+    /// Abstentions (voting power) — count toward quorum, not approval
+    pub abstain_votes: u128,
+    /// Voters who have been tallied (to prevent double voting/revealing)
+    pub voters: HashMap<Address, VoteChoice>,
+    /// Commit-reveal commitments awaiting reveal, keyed by voter
+    pub commitments: HashMap<Address, Hash>,
     /// Current status
     pub status: ProposalStatus,
 }
 
+/// Aggregate governance activity over a window of proposal creation times,
+/// returned by [`GovernanceContract::tally_stats`].
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TallyStats {
+    /// Proposals created within the window
+    pub proposals_created: u64,
+    /// Individual vote-casting actions tallied within the window — a
+    /// delegate's aggregated `Vote` counts once per delegator it represents,
+    /// same as each entry it adds to `Proposal::voters`
+    pub votes_cast: u64,
+    /// Voting power cast within the window as a percentage of total voting
+    /// power, averaged across `proposals_created` (0-100, saturating) — the
+    /// same quorum-style ratio `effective_status` uses per proposal, applied
+    /// in aggregate since voting power rather than raw voter count is what
+    /// this contract measures participation in everywhere else
+    pub participation_percent: u8,
+    /// Proposal with the highest `yes_votes` among those that closed (left
+    /// `Active`/`Pending`) within the window — `None` if none closed in the
+    /// window, or if two or more are tied for the highest
+    pub winning_proposal: Option<Hash>,
+}
+
 /// Governance contract — fully storage-backed, no in-memory state.
 #[derive(Clone)]
 pub struct GovernanceContract {
@@ -131,6 +432,24 @@ impl GovernanceContract {
         }
     }
 
+    /// Apply the genesis `UpdateVotingPower` effect, setting the total
+    /// voting power to `initial_power` at deploy time.
+    ///
+    /// `on_deploy` always zeroes total voting power, since storage starts
+    /// empty; callers wiring up genesis from a `GenesisDeploySpec` use this
+    /// immediately afterward to seed the real starting value.
+    pub fn set_initial_voting_power(&self, state: &mut ContractState<'_>, initial_power: u128) {
+        self.save_total_voting_power(state, initial_power);
+    }
+
+    /// Read the current total voting power.
+    ///
+    /// # Errors
+    /// Returns error if the backend fails to read the stored value
+    pub fn total_voting_power(&self, state: &ContractState<'_>) -> ContractResult<u128> {
+        self.load_total_voting_power(state)
+    }
+
     /// The contract address used for storage keys
     fn contract_address(&self) -> Address {
         // Derive a stable address from the contract ID
@@ -148,10 +467,17 @@ impl GovernanceContract {
         key
     }
 
-    fn load_proposal(&self, state: &ContractState<'_>, proposal_id: &Hash) -> Option<Proposal> {
+    fn load_proposal(
+        &self,
+        state: &ContractState<'_>,
+        proposal_id: &Hash,
+    ) -> ContractResult<Option<Proposal>> {
         let key = Self::proposal_key(proposal_id);
-        let data = state.storage_read(&self.contract_address(), &key)?;
-        bincode::deserialize(&data).ok()
+        let data = match state.storage_read(&self.contract_address(), &key)? {
+            Some(data) => data,
+            None => return Ok(None),
+        };
+        Ok(bincode::deserialize(&data).ok())
     }
 
     fn save_proposal(&self, state: &mut ContractState<'_>, proposal: &Proposal) {
@@ -160,11 +486,11 @@ impl GovernanceContract {
         state.storage_write(self.contract_address(), key, data);
     }
 
-    fn load_total_voting_power(&self, state: &ContractState<'_>) -> u128 {
-        state
-            .storage_read(&self.contract_address(), KEY_TOTAL_VOTING_POWER)
+    fn load_total_voting_power(&self, state: &ContractState<'_>) -> ContractResult<u128> {
+        Ok(state
+            .storage_read(&self.contract_address(), KEY_TOTAL_VOTING_POWER)?
             .and_then(|d| bincode::deserialize(&d).ok())
-            .unwrap_or(0)
+            .unwrap_or(0))
     }
 
     fn save_total_voting_power(&self, state: &mut ContractState<'_>, power: u128) {
@@ -176,605 +502,4276 @@ impl GovernanceContract {
         );
     }
 
-    fn load_proposal_index(&self, state: &ContractState<'_>) -> Vec<Hash> {
-        state
-            .storage_read(&self.contract_address(), KEY_PROPOSAL_INDEX)
+    fn load_voting_config(&self, state: &ContractState<'_>) -> ContractResult<VotingConfig> {
+        Ok(state
+            .storage_read(&self.contract_address(), KEY_VOTING_CONFIG)?
             .and_then(|d| bincode::deserialize(&d).ok())
-            .unwrap_or_default()
+            .unwrap_or_default())
     }
 
-    fn save_proposal_index(&self, state: &mut ContractState<'_>, index: &[Hash]) {
-        let data = bincode::serialize(index).expect("proposal index serialization");
-        state.storage_write(self.contract_address(), KEY_PROPOSAL_INDEX.to_vec(), data);
+    fn save_voting_config(&self, state: &mut ContractState<'_>, config: &VotingConfig) {
+        let data = bincode::serialize(config).expect("voting config serialization");
+        state.storage_write(self.contract_address(), KEY_VOTING_CONFIG.to_vec(), data);
     }
 
-    /// Parse action from transaction input
-    fn parse_action(input: &[u8]) -> ContractResult<GovernanceTransactionKind> {
-        bincode::deserialize(input).map_err(|e| {
-            ContractError::InvalidTransaction(format!("Failed to parse action: {}", e))
-        })
+    fn funding_key(stream_id: &Hash) -> Vec<u8> {
+        let mut key = FUNDING_PREFIX.to_vec();
+        key.extend_from_slice(stream_id.as_bytes());
+        key
     }
 
-    /// Create a new proposal
-    fn execute_create_proposal(
+    fn load_funding_stream(
         &self,
-        state: &mut ContractState<'_>,
-        proposer: Address,
-        title: String,
-        description: String,
-        actions: Vec<GovernanceAction>,
-        voting_ends_at: i64,
-    ) -> ContractResult<Hash> {
-        // Validate voting period
-        let now = crate::types::now_millis();
-        if voting_ends_at <= now {
-            return Err(ContractError::ExecutionFailed(
-                "Voting end time must be in the future".to_string(),
-            ));
-        }
+        state: &ContractState<'_>,
+        stream_id: &Hash,
+    ) -> ContractResult<Option<FundingStream>> {
+        let key = Self::funding_key(stream_id);
+        let data = match state.storage_read(&self.contract_address(), &key)? {
+            Some(data) => data,
+            None => return Ok(None),
+        };
+        Ok(bincode::deserialize(&data).ok())
+    }
 
-        if voting_ends_at - now < MIN_VOTING_PERIOD {
-            return Err(ContractError::ExecutionFailed(format!(
-                "Voting period must be at least {} days",
-                MIN_VOTING_PERIOD / (24 * 60 * 60 * 1000)
-            )));
-        }
+    fn save_funding_stream(&self, state: &mut ContractState<'_>, stream: &FundingStream) {
+        let key = Self::funding_key(&stream.id);
+        let data = bincode::serialize(stream).expect("funding stream serialization");
+        state.storage_write(self.contract_address(), key, data);
+    }
 
-        // Generate proposal ID
-        let proposal_id = crate::crypto::hash_data(
-            &bincode::serialize(&(&proposer, &title, &description, &actions, now)).unwrap(),
-        );
+    fn load_funding_index(&self, state: &ContractState<'_>) -> ContractResult<Vec<Hash>> {
+        Ok(state
+            .storage_read(&self.contract_address(), KEY_FUNDING_INDEX)?
+            .and_then(|d| bincode::deserialize(&d).ok())
+            .unwrap_or_default())
+    }
 
-        // Create proposal
-        let proposal = Proposal {
-            id: proposal_id,
-            proposer,
-            title: title.clone(),
-            description,
-            actions,
-            created_at: now,
-            voting_ends_at,
-            yes_votes: 0,
-            no_votes: 0,
-            voters: HashMap::new(),
-            status: ProposalStatus::Active,
+    fn save_funding_index(&self, state: &mut ContractState<'_>, index: &[Hash]) {
+        let data = bincode::serialize(index).expect("funding index serialization");
+        state.storage_write(self.contract_address(), KEY_FUNDING_INDEX.to_vec(), data);
+    }
+
+    fn spend_key(spend_id: &Hash) -> Vec<u8> {
+        let mut key = SPEND_PREFIX.to_vec();
+        key.extend_from_slice(spend_id.as_bytes());
+        key
+    }
+
+    fn load_conditional_spend(
+        &self,
+        state: &ContractState<'_>,
+        spend_id: &Hash,
+    ) -> ContractResult<Option<ConditionalSpend>> {
+        let key = Self::spend_key(spend_id);
+        let data = match state.storage_read(&self.contract_address(), &key)? {
+            Some(data) => data,
+            None => return Ok(None),
         };
+        Ok(bincode::deserialize(&data).ok())
+    }
 
-        // Save to storage
-        self.save_proposal(state, &proposal);
+    fn save_conditional_spend(&self, state: &mut ContractState<'_>, spend: &ConditionalSpend) {
+        let key = Self::spend_key(&spend.id);
+        let data = bincode::serialize(spend).expect("conditional spend serialization");
+        state.storage_write(self.contract_address(), key, data);
+    }
 
-        // Update proposal index
-        let mut index = self.load_proposal_index(state);
-        index.push(proposal_id);
-        self.save_proposal_index(state, &index);
+    fn load_spend_index(&self, state: &ContractState<'_>) -> ContractResult<Vec<Hash>> {
+        Ok(state
+            .storage_read(&self.contract_address(), KEY_SPEND_INDEX)?
+            .and_then(|d| bincode::deserialize(&d).ok())
+            .unwrap_or_default())
+    }
 
-        // Emit event
-        state.emit_event(ContractEvent {
-            contract_id: self.id,
-            topic: "ProposalCreated".to_string(),
-            data: bincode::serialize(&(proposal_id, title)).unwrap(),
-        });
+    fn save_spend_index(&self, state: &mut ContractState<'_>, index: &[Hash]) {
+        let data = bincode::serialize(index).expect("spend index serialization");
+        state.storage_write(self.contract_address(), KEY_SPEND_INDEX.to_vec(), data);
+    }
 
-        Ok(proposal_id)
+    fn delegate_key(delegator: &Address) -> Vec<u8> {
+        let mut key = DELEGATE_PREFIX.to_vec();
+        key.extend_from_slice(delegator.as_bytes());
+        key
     }
 
-    /// Cast a vote on a proposal
-    fn execute_vote(
+    /// Who `delegator` currently delegates its voting power to, if anyone
+    fn load_delegate(
+        &self,
+        state: &ContractState<'_>,
+        delegator: &Address,
+    ) -> ContractResult<Option<Address>> {
+        let key = Self::delegate_key(delegator);
+        Ok(state
+            .storage_read(&self.contract_address(), &key)?
+            .and_then(|d| bincode::deserialize(&d).ok()))
+    }
+
+    fn save_delegate(
         &self,
         state: &mut ContractState<'_>,
-        voter: Address,
-        proposal_id: Hash,
-        in_favor: bool,
-        voting_power: u128,
-    ) -> ContractResult<()> {
-        // Load proposal from storage
-        let mut proposal = self
-            .load_proposal(state, &proposal_id)
-            .ok_or_else(|| ContractError::ExecutionFailed("Proposal not found".to_string()))?;
+        delegator: &Address,
+        delegate: Option<Address>,
+    ) {
+        let key = Self::delegate_key(delegator);
+        let data = bincode::serialize(&delegate).expect("delegate serialization");
+        state.storage_write(self.contract_address(), key, data);
+    }
 
-        // Check proposal is active
-        if proposal.status != ProposalStatus::Active {
-            return Err(ContractError::ExecutionFailed(
-                "Proposal is not active".to_string(),
-            ));
-        }
+    fn delegators_key(delegate: &Address) -> Vec<u8> {
+        let mut key = DELEGATORS_PREFIX.to_vec();
+        key.extend_from_slice(delegate.as_bytes());
+        key
+    }
 
-        // Check voting period not ended
-        let now = crate::types::now_millis();
-        if now >= proposal.voting_ends_at {
-            return Err(ContractError::ExecutionFailed(
-                "Voting period has ended".to_string(),
-            ));
+    /// Addresses currently delegating their voting power to `delegate`
+    fn load_delegators(
+        &self,
+        state: &ContractState<'_>,
+        delegate: &Address,
+    ) -> ContractResult<Vec<Address>> {
+        let key = Self::delegators_key(delegate);
+        Ok(state
+            .storage_read(&self.contract_address(), &key)?
+            .and_then(|d| bincode::deserialize(&d).ok())
+            .unwrap_or_default())
+    }
+
+    fn save_delegators(
+        &self,
+        state: &mut ContractState<'_>,
+        delegate: &Address,
+        delegators: &[Address],
+    ) {
+        let key = Self::delegators_key(delegate);
+        if delegators.is_empty() {
+            state.storage_delete(self.contract_address(), key);
+            return;
         }
+        let data = bincode::serialize(delegators).expect("delegators serialization");
+        state.storage_write(self.contract_address(), key, data);
+    }
 
-        // Check not already voted
-        if proposal.voters.contains_key(&voter) {
-            return Err(ContractError::ExecutionFailed(
-                "Already voted on this proposal".to_string(),
-            ));
+    /// Whether following `start`'s delegate chain (`start`, `start`'s
+    /// delegate, that delegate's delegate, ...) ever reaches `target`. Used
+    /// to reject a new delegation before it's recorded, since accepting one
+    /// that would complete a cycle leaves every account on the cycle unable
+    /// to vote at all (each has delegated its power away, but the chain
+    /// never terminates at a delegate who votes directly).
+    fn delegate_chain_reaches(
+        &self,
+        state: &ContractState<'_>,
+        start: Address,
+        target: Address,
+    ) -> ContractResult<bool> {
+        let mut current = start;
+        let mut visited = std::collections::HashSet::new();
+        loop {
+            if current == target {
+                return Ok(true);
+            }
+            if !visited.insert(current) {
+                // Already-cyclic chain independent of `target` — shouldn't
+                // happen once this check guards every delegation, but don't
+                // loop forever if it somehow does.
+                return Ok(false);
+            }
+            match self.load_delegate(state, &current)? {
+                Some(next) => current = next,
+                None => return Ok(false),
+            }
         }
+    }
 
-        // Record vote
-        proposal.voters.insert(voter, in_favor);
-        if in_favor {
-            proposal.yes_votes += voting_power;
-        } else {
-            proposal.no_votes += voting_power;
+    /// Every account whose voting power currently flows to `root`, directly
+    /// or transitively through a chain of delegations, not including `root`
+    /// itself. Walks the reverse `gov:delegators:` index breadth-first with
+    /// a visited set, so a delegation cycle (which `delegate_chain_reaches`
+    /// should already prevent from being created) can't cause this to loop
+    /// forever either.
+    fn resolve_transitive_delegators(
+        &self,
+        state: &ContractState<'_>,
+        root: Address,
+    ) -> ContractResult<Vec<Address>> {
+        let mut resolved = Vec::new();
+        let mut visited = std::collections::HashSet::new();
+        visited.insert(root);
+        let mut frontier = vec![root];
+        while let Some(delegate) = frontier.pop() {
+            for delegator in self.load_delegators(state, &delegate)? {
+                if visited.insert(delegator) {
+                    resolved.push(delegator);
+                    frontier.push(delegator);
+                }
+            }
         }
+        Ok(resolved)
+    }
 
-        // Save updated proposal back to storage
-        self.save_proposal(state, &proposal);
+    fn load_chairperson(&self, state: &ContractState<'_>) -> ContractResult<Option<Address>> {
+        Ok(state
+            .storage_read(&self.contract_address(), KEY_CHAIRPERSON)?
+            .and_then(|d| bincode::deserialize(&d).ok()))
+    }
 
-        // Emit event
-        state.emit_event(ContractEvent {
-            contract_id: self.id,
-            topic: "VoteCast".to_string(),
-            data: bincode::serialize(&(proposal_id, voter, in_favor, voting_power)).unwrap(),
-        });
+    fn save_chairperson(&self, state: &mut ContractState<'_>, chairperson: Address) {
+        let data = bincode::serialize(&chairperson).expect("chairperson serialization");
+        state.storage_write(self.contract_address(), KEY_CHAIRPERSON.to_vec(), data);
+    }
 
-        Ok(())
+    fn registered_voter_key(account: &Address) -> Vec<u8> {
+        let mut key = REGISTERED_VOTER_PREFIX.to_vec();
+        key.extend_from_slice(account.as_bytes());
+        key
     }
 
-    /// Execute an approved proposal
-    fn execute_proposal(
+    fn load_registered_voter(
+        &self,
+        state: &ContractState<'_>,
+        account: &Address,
+    ) -> ContractResult<Option<u128>> {
+        let key = Self::registered_voter_key(account);
+        Ok(state
+            .storage_read(&self.contract_address(), &key)?
+            .and_then(|d| bincode::deserialize(&d).ok()))
+    }
+
+    fn save_registered_voter(
         &self,
         state: &mut ContractState<'_>,
-        proposal_id: Hash,
-    ) -> ContractResult<()> {
-        // Load proposal from storage
-        let mut proposal = self
-            .load_proposal(state, &proposal_id)
-            .ok_or_else(|| ContractError::ExecutionFailed("Proposal not found".to_string()))?;
+        account: &Address,
+        weight: u128,
+    ) {
+        let key = Self::registered_voter_key(account);
+        let data = bincode::serialize(&weight).expect("voter weight serialization");
+        state.storage_write(self.contract_address(), key, data);
+    }
 
-        // Check voting period ended
-        let now = crate::types::now_millis();
-        if now < proposal.voting_ends_at {
-            return Err(ContractError::ExecutionFailed(
-                "Voting period not yet ended".to_string(),
-            ));
-        }
+    fn revoke_registered_voter(&self, state: &mut ContractState<'_>, account: &Address) {
+        let key = Self::registered_voter_key(account);
+        state.storage_delete(self.contract_address(), key);
+    }
 
-        // Calculate quorum
-        let total_votes = proposal.yes_votes + proposal.no_votes;
-        let total_voting_power = self.load_total_voting_power(state);
-        let quorum = total_voting_power * u128::from(QUORUM_PERCENT) / 100;
+    fn event_log_key(index: u64) -> Vec<u8> {
+        let mut key = EVENT_LOG_PREFIX.to_vec();
+        key.extend_from_slice(&index.to_be_bytes());
+        key
+    }
 
-        if total_votes < quorum {
-            proposal.status = ProposalStatus::Rejected;
-            self.save_proposal(state, &proposal);
-            return Err(ContractError::ExecutionFailed(
-                "Quorum not reached".to_string(),
-            ));
-        }
+    fn load_event_log_len(&self, state: &ContractState<'_>) -> ContractResult<u64> {
+        Ok(state
+            .storage_read(&self.contract_address(), KEY_EVENT_LOG_LEN)?
+            .and_then(|d| bincode::deserialize(&d).ok())
+            .unwrap_or(0))
+    }
 
-        // Check approval threshold
-        let approval_percent = if total_votes > 0 {
-            proposal.yes_votes * 100 / total_votes
-        } else {
-            0
-        };
+    fn save_event_log_len(&self, state: &mut ContractState<'_>, len: u64) {
+        let data = bincode::serialize(&len).expect("event log length serialization");
+        state.storage_write(self.contract_address(), KEY_EVENT_LOG_LEN.to_vec(), data);
+    }
 
-        if approval_percent < u128::from(APPROVAL_THRESHOLD_PERCENT) {
-            proposal.status = ProposalStatus::Rejected;
-            self.save_proposal(state, &proposal);
-            return Err(ContractError::ExecutionFailed(
-                "Approval threshold not met".to_string(),
-            ));
-        }
+    /// Emit `event` for this execution (as every other contract does via
+    /// [`ContractState::emit_event`]) and additionally append a durable copy
+    /// to a storage-backed, append-only log, so off-chain indexers can
+    /// reconstruct governance history later via [`Self::read_events`]
+    /// instead of only seeing events from the `ExecutionResult` of the
+    /// transaction that produced them.
+    fn log_event(&self, state: &mut ContractState<'_>, event: ContractEvent) -> ContractResult<()> {
+        state.emit_event(event.clone());
 
-        // Mark as passed
-        proposal.status = ProposalStatus::Passed;
+        let index = self.load_event_log_len(state)?;
+        let key = Self::event_log_key(index);
+        let data = bincode::serialize(&event).expect("event serialization");
+        state.storage_write(self.contract_address(), key, data);
+        self.save_event_log_len(state, index + 1);
 
-        // Clone actions to execute
-        let actions_to_execute = proposal.actions.clone();
+        Ok(())
+    }
 
-        // Execute actions
-        for action in &actions_to_execute {
-            if let Err(e) = self.execute_governance_action(state, action) {
-                proposal.status = ProposalStatus::ExecutionFailed;
-                self.save_proposal(state, &proposal);
-                return Err(e);
+    /// Page through the durable event log in emission order, starting at
+    /// `from_index`, returning at most `limit` events.
+    ///
+    /// # Errors
+    /// Returns error if the backend fails to read the log length or an
+    /// entry in it
+    pub fn read_events(
+        &self,
+        state: &ContractState<'_>,
+        from_index: u64,
+        limit: usize,
+    ) -> ContractResult<Vec<ContractEvent>> {
+        let len = self.load_event_log_len(state)?;
+        let mut results = Vec::new();
+        let mut index = from_index;
+        while index < len && results.len() < limit {
+            let key = Self::event_log_key(index);
+            if let Some(data) = state.storage_read(&self.contract_address(), &key)? {
+                if let Ok(event) = bincode::deserialize::<ContractEvent>(&data) {
+                    results.push(event);
+                }
             }
+            index += 1;
         }
+        Ok(results)
+    }
 
-        // Mark as executed
-        proposal.status = ProposalStatus::Executed;
-        self.save_proposal(state, &proposal);
+    fn load_total_proposals_created(&self, state: &ContractState<'_>) -> ContractResult<u64> {
+        Ok(state
+            .storage_read(&self.contract_address(), KEY_TOTAL_PROPOSALS_CREATED)?
+            .and_then(|d| bincode::deserialize(&d).ok())
+            .unwrap_or(0))
+    }
 
-        // Emit event
-        state.emit_event(ContractEvent {
-            contract_id: self.id,
-            topic: "ProposalExecuted".to_string(),
-            data: bincode::serialize(&proposal_id).unwrap(),
-        });
+    fn save_total_proposals_created(&self, state: &mut ContractState<'_>, count: u64) {
+        let data = bincode::serialize(&count).expect("proposal counter serialization");
+        state.storage_write(
+            self.contract_address(),
+            KEY_TOTAL_PROPOSALS_CREATED.to_vec(),
+            data,
+        );
+    }
 
+    /// Bump the running "proposals created" counter backing the O(1) path of
+    /// [`Self::tally_stats`]
+    fn record_proposal_created(&self, state: &mut ContractState<'_>) -> ContractResult<()> {
+        let created = self.load_total_proposals_created(state)?;
+        self.save_total_proposals_created(state, created + 1);
         Ok(())
     }
 
-    /// Execute a governance action
-    fn execute_governance_action(
+    fn load_total_votes_cast(&self, state: &ContractState<'_>) -> ContractResult<u64> {
+        Ok(state
+            .storage_read(&self.contract_address(), KEY_TOTAL_VOTES_CAST)?
+            .and_then(|d| bincode::deserialize(&d).ok())
+            .unwrap_or(0))
+    }
+
+    fn save_total_votes_cast(&self, state: &mut ContractState<'_>, count: u64) {
+        let data = bincode::serialize(&count).expect("vote counter serialization");
+        state.storage_write(self.contract_address(), KEY_TOTAL_VOTES_CAST.to_vec(), data);
+    }
+
+    fn load_total_weight_cast(&self, state: &ContractState<'_>) -> ContractResult<u128> {
+        Ok(state
+            .storage_read(&self.contract_address(), KEY_TOTAL_WEIGHT_CAST)?
+            .and_then(|d| bincode::deserialize(&d).ok())
+            .unwrap_or(0))
+    }
+
+    fn save_total_weight_cast(&self, state: &mut ContractState<'_>, weight: u128) {
+        let data = bincode::serialize(&weight).expect("vote weight counter serialization");
+        state.storage_write(
+            self.contract_address(),
+            KEY_TOTAL_WEIGHT_CAST.to_vec(),
+            data,
+        );
+    }
+
+    /// Bump the running "votes cast" and "weight cast" counters backing the
+    /// O(1) path of [`Self::tally_stats`]. `count` is the number of voters
+    /// newly tallied (more than one when a delegate's vote also covers its
+    /// delegators) and `weight` is the voting power their vote(s) carried.
+    fn record_votes_cast(
         &self,
         state: &mut ContractState<'_>,
-        action: &GovernanceAction,
+        count: u64,
+        weight: u128,
     ) -> ContractResult<()> {
-        match action {
-            GovernanceAction::ParameterUpdate { key, value } => {
-                let param_key = format!("param:{}", key);
-                state.storage_write(
-                    Address::from_bytes([0; 20]),
-                    param_key.as_bytes().to_vec(),
-                    value.clone(),
-                );
-                Ok(())
-            }
-            GovernanceAction::ContractUpgrade {
-                contract_id,
-                new_code,
-                new_code_hash,
-            } => {
-                let upgrade_key = format!("upgrade:{}", hex::encode(contract_id.as_bytes()));
-                state.storage_write(
-                    Address::from_bytes([0; 20]),
-                    upgrade_key.as_bytes().to_vec(),
-                    new_code.clone(),
-                );
-                let hash_key = format!("upgrade_hash:{}", hex::encode(contract_id.as_bytes()));
-                state.storage_write(
-                    Address::from_bytes([0; 20]),
-                    hash_key.as_bytes().to_vec(),
-                    new_code_hash.as_bytes().to_vec(),
-                );
-                Ok(())
-            }
-            GovernanceAction::TreasurySpend {
-                recipient,
-                amount,
-                purpose,
-            } => {
-                let treasury = Address::from_bytes([0; 20]);
-                state.transfer(treasury, *recipient, *amount)?;
+        let votes = self.load_total_votes_cast(state)?;
+        self.save_total_votes_cast(state, votes + count);
+        let total_weight = self.load_total_weight_cast(state)?;
+        self.save_total_weight_cast(state, total_weight + weight);
+        Ok(())
+    }
 
-                let spend_key = format!("treasury_spend:{}", hex::encode(recipient.as_bytes()));
-                state.storage_write(
-                    treasury,
-                    spend_key.as_bytes().to_vec(),
-                    purpose.as_bytes().to_vec(),
-                );
-                Ok(())
-            }
-            GovernanceAction::EmergencyPause {
-                contract_id,
-                reason,
-            } => {
-                let pause_key = format!("paused:{}", hex::encode(contract_id.as_bytes()));
-                state.storage_write(
-                    Address::from_bytes([0; 20]),
-                    pause_key.as_bytes().to_vec(),
-                    reason.as_bytes().to_vec(),
-                );
-                Ok(())
+    fn load_leading_proposal(
+        &self,
+        state: &ContractState<'_>,
+    ) -> ContractResult<(Option<Hash>, u128, bool)> {
+        Ok(state
+            .storage_read(&self.contract_address(), KEY_LEADING_PROPOSAL)?
+            .and_then(|d| bincode::deserialize(&d).ok())
+            .unwrap_or((None, 0, false)))
+    }
+
+    fn save_leading_proposal(
+        &self,
+        state: &mut ContractState<'_>,
+        leader: (Option<Hash>, u128, bool),
+    ) {
+        let data = bincode::serialize(&leader).expect("leading proposal serialization");
+        state.storage_write(self.contract_address(), KEY_LEADING_PROPOSAL.to_vec(), data);
+    }
+
+    /// Fold one more closed proposal's `(id, yes_votes)` into a running
+    /// `(leader_id, leader_votes, tied)` accumulator: a strictly higher vote
+    /// count replaces the leader outright, an equal count marks the current
+    /// maximum as tied (so [`Self::tally_stats`] reports `None`) without
+    /// forgetting what that maximum is, and a lower count leaves the
+    /// accumulator untouched so it can't un-tie a maximum that a later,
+    /// lower-voted proposal doesn't actually affect.
+    fn fold_leader(
+        current: (Option<Hash>, u128, bool),
+        candidate_id: Hash,
+        candidate_votes: u128,
+    ) -> (Option<Hash>, u128, bool) {
+        let (best_id, best_votes, tied) = current;
+        if best_id.is_none() || candidate_votes > best_votes {
+            (Some(candidate_id), candidate_votes, false)
+        } else if candidate_votes == best_votes {
+            (best_id, best_votes, true)
+        } else {
+            (best_id, best_votes, tied)
+        }
+    }
+
+    /// Fold a just-closed proposal into the running leading-proposal
+    /// accumulator backing the O(1) path of [`Self::tally_stats`]
+    fn record_proposal_closed(
+        &self,
+        state: &mut ContractState<'_>,
+        proposal_id: Hash,
+        yes_votes: u128,
+    ) -> ContractResult<()> {
+        let current = self.load_leading_proposal(state)?;
+        let updated = Self::fold_leader(current, proposal_id, yes_votes);
+        self.save_leading_proposal(state, updated);
+        Ok(())
+    }
+
+    /// Shared participation-percentage math for both the O(1) and scan paths
+    /// of [`Self::tally_stats`]
+    fn participation_percent(
+        total_weight_cast: u128,
+        total_voting_power: u128,
+        proposal_count: u64,
+    ) -> u8 {
+        if total_voting_power == 0 || proposal_count == 0 {
+            return 0;
+        }
+        let denominator = total_voting_power * u128::from(proposal_count);
+        let percent = total_weight_cast.saturating_mul(100) / denominator;
+        percent.min(100) as u8
+    }
+
+    /// Walk the proposal index to compute aggregate governance activity
+    /// created within `[from_ts, to_ts]` (inclusive), keyed by
+    /// [`Proposal::created_at`].
+    ///
+    /// Named `from_ts`/`to_ts` rather than by block height: unlike voting
+    /// windows (see `Proposal::start_block`/`end_block`), most proposals
+    /// never set a block-height gate, so filtering by chain height would
+    /// silently exclude most history from an analytics query meant to
+    /// mirror off-chain ballot-stats tooling. `created_at` is the timestamp
+    /// every proposal carries, matching how `list_proposals` and
+    /// `effective_status` already key off timestamps by default.
+    ///
+    /// The common "current totals" query — `(i64::MIN, i64::MAX)` — is
+    /// answered in O(1) from counters maintained incrementally alongside
+    /// proposal creation, vote tallying, and proposal execution; any
+    /// narrower window falls back to a bounded scan over the proposal
+    /// index.
+    ///
+    /// # Errors
+    /// Returns error if the backend fails to read the proposal index or an
+    /// entry in it
+    pub fn tally_stats(
+        &self,
+        state: &ContractState<'_>,
+        from_ts: i64,
+        to_ts: i64,
+    ) -> ContractResult<TallyStats> {
+        let total_voting_power = self.load_total_voting_power(state)?;
+
+        if from_ts == i64::MIN && to_ts == i64::MAX {
+            let proposals_created = self.load_total_proposals_created(state)?;
+            let votes_cast = self.load_total_votes_cast(state)?;
+            let total_weight_cast = self.load_total_weight_cast(state)?;
+            let (winning_proposal, _, tied) = self.load_leading_proposal(state)?;
+            return Ok(TallyStats {
+                proposals_created,
+                votes_cast,
+                participation_percent: Self::participation_percent(
+                    total_weight_cast,
+                    total_voting_power,
+                    proposals_created,
+                ),
+                winning_proposal: if tied { None } else { winning_proposal },
+            });
+        }
+
+        let index = self.load_proposal_index(state)?;
+        let mut proposals_created: u64 = 0;
+        let mut votes_cast: u64 = 0;
+        let mut total_weight_cast: u128 = 0;
+        let mut leader: (Option<Hash>, u128, bool) = (None, 0, false);
+
+        for proposal_id in &index {
+            let Some(proposal) = self.load_proposal(state, proposal_id)? else {
+                continue;
+            };
+            if proposal.created_at < from_ts || proposal.created_at > to_ts {
+                continue;
             }
-            GovernanceAction::Resume { contract_id } => {
-                let pause_key = format!("paused:{}", hex::encode(contract_id.as_bytes()));
-                state.storage_write(
-                    Address::from_bytes([0; 20]),
-                    pause_key.as_bytes().to_vec(),
-                    Vec::new(),
-                );
-                Ok(())
+
+            proposals_created += 1;
+            votes_cast += proposal.voters.len() as u64;
+            total_weight_cast += proposal.yes_votes + proposal.no_votes + proposal.abstain_votes;
+
+            let effective = self.effective_status(state, &proposal)?;
+            if !matches!(effective, ProposalStatus::Active | ProposalStatus::Pending) {
+                leader = Self::fold_leader(leader, proposal.id, proposal.yes_votes);
             }
         }
+
+        Ok(TallyStats {
+            proposals_created,
+            votes_cast,
+            participation_percent: Self::participation_percent(
+                total_weight_cast,
+                total_voting_power,
+                proposals_created,
+            ),
+            winning_proposal: if leader.2 { None } else { leader.0 },
+        })
     }
-}
 
-impl Default for GovernanceContract {
-    fn default() -> Self {
-        Self::new()
+    fn load_proposal_index(&self, state: &ContractState<'_>) -> ContractResult<Vec<Hash>> {
+        Ok(state
+            .storage_read(&self.contract_address(), KEY_PROPOSAL_INDEX)?
+            .and_then(|d| bincode::deserialize(&d).ok())
+            .unwrap_or_default())
     }
-}
 
-impl Contract for GovernanceContract {
-    fn id(&self) -> Hash {
-        self.id
+    fn save_proposal_index(&self, state: &mut ContractState<'_>, index: &[Hash]) {
+        let data = bincode::serialize(index).expect("proposal index serialization");
+        state.storage_write(self.contract_address(), KEY_PROPOSAL_INDEX.to_vec(), data);
     }
 
-    fn name(&self) -> &str {
-        "GovernanceContract"
+    /// A proposal's status as stored can go stale: an `Active` proposal whose
+    /// voting (and, for commit-reveal, reveal) period has already elapsed
+    /// hasn't actually been tallied against quorum/threshold until someone
+    /// submits `Execute`. This recomputes what that tally would say right
+    /// now, without mutating storage, so read paths can surface the outcome
+    /// ahead of execution.
+    fn effective_status(
+        &self,
+        state: &ContractState<'_>,
+        proposal: &Proposal,
+    ) -> ContractResult<ProposalStatus> {
+        if proposal.status != ProposalStatus::Active {
+            return Ok(proposal.status.clone());
+        }
+
+        if let (Some(start), Some(ctx)) = (proposal.start_block, state.block_context()) {
+            if ctx.height < start {
+                return Ok(ProposalStatus::Pending);
+            }
+        }
+
+        let now = crate::types::now_millis();
+        let voting_concluded_at = proposal.reveal_ends_at.unwrap_or(proposal.voting_ends_at);
+        let block_window_open = match (proposal.end_block, state.block_context()) {
+            (Some(end), Some(ctx)) => ctx.height <= end,
+            _ => true,
+        };
+        if now < voting_concluded_at && block_window_open {
+            return Ok(ProposalStatus::Active);
+        }
+
+        let directional_votes = proposal.yes_votes + proposal.no_votes;
+        let total_votes = directional_votes + proposal.abstain_votes;
+        let total_voting_power = self.load_total_voting_power(state)?;
+        let quorum = total_voting_power * u128::from(proposal.voting_config.quorum_percent) / 100;
+        if total_votes < quorum {
+            return Ok(ProposalStatus::QuorumNotMet);
+        }
+
+        let approval_percent = if directional_votes > 0 {
+            proposal.yes_votes * 100 / directional_votes
+        } else {
+            0
+        };
+        if approval_percent < u128::from(proposal.voting_config.approval_threshold) {
+            Ok(ProposalStatus::Rejected)
+        } else {
+            Ok(ProposalStatus::Passed)
+        }
     }
 
-    fn version(&self) -> u32 {
-        1
+    /// Look up a proposal's up-to-the-moment status without requiring an
+    /// `Execute` call first — `Pending` while gated by an unreached
+    /// `start_block`, `Active` while still accepting votes, and otherwise
+    /// whichever of `QuorumNotMet`/`Rejected`/`Passed` (or a terminal
+    /// `Executed`/`ExecutionFailed`) the stored tally resolves to. Thin
+    /// wrapper around [`Self::effective_status`] for callers that only have
+    /// a `proposal_id` on hand.
+    ///
+    /// # Errors
+    /// Returns error if the backend fails to read the proposal, or if no
+    /// proposal exists with this ID
+    pub fn proposal_status(
+        &self,
+        state: &ContractState<'_>,
+        proposal_id: Hash,
+    ) -> ContractResult<ProposalStatus> {
+        let proposal = self
+            .load_proposal(state, &proposal_id)?
+            .ok_or_else(|| ContractError::ExecutionFailed("Proposal not found".to_string()))?;
+        self.effective_status(state, &proposal)
     }
 
-    fn execute(
+    /// Page through the proposal index in creation order, starting just
+    /// after `start_after` (exclusive) when given, returning at most `limit`
+    /// proposals with their status lazily brought up to date via
+    /// [`Self::effective_status`].
+    ///
+    /// # Errors
+    /// Returns error if the backend fails to read the proposal index or an
+    /// entry in it
+    pub fn list_proposals(
         &self,
-        state: &mut ContractState<'_>,
-        tx: &ContractTransaction,
-    ) -> ContractResult<ExecutionResult> {
-        let action = Self::parse_action(&tx.input)?;
+        state: &ContractState<'_>,
+        start_after: Option<Hash>,
+        limit: usize,
+    ) -> ContractResult<Vec<Proposal>> {
+        let index = self.load_proposal_index(state)?;
+        let start_pos = Self::index_start_pos(&index, start_after);
 
-        match action {
-            GovernanceTransactionKind::CreateProposal {
-                title,
-                description,
-                actions,
-                voting_ends_at,
-            } => {
-                self.execute_create_proposal(
-                    state,
-                    tx.sender_address,
-                    title,
-                    description,
-                    actions,
-                    voting_ends_at,
-                )?;
-            }
-            GovernanceTransactionKind::Vote {
-                proposal_id,
-                in_favor,
-                voting_power,
-            } => {
-                self.execute_vote(
-                    state,
-                    tx.sender_address,
-                    proposal_id,
-                    in_favor,
-                    voting_power,
-                )?;
+        let mut results = Vec::new();
+        for proposal_id in index.into_iter().skip(start_pos) {
+            if results.len() >= limit {
+                break;
             }
-            GovernanceTransactionKind::Execute { proposal_id } => {
-                self.execute_proposal(state, proposal_id)?;
+            let Some(mut proposal) = self.load_proposal(state, &proposal_id)? else {
+                continue;
+            };
+            proposal.status = self.effective_status(state, &proposal)?;
+            results.push(proposal);
+        }
+        Ok(results)
+    }
+
+    /// Like [`Self::list_proposals`], but only returns proposals whose
+    /// effective status matches `status` — e.g. listing every proposal
+    /// currently `Active` or `Passed` without an indexer having to scan and
+    /// deserialize the whole index itself.
+    ///
+    /// # Errors
+    /// Returns error if the backend fails to read the proposal index or an
+    /// entry in it
+    pub fn list_proposals_by_status(
+        &self,
+        state: &ContractState<'_>,
+        status: ProposalStatus,
+        start_after: Option<Hash>,
+        limit: usize,
+    ) -> ContractResult<Vec<Proposal>> {
+        let index = self.load_proposal_index(state)?;
+        let start_pos = Self::index_start_pos(&index, start_after);
+
+        let mut results = Vec::new();
+        for proposal_id in index.into_iter().skip(start_pos) {
+            if results.len() >= limit {
+                break;
             }
-            GovernanceTransactionKind::UpdateVotingPower { total_power } => {
-                self.save_total_voting_power(state, total_power);
+            let Some(mut proposal) = self.load_proposal(state, &proposal_id)? else {
+                continue;
+            };
+            let effective = self.effective_status(state, &proposal)?;
+            if effective == status {
+                proposal.status = effective;
+                results.push(proposal);
             }
         }
+        Ok(results)
+    }
 
-        Ok(ExecutionResult {
-            new_state_root: state.compute_state_root(),
-            gas_used: 150_000,
-            events: state.events().to_vec(),
-            output: Vec::new(),
+    /// Position to resume pagination from: just after `start_after` if it's
+    /// present in `index`, or the beginning otherwise (including when
+    /// `start_after` is `None`).
+    fn index_start_pos(index: &[Hash], start_after: Option<Hash>) -> usize {
+        match start_after {
+            Some(id) => index
+                .iter()
+                .position(|candidate| *candidate == id)
+                .map_or(0, |pos| pos + 1),
+            None => 0,
+        }
+    }
+
+    /// Parse action from transaction input
+    fn parse_action(input: &[u8]) -> ContractResult<GovernanceTransactionKind> {
+        bincode::deserialize(input).map_err(|e| {
+            ContractError::InvalidTransaction(format!("Failed to parse action: {}", e))
         })
     }
 
-    fn verify(
+    /// Create a new proposal
+    fn execute_create_proposal(
         &self,
-        state: &ContractState<'_>,
-        _tx: &ContractTransaction,
-        result: &ExecutionResult,
-    ) -> ContractResult<bool> {
-        let computed_root = state.compute_state_root();
-        Ok(computed_root == result.new_state_root)
-    }
+        state: &mut ContractState<'_>,
+        proposer: Address,
+        title: String,
+        description: String,
+        actions: Vec<GovernanceAction>,
+        voting_ends_at: i64,
+        reveal_ends_at: Option<i64>,
+        start_block: Option<u64>,
+        end_block: Option<u64>,
+    ) -> ContractResult<Hash> {
+        // Validate voting period against the voting rules currently in
+        // effect; these get snapshotted onto the proposal below so later
+        // ConfigUpdates don't retroactively change it.
+        let config = self.load_voting_config(state)?;
+        let now = crate::types::now_millis();
+        if voting_ends_at <= now {
+            return Err(ContractError::ExecutionFailed(
+                "Voting end time must be in the future".to_string(),
+            ));
+        }
+
+        if voting_ends_at - now < config.min_voting_period {
+            return Err(ContractError::ExecutionFailed(format!(
+                "Voting period must be at least {} days",
+                config.min_voting_period / (24 * 60 * 60 * 1000)
+            )));
+        }
+
+        if let Some(reveal_ends_at) = reveal_ends_at {
+            if reveal_ends_at <= voting_ends_at {
+                return Err(ContractError::ExecutionFailed(
+                    "Reveal end time must be after the commit period ends".to_string(),
+                ));
+            }
+        }
+
+        if let (Some(start), Some(end)) = (start_block, end_block) {
+            if end < start {
+                return Err(ContractError::ExecutionFailed(
+                    "Proposal end_block must not precede its start_block".to_string(),
+                ));
+            }
+        }
+
+        // Generate proposal ID
+        let proposal_id = crate::crypto::hash_data(
+            &bincode::serialize(&(&proposer, &title, &description, &actions, now)).unwrap(),
+        );
+
+        // Create proposal
+        let proposal = Proposal {
+            id: proposal_id,
+            proposer,
+            title: title.clone(),
+            description,
+            actions,
+            created_at: now,
+            voting_ends_at,
+            reveal_ends_at,
+            start_block,
+            end_block,
+            voting_config: config,
+            yes_votes: 0,
+            no_votes: 0,
+            abstain_votes: 0,
+            voters: HashMap::new(),
+            commitments: HashMap::new(),
+            status: ProposalStatus::Active,
+        };
+
+        // Save to storage
+        self.save_proposal(state, &proposal);
+
+        // Update proposal index
+        let mut index = self.load_proposal_index(state)?;
+        index.push(proposal_id);
+        self.save_proposal_index(state, &index);
+
+        self.record_proposal_created(state)?;
+
+        // Emit event
+        self.log_event(
+            state,
+            ContractEvent {
+                contract_id: self.id,
+                topic: "ProposalCreated".to_string(),
+                data: bincode::serialize(&(proposal_id, title)).unwrap(),
+            },
+        )?;
+
+        Ok(proposal_id)
+    }
+
+    /// Delegate (or revoke delegation of) the caller's voting power
+    fn execute_set_delegate(
+        &self,
+        state: &mut ContractState<'_>,
+        delegator: Address,
+        delegate: Option<Address>,
+    ) -> ContractResult<()> {
+        if delegate == Some(delegator) {
+            return Err(ContractError::ExecutionFailed(
+                "Cannot delegate to self".to_string(),
+            ));
+        }
+
+        if let Some(new_delegate) = delegate {
+            if self.delegate_chain_reaches(state, new_delegate, delegator)? {
+                return Err(ContractError::ExecutionFailed(
+                    "Delegation would create a cycle".to_string(),
+                ));
+            }
+        }
+
+        let old_delegate = self.load_delegate(state, &delegator)?;
+        if old_delegate == delegate {
+            return Ok(());
+        }
+
+        if let Some(old) = old_delegate {
+            let mut old_delegators = self.load_delegators(state, &old)?;
+            old_delegators.retain(|d| *d != delegator);
+            self.save_delegators(state, &old, &old_delegators);
+        }
+
+        match delegate {
+            Some(new_delegate) => {
+                let mut new_delegators = self.load_delegators(state, &new_delegate)?;
+                new_delegators.push(delegator);
+                self.save_delegators(state, &new_delegate, &new_delegators);
+                self.save_delegate(state, &delegator, Some(new_delegate));
+            }
+            None => {
+                self.save_delegate(state, &delegator, None);
+            }
+        }
+
+        self.log_event(
+            state,
+            ContractEvent {
+                contract_id: self.id,
+                topic: "DelegateSet".to_string(),
+                data: bincode::serialize(&(delegator, delegate)).unwrap(),
+            },
+        )?;
+
+        Ok(())
+    }
+
+    /// Check that `caller` is the chairperson recorded at deploy time
+    fn require_chairperson(
+        &self,
+        state: &ContractState<'_>,
+        caller: Address,
+    ) -> ContractResult<()> {
+        match self.load_chairperson(state)? {
+            Some(chairperson) if chairperson == caller => Ok(()),
+            Some(_) => Err(ContractError::Unauthorized(
+                "Only the chairperson may perform this action".to_string(),
+            )),
+            None => Err(ContractError::ExecutionFailed(
+                "No chairperson has been configured".to_string(),
+            )),
+        }
+    }
+
+    /// Grant `account` a voting weight in the chairperson-curated voter set
+    fn execute_register_voter(
+        &self,
+        state: &mut ContractState<'_>,
+        caller: Address,
+        account: Address,
+        weight: u128,
+    ) -> ContractResult<()> {
+        self.require_chairperson(state, caller)?;
+        self.save_registered_voter(state, &account, weight);
+
+        self.log_event(
+            state,
+            ContractEvent {
+                contract_id: self.id,
+                topic: "VoterRegistered".to_string(),
+                data: bincode::serialize(&(account, weight)).unwrap(),
+            },
+        )?;
+
+        Ok(())
+    }
+
+    /// Remove `account` from the chairperson-curated voter set
+    fn execute_revoke_voter(
+        &self,
+        state: &mut ContractState<'_>,
+        caller: Address,
+        account: Address,
+    ) -> ContractResult<()> {
+        self.require_chairperson(state, caller)?;
+        self.revoke_registered_voter(state, &account);
+
+        self.log_event(
+            state,
+            ContractEvent {
+                contract_id: self.id,
+                topic: "VoterRevoked".to_string(),
+                data: bincode::serialize(&account).unwrap(),
+            },
+        )?;
+
+        Ok(())
+    }
+
+    /// Cast a vote using the weight assigned by `RegisterVoter` — a closed,
+    /// chairperson-curated complement to `execute_vote`'s open model where
+    /// the caller supplies its own voting power
+    fn execute_cast_vote(
+        &self,
+        state: &mut ContractState<'_>,
+        voter: Address,
+        proposal_id: Hash,
+        choice: VoteChoice,
+    ) -> ContractResult<()> {
+        let weight = self.load_registered_voter(state, &voter)?.ok_or_else(|| {
+            ContractError::Unauthorized("Account is not a registered voter".to_string())
+        })?;
+
+        let mut proposal = self
+            .load_proposal(state, &proposal_id)?
+            .ok_or_else(|| ContractError::ExecutionFailed("Proposal not found".to_string()))?;
+
+        if proposal.status != ProposalStatus::Active {
+            return Err(ContractError::ExecutionFailed(
+                "Proposal is not active".to_string(),
+            ));
+        }
+
+        if proposal.reveal_ends_at.is_some() {
+            return Err(ContractError::ExecutionFailed(
+                "Proposal uses commit-reveal voting; use CommitVote/RevealVote".to_string(),
+            ));
+        }
+
+        let now = crate::types::now_millis();
+        if now >= proposal.voting_ends_at {
+            return Err(ContractError::ExecutionFailed(
+                "Voting period has ended".to_string(),
+            ));
+        }
+
+        // Block-height window, when the proposal set one and the executing
+        // state has a block context attached to check it against (absent in
+        // most unit tests, which exercise the timestamp window instead).
+        if let Some(ctx) = state.block_context() {
+            if let Some(start) = proposal.start_block {
+                if ctx.height < start {
+                    return Err(ContractError::ExecutionFailed(
+                        "Voting has not started yet".to_string(),
+                    ));
+                }
+            }
+            if let Some(end) = proposal.end_block {
+                if ctx.height > end {
+                    return Err(ContractError::ExecutionFailed(
+                        "Voting period has ended".to_string(),
+                    ));
+                }
+            }
+        }
+
+        if proposal.voters.contains_key(&voter) {
+            return Err(ContractError::ExecutionFailed(
+                "Already voted on this proposal".to_string(),
+            ));
+        }
+
+        proposal.voters.insert(voter, choice);
+        match choice {
+            VoteChoice::Yes => proposal.yes_votes += weight,
+            VoteChoice::No => proposal.no_votes += weight,
+            VoteChoice::Abstain => proposal.abstain_votes += weight,
+        }
+
+        self.save_proposal(state, &proposal);
+        self.record_votes_cast(state, 1, weight)?;
+
+        self.log_event(
+            state,
+            ContractEvent {
+                contract_id: self.id,
+                topic: "VoteCast".to_string(),
+                data: bincode::serialize(&(proposal_id, voter, choice, weight)).unwrap(),
+            },
+        )?;
+
+        Ok(())
+    }
+
+    /// Cast a vote on a proposal. If addresses currently delegate their
+    /// voting power to `voter`, transitively through any length of
+    /// delegation chain, this single call tallies `voting_power` (the
+    /// caller-supplied aggregate covering itself plus every delegator) and
+    /// marks all of them as having voted too, so a delegator that later
+    /// revokes can't cast a second vote on the same proposal through the
+    /// same delegate's ballot.
+    fn execute_vote(
+        &self,
+        state: &mut ContractState<'_>,
+        voter: Address,
+        proposal_id: Hash,
+        choice: VoteChoice,
+        voting_power: u128,
+    ) -> ContractResult<()> {
+        // Load proposal from storage
+        let mut proposal = self
+            .load_proposal(state, &proposal_id)?
+            .ok_or_else(|| ContractError::ExecutionFailed("Proposal not found".to_string()))?;
+
+        // Check proposal is active
+        if proposal.status != ProposalStatus::Active {
+            return Err(ContractError::ExecutionFailed(
+                "Proposal is not active".to_string(),
+            ));
+        }
+
+        // Commit-reveal proposals keep votes private until revealed; direct
+        // voting would defeat that, so it's rejected outright.
+        if proposal.reveal_ends_at.is_some() {
+            return Err(ContractError::ExecutionFailed(
+                "Proposal uses commit-reveal voting; use CommitVote/RevealVote".to_string(),
+            ));
+        }
+
+        // Check voting period not ended
+        let now = crate::types::now_millis();
+        if now >= proposal.voting_ends_at {
+            return Err(ContractError::ExecutionFailed(
+                "Voting period has ended".to_string(),
+            ));
+        }
+
+        // An address that has delegated its voting power away must vote
+        // through its delegate instead of directly.
+        if self.load_delegate(state, &voter)?.is_some() {
+            return Err(ContractError::ExecutionFailed(
+                "Address has delegated its voting power; cannot vote directly".to_string(),
+            ));
+        }
+
+        // Check not already voted
+        if proposal.voters.contains_key(&voter) {
+            return Err(ContractError::ExecutionFailed(
+                "Already voted on this proposal".to_string(),
+            ));
+        }
+
+        let delegators = self.resolve_transitive_delegators(state, voter)?;
+        for delegator in &delegators {
+            if proposal.voters.contains_key(delegator) {
+                return Err(ContractError::ExecutionFailed(
+                    "A delegator has already voted on this proposal".to_string(),
+                ));
+            }
+        }
+
+        // Record vote for the voter and every delegator it represents
+        proposal.voters.insert(voter, choice);
+        for delegator in &delegators {
+            proposal.voters.insert(*delegator, choice);
+        }
+        match choice {
+            VoteChoice::Yes => proposal.yes_votes += voting_power,
+            VoteChoice::No => proposal.no_votes += voting_power,
+            VoteChoice::Abstain => proposal.abstain_votes += voting_power,
+        }
+
+        // Save updated proposal back to storage
+        self.save_proposal(state, &proposal);
+        self.record_votes_cast(state, 1 + delegators.len() as u64, voting_power)?;
+
+        // Emit event
+        self.log_event(
+            state,
+            ContractEvent {
+                contract_id: self.id,
+                topic: "VoteCast".to_string(),
+                data: bincode::serialize(&(proposal_id, voter, choice, voting_power)).unwrap(),
+            },
+        )?;
+
+        Ok(())
+    }
+
+    /// Compute the commitment hash for a commit-reveal vote: a voter submits
+    /// `commitment` via `CommitVote` without disclosing `choice`/`voting_power`,
+    /// then later opens it via `RevealVote` by resupplying all three inputs
+    /// (including `salt`) for this function to recompute and compare.
+    fn vote_commitment(choice: VoteChoice, voting_power: u128, salt: &[u8]) -> Hash {
+        let mut data = Vec::with_capacity(1 + 16 + salt.len());
+        data.push(choice.as_byte());
+        data.extend_from_slice(&voting_power.to_le_bytes());
+        data.extend_from_slice(salt);
+        hash_data(&data)
+    }
+
+    /// Commit to a vote on a commit-reveal proposal without disclosing it
+    fn execute_commit_vote(
+        &self,
+        state: &mut ContractState<'_>,
+        voter: Address,
+        proposal_id: Hash,
+        commitment: Hash,
+    ) -> ContractResult<()> {
+        let mut proposal = self
+            .load_proposal(state, &proposal_id)?
+            .ok_or_else(|| ContractError::ExecutionFailed("Proposal not found".to_string()))?;
+
+        if proposal.status != ProposalStatus::Active {
+            return Err(ContractError::ExecutionFailed(
+                "Proposal is not active".to_string(),
+            ));
+        }
+
+        if proposal.reveal_ends_at.is_none() {
+            return Err(ContractError::ExecutionFailed(
+                "Proposal does not use commit-reveal voting".to_string(),
+            ));
+        }
+
+        let now = crate::types::now_millis();
+        if now >= proposal.voting_ends_at {
+            return Err(ContractError::ExecutionFailed(
+                "Commit period has ended".to_string(),
+            ));
+        }
+
+        if proposal.commitments.contains_key(&voter) {
+            return Err(ContractError::ExecutionFailed(
+                "Already committed a vote on this proposal".to_string(),
+            ));
+        }
+
+        proposal.commitments.insert(voter, commitment);
+        self.save_proposal(state, &proposal);
+
+        self.log_event(
+            state,
+            ContractEvent {
+                contract_id: self.id,
+                topic: "VoteCommitted".to_string(),
+                data: bincode::serialize(&(proposal_id, voter)).unwrap(),
+            },
+        )?;
+
+        Ok(())
+    }
+
+    /// Open a prior `CommitVote` commitment and tally it
+    fn execute_reveal_vote(
+        &self,
+        state: &mut ContractState<'_>,
+        voter: Address,
+        proposal_id: Hash,
+        choice: VoteChoice,
+        voting_power: u128,
+        salt: Vec<u8>,
+    ) -> ContractResult<()> {
+        let mut proposal = self
+            .load_proposal(state, &proposal_id)?
+            .ok_or_else(|| ContractError::ExecutionFailed("Proposal not found".to_string()))?;
+
+        let reveal_ends_at = proposal.reveal_ends_at.ok_or_else(|| {
+            ContractError::ExecutionFailed("Proposal does not use commit-reveal voting".to_string())
+        })?;
+
+        let now = crate::types::now_millis();
+        if now < proposal.voting_ends_at {
+            return Err(ContractError::ExecutionFailed(
+                "Commit period has not ended yet".to_string(),
+            ));
+        }
+        if now > reveal_ends_at {
+            return Err(ContractError::ExecutionFailed(
+                "Reveal period has ended".to_string(),
+            ));
+        }
+
+        let commitment = proposal
+            .commitments
+            .get(&voter)
+            .copied()
+            .ok_or_else(|| ContractError::ExecutionFailed("No commitment found".to_string()))?;
+
+        if proposal.voters.contains_key(&voter) {
+            return Err(ContractError::ExecutionFailed(
+                "Already revealed a vote on this proposal".to_string(),
+            ));
+        }
+
+        if Self::vote_commitment(choice, voting_power, &salt) != commitment {
+            return Err(ContractError::ExecutionFailed(
+                "Commitment mismatch".to_string(),
+            ));
+        }
+
+        proposal.voters.insert(voter, choice);
+        match choice {
+            VoteChoice::Yes => proposal.yes_votes += voting_power,
+            VoteChoice::No => proposal.no_votes += voting_power,
+            VoteChoice::Abstain => proposal.abstain_votes += voting_power,
+        }
+
+        self.save_proposal(state, &proposal);
+        self.record_votes_cast(state, 1, voting_power)?;
+
+        self.log_event(
+            state,
+            ContractEvent {
+                contract_id: self.id,
+                topic: "VoteRevealed".to_string(),
+                data: bincode::serialize(&(proposal_id, voter, choice, voting_power)).unwrap(),
+            },
+        )?;
+
+        Ok(())
+    }
+
+    /// Execute an approved proposal
+    fn execute_proposal(
+        &self,
+        state: &mut ContractState<'_>,
+        proposal_id: Hash,
+    ) -> ContractResult<()> {
+        // Load proposal from storage
+        let mut proposal = self
+            .load_proposal(state, &proposal_id)?
+            .ok_or_else(|| ContractError::ExecutionFailed("Proposal not found".to_string()))?;
+
+        // Check voting (and, for commit-reveal proposals, reveal) period
+        // ended, plus any configured post-reveal execution delay (a timelock
+        // on passed proposals taking effect).
+        let now = crate::types::now_millis();
+        let voting_concluded_at = proposal.reveal_ends_at.unwrap_or(proposal.voting_ends_at);
+        if now < voting_concluded_at {
+            return Err(ContractError::ExecutionFailed(
+                "Voting period not yet ended".to_string(),
+            ));
+        }
+        if now < voting_concluded_at + proposal.voting_config.execution_delay {
+            return Err(ContractError::ExecutionFailed(
+                "Execution delay has not elapsed".to_string(),
+            ));
+        }
+
+        // Calculate quorum — abstentions count as attendance
+        let directional_votes = proposal.yes_votes + proposal.no_votes;
+        let total_votes = directional_votes + proposal.abstain_votes;
+        let total_voting_power = self.load_total_voting_power(state)?;
+        let quorum = total_voting_power * u128::from(proposal.voting_config.quorum_percent) / 100;
+
+        if total_votes < quorum {
+            proposal.status = ProposalStatus::QuorumNotMet;
+            self.save_proposal(state, &proposal);
+            self.record_proposal_closed(state, proposal_id, proposal.yes_votes)?;
+            return Err(ContractError::ExecutionFailed(
+                "Quorum not reached".to_string(),
+            ));
+        }
+
+        // Check approval threshold — abstentions are excluded from the
+        // approval percentage entirely, so they don't drag down a proposal
+        // they didn't take a side on.
+        let approval_percent = if directional_votes > 0 {
+            proposal.yes_votes * 100 / directional_votes
+        } else {
+            0
+        };
+
+        if approval_percent < u128::from(proposal.voting_config.approval_threshold) {
+            proposal.status = ProposalStatus::Rejected;
+            self.save_proposal(state, &proposal);
+            self.record_proposal_closed(state, proposal_id, proposal.yes_votes)?;
+            return Err(ContractError::ExecutionFailed(
+                "Approval threshold not met".to_string(),
+            ));
+        }
+
+        // Mark as passed
+        proposal.status = ProposalStatus::Passed;
+
+        // Clone actions to execute
+        let actions_to_execute = proposal.actions.clone();
+
+        // Execute actions
+        for action in &actions_to_execute {
+            if let Err(e) = self.execute_governance_action(state, action) {
+                proposal.status = ProposalStatus::ExecutionFailed;
+                self.save_proposal(state, &proposal);
+                self.record_proposal_closed(state, proposal_id, proposal.yes_votes)?;
+                return Err(e);
+            }
+        }
+
+        // Mark as executed
+        proposal.status = ProposalStatus::Executed;
+        self.save_proposal(state, &proposal);
+        self.record_proposal_closed(state, proposal_id, proposal.yes_votes)?;
+
+        // Emit event
+        self.log_event(
+            state,
+            ContractEvent {
+                contract_id: self.id,
+                topic: "ProposalExecuted".to_string(),
+                data: bincode::serialize(&proposal_id).unwrap(),
+            },
+        )?;
+
+        Ok(())
+    }
+
+    /// Pay out `now_epoch`'s disbursement for every active funding stream
+    /// whose `[start, end]` window covers it
+    fn execute_disburse_funding(
+        &self,
+        state: &mut ContractState<'_>,
+        now_epoch: u64,
+    ) -> ContractResult<()> {
+        let treasury = Address::from_bytes([0; 20]);
+        let index = self.load_funding_index(state)?;
+
+        for stream_id in index {
+            let Some(mut stream) = self.load_funding_stream(state, &stream_id)? else {
+                continue;
+            };
+
+            if !stream.active {
+                continue;
+            }
+            if now_epoch < stream.start || now_epoch > stream.end {
+                continue;
+            }
+            if stream.last_disbursed_epoch == Some(now_epoch) {
+                continue;
+            }
+
+            state.transfer(treasury, stream.recipient, stream.amount_per_epoch)?;
+            stream.last_disbursed_epoch = Some(now_epoch);
+            self.save_funding_stream(state, &stream);
+
+            self.log_event(
+                state,
+                ContractEvent {
+                    contract_id: self.id,
+                    topic: "FundingDisbursed".to_string(),
+                    data: bincode::serialize(&(
+                        stream_id,
+                        stream.recipient,
+                        stream.amount_per_epoch,
+                        now_epoch,
+                    ))
+                    .unwrap(),
+                },
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Record a witness toward a [`ConditionalSpend`]'s conditions, and
+    /// release the escrow to its recipient once every condition is
+    /// satisfied
+    fn execute_apply_witness(
+        &self,
+        state: &mut ContractState<'_>,
+        caller: Address,
+        spend_id: Hash,
+        witness: Witness,
+    ) -> ContractResult<()> {
+        let mut spend = self
+            .load_conditional_spend(state, &spend_id)?
+            .ok_or_else(|| {
+                ContractError::ExecutionFailed("Conditional spend not found".to_string())
+            })?;
+
+        if spend.released || spend.cancelled {
+            return Err(ContractError::ExecutionFailed(
+                "Conditional spend is already settled".to_string(),
+            ));
+        }
+
+        match witness {
+            Witness::Signed { signer } => {
+                if signer != caller {
+                    return Err(ContractError::Unauthorized(
+                        "Witness signer must match the transaction sender".to_string(),
+                    ));
+                }
+                if !spend.witnessed_signers.contains(&signer) {
+                    spend.witnessed_signers.push(signer);
+                }
+            }
+        }
+
+        let now = crate::types::now_millis();
+        if spend.all_satisfied(now) {
+            state.transfer(self.contract_address(), spend.recipient, spend.amount)?;
+            spend.released = true;
+            self.save_conditional_spend(state, &spend);
+
+            self.log_event(
+                state,
+                ContractEvent {
+                    contract_id: self.id,
+                    topic: "ConditionalSpendReleased".to_string(),
+                    data: bincode::serialize(&(spend_id, spend.recipient, spend.amount)).unwrap(),
+                },
+            )?;
+        } else {
+            self.save_conditional_spend(state, &spend);
+        }
+
+        Ok(())
+    }
+
+    /// Refund an unsatisfied [`ConditionalSpend`] back to the treasury once
+    /// its `expires_at` deadline has lapsed
+    fn execute_cancel_conditional_spend(
+        &self,
+        state: &mut ContractState<'_>,
+        spend_id: Hash,
+    ) -> ContractResult<()> {
+        let mut spend = self
+            .load_conditional_spend(state, &spend_id)?
+            .ok_or_else(|| {
+                ContractError::ExecutionFailed("Conditional spend not found".to_string())
+            })?;
+
+        if spend.released || spend.cancelled {
+            return Err(ContractError::ExecutionFailed(
+                "Conditional spend is already settled".to_string(),
+            ));
+        }
+
+        let now = crate::types::now_millis();
+        let expires_at = spend.expires_at.ok_or_else(|| {
+            ContractError::ExecutionFailed("Conditional spend has no expiry".to_string())
+        })?;
+        if now < expires_at {
+            return Err(ContractError::ExecutionFailed(
+                "Conditional spend has not expired yet".to_string(),
+            ));
+        }
+        if spend.all_satisfied(now) {
+            return Err(ContractError::ExecutionFailed(
+                "Conditional spend's conditions are already satisfied".to_string(),
+            ));
+        }
+
+        let treasury = Address::from_bytes([0; 20]);
+        state.transfer(self.contract_address(), treasury, spend.amount)?;
+        spend.cancelled = true;
+        self.save_conditional_spend(state, &spend);
+
+        self.log_event(
+            state,
+            ContractEvent {
+                contract_id: self.id,
+                topic: "ConditionalSpendCancelled".to_string(),
+                data: bincode::serialize(&spend_id).unwrap(),
+            },
+        )?;
+
+        Ok(())
+    }
+
+    /// Execute a governance action
+    fn execute_governance_action(
+        &self,
+        state: &mut ContractState<'_>,
+        action: &GovernanceAction,
+    ) -> ContractResult<()> {
+        match action {
+            GovernanceAction::ParameterUpdate { key, value } => {
+                let param_key = format!("param:{}", key);
+                state.storage_write(
+                    Address::from_bytes([0; 20]),
+                    param_key.as_bytes().to_vec(),
+                    value.clone(),
+                );
+                Ok(())
+            }
+            GovernanceAction::ContractUpgrade {
+                contract_id,
+                new_code,
+                new_code_hash,
+            } => {
+                let upgrade_key = format!("upgrade:{}", hex::encode(contract_id.as_bytes()));
+                state.storage_write(
+                    Address::from_bytes([0; 20]),
+                    upgrade_key.as_bytes().to_vec(),
+                    new_code.clone(),
+                );
+                let hash_key = format!("upgrade_hash:{}", hex::encode(contract_id.as_bytes()));
+                state.storage_write(
+                    Address::from_bytes([0; 20]),
+                    hash_key.as_bytes().to_vec(),
+                    new_code_hash.as_bytes().to_vec(),
+                );
+                Ok(())
+            }
+            GovernanceAction::TreasurySpend {
+                recipient,
+                amount,
+                purpose,
+            } => {
+                let treasury = Address::from_bytes([0; 20]);
+                state.transfer(treasury, *recipient, *amount)?;
+
+                let spend_key = format!("treasury_spend:{}", hex::encode(recipient.as_bytes()));
+                state.storage_write(
+                    treasury,
+                    spend_key.as_bytes().to_vec(),
+                    purpose.as_bytes().to_vec(),
+                );
+                Ok(())
+            }
+            GovernanceAction::EmergencyPause {
+                contract_id,
+                reason,
+            } => {
+                let pause_key = format!("paused:{}", hex::encode(contract_id.as_bytes()));
+                state.storage_write(
+                    Address::from_bytes([0; 20]),
+                    pause_key.as_bytes().to_vec(),
+                    reason.as_bytes().to_vec(),
+                );
+                Ok(())
+            }
+            GovernanceAction::Resume { contract_id } => {
+                let pause_key = format!("paused:{}", hex::encode(contract_id.as_bytes()));
+                state.storage_write(
+                    Address::from_bytes([0; 20]),
+                    pause_key.as_bytes().to_vec(),
+                    Vec::new(),
+                );
+                Ok(())
+            }
+            GovernanceAction::ContinuousFunding {
+                recipient,
+                amount_per_epoch,
+                start,
+                end,
+            } => {
+                if end < start {
+                    return Err(ContractError::ExecutionFailed(
+                        "Funding stream end epoch must not precede its start epoch".to_string(),
+                    ));
+                }
+
+                let now = crate::types::now_millis();
+                let stream_id = hash_data(
+                    &bincode::serialize(&(recipient, amount_per_epoch, start, end, now)).unwrap(),
+                );
+                let stream = FundingStream {
+                    id: stream_id,
+                    recipient: *recipient,
+                    amount_per_epoch: *amount_per_epoch,
+                    start: *start,
+                    end: *end,
+                    last_disbursed_epoch: None,
+                    active: true,
+                };
+                self.save_funding_stream(state, &stream);
+
+                let mut index = self.load_funding_index(state)?;
+                index.push(stream_id);
+                self.save_funding_index(state, &index);
+
+                self.log_event(
+                    state,
+                    ContractEvent {
+                        contract_id: self.id,
+                        topic: "FundingStreamCreated".to_string(),
+                        data: bincode::serialize(&(
+                            stream_id,
+                            recipient,
+                            amount_per_epoch,
+                            start,
+                            end,
+                        ))
+                        .unwrap(),
+                    },
+                )?;
+                Ok(())
+            }
+            GovernanceAction::StopFunding { stream_id } => {
+                let mut stream = self.load_funding_stream(state, stream_id)?.ok_or_else(|| {
+                    ContractError::ExecutionFailed("Funding stream not found".to_string())
+                })?;
+                stream.active = false;
+                self.save_funding_stream(state, &stream);
+                Ok(())
+            }
+            GovernanceAction::ConditionalSpend {
+                recipient,
+                amount,
+                conditions,
+                expires_at,
+            } => {
+                let treasury = Address::from_bytes([0; 20]);
+                state.transfer(treasury, self.contract_address(), *amount)?;
+
+                let now = crate::types::now_millis();
+                let spend_id = hash_data(
+                    &bincode::serialize(&(recipient, amount, conditions, expires_at, now)).unwrap(),
+                );
+                let spend = ConditionalSpend {
+                    id: spend_id,
+                    recipient: *recipient,
+                    amount: *amount,
+                    conditions: conditions.clone(),
+                    expires_at: *expires_at,
+                    witnessed_signers: Vec::new(),
+                    released: false,
+                    cancelled: false,
+                };
+                self.save_conditional_spend(state, &spend);
+
+                let mut index = self.load_spend_index(state)?;
+                index.push(spend_id);
+                self.save_spend_index(state, &index);
+
+                self.log_event(
+                    state,
+                    ContractEvent {
+                        contract_id: self.id,
+                        topic: "ConditionalSpendCreated".to_string(),
+                        data: bincode::serialize(&(spend_id, recipient, amount)).unwrap(),
+                    },
+                )?;
+                Ok(())
+            }
+            GovernanceAction::ConfigUpdate {
+                min_voting_period,
+                quorum_percent,
+                approval_threshold,
+                execution_delay,
+            } => {
+                self.save_voting_config(
+                    state,
+                    &VotingConfig {
+                        min_voting_period: *min_voting_period,
+                        quorum_percent: *quorum_percent,
+                        approval_threshold: *approval_threshold,
+                        execution_delay: *execution_delay,
+                    },
+                );
+                Ok(())
+            }
+            GovernanceAction::AddCollateral { amount } => {
+                let treasury = Address::from_bytes([0; 20]);
+                state.transfer(treasury, self.contract_address(), *amount)?;
+
+                self.log_event(
+                    state,
+                    ContractEvent {
+                        contract_id: self.id,
+                        topic: "CollateralAdded".to_string(),
+                        data: bincode::serialize(amount).unwrap(),
+                    },
+                )?;
+                Ok(())
+            }
+            GovernanceAction::RemoveVoter { account } => {
+                self.revoke_registered_voter(state, account);
+
+                self.log_event(
+                    state,
+                    ContractEvent {
+                        contract_id: self.id,
+                        topic: "VoterRevoked".to_string(),
+                        data: bincode::serialize(account).unwrap(),
+                    },
+                )?;
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Default for GovernanceContract {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Contract for GovernanceContract {
+    fn id(&self) -> Hash {
+        self.id
+    }
+
+    fn name(&self) -> &str {
+        "GovernanceContract"
+    }
+
+    fn version(&self) -> u32 {
+        1
+    }
+
+    fn execute(
+        &self,
+        state: &mut ContractState<'_>,
+        tx: &ContractTransaction,
+    ) -> ContractResult<ExecutionResult> {
+        let action = Self::parse_action(&tx.input)?;
+
+        match action {
+            GovernanceTransactionKind::CreateProposal {
+                title,
+                description,
+                actions,
+                voting_ends_at,
+                reveal_ends_at,
+                start_block,
+                end_block,
+            } => {
+                self.execute_create_proposal(
+                    state,
+                    tx.sender_address,
+                    title,
+                    description,
+                    actions,
+                    voting_ends_at,
+                    reveal_ends_at,
+                    start_block,
+                    end_block,
+                )?;
+            }
+            GovernanceTransactionKind::SetDelegate { delegate } => {
+                self.execute_set_delegate(state, tx.sender_address, delegate)?;
+            }
+            GovernanceTransactionKind::RegisterVoter { account, weight } => {
+                self.execute_register_voter(state, tx.sender_address, account, weight)?;
+            }
+            GovernanceTransactionKind::RevokeVoter { account } => {
+                self.execute_revoke_voter(state, tx.sender_address, account)?;
+            }
+            GovernanceTransactionKind::CastVote {
+                proposal_id,
+                choice,
+            } => {
+                self.execute_cast_vote(state, tx.sender_address, proposal_id, choice)?;
+            }
+            GovernanceTransactionKind::Vote {
+                proposal_id,
+                choice,
+                voting_power,
+            } => {
+                self.execute_vote(state, tx.sender_address, proposal_id, choice, voting_power)?;
+            }
+            GovernanceTransactionKind::CommitVote {
+                proposal_id,
+                commitment,
+            } => {
+                self.execute_commit_vote(state, tx.sender_address, proposal_id, commitment)?;
+            }
+            GovernanceTransactionKind::RevealVote {
+                proposal_id,
+                choice,
+                voting_power,
+                salt,
+            } => {
+                self.execute_reveal_vote(
+                    state,
+                    tx.sender_address,
+                    proposal_id,
+                    choice,
+                    voting_power,
+                    salt,
+                )?;
+            }
+            GovernanceTransactionKind::Execute { proposal_id } => {
+                self.execute_proposal(state, proposal_id)?;
+            }
+            GovernanceTransactionKind::DisburseFunding { now_epoch } => {
+                self.execute_disburse_funding(state, now_epoch)?;
+            }
+            GovernanceTransactionKind::ApplyWitness { spend_id, witness } => {
+                self.execute_apply_witness(state, tx.sender_address, spend_id, witness)?;
+            }
+            GovernanceTransactionKind::CancelConditionalSpend { spend_id } => {
+                self.execute_cancel_conditional_spend(state, spend_id)?;
+            }
+            GovernanceTransactionKind::UpdateVotingPower { total_power } => {
+                self.save_total_voting_power(state, total_power);
+            }
+        }
+
+        Ok(ExecutionResult {
+            new_state_root: state.compute_state_root()?,
+            gas_used: 150_000,
+            events: state.events().to_vec(),
+            output: Vec::new(),
+        })
+    }
+
+    fn verify(
+        &self,
+        state: &ContractState<'_>,
+        _tx: &ContractTransaction,
+        result: &ExecutionResult,
+    ) -> ContractResult<bool> {
+        let computed_root = state.compute_state_root()?;
+        Ok(computed_root == result.new_state_root)
+    }
+
+    fn is_upgradeable(&self) -> bool {
+        true
+    }
+
+    fn on_deploy(&self, state: &mut ContractState<'_>, init_data: &[u8]) -> ContractResult<()> {
+        // Initialize storage with defaults
+        self.save_total_voting_power(state, 0);
+        self.save_proposal_index(state, &[]);
+        self.save_voting_config(state, &VotingConfig::default());
+        self.save_funding_index(state, &[]);
+        self.save_spend_index(state, &[]);
+        self.save_event_log_len(state, 0);
+        self.save_total_proposals_created(state, 0);
+        self.save_total_votes_cast(state, 0);
+        self.save_total_weight_cast(state, 0);
+        self.save_leading_proposal(state, (None, 0, false));
+
+        // `init_data` optionally carries the deploying account, recorded as
+        // chairperson of the registered-voter subsystem; omitted entirely
+        // (empty `init_data`) when a deployment doesn't use it.
+        if !init_data.is_empty() {
+            let chairperson: Address = bincode::deserialize(init_data).map_err(|e| {
+                ContractError::InvalidTransaction(format!("Invalid init_data: {e}"))
+            })?;
+            self.save_chairperson(state, chairperson);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::contracts::state::{BlockContext, ContractState};
+    use crate::crypto::Keypair;
+    use crate::types::{Address, HclawAmount};
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_create_proposal() {
+        let contract = GovernanceContract::new();
+        let mut accounts = HashMap::new();
+        let mut storage = HashMap::new();
+
+        let kp = Keypair::generate();
+        let proposer = Address::from_public_key(kp.public_key());
+
+        accounts.insert(
+            proposer,
+            crate::state::AccountState::new(HclawAmount::from_hclaw(1000)),
+        );
+
+        let mut state = ContractState::new(&mut accounts, &mut storage);
+
+        let now = crate::types::now_millis();
+        let voting_ends = now + MIN_VOTING_PERIOD + 1000;
+
+        let proposal_id = contract.execute_create_proposal(
+            &mut state,
+            proposer,
+            "Test Proposal".to_string(),
+            "This is a test".to_string(),
+            vec![],
+            voting_ends,
+            None,
+            None,
+            None,
+        );
+
+        assert!(proposal_id.is_ok());
+
+        // Verify proposal is in storage
+        let pid = proposal_id.unwrap();
+        let loaded = contract.load_proposal(&state, &pid).unwrap();
+        assert!(loaded.is_some());
+        assert_eq!(loaded.unwrap().title, "Test Proposal");
+
+        // Verify proposal index
+        let index = contract.load_proposal_index(&state).unwrap();
+        assert_eq!(index.len(), 1);
+        assert_eq!(index[0], pid);
+    }
+
+    #[test]
+    fn test_vote_on_proposal() {
+        let contract = GovernanceContract::new();
+
+        let mut accounts = HashMap::new();
+        let mut storage = HashMap::new();
+
+        let kp = Keypair::generate();
+        let voter = Address::from_public_key(kp.public_key());
+
+        accounts.insert(
+            voter,
+            crate::state::AccountState::new(HclawAmount::from_hclaw(1000)),
+        );
+
+        let mut state = ContractState::new(&mut accounts, &mut storage);
+
+        // Set voting power in storage
+        contract.save_total_voting_power(&mut state, 10000);
+
+        // Create proposal
+        let now = crate::types::now_millis();
+        let voting_ends = now + MIN_VOTING_PERIOD + 1000;
+        let proposal_id = contract
+            .execute_create_proposal(
+                &mut state,
+                voter,
+                "Test".to_string(),
+                "Test".to_string(),
+                vec![],
+                voting_ends,
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+
+        // Vote
+        let result = contract.execute_vote(&mut state, voter, proposal_id, VoteChoice::Yes, 100);
+        assert!(result.is_ok());
+
+        // Verify vote persisted in storage
+        let proposal = contract
+            .load_proposal(&state, &proposal_id)
+            .unwrap()
+            .unwrap();
+        assert_eq!(proposal.yes_votes, 100);
+        assert!(proposal.voters.contains_key(&voter));
+    }
+
+    #[test]
+    fn test_cannot_vote_twice() {
+        let contract = GovernanceContract::new();
+
+        let mut accounts = HashMap::new();
+        let mut storage = HashMap::new();
+
+        let kp = Keypair::generate();
+        let voter = Address::from_public_key(kp.public_key());
+
+        accounts.insert(
+            voter,
+            crate::state::AccountState::new(HclawAmount::from_hclaw(1000)),
+        );
+
+        let mut state = ContractState::new(&mut accounts, &mut storage);
+
+        contract.save_total_voting_power(&mut state, 10000);
+
+        let now = crate::types::now_millis();
+        let voting_ends = now + MIN_VOTING_PERIOD + 1000;
+        let proposal_id = contract
+            .execute_create_proposal(
+                &mut state,
+                voter,
+                "Test".to_string(),
+                "Test".to_string(),
+                vec![],
+                voting_ends,
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+
+        // First vote succeeds
+        assert!(contract
+            .execute_vote(&mut state, voter, proposal_id, VoteChoice::Yes, 100)
+            .is_ok());
+
+        // Second vote fails
+        assert!(contract
+            .execute_vote(&mut state, voter, proposal_id, VoteChoice::Yes, 100)
+            .is_err());
+    }
+
+    #[test]
+    fn test_state_persists_across_calls() {
+        let contract = GovernanceContract::new();
+
+        let mut accounts = HashMap::new();
+        let mut storage = HashMap::new();
+
+        let kp1 = Keypair::generate();
+        let kp2 = Keypair::generate();
+        let voter1 = Address::from_public_key(kp1.public_key());
+        let voter2 = Address::from_public_key(kp2.public_key());
+
+        accounts.insert(
+            voter1,
+            crate::state::AccountState::new(HclawAmount::from_hclaw(1000)),
+        );
+        accounts.insert(
+            voter2,
+            crate::state::AccountState::new(HclawAmount::from_hclaw(1000)),
+        );
+
+        // Scope 1: create proposal
+        let proposal_id = {
+            let mut state = ContractState::new(&mut accounts, &mut storage);
+            contract.save_total_voting_power(&mut state, 10000);
+
+            let now = crate::types::now_millis();
+            let voting_ends = now + MIN_VOTING_PERIOD + 1000;
+            let pid = contract
+                .execute_create_proposal(
+                    &mut state,
+                    voter1,
+                    "Persist Test".to_string(),
+                    "Test".to_string(),
+                    vec![],
+                    voting_ends,
+                    None,
+                    None,
+                    None,
+                )
+                .unwrap();
+            state.commit().unwrap();
+            pid
+        };
+
+        // Scope 2: vote with voter1 (fresh state wrapper)
+        {
+            let mut state = ContractState::new(&mut accounts, &mut storage);
+            contract
+                .execute_vote(&mut state, voter1, proposal_id, VoteChoice::Yes, 500)
+                .unwrap();
+            state.commit().unwrap();
+        }
+
+        // Scope 3: vote with voter2 (fresh state wrapper)
+        {
+            let mut state = ContractState::new(&mut accounts, &mut storage);
+            contract
+                .execute_vote(&mut state, voter2, proposal_id, VoteChoice::No, 300)
+                .unwrap();
+            state.commit().unwrap();
+        }
+
+        // Verify: both votes visible in storage
+        let state = ContractState::new(&mut accounts, &mut storage);
+        let proposal = contract
+            .load_proposal(&state, &proposal_id)
+            .unwrap()
+            .unwrap();
+        assert_eq!(proposal.yes_votes, 500);
+        assert_eq!(proposal.no_votes, 300);
+        assert_eq!(proposal.voters.len(), 2);
+    }
+
+    #[test]
+    fn test_abstain_counts_toward_quorum_but_not_approval() {
+        let contract = GovernanceContract::new();
+
+        let mut accounts = HashMap::new();
+        let mut storage = HashMap::new();
+
+        let kp1 = Keypair::generate();
+        let kp2 = Keypair::generate();
+        let voter1 = Address::from_public_key(kp1.public_key());
+        let voter2 = Address::from_public_key(kp2.public_key());
+
+        let mut state = ContractState::new(&mut accounts, &mut storage);
+        contract.save_total_voting_power(&mut state, 100);
+
+        let now = crate::types::now_millis();
+        let proposal_id = contract
+            .execute_create_proposal(
+                &mut state,
+                voter1,
+                "Test".to_string(),
+                "Test".to_string(),
+                vec![],
+                now + MIN_VOTING_PERIOD + 1000,
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+
+        // 20 voting power in favor, 15 abstaining: together they clear the
+        // 30% quorum (out of 100 total power), but the abstentions must not
+        // dilute the 100%-in-favor approval percentage.
+        contract
+            .execute_vote(&mut state, voter1, proposal_id, VoteChoice::Yes, 20)
+            .unwrap();
+        contract
+            .execute_vote(&mut state, voter2, proposal_id, VoteChoice::Abstain, 15)
+            .unwrap();
+
+        let mut proposal = contract
+            .load_proposal(&state, &proposal_id)
+            .unwrap()
+            .unwrap();
+        assert_eq!(proposal.abstain_votes, 15);
+
+        // Force the voting period closed so `execute_proposal` will run.
+        proposal.voting_ends_at = now - 1;
+        contract.save_proposal(&mut state, &proposal);
+
+        contract.execute_proposal(&mut state, proposal_id).unwrap();
+
+        let proposal = contract
+            .load_proposal(&state, &proposal_id)
+            .unwrap()
+            .unwrap();
+        assert_eq!(proposal.status, ProposalStatus::Executed);
+    }
+
+    #[test]
+    fn test_commit_reveal_vote_tallies_after_reveal() {
+        let contract = GovernanceContract::new();
+
+        let mut accounts = HashMap::new();
+        let mut storage = HashMap::new();
+
+        let kp = Keypair::generate();
+        let voter = Address::from_public_key(kp.public_key());
+
+        let mut state = ContractState::new(&mut accounts, &mut storage);
+        contract.save_total_voting_power(&mut state, 100);
+
+        let now = crate::types::now_millis();
+        let voting_ends_at = now + MIN_VOTING_PERIOD + 1000;
+        let proposal_id = contract
+            .execute_create_proposal(
+                &mut state,
+                voter,
+                "Test".to_string(),
+                "Test".to_string(),
+                vec![],
+                voting_ends_at,
+                Some(voting_ends_at + MIN_VOTING_PERIOD),
+                None,
+                None,
+            )
+            .unwrap();
+
+        let salt = b"some-salt".to_vec();
+        let commitment = GovernanceContract::vote_commitment(VoteChoice::Yes, 40, &salt);
+        contract
+            .execute_commit_vote(&mut state, voter, proposal_id, commitment)
+            .unwrap();
+
+        // No tally yet — the vote is still hidden.
+        let proposal = contract
+            .load_proposal(&state, &proposal_id)
+            .unwrap()
+            .unwrap();
+        assert_eq!(proposal.yes_votes, 0);
+
+        // Move into the reveal window.
+        let mut proposal = proposal;
+        proposal.voting_ends_at = now - 1;
+        contract.save_proposal(&mut state, &proposal);
+
+        contract
+            .execute_reveal_vote(&mut state, voter, proposal_id, VoteChoice::Yes, 40, salt)
+            .unwrap();
+
+        let proposal = contract
+            .load_proposal(&state, &proposal_id)
+            .unwrap()
+            .unwrap();
+        assert_eq!(proposal.yes_votes, 40);
+        assert!(proposal.voters.contains_key(&voter));
+    }
+
+    #[test]
+    fn test_reveal_vote_without_commitment_fails() {
+        let contract = GovernanceContract::new();
+
+        let mut accounts = HashMap::new();
+        let mut storage = HashMap::new();
+
+        let kp = Keypair::generate();
+        let voter = Address::from_public_key(kp.public_key());
+
+        let mut state = ContractState::new(&mut accounts, &mut storage);
+        contract.save_total_voting_power(&mut state, 100);
+
+        let now = crate::types::now_millis();
+        let voting_ends_at = now + MIN_VOTING_PERIOD + 1000;
+        let proposal_id = contract
+            .execute_create_proposal(
+                &mut state,
+                voter,
+                "Test".to_string(),
+                "Test".to_string(),
+                vec![],
+                voting_ends_at,
+                Some(voting_ends_at + MIN_VOTING_PERIOD),
+                None,
+                None,
+            )
+            .unwrap();
+
+        // Move the commit window closed without anyone committing.
+        let mut proposal = contract
+            .load_proposal(&state, &proposal_id)
+            .unwrap()
+            .unwrap();
+        proposal.voting_ends_at = now - 1;
+        contract.save_proposal(&mut state, &proposal);
+
+        assert!(contract
+            .execute_reveal_vote(
+                &mut state,
+                voter,
+                proposal_id,
+                VoteChoice::Yes,
+                40,
+                b"salt".to_vec(),
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn test_reveal_vote_with_mismatched_commitment_fails() {
+        let contract = GovernanceContract::new();
+
+        let mut accounts = HashMap::new();
+        let mut storage = HashMap::new();
+
+        let kp = Keypair::generate();
+        let voter = Address::from_public_key(kp.public_key());
+
+        let mut state = ContractState::new(&mut accounts, &mut storage);
+        contract.save_total_voting_power(&mut state, 100);
+
+        let now = crate::types::now_millis();
+        let voting_ends_at = now + MIN_VOTING_PERIOD + 1000;
+        let proposal_id = contract
+            .execute_create_proposal(
+                &mut state,
+                voter,
+                "Test".to_string(),
+                "Test".to_string(),
+                vec![],
+                voting_ends_at,
+                Some(voting_ends_at + MIN_VOTING_PERIOD),
+                None,
+                None,
+            )
+            .unwrap();
+
+        let commitment =
+            GovernanceContract::vote_commitment(VoteChoice::Yes, 40, b"real-salt".as_slice());
+        contract
+            .execute_commit_vote(&mut state, voter, proposal_id, commitment)
+            .unwrap();
+
+        let mut proposal = contract
+            .load_proposal(&state, &proposal_id)
+            .unwrap()
+            .unwrap();
+        proposal.voting_ends_at = now - 1;
+        contract.save_proposal(&mut state, &proposal);
+
+        // Wrong salt: the recomputed commitment won't match.
+        assert!(contract
+            .execute_reveal_vote(
+                &mut state,
+                voter,
+                proposal_id,
+                VoteChoice::Yes,
+                40,
+                b"wrong-salt".to_vec(),
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn test_double_commit_fails() {
+        let contract = GovernanceContract::new();
+
+        let mut accounts = HashMap::new();
+        let mut storage = HashMap::new();
+
+        let kp = Keypair::generate();
+        let voter = Address::from_public_key(kp.public_key());
+
+        let mut state = ContractState::new(&mut accounts, &mut storage);
+        contract.save_total_voting_power(&mut state, 100);
+
+        let now = crate::types::now_millis();
+        let voting_ends_at = now + MIN_VOTING_PERIOD + 1000;
+        let proposal_id = contract
+            .execute_create_proposal(
+                &mut state,
+                voter,
+                "Test".to_string(),
+                "Test".to_string(),
+                vec![],
+                voting_ends_at,
+                Some(voting_ends_at + MIN_VOTING_PERIOD),
+                None,
+                None,
+            )
+            .unwrap();
+
+        let commitment =
+            GovernanceContract::vote_commitment(VoteChoice::Yes, 40, b"salt".as_slice());
+        contract
+            .execute_commit_vote(&mut state, voter, proposal_id, commitment)
+            .unwrap();
+
+        assert!(contract
+            .execute_commit_vote(&mut state, voter, proposal_id, commitment)
+            .is_err());
+    }
+
+    #[test]
+    fn test_double_reveal_fails() {
+        let contract = GovernanceContract::new();
+
+        let mut accounts = HashMap::new();
+        let mut storage = HashMap::new();
+
+        let kp = Keypair::generate();
+        let voter = Address::from_public_key(kp.public_key());
+
+        let mut state = ContractState::new(&mut accounts, &mut storage);
+        contract.save_total_voting_power(&mut state, 100);
+
+        let now = crate::types::now_millis();
+        let voting_ends_at = now + MIN_VOTING_PERIOD + 1000;
+        let proposal_id = contract
+            .execute_create_proposal(
+                &mut state,
+                voter,
+                "Test".to_string(),
+                "Test".to_string(),
+                vec![],
+                voting_ends_at,
+                Some(voting_ends_at + MIN_VOTING_PERIOD),
+                None,
+                None,
+            )
+            .unwrap();
+
+        let salt = b"salt".to_vec();
+        let commitment = GovernanceContract::vote_commitment(VoteChoice::Yes, 40, &salt);
+        contract
+            .execute_commit_vote(&mut state, voter, proposal_id, commitment)
+            .unwrap();
+
+        let mut proposal = contract
+            .load_proposal(&state, &proposal_id)
+            .unwrap()
+            .unwrap();
+        proposal.voting_ends_at = now - 1;
+        contract.save_proposal(&mut state, &proposal);
+
+        contract
+            .execute_reveal_vote(
+                &mut state,
+                voter,
+                proposal_id,
+                VoteChoice::Yes,
+                40,
+                salt.clone(),
+            )
+            .unwrap();
+
+        assert!(contract
+            .execute_reveal_vote(&mut state, voter, proposal_id, VoteChoice::Yes, 40, salt)
+            .is_err());
+    }
+
+    #[test]
+    fn test_direct_vote_rejected_on_commit_reveal_proposal() {
+        let contract = GovernanceContract::new();
+
+        let mut accounts = HashMap::new();
+        let mut storage = HashMap::new();
+
+        let kp = Keypair::generate();
+        let voter = Address::from_public_key(kp.public_key());
+
+        let mut state = ContractState::new(&mut accounts, &mut storage);
+        contract.save_total_voting_power(&mut state, 100);
+
+        let now = crate::types::now_millis();
+        let voting_ends_at = now + MIN_VOTING_PERIOD + 1000;
+        let proposal_id = contract
+            .execute_create_proposal(
+                &mut state,
+                voter,
+                "Test".to_string(),
+                "Test".to_string(),
+                vec![],
+                voting_ends_at,
+                Some(voting_ends_at + MIN_VOTING_PERIOD),
+                None,
+                None,
+            )
+            .unwrap();
+
+        assert!(contract
+            .execute_vote(&mut state, voter, proposal_id, VoteChoice::Yes, 40)
+            .is_err());
+    }
+
+    #[test]
+    fn test_execute_proposal_waits_for_reveal_period() {
+        let contract = GovernanceContract::new();
+
+        let mut accounts = HashMap::new();
+        let mut storage = HashMap::new();
+
+        let kp = Keypair::generate();
+        let voter = Address::from_public_key(kp.public_key());
+
+        let mut state = ContractState::new(&mut accounts, &mut storage);
+        contract.save_total_voting_power(&mut state, 100);
+
+        let now = crate::types::now_millis();
+        let voting_ends_at = now + MIN_VOTING_PERIOD + 1000;
+        let proposal_id = contract
+            .execute_create_proposal(
+                &mut state,
+                voter,
+                "Test".to_string(),
+                "Test".to_string(),
+                vec![],
+                voting_ends_at,
+                Some(voting_ends_at + MIN_VOTING_PERIOD),
+                None,
+                None,
+            )
+            .unwrap();
+
+        let salt = b"salt".to_vec();
+        let commitment = GovernanceContract::vote_commitment(VoteChoice::Yes, 80, &salt);
+        contract
+            .execute_commit_vote(&mut state, voter, proposal_id, commitment)
+            .unwrap();
+
+        // Close the commit window but leave the reveal window open.
+        let mut proposal = contract
+            .load_proposal(&state, &proposal_id)
+            .unwrap()
+            .unwrap();
+        proposal.voting_ends_at = now - 1;
+        contract.save_proposal(&mut state, &proposal);
+
+        // Voting period has ended, but the reveal period has not — refuse to run.
+        assert!(contract.execute_proposal(&mut state, proposal_id).is_err());
+
+        contract
+            .execute_reveal_vote(&mut state, voter, proposal_id, VoteChoice::Yes, 80, salt)
+            .unwrap();
+
+        // Still within the reveal window: execute_proposal should still refuse
+        // since reveal_ends_at hasn't passed yet.
+        assert!(contract.execute_proposal(&mut state, proposal_id).is_err());
+
+        // Now close the reveal window too.
+        let mut proposal = contract
+            .load_proposal(&state, &proposal_id)
+            .unwrap()
+            .unwrap();
+        proposal.reveal_ends_at = Some(now - 1);
+        contract.save_proposal(&mut state, &proposal);
+
+        contract.execute_proposal(&mut state, proposal_id).unwrap();
+
+        let proposal = contract
+            .load_proposal(&state, &proposal_id)
+            .unwrap()
+            .unwrap();
+        assert_eq!(proposal.status, ProposalStatus::Executed);
+    }
+
+    #[test]
+    fn test_on_deploy_initializes_storage() {
+        let contract = GovernanceContract::new();
+        let mut accounts = HashMap::new();
+        let mut storage = HashMap::new();
+        let mut state = ContractState::new(&mut accounts, &mut storage);
+
+        contract.on_deploy(&mut state, &[]).unwrap();
+
+        assert_eq!(contract.load_total_voting_power(&state).unwrap(), 0);
+        assert!(contract.load_proposal_index(&state).unwrap().is_empty());
+        assert_eq!(
+            contract.load_voting_config(&state).unwrap(),
+            VotingConfig::default()
+        );
+        assert!(contract.load_funding_index(&state).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_continuous_funding_disburses_within_window() {
+        let contract = GovernanceContract::new();
+
+        let mut accounts = HashMap::new();
+        let mut storage = HashMap::new();
+
+        let kp = Keypair::generate();
+        let recipient = Address::from_public_key(kp.public_key());
+        let treasury = Address::from_bytes([0; 20]);
+
+        accounts.insert(
+            treasury,
+            crate::state::AccountState::new(HclawAmount::from_hclaw(1000)),
+        );
+
+        let mut state = ContractState::new(&mut accounts, &mut storage);
+
+        contract
+            .execute_governance_action(
+                &mut state,
+                &GovernanceAction::ContinuousFunding {
+                    recipient,
+                    amount_per_epoch: HclawAmount::from_hclaw(10),
+                    start: 5,
+                    end: 7,
+                },
+            )
+            .unwrap();
+
+        // Before the window opens: no payout.
+        contract.execute_disburse_funding(&mut state, 4).unwrap();
+        assert_eq!(state.balance(&recipient).unwrap(), HclawAmount::ZERO);
+
+        // Inside the window: pays out once per epoch.
+        contract.execute_disburse_funding(&mut state, 5).unwrap();
+        assert_eq!(
+            state.balance(&recipient).unwrap(),
+            HclawAmount::from_hclaw(10)
+        );
+
+        contract.execute_disburse_funding(&mut state, 6).unwrap();
+        assert_eq!(
+            state.balance(&recipient).unwrap(),
+            HclawAmount::from_hclaw(20)
+        );
+
+        // After the window closes: no further payout.
+        contract.execute_disburse_funding(&mut state, 8).unwrap();
+        assert_eq!(
+            state.balance(&recipient).unwrap(),
+            HclawAmount::from_hclaw(20)
+        );
+    }
+
+    #[test]
+    fn test_disburse_funding_does_not_double_pay_same_epoch() {
+        let contract = GovernanceContract::new();
+
+        let mut accounts = HashMap::new();
+        let mut storage = HashMap::new();
+
+        let kp = Keypair::generate();
+        let recipient = Address::from_public_key(kp.public_key());
+        let treasury = Address::from_bytes([0; 20]);
+
+        accounts.insert(
+            treasury,
+            crate::state::AccountState::new(HclawAmount::from_hclaw(1000)),
+        );
+
+        let mut state = ContractState::new(&mut accounts, &mut storage);
+
+        contract
+            .execute_governance_action(
+                &mut state,
+                &GovernanceAction::ContinuousFunding {
+                    recipient,
+                    amount_per_epoch: HclawAmount::from_hclaw(10),
+                    start: 1,
+                    end: 3,
+                },
+            )
+            .unwrap();
+
+        contract.execute_disburse_funding(&mut state, 1).unwrap();
+        contract.execute_disburse_funding(&mut state, 1).unwrap();
+
+        assert_eq!(
+            state.balance(&recipient).unwrap(),
+            HclawAmount::from_hclaw(10)
+        );
+    }
+
+    #[test]
+    fn test_stop_funding_halts_disbursement() {
+        let contract = GovernanceContract::new();
+
+        let mut accounts = HashMap::new();
+        let mut storage = HashMap::new();
+
+        let kp = Keypair::generate();
+        let recipient = Address::from_public_key(kp.public_key());
+        let treasury = Address::from_bytes([0; 20]);
+
+        accounts.insert(
+            treasury,
+            crate::state::AccountState::new(HclawAmount::from_hclaw(1000)),
+        );
+
+        let mut state = ContractState::new(&mut accounts, &mut storage);
+
+        contract
+            .execute_governance_action(
+                &mut state,
+                &GovernanceAction::ContinuousFunding {
+                    recipient,
+                    amount_per_epoch: HclawAmount::from_hclaw(10),
+                    start: 1,
+                    end: 10,
+                },
+            )
+            .unwrap();
+
+        let stream_id = contract.load_funding_index(&state).unwrap()[0];
+
+        contract.execute_disburse_funding(&mut state, 1).unwrap();
+        assert_eq!(
+            state.balance(&recipient).unwrap(),
+            HclawAmount::from_hclaw(10)
+        );
+
+        contract
+            .execute_governance_action(&mut state, &GovernanceAction::StopFunding { stream_id })
+            .unwrap();
+
+        contract.execute_disburse_funding(&mut state, 2).unwrap();
+        assert_eq!(
+            state.balance(&recipient).unwrap(),
+            HclawAmount::from_hclaw(10)
+        );
+    }
+
+    #[test]
+    fn test_config_update_only_affects_later_proposals() {
+        let contract = GovernanceContract::new();
+
+        let mut accounts = HashMap::new();
+        let mut storage = HashMap::new();
+
+        let kp = Keypair::generate();
+        let proposer = Address::from_public_key(kp.public_key());
+
+        let mut state = ContractState::new(&mut accounts, &mut storage);
+        contract.save_total_voting_power(&mut state, 10000);
+
+        let now = crate::types::now_millis();
+        let voting_ends = now + MIN_VOTING_PERIOD + 1000;
+        let early_proposal_id = contract
+            .execute_create_proposal(
+                &mut state,
+                proposer,
+                "Early".to_string(),
+                "Test".to_string(),
+                vec![],
+                voting_ends,
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+
+        contract
+            .execute_governance_action(
+                &mut state,
+                &crate::types::GovernanceAction::ConfigUpdate {
+                    min_voting_period: MIN_VOTING_PERIOD,
+                    quorum_percent: 50,
+                    approval_threshold: 80,
+                    execution_delay: 1000,
+                },
+            )
+            .unwrap();
+
+        let later_proposal_id = contract
+            .execute_create_proposal(
+                &mut state,
+                proposer,
+                "Later".to_string(),
+                "Test".to_string(),
+                vec![],
+                voting_ends,
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+
+        let early_proposal = contract
+            .load_proposal(&state, &early_proposal_id)
+            .unwrap()
+            .unwrap();
+        let later_proposal = contract
+            .load_proposal(&state, &later_proposal_id)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(early_proposal.voting_config, VotingConfig::default());
+        assert_eq!(
+            later_proposal.voting_config,
+            VotingConfig {
+                min_voting_period: MIN_VOTING_PERIOD,
+                quorum_percent: 50,
+                approval_threshold: 80,
+                execution_delay: 1000,
+            }
+        );
+    }
+
+    #[test]
+    fn test_execute_proposal_waits_for_execution_delay() {
+        let contract = GovernanceContract::new();
+
+        let mut accounts = HashMap::new();
+        let mut storage = HashMap::new();
+
+        let kp = Keypair::generate();
+        let voter = Address::from_public_key(kp.public_key());
+
+        let mut state = ContractState::new(&mut accounts, &mut storage);
+        contract.save_total_voting_power(&mut state, 100);
+        contract.save_voting_config(
+            &mut state,
+            &VotingConfig {
+                execution_delay: 10_000,
+                ..VotingConfig::default()
+            },
+        );
+
+        let now = crate::types::now_millis();
+        let voting_ends_at = now + MIN_VOTING_PERIOD + 1000;
+        let proposal_id = contract
+            .execute_create_proposal(
+                &mut state,
+                voter,
+                "Test".to_string(),
+                "Test".to_string(),
+                vec![],
+                voting_ends_at,
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+
+        contract
+            .execute_vote(&mut state, voter, proposal_id, VoteChoice::Yes, 100)
+            .unwrap();
+
+        let mut proposal = contract
+            .load_proposal(&state, &proposal_id)
+            .unwrap()
+            .unwrap();
+        // Voting period just ended, but the 10s execution delay hasn't.
+        proposal.voting_ends_at = now - 1;
+        contract.save_proposal(&mut state, &proposal);
+
+        assert!(contract.execute_proposal(&mut state, proposal_id).is_err());
+
+        let mut proposal = contract
+            .load_proposal(&state, &proposal_id)
+            .unwrap()
+            .unwrap();
+        proposal.voting_ends_at = now - 11_000;
+        contract.save_proposal(&mut state, &proposal);
+
+        contract.execute_proposal(&mut state, proposal_id).unwrap();
+    }
+
+    #[test]
+    fn test_conditional_spend_releases_once_witnessed() {
+        let contract = GovernanceContract::new();
+
+        let mut accounts = HashMap::new();
+        let mut storage = HashMap::new();
+
+        let kp = Keypair::generate();
+        let recipient = Address::from_public_key(kp.public_key());
+        let signer_kp = Keypair::generate();
+        let signer = Address::from_public_key(signer_kp.public_key());
+        let treasury = Address::from_bytes([0; 20]);
+
+        accounts.insert(
+            treasury,
+            crate::state::AccountState::new(HclawAmount::from_hclaw(1000)),
+        );
+
+        let mut state = ContractState::new(&mut accounts, &mut storage);
+
+        contract
+            .execute_governance_action(
+                &mut state,
+                &GovernanceAction::ConditionalSpend {
+                    recipient,
+                    amount: HclawAmount::from_hclaw(50),
+                    conditions: vec![SpendCondition::Signature(signer)],
+                    expires_at: None,
+                },
+            )
+            .unwrap();
+
+        let spend_id = contract.load_spend_index(&state).unwrap()[0];
+
+        // Escrowed funds sit under the contract's own address until released.
+        assert_eq!(
+            state.balance(&contract.contract_address()).unwrap(),
+            HclawAmount::from_hclaw(50)
+        );
+        assert_eq!(state.balance(&recipient).unwrap(), HclawAmount::ZERO);
+
+        contract
+            .execute_apply_witness(&mut state, signer, spend_id, Witness::Signed { signer })
+            .unwrap();
+
+        assert_eq!(
+            state.balance(&recipient).unwrap(),
+            HclawAmount::from_hclaw(50)
+        );
+        let spend = contract
+            .load_conditional_spend(&state, &spend_id)
+            .unwrap()
+            .unwrap();
+        assert!(spend.released);
+    }
+
+    #[test]
+    fn test_conditional_spend_waits_for_all_conditions() {
+        let contract = GovernanceContract::new();
+
+        let mut accounts = HashMap::new();
+        let mut storage = HashMap::new();
+
+        let kp = Keypair::generate();
+        let recipient = Address::from_public_key(kp.public_key());
+        let signer1_kp = Keypair::generate();
+        let signer2_kp = Keypair::generate();
+        let signer1 = Address::from_public_key(signer1_kp.public_key());
+        let signer2 = Address::from_public_key(signer2_kp.public_key());
+        let treasury = Address::from_bytes([0; 20]);
+
+        accounts.insert(
+            treasury,
+            crate::state::AccountState::new(HclawAmount::from_hclaw(1000)),
+        );
+
+        let mut state = ContractState::new(&mut accounts, &mut storage);
+
+        contract
+            .execute_governance_action(
+                &mut state,
+                &GovernanceAction::ConditionalSpend {
+                    recipient,
+                    amount: HclawAmount::from_hclaw(50),
+                    conditions: vec![
+                        SpendCondition::Signature(signer1),
+                        SpendCondition::Signature(signer2),
+                    ],
+                    expires_at: None,
+                },
+            )
+            .unwrap();
+
+        let spend_id = contract.load_spend_index(&state).unwrap()[0];
+
+        contract
+            .execute_apply_witness(
+                &mut state,
+                signer1,
+                spend_id,
+                Witness::Signed { signer: signer1 },
+            )
+            .unwrap();
+
+        // Only one of two conditions witnessed: still held in escrow.
+        assert_eq!(state.balance(&recipient).unwrap(), HclawAmount::ZERO);
+        let spend = contract
+            .load_conditional_spend(&state, &spend_id)
+            .unwrap()
+            .unwrap();
+        assert!(!spend.released);
+
+        contract
+            .execute_apply_witness(
+                &mut state,
+                signer2,
+                spend_id,
+                Witness::Signed { signer: signer2 },
+            )
+            .unwrap();
+
+        assert_eq!(
+            state.balance(&recipient).unwrap(),
+            HclawAmount::from_hclaw(50)
+        );
+    }
+
+    #[test]
+    fn test_conditional_spend_or_condition_satisfied_by_either_branch() {
+        let contract = GovernanceContract::new();
+
+        let mut accounts = HashMap::new();
+        let mut storage = HashMap::new();
+
+        let kp = Keypair::generate();
+        let recipient = Address::from_public_key(kp.public_key());
+        let signer_kp = Keypair::generate();
+        let signer = Address::from_public_key(signer_kp.public_key());
+        let other_kp = Keypair::generate();
+        let other = Address::from_public_key(other_kp.public_key());
+        let treasury = Address::from_bytes([0; 20]);
+
+        accounts.insert(
+            treasury,
+            crate::state::AccountState::new(HclawAmount::from_hclaw(1000)),
+        );
+
+        let mut state = ContractState::new(&mut accounts, &mut storage);
+
+        contract
+            .execute_governance_action(
+                &mut state,
+                &GovernanceAction::ConditionalSpend {
+                    recipient,
+                    amount: HclawAmount::from_hclaw(50),
+                    conditions: vec![SpendCondition::Or(
+                        Box::new(SpendCondition::Signature(signer)),
+                        Box::new(SpendCondition::Signature(other)),
+                    )],
+                    expires_at: None,
+                },
+            )
+            .unwrap();
+
+        let spend_id = contract.load_spend_index(&state).unwrap()[0];
+
+        // Witnessing either branch is enough to satisfy the Or.
+        contract
+            .execute_apply_witness(
+                &mut state,
+                other,
+                spend_id,
+                Witness::Signed { signer: other },
+            )
+            .unwrap();
+
+        assert_eq!(
+            state.balance(&recipient).unwrap(),
+            HclawAmount::from_hclaw(50)
+        );
+    }
+
+    #[test]
+    fn test_apply_witness_rejects_mismatched_signer() {
+        let contract = GovernanceContract::new();
+
+        let mut accounts = HashMap::new();
+        let mut storage = HashMap::new();
+
+        let kp = Keypair::generate();
+        let recipient = Address::from_public_key(kp.public_key());
+        let signer_kp = Keypair::generate();
+        let signer = Address::from_public_key(signer_kp.public_key());
+        let caller_kp = Keypair::generate();
+        let caller = Address::from_public_key(caller_kp.public_key());
+        let treasury = Address::from_bytes([0; 20]);
+
+        accounts.insert(
+            treasury,
+            crate::state::AccountState::new(HclawAmount::from_hclaw(1000)),
+        );
+
+        let mut state = ContractState::new(&mut accounts, &mut storage);
+
+        contract
+            .execute_governance_action(
+                &mut state,
+                &GovernanceAction::ConditionalSpend {
+                    recipient,
+                    amount: HclawAmount::from_hclaw(50),
+                    conditions: vec![SpendCondition::Signature(signer)],
+                    expires_at: None,
+                },
+            )
+            .unwrap();
+
+        let spend_id = contract.load_spend_index(&state).unwrap()[0];
+
+        assert!(matches!(
+            contract
+                .execute_apply_witness(&mut state, caller, spend_id, Witness::Signed { signer },)
+                .unwrap_err(),
+            ContractError::Unauthorized(_)
+        ));
+    }
+
+    #[test]
+    fn test_apply_witness_rejects_already_settled_spend() {
+        let contract = GovernanceContract::new();
+
+        let mut accounts = HashMap::new();
+        let mut storage = HashMap::new();
+
+        let kp = Keypair::generate();
+        let recipient = Address::from_public_key(kp.public_key());
+        let signer_kp = Keypair::generate();
+        let signer = Address::from_public_key(signer_kp.public_key());
+        let treasury = Address::from_bytes([0; 20]);
+
+        accounts.insert(
+            treasury,
+            crate::state::AccountState::new(HclawAmount::from_hclaw(1000)),
+        );
+
+        let mut state = ContractState::new(&mut accounts, &mut storage);
+
+        contract
+            .execute_governance_action(
+                &mut state,
+                &GovernanceAction::ConditionalSpend {
+                    recipient,
+                    amount: HclawAmount::from_hclaw(50),
+                    conditions: vec![SpendCondition::Signature(signer)],
+                    expires_at: None,
+                },
+            )
+            .unwrap();
+
+        let spend_id = contract.load_spend_index(&state).unwrap()[0];
+
+        contract
+            .execute_apply_witness(&mut state, signer, spend_id, Witness::Signed { signer })
+            .unwrap();
+
+        assert!(contract
+            .execute_apply_witness(&mut state, signer, spend_id, Witness::Signed { signer })
+            .is_err());
+    }
+
+    #[test]
+    fn test_cancel_conditional_spend_refunds_after_expiry() {
+        let contract = GovernanceContract::new();
+
+        let mut accounts = HashMap::new();
+        let mut storage = HashMap::new();
+
+        let kp = Keypair::generate();
+        let recipient = Address::from_public_key(kp.public_key());
+        let signer_kp = Keypair::generate();
+        let signer = Address::from_public_key(signer_kp.public_key());
+        let treasury = Address::from_bytes([0; 20]);
+
+        accounts.insert(
+            treasury,
+            crate::state::AccountState::new(HclawAmount::from_hclaw(1000)),
+        );
+
+        let mut state = ContractState::new(&mut accounts, &mut storage);
+
+        contract
+            .execute_governance_action(
+                &mut state,
+                &GovernanceAction::ConditionalSpend {
+                    recipient,
+                    amount: HclawAmount::from_hclaw(50),
+                    conditions: vec![SpendCondition::Signature(signer)],
+                    expires_at: Some(crate::types::now_millis() - 1),
+                },
+            )
+            .unwrap();
+
+        let spend_id = contract.load_spend_index(&state).unwrap()[0];
+
+        contract
+            .execute_cancel_conditional_spend(&mut state, spend_id)
+            .unwrap();
+
+        assert_eq!(
+            state.balance(&treasury).unwrap(),
+            HclawAmount::from_hclaw(1000)
+        );
+        let spend = contract
+            .load_conditional_spend(&state, &spend_id)
+            .unwrap()
+            .unwrap();
+        assert!(spend.cancelled);
+    }
+
+    #[test]
+    fn test_cancel_conditional_spend_rejects_before_expiry() {
+        let contract = GovernanceContract::new();
+
+        let mut accounts = HashMap::new();
+        let mut storage = HashMap::new();
+
+        let kp = Keypair::generate();
+        let recipient = Address::from_public_key(kp.public_key());
+        let signer_kp = Keypair::generate();
+        let signer = Address::from_public_key(signer_kp.public_key());
+        let treasury = Address::from_bytes([0; 20]);
+
+        accounts.insert(
+            treasury,
+            crate::state::AccountState::new(HclawAmount::from_hclaw(1000)),
+        );
+
+        let mut state = ContractState::new(&mut accounts, &mut storage);
+
+        contract
+            .execute_governance_action(
+                &mut state,
+                &GovernanceAction::ConditionalSpend {
+                    recipient,
+                    amount: HclawAmount::from_hclaw(50),
+                    conditions: vec![SpendCondition::Signature(signer)],
+                    expires_at: Some(crate::types::now_millis() + MIN_VOTING_PERIOD),
+                },
+            )
+            .unwrap();
+
+        let spend_id = contract.load_spend_index(&state).unwrap()[0];
+
+        assert!(contract
+            .execute_cancel_conditional_spend(&mut state, spend_id)
+            .is_err());
+    }
+
+    #[test]
+    fn test_cancel_conditional_spend_rejects_once_satisfied() {
+        let contract = GovernanceContract::new();
+
+        let mut accounts = HashMap::new();
+        let mut storage = HashMap::new();
+
+        let kp = Keypair::generate();
+        let recipient = Address::from_public_key(kp.public_key());
+        let treasury = Address::from_bytes([0; 20]);
+
+        accounts.insert(
+            treasury,
+            crate::state::AccountState::new(HclawAmount::from_hclaw(1000)),
+        );
+
+        let mut state = ContractState::new(&mut accounts, &mut storage);
+
+        let past = crate::types::now_millis() - 1;
+        contract
+            .execute_governance_action(
+                &mut state,
+                &GovernanceAction::ConditionalSpend {
+                    recipient,
+                    amount: HclawAmount::from_hclaw(50),
+                    conditions: vec![SpendCondition::AfterTimestamp(past)],
+                    expires_at: Some(past),
+                },
+            )
+            .unwrap();
+
+        let spend_id = contract.load_spend_index(&state).unwrap()[0];
+
+        // The AfterTimestamp condition is already satisfied, even though the
+        // expiry has also lapsed — cancellation should refuse in favor of a
+        // witness-driven release instead.
+        assert!(contract
+            .execute_cancel_conditional_spend(&mut state, spend_id)
+            .is_err());
+    }
+
+    #[test]
+    fn test_on_deploy_initializes_spend_index() {
+        let contract = GovernanceContract::new();
+        let mut accounts = HashMap::new();
+        let mut storage = HashMap::new();
+        let mut state = ContractState::new(&mut accounts, &mut storage);
+
+        contract.on_deploy(&mut state, &[]).unwrap();
+
+        assert!(contract.load_spend_index(&state).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_list_proposals_paginates_in_creation_order() {
+        let contract = GovernanceContract::new();
+        let mut accounts = HashMap::new();
+        let mut storage = HashMap::new();
+
+        let kp = Keypair::generate();
+        let proposer = Address::from_public_key(kp.public_key());
+
+        let mut state = ContractState::new(&mut accounts, &mut storage);
+        contract.save_total_voting_power(&mut state, 100);
+
+        let now = crate::types::now_millis();
+        let voting_ends = now + MIN_VOTING_PERIOD + 1000;
+        let mut ids = Vec::new();
+        for i in 0..3 {
+            let id = contract
+                .execute_create_proposal(
+                    &mut state,
+                    proposer,
+                    format!("Proposal {i}"),
+                    "Test".to_string(),
+                    vec![],
+                    voting_ends,
+                    None,
+                    None,
+                    None,
+                )
+                .unwrap();
+            ids.push(id);
+        }
+
+        let first_page = contract.list_proposals(&state, None, 2).unwrap();
+        assert_eq!(first_page.len(), 2);
+        assert_eq!(first_page[0].id, ids[0]);
+        assert_eq!(first_page[1].id, ids[1]);
+
+        let second_page = contract.list_proposals(&state, Some(ids[1]), 2).unwrap();
+        assert_eq!(second_page.len(), 1);
+        assert_eq!(second_page[0].id, ids[2]);
+    }
+
+    #[test]
+    fn test_list_proposals_surfaces_effective_status_without_execute() {
+        let contract = GovernanceContract::new();
+        let mut accounts = HashMap::new();
+        let mut storage = HashMap::new();
+
+        let kp = Keypair::generate();
+        let voter = Address::from_public_key(kp.public_key());
+
+        let mut state = ContractState::new(&mut accounts, &mut storage);
+        contract.save_total_voting_power(&mut state, 100);
+
+        let now = crate::types::now_millis();
+        let proposal_id = contract
+            .execute_create_proposal(
+                &mut state,
+                voter,
+                "Test".to_string(),
+                "Test".to_string(),
+                vec![],
+                now + MIN_VOTING_PERIOD + 1000,
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+
+        contract
+            .execute_vote(&mut state, voter, proposal_id, VoteChoice::Yes, 50)
+            .unwrap();
+
+        // Close the voting period without ever calling Execute.
+        let mut proposal = contract
+            .load_proposal(&state, &proposal_id)
+            .unwrap()
+            .unwrap();
+        proposal.voting_ends_at = now - 1;
+        contract.save_proposal(&mut state, &proposal);
+
+        // Still stored as Active, but quorum (30%) and approval (66%) are
+        // both met by the 50/100 Yes vote, so it should list as Passed.
+        let stored = contract
+            .load_proposal(&state, &proposal_id)
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored.status, ProposalStatus::Active);
+
+        let listed = contract.list_proposals(&state, None, 10).unwrap();
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0].status, ProposalStatus::Passed);
+    }
+
+    #[test]
+    fn test_list_proposals_by_status_filters() {
+        let contract = GovernanceContract::new();
+        let mut accounts = HashMap::new();
+        let mut storage = HashMap::new();
+
+        let kp = Keypair::generate();
+        let voter = Address::from_public_key(kp.public_key());
+
+        let mut state = ContractState::new(&mut accounts, &mut storage);
+        contract.save_total_voting_power(&mut state, 100);
+
+        let now = crate::types::now_millis();
+        let voting_ends = now + MIN_VOTING_PERIOD + 1000;
+
+        let active_id = contract
+            .execute_create_proposal(
+                &mut state,
+                voter,
+                "Active".to_string(),
+                "Test".to_string(),
+                vec![],
+                voting_ends,
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+
+        let rejected_id = contract
+            .execute_create_proposal(
+                &mut state,
+                voter,
+                "Rejected".to_string(),
+                "Test".to_string(),
+                vec![],
+                voting_ends,
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+        let mut rejected = contract
+            .load_proposal(&state, &rejected_id)
+            .unwrap()
+            .unwrap();
+        // Enough votes to clear the 30% quorum, all against, so it lands on
+        // Rejected rather than QuorumNotMet.
+        rejected.no_votes = 40;
+        rejected.voting_ends_at = now - 1;
+        contract.save_proposal(&mut state, &rejected);
+
+        let active_only = contract
+            .list_proposals_by_status(&state, ProposalStatus::Active, None, 10)
+            .unwrap();
+        assert_eq!(active_only.len(), 1);
+        assert_eq!(active_only[0].id, active_id);
+
+        let rejected_only = contract
+            .list_proposals_by_status(&state, ProposalStatus::Rejected, None, 10)
+            .unwrap();
+        assert_eq!(rejected_only.len(), 1);
+        assert_eq!(rejected_only[0].id, rejected_id);
+    }
+
+    #[test]
+    fn test_delegate_votes_on_behalf_of_delegator() {
+        let contract = GovernanceContract::new();
+        let mut accounts = HashMap::new();
+        let mut storage = HashMap::new();
+
+        let delegator_kp = Keypair::generate();
+        let delegator = Address::from_public_key(delegator_kp.public_key());
+        let delegate_kp = Keypair::generate();
+        let delegate = Address::from_public_key(delegate_kp.public_key());
+
+        let mut state = ContractState::new(&mut accounts, &mut storage);
+        contract.save_total_voting_power(&mut state, 100);
+
+        contract
+            .execute_set_delegate(&mut state, delegator, Some(delegate))
+            .unwrap();
+
+        let now = crate::types::now_millis();
+        let proposal_id = contract
+            .execute_create_proposal(
+                &mut state,
+                delegate,
+                "Test".to_string(),
+                "Test".to_string(),
+                vec![],
+                now + MIN_VOTING_PERIOD + 1000,
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+
+        // Delegate casts one vote covering its own power plus the
+        // delegator's.
+        contract
+            .execute_vote(&mut state, delegate, proposal_id, VoteChoice::Yes, 70)
+            .unwrap();
+
+        let proposal = contract
+            .load_proposal(&state, &proposal_id)
+            .unwrap()
+            .unwrap();
+        assert_eq!(proposal.yes_votes, 70);
+        assert!(proposal.voters.contains_key(&delegate));
+        assert!(proposal.voters.contains_key(&delegator));
+    }
+
+    #[test]
+    fn test_delegated_address_cannot_vote_directly() {
+        let contract = GovernanceContract::new();
+        let mut accounts = HashMap::new();
+        let mut storage = HashMap::new();
+
+        let delegator_kp = Keypair::generate();
+        let delegator = Address::from_public_key(delegator_kp.public_key());
+        let delegate_kp = Keypair::generate();
+        let delegate = Address::from_public_key(delegate_kp.public_key());
+
+        let mut state = ContractState::new(&mut accounts, &mut storage);
+        contract.save_total_voting_power(&mut state, 100);
+
+        contract
+            .execute_set_delegate(&mut state, delegator, Some(delegate))
+            .unwrap();
+
+        let now = crate::types::now_millis();
+        let proposal_id = contract
+            .execute_create_proposal(
+                &mut state,
+                delegate,
+                "Test".to_string(),
+                "Test".to_string(),
+                vec![],
+                now + MIN_VOTING_PERIOD + 1000,
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+
+        assert!(contract
+            .execute_vote(&mut state, delegator, proposal_id, VoteChoice::Yes, 30)
+            .is_err());
+    }
+
+    #[test]
+    fn test_revoked_delegator_cannot_revote_after_delegate_already_voted() {
+        let contract = GovernanceContract::new();
+        let mut accounts = HashMap::new();
+        let mut storage = HashMap::new();
+
+        let delegator_kp = Keypair::generate();
+        let delegator = Address::from_public_key(delegator_kp.public_key());
+        let delegate_kp = Keypair::generate();
+        let delegate = Address::from_public_key(delegate_kp.public_key());
+
+        let mut state = ContractState::new(&mut accounts, &mut storage);
+        contract.save_total_voting_power(&mut state, 100);
+
+        contract
+            .execute_set_delegate(&mut state, delegator, Some(delegate))
+            .unwrap();
+
+        let now = crate::types::now_millis();
+        let proposal_id = contract
+            .execute_create_proposal(
+                &mut state,
+                delegate,
+                "Test".to_string(),
+                "Test".to_string(),
+                vec![],
+                now + MIN_VOTING_PERIOD + 1000,
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+
+        contract
+            .execute_vote(&mut state, delegate, proposal_id, VoteChoice::Yes, 70)
+            .unwrap();
+
+        // Revoke after the delegate has already voted on the delegator's
+        // behalf — the delegator must not get a second bite at the proposal.
+        contract
+            .execute_set_delegate(&mut state, delegator, None)
+            .unwrap();
+
+        assert!(contract
+            .execute_vote(&mut state, delegator, proposal_id, VoteChoice::No, 30)
+            .is_err());
+    }
+
+    #[test]
+    fn test_set_delegate_rejects_self_delegation() {
+        let contract = GovernanceContract::new();
+        let mut accounts = HashMap::new();
+        let mut storage = HashMap::new();
+        let mut state = ContractState::new(&mut accounts, &mut storage);
+
+        let kp = Keypair::generate();
+        let addr = Address::from_public_key(kp.public_key());
+
+        assert!(contract
+            .execute_set_delegate(&mut state, addr, Some(addr))
+            .is_err());
+    }
+
+    #[test]
+    fn test_set_delegate_moves_between_delegates() {
+        let contract = GovernanceContract::new();
+        let mut accounts = HashMap::new();
+        let mut storage = HashMap::new();
+        let mut state = ContractState::new(&mut accounts, &mut storage);
+
+        let delegator_kp = Keypair::generate();
+        let delegator = Address::from_public_key(delegator_kp.public_key());
+        let first_kp = Keypair::generate();
+        let first_delegate = Address::from_public_key(first_kp.public_key());
+        let second_kp = Keypair::generate();
+        let second_delegate = Address::from_public_key(second_kp.public_key());
+
+        contract
+            .execute_set_delegate(&mut state, delegator, Some(first_delegate))
+            .unwrap();
+        assert_eq!(
+            contract.load_delegators(&state, &first_delegate).unwrap(),
+            vec![delegator]
+        );
+
+        contract
+            .execute_set_delegate(&mut state, delegator, Some(second_delegate))
+            .unwrap();
+
+        assert!(contract
+            .load_delegators(&state, &first_delegate)
+            .unwrap()
+            .is_empty());
+        assert_eq!(
+            contract.load_delegators(&state, &second_delegate).unwrap(),
+            vec![delegator]
+        );
+        assert_eq!(
+            contract.load_delegate(&state, &delegator).unwrap(),
+            Some(second_delegate)
+        );
+    }
+
+    #[test]
+    fn test_on_deploy_records_chairperson_from_init_data() {
+        let contract = GovernanceContract::new();
+        let mut accounts = HashMap::new();
+        let mut storage = HashMap::new();
+        let mut state = ContractState::new(&mut accounts, &mut storage);
+
+        let kp = Keypair::generate();
+        let chairperson = Address::from_public_key(kp.public_key());
+        let init_data = bincode::serialize(&chairperson).unwrap();
+
+        contract.on_deploy(&mut state, &init_data).unwrap();
+
+        assert_eq!(
+            contract.load_chairperson(&state).unwrap(),
+            Some(chairperson)
+        );
+    }
+
+    #[test]
+    fn test_chairperson_can_register_and_revoke_voters() {
+        let contract = GovernanceContract::new();
+        let mut accounts = HashMap::new();
+        let mut storage = HashMap::new();
+        let mut state = ContractState::new(&mut accounts, &mut storage);
+
+        let chair_kp = Keypair::generate();
+        let chairperson = Address::from_public_key(chair_kp.public_key());
+        contract.save_chairperson(&mut state, chairperson);
+
+        let voter_kp = Keypair::generate();
+        let voter = Address::from_public_key(voter_kp.public_key());
+
+        contract
+            .execute_register_voter(&mut state, chairperson, voter, 42)
+            .unwrap();
+        assert_eq!(
+            contract.load_registered_voter(&state, &voter).unwrap(),
+            Some(42)
+        );
+
+        contract
+            .execute_revoke_voter(&mut state, chairperson, voter)
+            .unwrap();
+        assert_eq!(
+            contract.load_registered_voter(&state, &voter).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_register_voter_rejects_non_chairperson() {
+        let contract = GovernanceContract::new();
+        let mut accounts = HashMap::new();
+        let mut storage = HashMap::new();
+        let mut state = ContractState::new(&mut accounts, &mut storage);
+
+        let chair_kp = Keypair::generate();
+        let chairperson = Address::from_public_key(chair_kp.public_key());
+        contract.save_chairperson(&mut state, chairperson);
+
+        let impostor_kp = Keypair::generate();
+        let impostor = Address::from_public_key(impostor_kp.public_key());
+        let voter_kp = Keypair::generate();
+        let voter = Address::from_public_key(voter_kp.public_key());
+
+        assert!(matches!(
+            contract
+                .execute_register_voter(&mut state, impostor, voter, 10)
+                .unwrap_err(),
+            ContractError::Unauthorized(_)
+        ));
+    }
+
+    #[test]
+    fn test_cast_vote_uses_registered_weight() {
+        let contract = GovernanceContract::new();
+        let mut accounts = HashMap::new();
+        let mut storage = HashMap::new();
+        let mut state = ContractState::new(&mut accounts, &mut storage);
+
+        let chair_kp = Keypair::generate();
+        let chairperson = Address::from_public_key(chair_kp.public_key());
+        contract.save_chairperson(&mut state, chairperson);
+        contract.save_total_voting_power(&mut state, 100);
+
+        let voter_kp = Keypair::generate();
+        let voter = Address::from_public_key(voter_kp.public_key());
+        contract
+            .execute_register_voter(&mut state, chairperson, voter, 60)
+            .unwrap();
+
+        let now = crate::types::now_millis();
+        let proposal_id = contract
+            .execute_create_proposal(
+                &mut state,
+                voter,
+                "Test".to_string(),
+                "Test".to_string(),
+                vec![],
+                now + MIN_VOTING_PERIOD + 1000,
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+
+        // Supplying a bogus weight on the generic Vote path is irrelevant —
+        // CastVote always uses the chairperson-assigned weight.
+        contract
+            .execute_cast_vote(&mut state, voter, proposal_id, VoteChoice::Yes)
+            .unwrap();
+
+        let proposal = contract
+            .load_proposal(&state, &proposal_id)
+            .unwrap()
+            .unwrap();
+        assert_eq!(proposal.yes_votes, 60);
+    }
+
+    #[test]
+    fn test_cast_vote_rejects_unregistered_account() {
+        let contract = GovernanceContract::new();
+        let mut accounts = HashMap::new();
+        let mut storage = HashMap::new();
+        let mut state = ContractState::new(&mut accounts, &mut storage);
+        contract.save_total_voting_power(&mut state, 100);
+
+        let kp = Keypair::generate();
+        let voter = Address::from_public_key(kp.public_key());
+
+        let now = crate::types::now_millis();
+        let proposal_id = contract
+            .execute_create_proposal(
+                &mut state,
+                voter,
+                "Test".to_string(),
+                "Test".to_string(),
+                vec![],
+                now + MIN_VOTING_PERIOD + 1000,
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+
+        assert!(matches!(
+            contract
+                .execute_cast_vote(&mut state, voter, proposal_id, VoteChoice::Yes)
+                .unwrap_err(),
+            ContractError::Unauthorized(_)
+        ));
+    }
+
+    #[test]
+    fn test_add_collateral_moves_treasury_funds_into_contract_custody() {
+        let contract = GovernanceContract::new();
+        let mut accounts = HashMap::new();
+        let mut storage = HashMap::new();
+
+        let treasury = Address::from_bytes([0; 20]);
+        accounts.insert(
+            treasury,
+            crate::state::AccountState::new(HclawAmount::from_hclaw(1000)),
+        );
+
+        let mut state = ContractState::new(&mut accounts, &mut storage);
+
+        contract
+            .execute_governance_action(
+                &mut state,
+                &GovernanceAction::AddCollateral {
+                    amount: HclawAmount::from_hclaw(200),
+                },
+            )
+            .unwrap();
+
+        assert_eq!(
+            state.balance(&contract.contract_address()).unwrap(),
+            HclawAmount::from_hclaw(200)
+        );
+        assert_eq!(
+            state.balance(&treasury).unwrap(),
+            HclawAmount::from_hclaw(800)
+        );
+    }
+
+    #[test]
+    fn test_remove_voter_action_deregisters_via_proposal() {
+        let contract = GovernanceContract::new();
+        let mut accounts = HashMap::new();
+        let mut storage = HashMap::new();
+        let mut state = ContractState::new(&mut accounts, &mut storage);
+
+        let kp = Keypair::generate();
+        let voter = Address::from_public_key(kp.public_key());
+        contract.save_registered_voter(&mut state, &voter, 10);
+
+        contract
+            .execute_governance_action(
+                &mut state,
+                &GovernanceAction::RemoveVoter { account: voter },
+            )
+            .unwrap();
+
+        assert_eq!(
+            contract.load_registered_voter(&state, &voter).unwrap(),
+            None
+        );
+    }
+
+    fn block_ctx(height: u64) -> BlockContext {
+        BlockContext {
+            height,
+            hash: Hash::ZERO,
+            timestamp: 0,
+        }
+    }
+
+    #[test]
+    fn test_cast_vote_rejects_before_start_block() {
+        let contract = GovernanceContract::new();
+        let mut accounts = HashMap::new();
+        let mut storage = HashMap::new();
+        let mut state = ContractState::new(&mut accounts, &mut storage);
+
+        let kp = Keypair::generate();
+        let voter = Address::from_public_key(kp.public_key());
+        contract.save_registered_voter(&mut state, &voter, 10);
+
+        let now = crate::types::now_millis();
+        let proposal_id = contract
+            .execute_create_proposal(
+                &mut state,
+                voter,
+                "Test".to_string(),
+                "Test".to_string(),
+                vec![],
+                now + MIN_VOTING_PERIOD + 1000,
+                None,
+                Some(100),
+                Some(200),
+            )
+            .unwrap();
+
+        let mut state = state.with_block_context(block_ctx(50));
+        assert!(matches!(
+            contract
+                .execute_cast_vote(&mut state, voter, proposal_id, VoteChoice::Yes)
+                .unwrap_err(),
+            ContractError::ExecutionFailed(_)
+        ));
+    }
+
+    #[test]
+    fn test_cast_vote_rejects_after_end_block() {
+        let contract = GovernanceContract::new();
+        let mut accounts = HashMap::new();
+        let mut storage = HashMap::new();
+        let mut state = ContractState::new(&mut accounts, &mut storage);
+
+        let kp = Keypair::generate();
+        let voter = Address::from_public_key(kp.public_key());
+        contract.save_registered_voter(&mut state, &voter, 10);
+
+        let now = crate::types::now_millis();
+        let proposal_id = contract
+            .execute_create_proposal(
+                &mut state,
+                voter,
+                "Test".to_string(),
+                "Test".to_string(),
+                vec![],
+                now + MIN_VOTING_PERIOD + 1000,
+                None,
+                Some(100),
+                Some(200),
+            )
+            .unwrap();
 
-    fn is_upgradeable(&self) -> bool {
-        true
+        let mut state = state.with_block_context(block_ctx(201));
+        assert!(matches!(
+            contract
+                .execute_cast_vote(&mut state, voter, proposal_id, VoteChoice::Yes)
+                .unwrap_err(),
+            ContractError::ExecutionFailed(_)
+        ));
     }
 
-    fn on_deploy(&self, state: &mut ContractState<'_>, _init_data: &[u8]) -> ContractResult<()> {
-        // Initialize storage with defaults
-        self.save_total_voting_power(state, 0);
-        self.save_proposal_index(state, &[]);
-        Ok(())
-    }
-}
+    #[test]
+    fn test_cast_vote_accepts_within_block_window() {
+        let contract = GovernanceContract::new();
+        let mut accounts = HashMap::new();
+        let mut storage = HashMap::new();
+        let mut state = ContractState::new(&mut accounts, &mut storage);
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::contracts::state::ContractState;
-    use crate::crypto::Keypair;
-    use crate::types::{Address, HclawAmount};
-    use std::collections::HashMap;
+        let kp = Keypair::generate();
+        let voter = Address::from_public_key(kp.public_key());
+        contract.save_registered_voter(&mut state, &voter, 10);
+
+        let now = crate::types::now_millis();
+        let proposal_id = contract
+            .execute_create_proposal(
+                &mut state,
+                voter,
+                "Test".to_string(),
+                "Test".to_string(),
+                vec![],
+                now + MIN_VOTING_PERIOD + 1000,
+                None,
+                Some(100),
+                Some(200),
+            )
+            .unwrap();
+
+        let mut state = state.with_block_context(block_ctx(150));
+        assert!(contract
+            .execute_cast_vote(&mut state, voter, proposal_id, VoteChoice::Yes)
+            .is_ok());
+    }
 
     #[test]
-    fn test_create_proposal() {
+    fn test_proposal_status_pending_before_start_block() {
         let contract = GovernanceContract::new();
         let mut accounts = HashMap::new();
         let mut storage = HashMap::new();
+        let mut state = ContractState::new(&mut accounts, &mut storage);
 
         let kp = Keypair::generate();
         let proposer = Address::from_public_key(kp.public_key());
 
-        accounts.insert(
-            proposer,
-            crate::state::AccountState::new(HclawAmount::from_hclaw(1000)),
+        let now = crate::types::now_millis();
+        let proposal_id = contract
+            .execute_create_proposal(
+                &mut state,
+                proposer,
+                "Test".to_string(),
+                "Test".to_string(),
+                vec![],
+                now + MIN_VOTING_PERIOD + 1000,
+                None,
+                Some(100),
+                Some(200),
+            )
+            .unwrap();
+
+        let state = state.with_block_context(block_ctx(50));
+        assert_eq!(
+            contract.proposal_status(&state, proposal_id).unwrap(),
+            ProposalStatus::Pending
         );
+    }
 
+    #[test]
+    fn test_proposal_status_distinguishes_quorum_not_met_from_rejected() {
+        let contract = GovernanceContract::new();
+        let mut accounts = HashMap::new();
+        let mut storage = HashMap::new();
         let mut state = ContractState::new(&mut accounts, &mut storage);
 
+        contract.save_total_voting_power(&mut state, 1000);
+
+        let kp = Keypair::generate();
+        let voter = Address::from_public_key(kp.public_key());
+
         let now = crate::types::now_millis();
-        let voting_ends = now + MIN_VOTING_PERIOD + 1000;
+        let voting_ends_at = now + MIN_VOTING_PERIOD + 1000;
+        let proposal_id = contract
+            .execute_create_proposal(
+                &mut state,
+                voter,
+                "Test".to_string(),
+                "Test".to_string(),
+                vec![],
+                voting_ends_at,
+                None,
+                None,
+                None,
+            )
+            .unwrap();
 
-        let proposal_id = contract.execute_create_proposal(
-            &mut state,
-            proposer,
-            "Test Proposal".to_string(),
-            "This is a test".to_string(),
-            vec![],
-            voting_ends,
-        );
+        // No votes at all — below quorum, not just below the approval
+        // threshold.
+        let mut proposal = contract
+            .load_proposal(&state, &proposal_id)
+            .unwrap()
+            .unwrap();
+        proposal.voting_ends_at = now - 1;
+        contract.save_proposal(&mut state, &proposal);
 
-        assert!(proposal_id.is_ok());
+        assert_eq!(
+            contract.proposal_status(&state, proposal_id).unwrap(),
+            ProposalStatus::QuorumNotMet
+        );
 
-        // Verify proposal is in storage
-        let pid = proposal_id.unwrap();
-        let loaded = contract.load_proposal(&state, &pid);
-        assert!(loaded.is_some());
-        assert_eq!(loaded.unwrap().title, "Test Proposal");
+        // Enough votes to clear quorum, but a losing split.
+        proposal.yes_votes = 100;
+        proposal.no_votes = 300;
+        contract.save_proposal(&mut state, &proposal);
 
-        // Verify proposal index
-        let index = contract.load_proposal_index(&state);
-        assert_eq!(index.len(), 1);
-        assert_eq!(index[0], pid);
+        assert_eq!(
+            contract.proposal_status(&state, proposal_id).unwrap(),
+            ProposalStatus::Rejected
+        );
     }
 
     #[test]
-    fn test_vote_on_proposal() {
+    fn test_transitive_delegation_chain_is_aggregated_by_final_delegate() {
         let contract = GovernanceContract::new();
-
         let mut accounts = HashMap::new();
         let mut storage = HashMap::new();
 
-        let kp = Keypair::generate();
-        let voter = Address::from_public_key(kp.public_key());
-
-        accounts.insert(
-            voter,
-            crate::state::AccountState::new(HclawAmount::from_hclaw(1000)),
-        );
+        let a_kp = Keypair::generate();
+        let a = Address::from_public_key(a_kp.public_key());
+        let b_kp = Keypair::generate();
+        let b = Address::from_public_key(b_kp.public_key());
+        let c_kp = Keypair::generate();
+        let c = Address::from_public_key(c_kp.public_key());
 
         let mut state = ContractState::new(&mut accounts, &mut storage);
+        contract.save_total_voting_power(&mut state, 100);
 
-        // Set voting power in storage
-        contract.save_total_voting_power(&mut state, 10000);
+        // a -> b -> c: c's ballot should cover all three.
+        contract
+            .execute_set_delegate(&mut state, a, Some(b))
+            .unwrap();
+        contract
+            .execute_set_delegate(&mut state, b, Some(c))
+            .unwrap();
 
-        // Create proposal
         let now = crate::types::now_millis();
-        let voting_ends = now + MIN_VOTING_PERIOD + 1000;
         let proposal_id = contract
             .execute_create_proposal(
                 &mut state,
-                voter,
+                c,
                 "Test".to_string(),
                 "Test".to_string(),
                 vec![],
-                voting_ends,
+                now + MIN_VOTING_PERIOD + 1000,
+                None,
+                None,
+                None,
             )
             .unwrap();
 
-        // Vote
-        let result = contract.execute_vote(&mut state, voter, proposal_id, true, 100);
-        assert!(result.is_ok());
+        contract
+            .execute_vote(&mut state, c, proposal_id, VoteChoice::Yes, 90)
+            .unwrap();
 
-        // Verify vote persisted in storage
-        let proposal = contract.load_proposal(&state, &proposal_id).unwrap();
-        assert_eq!(proposal.yes_votes, 100);
-        assert!(proposal.voters.contains_key(&voter));
+        let proposal = contract
+            .load_proposal(&state, &proposal_id)
+            .unwrap()
+            .unwrap();
+        assert_eq!(proposal.yes_votes, 90);
+        assert!(proposal.voters.contains_key(&a));
+        assert!(proposal.voters.contains_key(&b));
+        assert!(proposal.voters.contains_key(&c));
     }
 
     #[test]
-    fn test_cannot_vote_twice() {
+    fn test_set_delegate_rejects_cycle() {
         let contract = GovernanceContract::new();
-
         let mut accounts = HashMap::new();
         let mut storage = HashMap::new();
+        let mut state = ContractState::new(&mut accounts, &mut storage);
 
-        let kp = Keypair::generate();
-        let voter = Address::from_public_key(kp.public_key());
+        let a_kp = Keypair::generate();
+        let a = Address::from_public_key(a_kp.public_key());
+        let b_kp = Keypair::generate();
+        let b = Address::from_public_key(b_kp.public_key());
+        let c_kp = Keypair::generate();
+        let c = Address::from_public_key(c_kp.public_key());
 
-        accounts.insert(
-            voter,
-            crate::state::AccountState::new(HclawAmount::from_hclaw(1000)),
-        );
+        // a -> b -> c, then c -> a would close the loop.
+        contract
+            .execute_set_delegate(&mut state, a, Some(b))
+            .unwrap();
+        contract
+            .execute_set_delegate(&mut state, b, Some(c))
+            .unwrap();
+
+        assert!(matches!(
+            contract
+                .execute_set_delegate(&mut state, c, Some(a))
+                .unwrap_err(),
+            ContractError::ExecutionFailed(_)
+        ));
+    }
 
+    #[test]
+    fn test_read_events_returns_durable_log_in_emission_order() {
+        let contract = GovernanceContract::new();
+        let mut accounts = HashMap::new();
+        let mut storage = HashMap::new();
         let mut state = ContractState::new(&mut accounts, &mut storage);
 
-        contract.save_total_voting_power(&mut state, 10000);
+        contract.on_deploy(&mut state, &[]).unwrap();
 
+        let kp = Keypair::generate();
+        let proposer = Address::from_public_key(kp.public_key());
         let now = crate::types::now_millis();
-        let voting_ends = now + MIN_VOTING_PERIOD + 1000;
-        let proposal_id = contract
+        contract
             .execute_create_proposal(
                 &mut state,
-                voter,
+                proposer,
+                "First".to_string(),
                 "Test".to_string(),
+                vec![],
+                now + MIN_VOTING_PERIOD + 1000,
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+        contract
+            .execute_create_proposal(
+                &mut state,
+                proposer,
+                "Second".to_string(),
                 "Test".to_string(),
                 vec![],
-                voting_ends,
+                now + MIN_VOTING_PERIOD + 1000,
+                None,
+                None,
+                None,
             )
             .unwrap();
 
-        // First vote succeeds
-        assert!(contract
-            .execute_vote(&mut state, voter, proposal_id, true, 100)
-            .is_ok());
+        let events = contract.read_events(&state, 0, 10).unwrap();
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].topic, "ProposalCreated");
+        assert_eq!(events[1].topic, "ProposalCreated");
 
-        // Second vote fails
-        assert!(contract
-            .execute_vote(&mut state, voter, proposal_id, true, 100)
-            .is_err());
+        let (_, first_title): (Hash, String) = bincode::deserialize(&events[0].data).unwrap();
+        let (_, second_title): (Hash, String) = bincode::deserialize(&events[1].data).unwrap();
+        assert_eq!(first_title, "First");
+        assert_eq!(second_title, "Second");
     }
 
     #[test]
-    fn test_state_persists_across_calls() {
+    fn test_read_events_paginates_from_index() {
         let contract = GovernanceContract::new();
-
         let mut accounts = HashMap::new();
         let mut storage = HashMap::new();
+        let mut state = ContractState::new(&mut accounts, &mut storage);
 
-        let kp1 = Keypair::generate();
-        let kp2 = Keypair::generate();
-        let voter1 = Address::from_public_key(kp1.public_key());
-        let voter2 = Address::from_public_key(kp2.public_key());
-
-        accounts.insert(
-            voter1,
-            crate::state::AccountState::new(HclawAmount::from_hclaw(1000)),
-        );
-        accounts.insert(
-            voter2,
-            crate::state::AccountState::new(HclawAmount::from_hclaw(1000)),
-        );
-
-        // Scope 1: create proposal
-        let proposal_id = {
-            let mut state = ContractState::new(&mut accounts, &mut storage);
-            contract.save_total_voting_power(&mut state, 10000);
+        contract.on_deploy(&mut state, &[]).unwrap();
 
-            let now = crate::types::now_millis();
-            let voting_ends = now + MIN_VOTING_PERIOD + 1000;
-            let pid = contract
+        let kp = Keypair::generate();
+        let proposer = Address::from_public_key(kp.public_key());
+        let now = crate::types::now_millis();
+        for title in ["First", "Second"] {
+            contract
                 .execute_create_proposal(
                     &mut state,
-                    voter1,
-                    "Persist Test".to_string(),
+                    proposer,
+                    title.to_string(),
                     "Test".to_string(),
                     vec![],
-                    voting_ends,
+                    now + MIN_VOTING_PERIOD + 1000,
+                    None,
+                    None,
+                    None,
                 )
                 .unwrap();
-            state.commit();
-            pid
-        };
-
-        // Scope 2: vote with voter1 (fresh state wrapper)
-        {
-            let mut state = ContractState::new(&mut accounts, &mut storage);
-            contract
-                .execute_vote(&mut state, voter1, proposal_id, true, 500)
-                .unwrap();
-            state.commit();
         }
 
-        // Scope 3: vote with voter2 (fresh state wrapper)
-        {
-            let mut state = ContractState::new(&mut accounts, &mut storage);
-            contract
-                .execute_vote(&mut state, voter2, proposal_id, false, 300)
+        assert_eq!(contract.load_event_log_len(&state).unwrap(), 2);
+
+        let first_page = contract.read_events(&state, 0, 1).unwrap();
+        assert_eq!(first_page.len(), 1);
+        let (_, title): (Hash, String) = bincode::deserialize(&first_page[0].data).unwrap();
+        assert_eq!(title, "First");
+
+        let second_page = contract.read_events(&state, 1, 10).unwrap();
+        assert_eq!(second_page.len(), 1);
+        let (_, title): (Hash, String) = bincode::deserialize(&second_page[0].data).unwrap();
+        assert_eq!(title, "Second");
+
+        assert!(contract.read_events(&state, 2, 10).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_tally_stats_all_time_uses_running_counters() {
+        let contract = GovernanceContract::new();
+        let mut accounts = HashMap::new();
+        let mut storage = HashMap::new();
+        let mut state = ContractState::new(&mut accounts, &mut storage);
+        contract.save_total_voting_power(&mut state, 100);
+
+        let kp_a = Keypair::generate();
+        let kp_b = Keypair::generate();
+        let kp_c = Keypair::generate();
+        let proposer = Address::from_public_key(kp_a.public_key());
+        let voter_b = Address::from_public_key(kp_b.public_key());
+        let voter_c = Address::from_public_key(kp_c.public_key());
+
+        let now = crate::types::now_millis();
+        let proposal_a = contract
+            .execute_create_proposal(
+                &mut state,
+                proposer,
+                "Passes".to_string(),
+                "Test".to_string(),
+                vec![],
+                now + MIN_VOTING_PERIOD + 1000,
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+        let proposal_b = contract
+            .execute_create_proposal(
+                &mut state,
+                proposer,
+                "Fails quorum".to_string(),
+                "Test".to_string(),
+                vec![],
+                now + MIN_VOTING_PERIOD + 1000,
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+
+        contract
+            .execute_vote(&mut state, proposer, proposal_a, VoteChoice::Yes, 60)
+            .unwrap();
+        contract
+            .execute_vote(&mut state, voter_b, proposal_b, VoteChoice::Yes, 10)
+            .unwrap();
+        contract
+            .execute_vote(&mut state, voter_c, proposal_b, VoteChoice::No, 5)
+            .unwrap();
+
+        for proposal_id in [proposal_a, proposal_b] {
+            let mut proposal = contract
+                .load_proposal(&state, &proposal_id)
+                .unwrap()
                 .unwrap();
-            state.commit();
+            proposal.voting_ends_at = now - 1;
+            contract.save_proposal(&mut state, &proposal);
         }
 
-        // Verify: both votes visible in storage
-        let state = ContractState::new(&mut accounts, &mut storage);
-        let proposal = contract.load_proposal(&state, &proposal_id).unwrap();
-        assert_eq!(proposal.yes_votes, 500);
-        assert_eq!(proposal.no_votes, 300);
-        assert_eq!(proposal.voters.len(), 2);
+        // Proposal A clears quorum and passes; proposal B never reaches
+        // quorum, so execution fails but still closes it out.
+        contract.execute_proposal(&mut state, proposal_a).unwrap();
+        assert!(contract.execute_proposal(&mut state, proposal_b).is_err());
+
+        let stats = contract.tally_stats(&state, i64::MIN, i64::MAX).unwrap();
+        assert_eq!(stats.proposals_created, 2);
+        assert_eq!(stats.votes_cast, 3);
+        // (60 + 10 + 5) * 100 / (100 * 2) == 37
+        assert_eq!(stats.participation_percent, 37);
+        assert_eq!(stats.winning_proposal, Some(proposal_a));
     }
 
     #[test]
-    fn test_on_deploy_initializes_storage() {
+    fn test_tally_stats_filters_by_created_at_window() {
         let contract = GovernanceContract::new();
         let mut accounts = HashMap::new();
         let mut storage = HashMap::new();
         let mut state = ContractState::new(&mut accounts, &mut storage);
+        contract.save_total_voting_power(&mut state, 100);
 
-        contract.on_deploy(&mut state, &[]).unwrap();
+        let kp = Keypair::generate();
+        let proposer = Address::from_public_key(kp.public_key());
+        let now = crate::types::now_millis();
+
+        let old_id = contract
+            .execute_create_proposal(
+                &mut state,
+                proposer,
+                "Old".to_string(),
+                "Test".to_string(),
+                vec![],
+                now + MIN_VOTING_PERIOD + 1000,
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+        let new_id = contract
+            .execute_create_proposal(
+                &mut state,
+                proposer,
+                "New".to_string(),
+                "Test".to_string(),
+                vec![],
+                now + MIN_VOTING_PERIOD + 1000,
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+
+        let mut old_proposal = contract.load_proposal(&state, &old_id).unwrap().unwrap();
+        old_proposal.created_at = 1000;
+        contract.save_proposal(&mut state, &old_proposal);
+
+        let mut new_proposal = contract.load_proposal(&state, &new_id).unwrap().unwrap();
+        new_proposal.created_at = 2000;
+        contract.save_proposal(&mut state, &new_proposal);
+
+        // The full-range counters cover both proposals regardless of window...
+        let all_time = contract.tally_stats(&state, i64::MIN, i64::MAX).unwrap();
+        assert_eq!(all_time.proposals_created, 2);
 
-        assert_eq!(contract.load_total_voting_power(&state), 0);
-        assert!(contract.load_proposal_index(&state).is_empty());
+        // ...but a narrower window falls back to a scan that only counts
+        // proposals whose `created_at` actually falls inside it.
+        let narrow = contract.tally_stats(&state, 1500, 3000).unwrap();
+        assert_eq!(narrow.proposals_created, 1);
+        assert_eq!(narrow.votes_cast, 0);
+        assert_eq!(narrow.winning_proposal, None);
     }
 }
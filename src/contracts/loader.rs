@@ -3,8 +3,11 @@
 //! Handles loading contracts from bytecode, enforcing versioning,
 //! and routing to the appropriate runtime (WASM vs Native).
 
+use std::collections::HashMap;
+
 #[cfg(feature = "wasm-contracts")]
 use crate::contracts::wasm::WasmContract;
+use crate::contracts::gas::ContractSchedule;
 use crate::contracts::Contract;
 use crate::contracts::ContractError;
 use crate::contracts::ContractResult;
@@ -12,8 +15,18 @@ use crate::types::Id;
 
 /// A trait for loading contracts from bytecode
 pub trait ContractLoader: Send + Sync {
-    /// Try to load a contract from bytecode
-    fn load(&self, id: Id, code: &[u8]) -> ContractResult<Box<dyn Contract>>;
+    /// Try to load a contract from bytecode.
+    ///
+    /// `schedule` governs the resource limits instrumented into a WASM
+    /// contract's module before instantiation (see
+    /// [`crate::contracts::wasm::instrument`]); loaders for other code
+    /// formats are free to ignore it.
+    fn load(
+        &self,
+        id: Id,
+        code: &[u8],
+        schedule: &ContractSchedule,
+    ) -> ContractResult<Box<dyn Contract>>;
 }
 
 /// The main contract loader that delegates to specific runtimes
@@ -27,7 +40,7 @@ impl UniversalLoader {
     /// Create a new universal loader with native and WASM runtimes.
     pub fn new() -> Self {
         Self {
-            native_loader: NativeLoader {},
+            native_loader: NativeLoader::new(),
             #[cfg(feature = "wasm-contracts")]
             wasm_loader: WasmLoader {},
         }
@@ -41,16 +54,21 @@ impl Default for UniversalLoader {
 }
 
 impl ContractLoader for UniversalLoader {
-    fn load(&self, id: Id, code: &[u8]) -> ContractResult<Box<dyn Contract>> {
+    fn load(
+        &self,
+        id: Id,
+        code: &[u8],
+        schedule: &ContractSchedule,
+    ) -> ContractResult<Box<dyn Contract>> {
         // Check for native marker
         if code.starts_with(b"native:") {
-            return self.native_loader.load(id, code);
+            return self.native_loader.load(id, code, schedule);
         }
 
         // Check for WASM magic bytes (\0asm)
         #[cfg(feature = "wasm-contracts")]
         if code.starts_with(&[0x00, 0x61, 0x73, 0x6d]) {
-            return self.wasm_loader.load(id, code);
+            return self.wasm_loader.load(id, code, schedule);
         }
 
         #[cfg(not(feature = "wasm-contracts"))]
@@ -66,34 +84,283 @@ impl ContractLoader for UniversalLoader {
     }
 }
 
-/// Loads native implementations (Genesis contracts)
-struct NativeLoader;
+/// A constructor for a native contract implementation. Takes no
+/// arguments — anything a contract needs beyond its own defaults is
+/// supplied later, through `on_deploy`'s init data.
+type NativeContractCtor = Box<dyn Fn() -> Box<dyn Contract> + Send + Sync>;
+
+/// Version-lifecycle metadata for one `(name, version)` entry in
+/// [`NativeLoader`]'s registry, mirroring how a runtime contract schedule
+/// tracks versioned behavior rather than a single hardcoded
+/// implementation per name.
+#[derive(Clone, Debug)]
+pub struct NativeContractMetadata {
+    /// Oldest protocol version this contract version can run under
+    pub min_protocol_version: u32,
+    /// Newest protocol version this contract version can run under
+    pub max_protocol_version: u32,
+    /// Whether this version is deprecated in favor of a successor
+    pub deprecated: bool,
+    /// `(name, version)` to route to instead, if `deprecated` and a
+    /// successor exists. A deprecated entry with no successor still
+    /// loads as itself — deprecation alone doesn't brick existing
+    /// deployments.
+    pub superseded_by: Option<(String, u32)>,
+}
+
+impl Default for NativeContractMetadata {
+    /// A current, non-deprecated entry supported on every protocol
+    /// version to date.
+    fn default() -> Self {
+        Self {
+            min_protocol_version: 1,
+            max_protocol_version: u32::MAX,
+            deprecated: false,
+            superseded_by: None,
+        }
+    }
+}
+
+/// One registered native contract version: its constructor plus
+/// [`NativeContractMetadata`].
+struct NativeContractEntry {
+    ctor: NativeContractCtor,
+    metadata: NativeContractMetadata,
+}
+
+/// Loads native implementations (Genesis contracts), keyed by
+/// `(name, version)` rather than a hardcoded `match` — shipping a new
+/// version or deprecating an old one is a [`Self::register`] call, not a
+/// loader edit.
+pub struct NativeLoader {
+    registry: HashMap<(String, u32), NativeContractEntry>,
+}
+
+impl NativeLoader {
+    /// A loader pre-registered with this crate's built-in native
+    /// contracts (`genesis_bounty_v1`, `governance_v1`).
+    #[must_use]
+    pub fn new() -> Self {
+        let mut loader = Self {
+            registry: HashMap::new(),
+        };
+        loader.register(
+            "genesis_bounty",
+            1,
+            || Box::new(crate::contracts::genesis_bounty::GenesisBountyContract::new(0)),
+            NativeContractMetadata::default(),
+        );
+        loader.register(
+            "governance",
+            1,
+            || Box::new(crate::contracts::governance::GovernanceContract::new()),
+            NativeContractMetadata::default(),
+        );
+        loader
+    }
+
+    /// Register a native contract version, so new native contracts (or
+    /// new versions of existing ones) can be added without touching
+    /// [`Self::load`].
+    pub fn register(
+        &mut self,
+        name: impl Into<String>,
+        version: u32,
+        ctor: impl Fn() -> Box<dyn Contract> + Send + Sync + 'static,
+        metadata: NativeContractMetadata,
+    ) {
+        self.registry.insert(
+            (name.into(), version),
+            NativeContractEntry {
+                ctor: Box::new(ctor),
+                metadata,
+            },
+        );
+    }
+
+    /// Every registered version of `name`, ascending.
+    #[must_use]
+    pub fn supported_versions(&self, name: &str) -> Vec<u32> {
+        let mut versions: Vec<u32> = self
+            .registry
+            .keys()
+            .filter(|(registered_name, _)| registered_name == name)
+            .map(|(_, version)| *version)
+            .collect();
+        versions.sort_unstable();
+        versions
+    }
+
+    /// Resolve `(name, version)` to a contract instance, following
+    /// [`NativeContractMetadata::superseded_by`] if the requested version
+    /// is deprecated and a successor is registered.
+    fn resolve(&self, name: &str, version: u32) -> ContractResult<Box<dyn Contract>> {
+        let entry = self
+            .registry
+            .get(&(name.to_string(), version))
+            .ok_or_else(|| {
+                ContractError::ExecutionFailed(format!(
+                    "unknown native contract: native:{name}_v{version}"
+                ))
+            })?;
+
+        if entry.metadata.deprecated {
+            if let Some((successor_name, successor_version)) = &entry.metadata.superseded_by {
+                tracing::info!(
+                    "native contract native:{name}_v{version} is deprecated; \
+                     routing to successor native:{successor_name}_v{successor_version}"
+                );
+                return self.resolve(successor_name, *successor_version);
+            }
+        }
+
+        Ok((entry.ctor)())
+    }
+}
+
+impl Default for NativeLoader {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
 impl ContractLoader for NativeLoader {
-    fn load(&self, _id: Id, code: &[u8]) -> ContractResult<Box<dyn Contract>> {
+    fn load(
+        &self,
+        _id: Id,
+        code: &[u8],
+        _schedule: &ContractSchedule,
+    ) -> ContractResult<Box<dyn Contract>> {
+        // Native contracts run as trusted host-compiled Rust, not
+        // instrumented bytecode, so the resource schedule doesn't apply.
         let marker = String::from_utf8_lossy(code);
-        match marker.trim() {
-            "native:genesis_bounty_v1" => Ok(Box::new(
-                crate::contracts::genesis_bounty::GenesisBountyContract::new(0),
-            )),
-            "native:governance_v1" => Ok(Box::new(
-                crate::contracts::governance::GovernanceContract::new(),
-            )),
-            _ => Err(ContractError::ExecutionFailed(format!(
-                "Unknown native contract: {}",
-                marker
-            ))),
-        }
+        let marker = marker.trim();
+        let (name, version) = parse_native_marker(marker).ok_or_else(|| {
+            ContractError::ExecutionFailed(format!("invalid native contract marker: {marker}"))
+        })?;
+        self.resolve(&name, version)
     }
 }
 
+/// Parse a `native:<name>_v<n>` marker into `(name, n)`.
+fn parse_native_marker(marker: &str) -> Option<(String, u32)> {
+    let rest = marker.strip_prefix("native:")?;
+    let split_at = rest.rfind("_v")?;
+    let (name, version_suffix) = rest.split_at(split_at);
+    let version: u32 = version_suffix[2..].parse().ok()?;
+    Some((name.to_string(), version))
+}
+
 /// Loads WASM contracts
 #[cfg(feature = "wasm-contracts")]
 struct WasmLoader;
 
 #[cfg(feature = "wasm-contracts")]
 impl ContractLoader for WasmLoader {
-    fn load(&self, id: Id, code: &[u8]) -> ContractResult<Box<dyn Contract>> {
-        Ok(Box::new(WasmContract::new(id, code.to_vec())))
+    fn load(
+        &self,
+        id: Id,
+        code: &[u8],
+        schedule: &ContractSchedule,
+    ) -> ContractResult<Box<dyn Contract>> {
+        let instrumented = crate::contracts::wasm::instrument(code, schedule)?;
+        Ok(Box::new(WasmContract::new(id, instrumented, schedule.clone())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_native_marker() {
+        assert_eq!(
+            parse_native_marker("native:genesis_bounty_v1"),
+            Some(("genesis_bounty".to_string(), 1))
+        );
+        assert_eq!(
+            parse_native_marker("native:governance_v2"),
+            Some(("governance".to_string(), 2))
+        );
+        assert_eq!(parse_native_marker("garbage"), None);
+        assert_eq!(parse_native_marker("native:no_version"), None);
+    }
+
+    #[test]
+    fn test_load_builtin_native_contracts() {
+        let loader = NativeLoader::new();
+        assert!(loader
+            .load(Id::ZERO, b"native:genesis_bounty_v1", &ContractSchedule::mainnet())
+            .is_ok());
+        assert!(loader
+            .load(Id::ZERO, b"native:governance_v1", &ContractSchedule::mainnet())
+            .is_ok());
+    }
+
+    #[test]
+    fn test_load_unknown_native_contract_fails() {
+        let loader = NativeLoader::new();
+        assert!(loader
+            .load(Id::ZERO, b"native:does_not_exist_v1", &ContractSchedule::mainnet())
+            .is_err());
+    }
+
+    #[test]
+    fn test_supported_versions() {
+        let mut loader = NativeLoader::new();
+        assert_eq!(loader.supported_versions("genesis_bounty"), vec![1]);
+
+        loader.register(
+            "genesis_bounty",
+            2,
+            || Box::new(crate::contracts::genesis_bounty::GenesisBountyContract::new(0)),
+            NativeContractMetadata::default(),
+        );
+        assert_eq!(loader.supported_versions("genesis_bounty"), vec![1, 2]);
+        assert!(loader.supported_versions("does_not_exist").is_empty());
+    }
+
+    #[test]
+    fn test_deprecated_version_routes_to_successor() {
+        let mut loader = NativeLoader::new();
+        loader.register(
+            "genesis_bounty",
+            2,
+            || Box::new(crate::contracts::genesis_bounty::GenesisBountyContract::new(0)),
+            NativeContractMetadata::default(),
+        );
+        loader.register(
+            "genesis_bounty",
+            1,
+            || Box::new(crate::contracts::genesis_bounty::GenesisBountyContract::new(0)),
+            NativeContractMetadata {
+                deprecated: true,
+                superseded_by: Some(("genesis_bounty".to_string(), 2)),
+                ..NativeContractMetadata::default()
+            },
+        );
+
+        let contract = loader
+            .load(Id::ZERO, b"native:genesis_bounty_v1", &ContractSchedule::mainnet())
+            .expect("deprecated version routes to its successor");
+        assert_eq!(contract.version(), 2);
+    }
+
+    #[test]
+    fn test_deprecated_version_without_successor_still_loads() {
+        let mut loader = NativeLoader::new();
+        loader.register(
+            "genesis_bounty",
+            1,
+            || Box::new(crate::contracts::genesis_bounty::GenesisBountyContract::new(0)),
+            NativeContractMetadata {
+                deprecated: true,
+                ..NativeContractMetadata::default()
+            },
+        );
+
+        assert!(loader
+            .load(Id::ZERO, b"native:genesis_bounty_v1", &ContractSchedule::mainnet())
+            .is_ok());
     }
 }
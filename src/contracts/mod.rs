@@ -21,14 +21,22 @@
 //! - Invalid transitions are rejected by consensus
 //! - All state mutations are atomic (all-or-nothing)
 
+pub mod bloom;
+pub mod gas;
 pub mod genesis_bounty;
 pub mod governance;
+pub mod loader;
 pub mod processor;
+pub mod proof;
 pub mod state;
 pub mod transaction;
+pub mod trie;
+pub mod verification_queue;
+#[cfg(feature = "wasm-contracts")]
+pub mod wasm;
 
 use crate::crypto::Hash;
-use crate::types::{HclawAmount, Id};
+use crate::types::{Address, HclawAmount, Id};
 
 use self::state::ContractState;
 use self::transaction::ContractTransaction;
@@ -82,8 +90,49 @@ pub trait Contract: Send + Sync {
         false
     }
 
-    /// Hook called when contract is deployed
-    fn on_deploy(&self, _state: &mut ContractState<'_>) -> ContractResult<()> {
+    /// Whether this contract is allowed to change the total `HclawAmount`
+    /// balance across its write-set — i.e. to mint or burn supply rather
+    /// than just move it between accounts. Checked by
+    /// [`processor::TransactionProcessor`] after every `execute`/`on_deploy`
+    /// call; an unflagged contract whose execution changes the aggregate
+    /// balance is rolled back with [`ContractError::BalanceNotConserved`]
+    /// instead of committed. Defaults to `false`, since most contracts only
+    /// move value that already exists.
+    fn is_mint_authority(&self) -> bool {
+        false
+    }
+
+    /// Hook called when contract is deployed, with the deploy-time init
+    /// data supplied by whoever is registering the contract (e.g. a
+    /// bincode-serialized `GenesisDeploymentConfig`)
+    fn on_deploy(&self, _state: &mut ContractState<'_>, _init_data: &[u8]) -> ContractResult<()> {
+        Ok(())
+    }
+
+    /// Whether `upgrader` is allowed to replace this contract's code via an
+    /// `Upgrade` transaction. Checked in addition to [`Self::is_upgradeable`],
+    /// so this only matters for contracts that return `true` there. Defaults
+    /// to allowing any upgrader, which is fine for contracts with no owner
+    /// concept; contracts that need finer-grained control should override
+    /// this to check a stored owner/admin address instead.
+    ///
+    /// # Errors
+    /// Returns error if checking authorization itself fails (e.g. a storage
+    /// read error)
+    fn authorize_upgrade(
+        &self,
+        _state: &ContractState<'_>,
+        _upgrader: &Address,
+    ) -> ContractResult<bool> {
+        Ok(true)
+    }
+
+    /// Hook called after an `Upgrade` transaction installs this contract's
+    /// code in place of `old_version`, for migrating storage laid out by the
+    /// previous version. Runs inside the same commit/rollback wrapper as the
+    /// upgrade: returning an error discards any changes made here and the
+    /// upgrade as a whole fails, leaving the old code registered.
+    fn on_upgrade(&self, _state: &mut ContractState<'_>, _old_version: u32) -> ContractResult<()> {
         Ok(())
     }
 }
@@ -145,14 +194,52 @@ pub enum ContractError {
         got: Hash,
     },
 
+    /// A non-mint-authority contract's execution changed the total balance
+    /// across its write-set, instead of just moving value between accounts
+    #[error("balance not conserved: before {before}, after {after}")]
+    BalanceNotConserved {
+        /// Total balance across the write-set before execution
+        before: HclawAmount,
+        /// Total balance across the write-set after execution
+        after: HclawAmount,
+    },
+
     /// Contract is not upgradeable
     #[error("contract is not upgradeable")]
     NotUpgradeable,
 
+    /// A contract re-entered its own execution (directly or via a nested
+    /// call) while already on the call stack, without the transaction
+    /// opting in via `allow_reentrancy`
+    #[error("reentrant call into contract {0}")]
+    Reentrancy(Id),
+
     /// Unauthorized access
     #[error("unauthorized: {0}")]
     Unauthorized(String),
 
+    /// Underlying state backend failed (I/O error or corrupt entry)
+    #[error("state error: {0}")]
+    State(#[from] state::StateError),
+
+    /// Gas limit exceeded during execution
+    #[error("out of gas: used {used}, limit {limit}")]
+    OutOfGas {
+        /// Gas consumed so far
+        used: u64,
+        /// Gas limit configured for this execution
+        limit: u64,
+    },
+
+    /// Execution used more gas than a portable proof is allowed to cover
+    #[error("execution too expensive to prove: used {gas_used}, max_proof_gas {max_proof_gas}")]
+    ProofTooExpensive {
+        /// Gas the execution actually consumed
+        gas_used: u64,
+        /// Ceiling configured for proof generation
+        max_proof_gas: u64,
+    },
+
     /// Generic error
     #[error("{0}")]
     Other(String),
@@ -0,0 +1,266 @@
+//! Portable proof of execution for light verifiers.
+//!
+//! [`super::processor::TransactionProcessor::execute_transaction`] and
+//! [`super::processor::TransactionProcessor::verify_execution`] both need
+//! the *entire* account/storage map, because the contract is free to read
+//! any key in the world. That's fine for a node tracking full state, but
+//! unworkable for a light client that only follows state roots.
+//!
+//! This module adds a proof-generating alternative: wrap [`StateBackend`]
+//! in [`RecordingBackend`], which records every key a transaction actually
+//! reads (its pre-image) as it executes, alongside the backend's real
+//! values. The resulting [`Witness`] plus the transaction's claimed
+//! write-set is bundled into an [`ExecutionProof`] — small, self-contained,
+//! and enough for a verifier to reconstruct a [`ContractState`] backed only
+//! by [`WitnessBackend`] (any read of a key the witness doesn't cover is a
+//! hard failure) and independently replay `contract.execute`.
+//!
+//! Because [`RecordingBackend`]/[`WitnessBackend`] only ever expose the
+//! touched keys through `account_addresses`/`storage_entries`, the state
+//! root folded into an [`ExecutionProof`] is scoped to just this
+//! transaction's footprint, not the chain's full account set — it lets a
+//! verifier confirm the replay is self-consistent with the witness it was
+//! handed, which is a different (and weaker) guarantee than the consensus
+//! state root checked by [`super::processor::TransactionProcessor::verify_execution`]
+//! against the complete world state.
+
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
+
+use serde::{Deserialize, Serialize};
+
+use super::state::{StateBackend, StateError};
+use super::ExecutionResult;
+use crate::state::AccountState;
+use crate::types::Address;
+
+/// Every key a transaction's execution read, recorded the first time it
+/// was read (so a later write by the same execution never overwrites the
+/// witnessed pre-image). A key present with value `None` means the
+/// execution read it and found nothing; a key absent entirely means it was
+/// never read and a verifier replaying against just this witness must
+/// treat any attempt to read it as a hard failure.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Witness {
+    accounts: HashMap<Address, Option<AccountState>>,
+    storage: HashMap<(Address, Vec<u8>), Option<Vec<u8>>>,
+}
+
+impl Witness {
+    /// Every account address the execution read, regardless of whether it
+    /// found a value. Used by [`super::processor::TransactionProcessor`] to
+    /// build a transaction's read-set for batch conflict analysis.
+    pub(super) fn read_accounts(&self) -> impl Iterator<Item = &Address> {
+        self.accounts.keys()
+    }
+
+    /// Every storage key the execution read, regardless of whether it found
+    /// a value. Used by [`super::processor::TransactionProcessor`] to build
+    /// a transaction's read-set for batch conflict analysis.
+    pub(super) fn read_storage_keys(&self) -> impl Iterator<Item = &(Address, Vec<u8>)> {
+        self.storage.keys()
+    }
+}
+
+/// Mutable bookkeeping shared between a [`RecordingBackend`] and the
+/// [`ExecutionProof`] generator, via an `Rc<RefCell<_>>` so it survives
+/// after the backend itself has been boxed away inside a `ContractState`.
+#[derive(Default)]
+pub(super) struct RecordingState {
+    witness: Witness,
+    touched_accounts: HashSet<Address>,
+    touched_storage: HashMap<Address, HashSet<Vec<u8>>>,
+}
+
+/// Wraps a [`StateBackend`], recording every account/storage key it reads
+/// into a [`Witness`] while forwarding the actual read/write to `inner`.
+///
+/// `account_addresses`/`storage_entries` are scoped to exactly the read
+/// keys (writes don't need to be tracked here — [`ContractState`] already
+/// folds written addresses/keys into its own overlay independent of the
+/// backend), which is what makes the resulting state root a commitment to
+/// just this transaction's footprint. See the module docs for why that's a
+/// different guarantee than the chain's full consensus root.
+pub struct RecordingBackend<'a, B: StateBackend> {
+    inner: &'a mut B,
+    state: Rc<RefCell<RecordingState>>,
+}
+
+impl<'a, B: StateBackend> RecordingBackend<'a, B> {
+    /// Wrap `inner`, recording reads into `state` as they happen. Callers
+    /// keep their own clone of `state` to pull the finished [`Witness`] out
+    /// of once the backend has been dropped.
+    fn new(inner: &'a mut B, state: Rc<RefCell<RecordingState>>) -> Self {
+        Self { inner, state }
+    }
+}
+
+impl<B: StateBackend> StateBackend for RecordingBackend<'_, B> {
+    fn get_account(&self, address: &Address) -> Result<Option<AccountState>, StateError> {
+        let value = self.inner.get_account(address)?;
+        let mut state = self.state.borrow_mut();
+        state.touched_accounts.insert(*address);
+        state
+            .witness
+            .accounts
+            .entry(*address)
+            .or_insert_with(|| value.clone());
+        Ok(value)
+    }
+
+    fn put_account(&mut self, address: Address, account: AccountState) -> Result<(), StateError> {
+        self.inner.put_account(address, account)
+    }
+
+    fn account_addresses(&self) -> Result<Vec<Address>, StateError> {
+        Ok(self
+            .state
+            .borrow()
+            .touched_accounts
+            .iter()
+            .copied()
+            .collect())
+    }
+
+    fn get_storage(&self, contract: &Address, key: &[u8]) -> Result<Option<Vec<u8>>, StateError> {
+        let value = self.inner.get_storage(contract, key)?;
+        let mut state = self.state.borrow_mut();
+        state
+            .touched_storage
+            .entry(*contract)
+            .or_default()
+            .insert(key.to_vec());
+        state
+            .witness
+            .storage
+            .entry((*contract, key.to_vec()))
+            .or_insert_with(|| value.clone());
+        Ok(value)
+    }
+
+    fn put_storage(
+        &mut self,
+        contract: Address,
+        key: Vec<u8>,
+        value: Option<Vec<u8>>,
+    ) -> Result<(), StateError> {
+        self.inner.put_storage(contract, key, value)
+    }
+
+    fn storage_entries(&self, contract: &Address) -> Result<Vec<(Vec<u8>, Vec<u8>)>, StateError> {
+        let state = self.state.borrow();
+        let Some(keys) = state.touched_storage.get(contract) else {
+            return Ok(Vec::new());
+        };
+        let mut entries = Vec::new();
+        for key in keys {
+            if let Some(value) = self.inner.get_storage(contract, key)? {
+                entries.push((key.clone(), value));
+            }
+        }
+        Ok(entries)
+    }
+}
+
+/// Pull the recorded [`Witness`] out of a `RecordingBackend`'s shared
+/// state. Only meaningful after the `ContractState` holding the backend
+/// has been dropped, so this is the sole remaining reference.
+pub(super) fn witness_from_recording(state: Rc<RefCell<RecordingState>>) -> Witness {
+    Rc::try_unwrap(state)
+        .unwrap_or_else(|_| panic!("RecordingBackend outlived its ContractState"))
+        .into_inner()
+        .witness
+}
+
+/// Let a fresh recording session start against `inner`, returning both the
+/// wrapped backend and the handle used to recover the [`Witness`]
+/// afterward via [`witness_from_recording`].
+pub(super) fn new_recording_backend<B: StateBackend>(
+    inner: &mut B,
+) -> (RecordingBackend<'_, B>, Rc<RefCell<RecordingState>>) {
+    let state = Rc::new(RefCell::new(RecordingState::default()));
+    let backend = RecordingBackend::new(inner, Rc::clone(&state));
+    (backend, state)
+}
+
+/// Stateless [`StateBackend`] serving reads only from a [`Witness`] — no
+/// access to the rest of the world. A read of a key the witness doesn't
+/// cover is a hard failure rather than an `Ok(None)`, since "not in the
+/// witness" and "confirmed absent" are different things here.
+pub struct WitnessBackend {
+    witness: Witness,
+}
+
+impl WitnessBackend {
+    /// Wrap `witness` as a backend a verifier can replay execution against.
+    #[must_use]
+    pub fn new(witness: Witness) -> Self {
+        Self { witness }
+    }
+}
+
+impl StateBackend for WitnessBackend {
+    fn get_account(&self, address: &Address) -> Result<Option<AccountState>, StateError> {
+        self.witness.accounts.get(address).cloned().ok_or_else(|| {
+            StateError::Corrupt(format!(
+                "read of account {address} not covered by the execution proof's witness"
+            ))
+        })
+    }
+
+    fn put_account(&mut self, _address: Address, _account: AccountState) -> Result<(), StateError> {
+        Ok(())
+    }
+
+    fn account_addresses(&self) -> Result<Vec<Address>, StateError> {
+        Ok(self.witness.accounts.keys().copied().collect())
+    }
+
+    fn get_storage(&self, contract: &Address, key: &[u8]) -> Result<Option<Vec<u8>>, StateError> {
+        self.witness
+            .storage
+            .get(&(*contract, key.to_vec()))
+            .cloned()
+            .ok_or_else(|| {
+                StateError::Corrupt(format!(
+                    "read of storage key under {contract} not covered by the execution proof's witness"
+                ))
+            })
+    }
+
+    fn put_storage(
+        &mut self,
+        _contract: Address,
+        _key: Vec<u8>,
+        _value: Option<Vec<u8>>,
+    ) -> Result<(), StateError> {
+        Ok(())
+    }
+
+    fn storage_entries(&self, contract: &Address) -> Result<Vec<(Vec<u8>, Vec<u8>)>, StateError> {
+        Ok(self
+            .witness
+            .storage
+            .iter()
+            .filter(|((c, _), v)| c == contract && v.is_some())
+            .map(|((_, k), v)| (k.clone(), v.clone().expect("filtered to Some above")))
+            .collect())
+    }
+}
+
+/// A self-contained record that executing a transaction produced a given
+/// result, replayable by anyone holding just this proof: the witness (every
+/// key the execution read), the claimed write-set, and the claimed result.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ExecutionProof {
+    /// Every account/storage key the original execution read, with its
+    /// pre-execution value.
+    pub witness: Witness,
+    /// Accounts the original execution wrote, by final value.
+    pub account_writes: HashMap<Address, AccountState>,
+    /// Storage entries the original execution wrote (`None` = deleted).
+    pub storage_writes: HashMap<(Address, Vec<u8>), Option<Vec<u8>>>,
+    /// The claimed execution result (gas used, state root, events, output).
+    pub result: ExecutionResult,
+}
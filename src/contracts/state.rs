@@ -5,109 +5,427 @@
 //! - Manages stakes and rewards
 //! - Tracks contract-specific storage
 //! - Ensures atomicity of state mutations
+//!
+//! ## Overlay model
+//!
+//! Writes are never applied to the backend directly. Instead they land in a
+//! pending overlay tagged [`Overlay::Dirty`]. Reads consult the overlay
+//! first and fall back to the backend, so a transaction sees its own writes
+//! without the backend being touched. `commit` flushes only the dirty
+//! entries into the backend; `rollback` simply drops the overlay, with no
+//! arithmetic reversal and therefore no risk of a
+//! `saturating_add`/`saturating_sub` pair producing the wrong balance when
+//! an account was touched by more than one mutation in the same execution.
+//!
+//! ## Pluggable backend
+//!
+//! Reads and writes go through a [`StateBackend`] rather than hard-coded
+//! `HashMap` references, so the same contract-execution path can run
+//! against the in-memory map used today ([`InMemoryStateBackend`]) or a
+//! disk- or trie-backed store later. Backend lookups return a
+//! [`StateError`], so a corrupt on-disk entry surfaces as a recoverable
+//! error instead of a panic or a silently wrong answer.
+//!
+//! ## Gas metering
+//!
+//! `storage_read`, `storage_write`, `storage_delete`, `credit`, `debit` and
+//! `emit_event` each charge a [`Schedule`] cost against a running total as
+//! they're called, so a contract that loops over caller-supplied data (e.g.
+//! a bounty distribution over N verifiers) pays gas proportional to what it
+//! actually touches. [`ContractState::with_gas_limit`] sets the ceiling;
+//! [`ContractState::check_gas`] is what actually enforces it — contracts
+//! that loop should call it each iteration so an oversized list aborts
+//! before its writes are committed, rather than only failing at the very
+//! end. With no limit set (the default), usage is tracked but never
+//! enforced.
+
+use std::cell::Cell;
+use std::collections::{HashMap, HashSet};
 
-use std::collections::HashMap;
+use super::gas::Schedule;
 
 use crate::crypto::Hash;
 use crate::state::AccountState;
 use crate::types::{Address, HclawAmount};
 
+/// Errors surfaced by a [`StateBackend`] — backend I/O failure, or a stored
+/// entry that fails to decode. Kept distinct from [`super::ContractError`]
+/// so backend implementations don't need to know about contract semantics;
+/// `ContractState` maps these into `ContractError::State` at the call site.
+#[derive(Debug, thiserror::Error)]
+pub enum StateError {
+    /// The backend failed to read or write (disk I/O, network, etc.)
+    #[error("state backend I/O error: {0}")]
+    Io(String),
+    /// A stored entry could not be decoded — likely corruption
+    #[error("corrupt state entry: {0}")]
+    Corrupt(String),
+}
+
+/// Storage backend consulted by [`ContractState`] for account and contract
+/// storage reads/writes.
+///
+/// [`InMemoryStateBackend`] implements this over the plain `HashMap`s used
+/// today; a disk- or trie-backed implementation can plug in without
+/// `ContractState` or any `Contract` impl changing.
+pub trait StateBackend {
+    /// Look up an account by address
+    fn get_account(&self, address: &Address) -> Result<Option<AccountState>, StateError>;
+    /// Write (or overwrite) an account
+    fn put_account(&mut self, address: Address, account: AccountState) -> Result<(), StateError>;
+    /// All addresses with a stored account (for state root computation)
+    fn account_addresses(&self) -> Result<Vec<Address>, StateError>;
+
+    /// Look up a contract storage value
+    fn get_storage(&self, contract: &Address, key: &[u8]) -> Result<Option<Vec<u8>>, StateError>;
+    /// Write (`Some`) or delete (`None`) a contract storage value
+    fn put_storage(
+        &mut self,
+        contract: Address,
+        key: Vec<u8>,
+        value: Option<Vec<u8>>,
+    ) -> Result<(), StateError>;
+    /// All `(key, value)` storage entries belonging to one contract (for
+    /// storage root computation)
+    fn storage_entries(&self, contract: &Address) -> Result<Vec<(Vec<u8>, Vec<u8>)>, StateError>;
+}
+
+/// [`StateBackend`] over plain in-memory maps — what every test and the
+/// genesis deployment path use today.
+pub struct InMemoryStateBackend<'a> {
+    accounts: &'a mut HashMap<Address, AccountState>,
+    storage: &'a mut HashMap<(Address, Vec<u8>), Vec<u8>>,
+}
+
+impl<'a> InMemoryStateBackend<'a> {
+    /// Wrap the given account/storage maps as a backend
+    #[must_use]
+    pub fn new(
+        accounts: &'a mut HashMap<Address, AccountState>,
+        storage: &'a mut HashMap<(Address, Vec<u8>), Vec<u8>>,
+    ) -> Self {
+        Self { accounts, storage }
+    }
+}
+
+impl StateBackend for InMemoryStateBackend<'_> {
+    fn get_account(&self, address: &Address) -> Result<Option<AccountState>, StateError> {
+        Ok(self.accounts.get(address).cloned())
+    }
+
+    fn put_account(&mut self, address: Address, account: AccountState) -> Result<(), StateError> {
+        self.accounts.insert(address, account);
+        Ok(())
+    }
+
+    fn account_addresses(&self) -> Result<Vec<Address>, StateError> {
+        Ok(self.accounts.keys().copied().collect())
+    }
+
+    fn get_storage(&self, contract: &Address, key: &[u8]) -> Result<Option<Vec<u8>>, StateError> {
+        Ok(self.storage.get(&(*contract, key.to_vec())).cloned())
+    }
+
+    fn put_storage(
+        &mut self,
+        contract: Address,
+        key: Vec<u8>,
+        value: Option<Vec<u8>>,
+    ) -> Result<(), StateError> {
+        match value {
+            Some(v) => {
+                self.storage.insert((contract, key), v);
+            }
+            None => {
+                self.storage.remove(&(contract, key));
+            }
+        }
+        Ok(())
+    }
+
+    fn storage_entries(&self, contract: &Address) -> Result<Vec<(Vec<u8>, Vec<u8>)>, StateError> {
+        Ok(self
+            .storage
+            .iter()
+            .filter(|((c, _), _)| c == contract)
+            .map(|((_, k), v)| (k.clone(), v.clone()))
+            .collect())
+    }
+}
+
+/// An overlay entry, tagged by whether it reflects a pending write.
+///
+/// Only [`Overlay::Dirty`] entries are flushed to the backend on commit.
+/// `Clean` is reserved for cached reads that shouldn't be re-persisted.
+#[derive(Clone, Debug)]
+enum Overlay<T> {
+    /// Cached value matching the backend, not re-written on commit
+    Clean(T),
+    /// Pending write, applied to the backend on commit
+    Dirty(T),
+}
+
+impl<T> Overlay<T> {
+    fn value(&self) -> &T {
+        match self {
+            Self::Clean(v) | Self::Dirty(v) => v,
+        }
+    }
+
+    fn into_value(self) -> T {
+        match self {
+            Self::Clean(v) | Self::Dirty(v) => v,
+        }
+    }
+
+    const fn is_dirty(&self) -> bool {
+        matches!(self, Self::Dirty(_))
+    }
+}
+
 /// State interface for contract execution
 ///
-/// This wraps the full chain state and provides controlled access
+/// This wraps the chain state backend and provides controlled access
 /// to prevent contracts from corrupting state.
-#[derive(Debug)]
 pub struct ContractState<'a> {
-    /// Account balances and metadata
-    pub accounts: &'a mut HashMap<Address, AccountState>,
-    /// Contract-specific key-value storage
-    pub storage: &'a mut HashMap<(Address, Vec<u8>), Vec<u8>>,
-    /// Pending state mutations (for atomic commit/rollback)
-    mutations: Vec<StateMutation>,
+    /// Backend consulted for reads and flushed to on commit
+    backend: Box<dyn StateBackend + 'a>,
+    /// Pending account overlay, consulted before falling back to the backend
+    account_overlay: HashMap<Address, Overlay<AccountState>>,
+    /// Pending storage overlay (`None` = deleted), consulted before the backend
+    storage_overlay: HashMap<(Address, Vec<u8>), Overlay<Option<Vec<u8>>>>,
     /// Events emitted during execution
     events: Vec<super::ContractEvent>,
+    /// Cost table consulted by the metered helpers below
+    gas_schedule: Schedule,
+    /// Gas accumulated so far this execution. A `Cell` so `storage_read`
+    /// can charge gas while remaining a `&self` method — reads are called
+    /// from contexts (e.g. `Contract::verify`) that only hold a shared
+    /// reference to `ContractState`.
+    gas_used: Cell<u64>,
+    /// Gas ceiling checked by [`Self::check_gas`]. `u64::MAX` (the default)
+    /// means unmetered — only callers that want enforcement, e.g. the
+    /// transaction processor threading a transaction's `gas_limit`, need to
+    /// opt in via [`Self::with_gas_limit`].
+    gas_limit: u64,
+    /// Block this execution is part of, if the caller opted in via
+    /// [`Self::with_block_context`].
+    block_context: Option<BlockContext>,
+    /// Ids of contracts currently executing on this state, outermost first.
+    /// Pushed by [`Self::enter_call`] and popped by [`Self::exit_call`] around
+    /// each `Contract::execute` dispatch, so a contract calling back into
+    /// itself (directly or through a chain of nested calls) can be rejected
+    /// instead of re-entering with stale, half-written state.
+    call_stack: Vec<super::Id>,
+}
+
+/// Identifies the block an execution is taking place in, so a contract can
+/// cross-check caller-supplied data (e.g. a bounty hour index) against the
+/// block it actually lands in rather than trusting the value outright.
+/// Attached via [`ContractState::with_block_context`]; `None` by default, so
+/// existing callers (tests, direct `execute` calls) are unaffected.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BlockContext {
+    /// Height of the block this execution is part of
+    pub height: u64,
+    /// Hash of that block
+    pub hash: Hash,
+    /// Block timestamp, milliseconds since the Unix epoch
+    pub timestamp: u64,
 }
 
-/// Represents a state mutation that can be rolled back
+/// A savepoint into the pending overlay, returned by [`ContractState::checkpoint`].
+///
+/// Passing it to [`ContractState::rollback_to`] reverts only the overlay
+/// entries and events recorded after the checkpoint was taken, leaving the
+/// outer frame's earlier work intact — useful when a contract calls into
+/// another contract that may fail independently.
 #[derive(Clone, Debug)]
-enum StateMutation {
-    /// Account balance credit
-    Credit {
-        /// Account address
-        address: Address,
-        /// Amount credited
-        amount: HclawAmount,
-    },
-    /// Account balance debit
-    Debit {
-        /// Account address
-        address: Address,
-        /// Amount debited
-        amount: HclawAmount,
-    },
-    /// Storage write
-    StorageWrite {
-        /// Contract address
-        contract: Address,
-        /// Storage key
-        key: Vec<u8>,
-        /// Old value (for rollback)
-        old_value: Option<Vec<u8>>,
-        /// New value
-        new_value: Vec<u8>,
-    },
+pub struct Checkpoint {
+    account_overlay: HashMap<Address, Overlay<AccountState>>,
+    storage_overlay: HashMap<(Address, Vec<u8>), Overlay<Option<Vec<u8>>>>,
+    events_len: usize,
 }
 
 impl<'a> ContractState<'a> {
-    /// Create new contract state wrapper
+    /// Create new contract state wrapper over plain in-memory maps
     #[must_use]
     pub fn new(
         accounts: &'a mut HashMap<Address, AccountState>,
         storage: &'a mut HashMap<(Address, Vec<u8>), Vec<u8>>,
     ) -> Self {
+        Self::with_backend(InMemoryStateBackend::new(accounts, storage))
+    }
+
+    /// Create new contract state wrapper over an arbitrary [`StateBackend`]
+    #[must_use]
+    pub fn with_backend(backend: impl StateBackend + 'a) -> Self {
         Self {
-            accounts,
-            storage,
-            mutations: Vec::new(),
+            backend: Box::new(backend),
+            account_overlay: HashMap::new(),
+            storage_overlay: HashMap::new(),
             events: Vec::new(),
+            gas_schedule: Schedule::standard(),
+            gas_used: Cell::new(0),
+            gas_limit: u64::MAX,
+            block_context: None,
+            call_stack: Vec::new(),
         }
     }
 
-    /// Get account balance
+    /// Cap gas at `limit`; [`Self::check_gas`] fails once accumulated usage
+    /// exceeds it. The transaction processor calls this with a transaction's
+    /// `gas_limit` before handing `ContractState` to a contract's `execute`.
     #[must_use]
-    pub fn balance(&self, address: &Address) -> HclawAmount {
-        self.accounts
-            .get(address)
-            .map_or(HclawAmount::ZERO, |a| a.balance)
+    pub fn with_gas_limit(mut self, limit: u64) -> Self {
+        self.gas_limit = limit;
+        self
     }
 
-    /// Get available balance (not staked)
+    /// Override the per-operation cost table (defaults to
+    /// [`Schedule::standard`]). The transaction processor calls this with
+    /// the schedule loaded from genesis so testnet and mainnet can charge
+    /// different costs for the same operations.
+    #[must_use]
+    pub fn with_gas_schedule(mut self, schedule: Schedule) -> Self {
+        self.gas_schedule = schedule;
+        self
+    }
+
+    /// Charge the active schedule's flat `base_tx_cost`. The transaction
+    /// processor calls this once per transaction, before handing off to
+    /// `Contract::execute`, so every transaction pays the base fee
+    /// regardless of what the contract itself goes on to touch.
+    pub fn charge_base_tx_cost(&self) {
+        self.charge_gas(self.gas_schedule.base_tx_cost);
+    }
+
+    /// Charge the active schedule's cost for an `output_len`-byte
+    /// `ExecutionResult::output`. The transaction processor calls this
+    /// once execution returns, since `ContractState` itself never sees the
+    /// returned `ExecutionResult`.
+    pub fn charge_output_bytes(&self, output_len: usize) {
+        self.charge_gas(self.gas_schedule.output_cost(output_len));
+    }
+
+    /// Charge the active schedule's cost for deploying `code_len` bytes of
+    /// contract code. The transaction processor calls this once per
+    /// `Deploy` transaction, before running `Contract::on_deploy`.
+    pub fn charge_deploy_cost(&self, code_len: usize) {
+        self.charge_gas(self.gas_schedule.deploy_cost(code_len));
+    }
+
+    /// Attach the block this execution is part of. A contract can read this
+    /// back via [`Self::block_context`] to validate caller-supplied data
+    /// (e.g. a bounty epoch) against the block it's actually executing in.
+    #[must_use]
+    pub fn with_block_context(mut self, context: BlockContext) -> Self {
+        self.block_context = Some(context);
+        self
+    }
+
+    /// Block context attached via [`Self::with_block_context`], if any.
+    #[must_use]
+    pub fn block_context(&self) -> Option<BlockContext> {
+        self.block_context
+    }
+
+    /// Charge `cost` against the running gas total.
+    fn charge_gas(&self, cost: u64) {
+        self.gas_used.set(self.gas_used.get().saturating_add(cost));
+    }
+
+    /// Gas accumulated so far by the metered helpers on this state.
     #[must_use]
-    pub fn available_balance(&self, address: &Address) -> HclawAmount {
-        self.accounts
-            .get(address)
-            .map_or(HclawAmount::ZERO, |a| a.available_balance())
+    pub fn gas_used(&self) -> u64 {
+        self.gas_used.get()
+    }
+
+    /// Fail with [`super::ContractError::OutOfGas`] if accumulated usage has
+    /// exceeded the configured limit. A contract that loops over
+    /// caller-supplied data should call this each iteration so an oversized
+    /// list aborts before its writes are committed, instead of only failing
+    /// once the whole loop has already run.
+    ///
+    /// # Errors
+    /// Returns `ContractError::OutOfGas` if gas used exceeds the gas limit
+    pub fn check_gas(&self) -> Result<(), super::ContractError> {
+        let used = self.gas_used.get();
+        if used > self.gas_limit {
+            return Err(super::ContractError::OutOfGas {
+                used,
+                limit: self.gas_limit,
+            });
+        }
+        Ok(())
+    }
+
+    /// The account as it would read right now: the overlay entry if the
+    /// account has been touched this execution, otherwise the backend value.
+    fn effective_account(&self, address: &Address) -> Result<AccountState, StateError> {
+        match self.account_overlay.get(address) {
+            Some(overlay) => Ok(overlay.value().clone()),
+            None => Ok(self.backend.get_account(address)?.unwrap_or_default()),
+        }
+    }
+
+    /// The storage value as it would read right now: the overlay entry if
+    /// this key has been touched this execution, otherwise the backend value.
+    fn effective_storage(
+        &self,
+        contract: &Address,
+        key: &[u8],
+    ) -> Result<Option<Vec<u8>>, StateError> {
+        match self.storage_overlay.get(&(*contract, key.to_vec())) {
+            Some(overlay) => Ok(overlay.value().clone()),
+            None => self.backend.get_storage(contract, key),
+        }
+    }
+
+    /// Get account balance
+    ///
+    /// # Errors
+    /// Returns error if the backend fails to read the account
+    pub fn balance(&self, address: &Address) -> Result<HclawAmount, StateError> {
+        Ok(self.effective_account(address)?.balance)
+    }
+
+    /// Get available balance (not staked)
+    ///
+    /// # Errors
+    /// Returns error if the backend fails to read the account
+    pub fn available_balance(&self, address: &Address) -> Result<HclawAmount, StateError> {
+        Ok(self.effective_account(address)?.available_balance())
     }
 
     /// Credit an account
     ///
-    /// This queues a mutation that will be applied on commit.
-    pub fn credit(&mut self, address: Address, amount: HclawAmount) {
-        // Apply immediately
-        let account = self.accounts.entry(address).or_default();
+    /// This only touches the pending overlay; nothing is written to the
+    /// backend until [`Self::commit`].
+    ///
+    /// # Errors
+    /// Returns error if the backend fails to read the account's current value
+    pub fn credit(&mut self, address: Address, amount: HclawAmount) -> Result<(), StateError> {
+        self.charge_gas(self.gas_schedule.credit);
+        let mut account = self.effective_account(&address)?;
         account.credit(amount);
-
-        // Record mutation for potential rollback
-        self.mutations.push(StateMutation::Credit { address, amount });
+        self.account_overlay
+            .insert(address, Overlay::Dirty(account));
+        Ok(())
     }
 
     /// Debit an account
     ///
     /// # Errors
-    /// Returns error if insufficient balance
-    pub fn debit(&mut self, address: Address, amount: HclawAmount) -> Result<(), super::ContractError> {
-        // Validate balance
-        let account = self.accounts.entry(address).or_default();
+    /// Returns error if insufficient balance or the backend fails to read
+    /// the account's current value
+    pub fn debit(
+        &mut self,
+        address: Address,
+        amount: HclawAmount,
+    ) -> Result<(), super::ContractError> {
+        self.charge_gas(self.gas_schedule.debit);
+        let mut account = self.effective_account(&address)?;
         if account.available_balance() < amount {
             return Err(super::ContractError::InsufficientBalance {
                 need: amount,
@@ -115,13 +433,12 @@ impl<'a> ContractState<'a> {
             });
         }
 
-        // Apply debit
-        account.debit(amount).map_err(|e| {
-            super::ContractError::ExecutionFailed(format!("debit failed: {}", e))
-        })?;
+        account
+            .debit(amount)
+            .map_err(|e| super::ContractError::ExecutionFailed(format!("debit failed: {}", e)))?;
 
-        // Record mutation
-        self.mutations.push(StateMutation::Debit { address, amount });
+        self.account_overlay
+            .insert(address, Overlay::Dirty(account));
 
         Ok(())
     }
@@ -129,7 +446,8 @@ impl<'a> ContractState<'a> {
     /// Transfer tokens between accounts
     ///
     /// # Errors
-    /// Returns error if insufficient balance
+    /// Returns error if insufficient balance or the backend fails to read
+    /// either account's current value
     pub fn transfer(
         &mut self,
         from: Address,
@@ -137,44 +455,43 @@ impl<'a> ContractState<'a> {
         amount: HclawAmount,
     ) -> Result<(), super::ContractError> {
         self.debit(from, amount)?;
-        self.credit(to, amount);
+        self.credit(to, amount)?;
         Ok(())
     }
 
     /// Read from contract storage
-    #[must_use]
-    pub fn storage_read(&self, contract: &Address, key: &[u8]) -> Option<Vec<u8>> {
-        self.storage.get(&(*contract, key.to_vec())).cloned()
+    ///
+    /// # Errors
+    /// Returns error if the backend fails to read the entry
+    pub fn storage_read(
+        &self,
+        contract: &Address,
+        key: &[u8],
+    ) -> Result<Option<Vec<u8>>, StateError> {
+        self.charge_gas(self.gas_schedule.storage_read);
+        self.effective_storage(contract, key)
     }
 
     /// Write to contract storage
+    ///
+    /// This only touches the pending overlay; nothing is written to the
+    /// backend until [`Self::commit`].
     pub fn storage_write(&mut self, contract: Address, key: Vec<u8>, value: Vec<u8>) {
-        let old_value = self.storage.get(&(contract, key.clone())).cloned();
-        self.storage.insert((contract, key.clone()), value.clone());
-
-        self.mutations.push(StateMutation::StorageWrite {
-            contract,
-            key,
-            old_value,
-            new_value: value,
-        });
+        self.charge_gas(self.gas_schedule.storage_write_cost(value.len()));
+        self.storage_overlay
+            .insert((contract, key), Overlay::Dirty(Some(value)));
     }
 
     /// Delete from contract storage
     pub fn storage_delete(&mut self, contract: Address, key: Vec<u8>) {
-        let old_value = self.storage.remove(&(contract, key.clone()));
-        if let Some(old_val) = old_value {
-            self.mutations.push(StateMutation::StorageWrite {
-                contract,
-                key,
-                old_value: Some(old_val),
-                new_value: Vec::new(),
-            });
-        }
+        self.charge_gas(self.gas_schedule.storage_write);
+        self.storage_overlay
+            .insert((contract, key), Overlay::Dirty(None));
     }
 
     /// Emit an event
     pub fn emit_event(&mut self, event: super::ContractEvent) {
+        self.charge_gas(self.gas_schedule.emit_event);
         self.events.push(event);
     }
 
@@ -186,74 +503,220 @@ impl<'a> ContractState<'a> {
 
     /// Commit all pending mutations
     ///
-    /// This finalizes the state changes. After commit, rollback is no longer possible.
-    pub fn commit(&mut self) {
-        // Mutations are already applied, just clear the log
-        self.mutations.clear();
+    /// Flushes every dirty overlay entry into the backend, then clears the
+    /// overlay. After commit, rollback is no longer possible.
+    ///
+    /// # Errors
+    /// Returns error if the backend fails to persist an entry; entries
+    /// already flushed before the failing one remain applied.
+    pub fn commit(&mut self) -> Result<(), StateError> {
+        for (address, overlay) in self.account_overlay.drain() {
+            if overlay.is_dirty() {
+                self.backend.put_account(address, overlay.into_value())?;
+            }
+        }
+
+        for ((contract, key), overlay) in self.storage_overlay.drain() {
+            if overlay.is_dirty() {
+                self.backend
+                    .put_storage(contract, key, overlay.into_value())?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Snapshot of everything this execution has written so far — every
+    /// `Dirty` overlay entry — without committing it to the backend or
+    /// clearing the overlay. Used by [`super::proof::ExecutionProof`]
+    /// generation to capture a transaction's write-set for a verifier that
+    /// only has the proof, not the backend it was generated against.
+    #[must_use]
+    pub fn dirty_writes(
+        &self,
+    ) -> (
+        HashMap<Address, AccountState>,
+        HashMap<(Address, Vec<u8>), Option<Vec<u8>>>,
+    ) {
+        let accounts = self
+            .account_overlay
+            .iter()
+            .filter(|(_, overlay)| overlay.is_dirty())
+            .map(|(address, overlay)| (*address, overlay.value().clone()))
+            .collect();
+        let storage = self
+            .storage_overlay
+            .iter()
+            .filter(|(_, overlay)| overlay.is_dirty())
+            .map(|(key, overlay)| (key.clone(), overlay.value().clone()))
+            .collect();
+        (accounts, storage)
     }
 
     /// Rollback all pending mutations
     ///
-    /// This reverts all state changes made during contract execution.
-    /// Used when execution fails or verification rejects the result.
+    /// This discards the entire pending overlay — an O(1) drop, since
+    /// nothing was ever applied to the backend. Used when execution fails
+    /// or verification rejects the result.
     pub fn rollback(&mut self) {
-        // Reverse mutations in reverse order
-        for mutation in self.mutations.drain(..).rev() {
-            match mutation {
-                StateMutation::Credit { address, amount } => {
-                    // Reverse credit = debit
-                    if let Some(account) = self.accounts.get_mut(&address) {
-                        account.balance = account.balance.saturating_sub(amount);
-                    }
-                }
-                StateMutation::Debit { address, amount } => {
-                    // Reverse debit = credit
-                    if let Some(account) = self.accounts.get_mut(&address) {
-                        account.balance = account.balance.saturating_add(amount);
+        self.account_overlay.clear();
+        self.storage_overlay.clear();
+        self.events.clear();
+    }
+
+    /// Record a savepoint of the current pending overlay.
+    ///
+    /// Pass the returned [`Checkpoint`] to [`Self::rollback_to`] to undo
+    /// only the overlay entries and events recorded since this call,
+    /// without discarding the outer frame's earlier work.
+    #[must_use]
+    pub fn checkpoint(&self) -> Checkpoint {
+        Checkpoint {
+            account_overlay: self.account_overlay.clone(),
+            storage_overlay: self.storage_overlay.clone(),
+            events_len: self.events.len(),
+        }
+    }
+
+    /// Restore the pending overlay and events to a prior [`Checkpoint`],
+    /// discarding everything recorded since.
+    pub fn rollback_to(&mut self, checkpoint: Checkpoint) {
+        self.account_overlay = checkpoint.account_overlay;
+        self.storage_overlay = checkpoint.storage_overlay;
+        self.events.truncate(checkpoint.events_len);
+    }
+
+    /// Discard a [`Checkpoint`] without reverting to it, canonicalizing
+    /// every write recorded since it was taken into the current overlay.
+    /// A no-op today since [`Self::checkpoint`] snapshots rather than
+    /// forking the overlay, but callers should still pair every
+    /// `checkpoint()` with either this or [`Self::rollback_to`] so the
+    /// success path reads the same as the failure path, and so a future
+    /// checkpoint representation (e.g. a real overlay stack) doesn't
+    /// silently change success-path behavior.
+    pub fn commit_checkpoint(&self, _checkpoint: Checkpoint) {}
+
+    /// Push `id` onto the call stack, rejecting the call if `id` is already
+    /// on it. Callers (today, only [`super::processor::TransactionProcessor`]
+    /// around its single `Contract::execute` dispatch) should call this
+    /// before invoking a contract and [`Self::exit_call`] afterward,
+    /// regardless of whether execution succeeded.
+    ///
+    /// # Errors
+    /// Returns [`super::ContractError::Reentrancy`] if `id` is already
+    /// executing somewhere on the stack.
+    pub fn enter_call(&mut self, id: super::Id) -> Result<(), super::ContractError> {
+        if self.call_stack.contains(&id) {
+            return Err(super::ContractError::Reentrancy(id));
+        }
+        self.call_stack.push(id);
+        Ok(())
+    }
+
+    /// Pop the most recently entered call off the stack. Pairs with
+    /// [`Self::enter_call`]; a no-op if the stack is already empty, so a
+    /// caller that short-circuited before ever entering can call this
+    /// unconditionally.
+    pub fn exit_call(&mut self) {
+        self.call_stack.pop();
+    }
+
+    /// All effective `(key, value)` storage entries for `address` — backend
+    /// entries with any pending overlay writes (or deletes) folded in —
+    /// sorted by key. Exposed so callers that need to enumerate a
+    /// contract's whole storage space (e.g. a snapshot exporter) don't have
+    /// to reimplement the overlay merge `compute_storage_root` does
+    /// internally.
+    ///
+    /// # Errors
+    /// Returns error if the backend fails to enumerate storage entries
+    pub fn effective_storage_entries(
+        &self,
+        address: &Address,
+    ) -> Result<Vec<(Vec<u8>, Vec<u8>)>, StateError> {
+        let mut entries: HashMap<Vec<u8>, Vec<u8>> =
+            self.backend.storage_entries(address)?.into_iter().collect();
+
+        for ((contract, key), overlay) in &self.storage_overlay {
+            if contract == address {
+                match overlay.value() {
+                    Some(value) => {
+                        entries.insert(key.clone(), value.clone());
                     }
-                }
-                StateMutation::StorageWrite {
-                    contract,
-                    key,
-                    old_value,
-                    ..
-                } => {
-                    // Restore old value (or delete if it didn't exist)
-                    if let Some(old_val) = old_value {
-                        self.storage.insert((contract, key), old_val);
-                    } else {
-                        self.storage.remove(&(contract, key));
+                    None => {
+                        entries.remove(key);
                     }
                 }
             }
         }
 
-        // Clear events
-        self.events.clear();
+        let mut entries: Vec<(Vec<u8>, Vec<u8>)> = entries.into_iter().collect();
+        entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+        Ok(entries)
+    }
+
+    /// Compute the per-account storage root: the root of a Merkle Patricia
+    /// trie over every effective storage entry keyed to `address` (backend
+    /// plus pending overlay). Building a real trie rather than a flat
+    /// merkle-of-leaves lets [`Self::prove`] produce an inclusion proof for
+    /// a single key without the rest of the account's storage.
+    ///
+    /// # Errors
+    /// Returns error if the backend fails to enumerate storage entries
+    fn compute_storage_root(&self, address: &Address) -> Result<Hash, StateError> {
+        let entries = self.effective_storage_entries(address)?;
+        Ok(super::trie::root_hash(&entries))
+    }
+
+    /// Build an inclusion proof that `key` is present (with its current
+    /// effective value) in `address`'s storage trie, for a light client or
+    /// cross-contract verifier to check against a claimed
+    /// `ExecutionResult::new_state_root`'s storage root without replaying
+    /// the transaction. Returns `None` if `address` has no entry for `key`.
+    ///
+    /// # Errors
+    /// Returns error if the backend fails to enumerate storage entries
+    pub fn prove(
+        &self,
+        address: &Address,
+        key: &[u8],
+    ) -> Result<Option<super::trie::MerkleProof>, StateError> {
+        let entries = self.effective_storage_entries(address)?;
+        Ok(super::trie::prove(&entries, key))
     }
 
     /// Compute state root hash
     ///
     /// This is used to verify state transitions match across verifiers.
-    #[must_use]
-    pub fn compute_state_root(&self) -> Hash {
+    /// Folds both account balances and each account's contract storage
+    /// into the root (backend state plus any pending overlay), so
+    /// divergent storage — or an uncommitted pending write — is caught.
+    ///
+    /// # Errors
+    /// Returns error if the backend fails to enumerate accounts or storage
+    pub fn compute_state_root(&self) -> Result<Hash, StateError> {
         use crate::crypto::{hash_data, merkle_root};
 
-        let mut hashes: Vec<Hash> = self
-            .accounts
-            .iter()
-            .map(|(addr, state)| {
-                let mut data = Vec::new();
-                data.extend_from_slice(addr.as_bytes());
-                data.extend_from_slice(&state.balance.raw().to_le_bytes());
-                data.extend_from_slice(&state.nonce.to_le_bytes());
-                data.extend_from_slice(&state.staked.raw().to_le_bytes());
-                hash_data(&data)
-            })
-            .collect();
+        let mut addresses: HashSet<Address> =
+            self.backend.account_addresses()?.into_iter().collect();
+        addresses.extend(self.account_overlay.keys().copied());
+
+        let mut hashes = Vec::with_capacity(addresses.len());
+        for addr in addresses {
+            let state = self.effective_account(&addr)?;
+            let storage_root = self.compute_storage_root(&addr)?;
+
+            let mut data = Vec::new();
+            data.extend_from_slice(addr.as_bytes());
+            data.extend_from_slice(&state.balance.raw().to_le_bytes());
+            data.extend_from_slice(&state.nonce.to_le_bytes());
+            data.extend_from_slice(&state.staked.raw().to_le_bytes());
+            data.extend_from_slice(storage_root.as_bytes());
+            hashes.push(hash_data(&data));
+        }
 
         hashes.sort_by(|a, b| a.as_bytes().cmp(b.as_bytes()));
-        merkle_root(&hashes)
+        Ok(merkle_root(&hashes))
     }
 }
 
@@ -276,12 +739,12 @@ mod tests {
         let addr = test_address();
 
         // Credit account
-        state.credit(addr, HclawAmount::from_hclaw(100));
-        assert_eq!(state.balance(&addr).whole_hclaw(), 100);
+        state.credit(addr, HclawAmount::from_hclaw(100)).unwrap();
+        assert_eq!(state.balance(&addr).unwrap().whole_hclaw(), 100);
 
         // Debit account
         state.debit(addr, HclawAmount::from_hclaw(30)).unwrap();
-        assert_eq!(state.balance(&addr).whole_hclaw(), 70);
+        assert_eq!(state.balance(&addr).unwrap().whole_hclaw(), 70);
     }
 
     #[test]
@@ -293,12 +756,12 @@ mod tests {
         let addr = test_address();
 
         // Make changes
-        state.credit(addr, HclawAmount::from_hclaw(100));
-        assert_eq!(state.balance(&addr).whole_hclaw(), 100);
+        state.credit(addr, HclawAmount::from_hclaw(100)).unwrap();
+        assert_eq!(state.balance(&addr).unwrap().whole_hclaw(), 100);
 
         // Rollback
         state.rollback();
-        assert_eq!(state.balance(&addr).whole_hclaw(), 0);
+        assert_eq!(state.balance(&addr).unwrap().whole_hclaw(), 0);
     }
 
     #[test]
@@ -311,13 +774,15 @@ mod tests {
         let bob = test_address();
 
         // Give Alice tokens
-        state.credit(alice, HclawAmount::from_hclaw(100));
+        state.credit(alice, HclawAmount::from_hclaw(100)).unwrap();
 
         // Transfer to Bob
-        state.transfer(alice, bob, HclawAmount::from_hclaw(30)).unwrap();
+        state
+            .transfer(alice, bob, HclawAmount::from_hclaw(30))
+            .unwrap();
 
-        assert_eq!(state.balance(&alice).whole_hclaw(), 70);
-        assert_eq!(state.balance(&bob).whole_hclaw(), 30);
+        assert_eq!(state.balance(&alice).unwrap().whole_hclaw(), 70);
+        assert_eq!(state.balance(&bob).unwrap().whole_hclaw(), 30);
     }
 
     #[test]
@@ -332,10 +797,230 @@ mod tests {
 
         // Write
         state.storage_write(contract, key.clone(), value.clone());
-        assert_eq!(state.storage_read(&contract, &key), Some(value));
+        assert_eq!(state.storage_read(&contract, &key).unwrap(), Some(value));
 
         // Rollback should revert
         state.rollback();
-        assert_eq!(state.storage_read(&contract, &key), None);
+        assert_eq!(state.storage_read(&contract, &key).unwrap(), None);
+    }
+
+    #[test]
+    fn test_enter_call_rejects_reentry() {
+        let mut accounts = HashMap::new();
+        let mut storage = HashMap::new();
+        let mut state = ContractState::new(&mut accounts, &mut storage);
+
+        let id = super::super::Id::from_bytes([1u8; 32]);
+        state.enter_call(id).unwrap();
+
+        let err = state.enter_call(id).unwrap_err();
+        assert!(matches!(err, super::super::ContractError::Reentrancy(got) if got == id));
+    }
+
+    #[test]
+    fn test_enter_call_rejects_reentry_before_withdrawal_flag_is_written() {
+        // Mirrors a classic withdraw-before-update reentrancy attack: a
+        // contract debits the caller, then (in a real cross-contract-call
+        // setup) would be reentered by the callee before it gets a chance
+        // to write the "already withdrawn" storage flag. The guard must
+        // reject the nested call regardless of what's been written to
+        // storage yet.
+        let mut accounts = HashMap::new();
+        let mut storage = HashMap::new();
+        let mut state = ContractState::new(&mut accounts, &mut storage);
+
+        let vault = test_address();
+        let attacker = test_address();
+        let id = super::super::Id::from_bytes([2u8; 32]);
+
+        state
+            .credit(vault, HclawAmount::from_hclaw(100))
+            .unwrap();
+        state.enter_call(id).unwrap();
+
+        // Debit happens, but the "withdrawn" flag hasn't been written yet.
+        state
+            .transfer(vault, attacker, HclawAmount::from_hclaw(100))
+            .unwrap();
+        assert_eq!(
+            state.storage_read(&vault, b"withdrawn").unwrap(),
+            None
+        );
+
+        // The simulated reentry attempt must be rejected before it can
+        // drain the vault a second time.
+        assert!(matches!(
+            state.enter_call(id),
+            Err(super::super::ContractError::Reentrancy(got)) if got == id
+        ));
+
+        state.storage_write(vault, b"withdrawn".to_vec(), b"1".to_vec());
+        state.exit_call();
+    }
+
+    #[test]
+    fn test_enter_call_allows_sequential_reuse() {
+        let mut accounts = HashMap::new();
+        let mut storage = HashMap::new();
+        let mut state = ContractState::new(&mut accounts, &mut storage);
+
+        let id = super::super::Id::from_bytes([3u8; 32]);
+        state.enter_call(id).unwrap();
+        state.exit_call();
+
+        // A prior call finishing cleanly shouldn't block a later,
+        // non-overlapping call with the same id.
+        state.enter_call(id).unwrap();
+        state.exit_call();
+    }
+
+    #[test]
+    fn test_state_root_changes_with_storage() {
+        let mut accounts = HashMap::new();
+        let mut storage = HashMap::new();
+        let mut state = ContractState::new(&mut accounts, &mut storage);
+
+        let contract = test_address();
+        state.credit(contract, HclawAmount::from_hclaw(10)).unwrap();
+        let root_before = state.compute_state_root().unwrap();
+
+        // Divergent storage alone must change the root, even though
+        // balance/nonce/staked are unchanged.
+        state.storage_write(contract, b"k".to_vec(), b"v".to_vec());
+        let root_after = state.compute_state_root().unwrap();
+
+        assert_ne!(root_before, root_after);
+    }
+
+    #[test]
+    fn test_checkpoint_partial_rollback() {
+        let mut accounts = HashMap::new();
+        let mut storage = HashMap::new();
+        let mut state = ContractState::new(&mut accounts, &mut storage);
+
+        let addr = test_address();
+
+        // Outer frame's work
+        state.credit(addr, HclawAmount::from_hclaw(100)).unwrap();
+
+        // Inner frame (e.g. a nested contract call) that ultimately fails
+        let cp = state.checkpoint();
+        state.credit(addr, HclawAmount::from_hclaw(50)).unwrap();
+        state.emit_event(super::super::ContractEvent {
+            contract_id: Hash::ZERO,
+            topic: "inner".into(),
+            data: Vec::new(),
+        });
+        assert_eq!(state.balance(&addr).unwrap().whole_hclaw(), 150);
+
+        // Roll back just the inner frame
+        state.rollback_to(cp);
+
+        // Outer frame's credit survives, inner frame's does not
+        assert_eq!(state.balance(&addr).unwrap().whole_hclaw(), 100);
+        assert_eq!(state.events().len(), 0);
+    }
+
+    #[test]
+    fn test_state_root_stable_for_empty_storage() {
+        let mut accounts = HashMap::new();
+        let mut storage = HashMap::new();
+        let mut state = ContractState::new(&mut accounts, &mut storage);
+
+        let addr = test_address();
+        state.credit(addr, HclawAmount::from_hclaw(1)).unwrap();
+
+        // Computing the root twice with no storage entries must be stable.
+        assert_eq!(
+            state.compute_state_root().unwrap(),
+            state.compute_state_root().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_commit_flushes_overlay_to_backend() {
+        let mut accounts = HashMap::new();
+        let mut storage = HashMap::new();
+
+        {
+            let mut state = ContractState::new(&mut accounts, &mut storage);
+            state
+                .credit(test_address(), HclawAmount::from_hclaw(5))
+                .unwrap();
+            state.commit().unwrap();
+        }
+
+        // The backend was untouched until commit, and now reflects it
+        // directly (no overlay needed for a fresh ContractState).
+        assert_eq!(accounts.values().next().unwrap().balance.whole_hclaw(), 5);
+    }
+
+    #[test]
+    fn test_rollback_does_not_touch_backend() {
+        let mut accounts = HashMap::new();
+        let mut storage = HashMap::new();
+
+        {
+            let mut state = ContractState::new(&mut accounts, &mut storage);
+            state
+                .credit(test_address(), HclawAmount::from_hclaw(5))
+                .unwrap();
+            state.rollback();
+        }
+
+        assert!(accounts.is_empty());
+    }
+
+    #[test]
+    fn test_double_touch_no_reversal_hazard() {
+        // Two separate credits to the same account within one execution,
+        // then a rollback, must leave the backend completely untouched —
+        // there is no arithmetic reversal to get wrong.
+        let mut accounts = HashMap::new();
+        let mut storage = HashMap::new();
+        let mut state = ContractState::new(&mut accounts, &mut storage);
+
+        let addr = test_address();
+        state.credit(addr, HclawAmount::from_hclaw(10)).unwrap();
+        state.credit(addr, HclawAmount::from_hclaw(20)).unwrap();
+        assert_eq!(state.balance(&addr).unwrap().whole_hclaw(), 30);
+
+        state.rollback();
+        assert_eq!(state.balance(&addr).unwrap().whole_hclaw(), 0);
+    }
+
+    #[test]
+    fn test_gas_accumulates_across_metered_calls() {
+        let mut accounts = HashMap::new();
+        let mut storage = HashMap::new();
+        let mut state = ContractState::new(&mut accounts, &mut storage);
+
+        assert_eq!(state.gas_used(), 0);
+
+        let addr = test_address();
+        state.credit(addr, HclawAmount::from_hclaw(1)).unwrap();
+        let after_credit = state.gas_used();
+        assert!(after_credit > 0);
+
+        state.storage_write(addr, b"k".to_vec(), b"v".to_vec());
+        assert!(state.gas_used() > after_credit);
+    }
+
+    #[test]
+    fn test_check_gas_fails_once_limit_exceeded() {
+        let mut accounts = HashMap::new();
+        let mut storage = HashMap::new();
+        let mut state = ContractState::new(&mut accounts, &mut storage).with_gas_limit(1);
+
+        // Unmetered so far, under the limit.
+        assert!(state.check_gas().is_ok());
+
+        let addr = test_address();
+        state.credit(addr, HclawAmount::from_hclaw(1)).unwrap();
+
+        assert!(matches!(
+            state.check_gas(),
+            Err(super::super::ContractError::OutOfGas { .. })
+        ));
     }
 }
@@ -5,9 +5,16 @@
 
 use serde::{Deserialize, Serialize};
 
+use super::ContractError;
 use crate::crypto::{Hash, PublicKey, Signature};
 use crate::types::{Address, HclawAmount, Id, Timestamp};
 
+/// `tx_type` tag for a legacy transaction with a single flat `gas_price`.
+pub const TX_TYPE_LEGACY: u8 = 0;
+/// `tx_type` tag for an EIP-1559-style fee-market transaction, carrying
+/// `max_fee_per_gas`/`max_priority_fee_per_gas` instead of a flat price.
+pub const TX_TYPE_EIP1559: u8 = 1;
+
 /// A transaction that executes a smart contract
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ContractTransaction {
@@ -23,18 +30,42 @@ pub struct ContractTransaction {
     pub input: Vec<u8>,
     /// Maximum gas willing to pay
     pub gas_limit: u64,
-    /// Gas price (HCLAW per unit)
+    /// Gas price (HCLAW per unit). For `tx_type == TX_TYPE_EIP1559`
+    /// transactions this is set to `max_fee_per_gas`, so gas-accounting
+    /// code that only knows about a flat price still charges a safe upper
+    /// bound; use [`effective_gas_price`](Self::effective_gas_price) for
+    /// the actual base-fee-aware price.
     pub gas_price: HclawAmount,
     /// Nonce (for ordering transactions from same sender)
     pub nonce: u64,
     /// When transaction was created
     pub timestamp: Timestamp,
-    /// Sender's signature
-    pub signature: Signature,
+    /// How the transaction authorizes itself against `sender_address`
+    pub authenticator: TransactionAuthenticator,
+    /// Fee-market tag: [`TX_TYPE_LEGACY`] or [`TX_TYPE_EIP1559`]. Defaults
+    /// to [`TX_TYPE_LEGACY`] on deserialization so transactions recorded
+    /// before this field existed still decode correctly.
+    #[serde(default)]
+    pub tx_type: u8,
+    /// Per-gas fee cap, set only for `tx_type == TX_TYPE_EIP1559`.
+    #[serde(default)]
+    pub max_fee_per_gas: Option<HclawAmount>,
+    /// Tip offered to the block author on top of the base fee, set only
+    /// for `tx_type == TX_TYPE_EIP1559`.
+    #[serde(default)]
+    pub max_priority_fee_per_gas: Option<HclawAmount>,
+    /// Opt-in escape hatch from [`ContractState`](super::state::ContractState)'s
+    /// reentrancy guard, for the rare contract that legitimately needs a
+    /// nested call into itself (e.g. a recursive tree traversal). `false`
+    /// for every transaction recorded before this field existed, so the
+    /// guard is enforced by default.
+    #[serde(default)]
+    pub allow_reentrancy: bool,
 }
 
 impl ContractTransaction {
-    /// Create new contract transaction (unsigned)
+    /// Create new contract transaction, authorized by `sender` alone
+    /// (unsigned)
     #[must_use]
     pub fn new(
         contract_id: Id,
@@ -57,7 +88,112 @@ impl ContractTransaction {
             gas_price,
             nonce,
             timestamp,
-            signature: Signature::placeholder(),
+            authenticator: TransactionAuthenticator::Single(Signature::placeholder()),
+            tx_type: TX_TYPE_LEGACY,
+            max_fee_per_gas: None,
+            max_priority_fee_per_gas: None,
+            allow_reentrancy: false,
+        };
+
+        tx.id = tx.compute_id();
+        tx
+    }
+
+    /// Create a new EIP-1559-style fee-market transaction, authorized by
+    /// `sender` alone (unsigned). Instead of naming one flat `gas_price`,
+    /// the sender names a cap (`max_fee_per_gas`) and a tip for the block
+    /// author on top of the prevailing base fee (`max_priority_fee_per_gas`),
+    /// giving the mempool a basis for tip-based ordering under congestion.
+    ///
+    /// # Errors
+    /// Returns [`ContractError::InvalidTransaction`] if
+    /// `max_priority_fee_per_gas` exceeds `max_fee_per_gas`.
+    pub fn new_eip1559(
+        contract_id: Id,
+        sender: PublicKey,
+        input: Vec<u8>,
+        gas_limit: u64,
+        max_fee_per_gas: HclawAmount,
+        max_priority_fee_per_gas: HclawAmount,
+        nonce: u64,
+    ) -> Result<Self, ContractError> {
+        let sender_address = Address::from_public_key(&sender);
+        let timestamp = crate::types::now_millis();
+
+        let mut tx = Self {
+            id: Hash::ZERO,
+            contract_id,
+            sender,
+            sender_address,
+            input,
+            gas_limit,
+            gas_price: max_fee_per_gas,
+            nonce,
+            timestamp,
+            authenticator: TransactionAuthenticator::Single(Signature::placeholder()),
+            tx_type: TX_TYPE_EIP1559,
+            max_fee_per_gas: Some(max_fee_per_gas),
+            max_priority_fee_per_gas: Some(max_priority_fee_per_gas),
+            allow_reentrancy: false,
+        };
+
+        tx.validate_fees()?;
+        tx.id = tx.compute_id();
+        Ok(tx)
+    }
+
+    /// Create a new transaction authorized by a k-of-n multisig group
+    /// (unsigned). `sender_address` is derived from the sorted key set and
+    /// `threshold` via [`Address::from_multisig`] rather than from any one
+    /// key, letting shared/treasury accounts and governance contracts gate
+    /// execution behind multiple approvers.
+    ///
+    /// # Panics
+    /// Panics if `public_keys` is empty, `threshold` is 0, or `threshold`
+    /// exceeds `public_keys.len()` — a 0 threshold would let an empty
+    /// signature set authorize the transaction, and a threshold above the
+    /// key count could never be met by any signature set.
+    #[must_use]
+    pub fn new_multisig(
+        contract_id: Id,
+        public_keys: Vec<PublicKey>,
+        threshold: u8,
+        input: Vec<u8>,
+        gas_limit: u64,
+        gas_price: HclawAmount,
+        nonce: u64,
+    ) -> Self {
+        assert!(
+            threshold as usize >= 1 && threshold as usize <= public_keys.len(),
+            "multisig threshold must be between 1 and the number of keys ({}), got {threshold}",
+            public_keys.len()
+        );
+        let sender_address = Address::from_multisig(&public_keys, threshold);
+        let sender = public_keys
+            .first()
+            .expect("multisig transaction needs at least one key")
+            .clone();
+        let timestamp = crate::types::now_millis();
+
+        let mut tx = Self {
+            id: Hash::ZERO,
+            contract_id,
+            sender,
+            sender_address,
+            input,
+            gas_limit,
+            gas_price,
+            nonce,
+            timestamp,
+            authenticator: TransactionAuthenticator::MultiSig {
+                public_keys,
+                signatures: Vec::new(),
+                threshold,
+            },
+            tx_type: TX_TYPE_LEGACY,
+            max_fee_per_gas: None,
+            max_priority_fee_per_gas: None,
+            allow_reentrancy: false,
         };
 
         tx.id = tx.compute_id();
@@ -65,6 +201,11 @@ impl ContractTransaction {
     }
 
     /// Compute transaction ID
+    ///
+    /// For `tx_type == TX_TYPE_EIP1559` the fee-market fields are folded in
+    /// place of `gas_price`; legacy transactions hash exactly the bytes
+    /// they always have, so ids computed before this field existed are
+    /// unchanged.
     #[must_use]
     pub fn compute_id(&self) -> Id {
         use crate::crypto::hash_data;
@@ -74,14 +215,22 @@ impl ContractTransaction {
         data.extend_from_slice(self.sender.as_bytes());
         data.extend_from_slice(&self.input);
         data.extend_from_slice(&self.gas_limit.to_le_bytes());
-        data.extend_from_slice(&self.gas_price.raw().to_le_bytes());
+        self.extend_fee_bytes(&mut data);
+        if self.allow_reentrancy {
+            data.push(1);
+        }
         data.extend_from_slice(&self.nonce.to_le_bytes());
         data.extend_from_slice(&self.timestamp.to_le_bytes());
 
         hash_data(&data)
     }
 
-    /// Get bytes to sign
+    /// Get bytes to sign.
+    ///
+    /// For `tx_type == TX_TYPE_EIP1559` the fee-market fields are folded in
+    /// place of `gas_price`; legacy transactions sign exactly the bytes
+    /// they always have, so signatures produced before this field existed
+    /// remain valid.
     #[must_use]
     pub fn signing_bytes(&self) -> Vec<u8> {
         let mut data = Vec::new();
@@ -90,26 +239,202 @@ impl ContractTransaction {
         data.extend_from_slice(self.sender.as_bytes());
         data.extend_from_slice(&self.input);
         data.extend_from_slice(&self.gas_limit.to_le_bytes());
-        data.extend_from_slice(&self.gas_price.raw().to_le_bytes());
+        self.extend_fee_bytes(&mut data);
+        if self.allow_reentrancy {
+            data.push(1);
+        }
         data.extend_from_slice(&self.nonce.to_le_bytes());
         data
     }
 
-    /// Verify transaction signature
+    /// Append this transaction's fee fields to `data`, in the shape
+    /// [`compute_id`](Self::compute_id) and
+    /// [`signing_bytes`](Self::signing_bytes) both need: the flat
+    /// `gas_price` for legacy transactions (unchanged from before
+    /// `tx_type` existed), or the `tx_type` tag followed by the fee-market
+    /// fields for EIP-1559 ones.
+    fn extend_fee_bytes(&self, data: &mut Vec<u8>) {
+        if self.tx_type == TX_TYPE_EIP1559 {
+            data.push(self.tx_type);
+            let max_fee = self.max_fee_per_gas.unwrap_or(HclawAmount::ZERO);
+            let max_priority = self.max_priority_fee_per_gas.unwrap_or(HclawAmount::ZERO);
+            data.extend_from_slice(&max_fee.raw().to_le_bytes());
+            data.extend_from_slice(&max_priority.raw().to_le_bytes());
+        } else {
+            data.extend_from_slice(&self.gas_price.raw().to_le_bytes());
+        }
+    }
+
+    /// Reject transactions whose fee-market fields are internally
+    /// inconsistent: a `max_priority_fee_per_gas` above `max_fee_per_gas`
+    /// would mean the tip alone could exceed what the sender is willing to
+    /// pay in total. Legacy transactions (no fee-market fields set) always
+    /// pass.
     ///
     /// # Errors
-    /// Returns error if signature is invalid
+    /// Returns [`ContractError::InvalidTransaction`] if
+    /// `max_priority_fee_per_gas > max_fee_per_gas`.
+    pub fn validate_fees(&self) -> Result<(), ContractError> {
+        if let (Some(max_fee), Some(max_priority)) =
+            (self.max_fee_per_gas, self.max_priority_fee_per_gas)
+        {
+            if max_priority.raw() > max_fee.raw() {
+                return Err(ContractError::InvalidTransaction(format!(
+                    "max_priority_fee_per_gas ({max_priority}) exceeds max_fee_per_gas ({max_fee})"
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Effective per-gas price at a given block `base_fee`:
+    /// `min(max_fee_per_gas, base_fee + max_priority_fee_per_gas)`. Legacy
+    /// transactions ignore `base_fee` and always pay their flat
+    /// `gas_price`.
+    #[must_use]
+    pub fn effective_gas_price(&self, base_fee: HclawAmount) -> HclawAmount {
+        match (self.max_fee_per_gas, self.max_priority_fee_per_gas) {
+            (Some(max_fee), Some(max_priority)) => {
+                let tip_price = HclawAmount::from_raw(base_fee.raw() + max_priority.raw());
+                if tip_price.raw() < max_fee.raw() {
+                    tip_price
+                } else {
+                    max_fee
+                }
+            }
+            _ => self.gas_price,
+        }
+    }
+
+    /// Sign this transaction with `signer`, replacing its authenticator
+    /// with a single signature over [`signing_bytes`](Self::signing_bytes).
+    ///
+    /// Accepts anything implementing [`Signer`](crate::crypto::Signer) —
+    /// a [`Wallet`](crate::wallet::Wallet) signs locally, while a
+    /// [`RemoteSigner`](crate::crypto::RemoteSigner) ships the signing
+    /// bytes out to an HSM, air-gapped machine, or threshold backend and
+    /// reads the signature back — so callers no longer need a local
+    /// [`Keypair`](crate::crypto::Keypair) to authorize a transaction.
+    ///
+    /// Only meaningful for single-key transactions; a multisig transaction
+    /// collects its signatures into `TransactionAuthenticator::MultiSig`
+    /// directly instead.
+    ///
+    /// # Errors
+    /// Returns [`SignerError::PublicKeyMismatch`](crate::crypto::SignerError::PublicKeyMismatch)
+    /// if `signer`'s public key isn't `self.sender`, or propagates whatever
+    /// error the signer itself returns.
+    pub fn sign_with(
+        &mut self,
+        signer: &impl crate::crypto::Signer,
+    ) -> Result<(), crate::crypto::SignerError> {
+        if signer.public_key() != &self.sender {
+            return Err(crate::crypto::SignerError::PublicKeyMismatch);
+        }
+
+        let signature = signer.sign(&self.signing_bytes())?;
+        self.authenticator = TransactionAuthenticator::Single(signature);
+        Ok(())
+    }
+
+    /// Verify the transaction's authorization against `sender_address`
+    ///
+    /// # Errors
+    /// Returns error if the single signature is invalid, or if the
+    /// multisig authorization doesn't derive `sender_address`, contains a
+    /// duplicate or out-of-bounds signer index, or has fewer than
+    /// `threshold` valid signatures
     pub fn verify_signature(&self) -> Result<(), crate::crypto::CryptoError> {
-        crate::crypto::verify(&self.sender, &self.signing_bytes(), &self.signature)
+        match &self.authenticator {
+            TransactionAuthenticator::Single(signature) => {
+                crate::crypto::verify(&self.sender, &self.signing_bytes(), signature)
+            }
+            TransactionAuthenticator::MultiSig {
+                public_keys,
+                signatures,
+                threshold,
+            } => self.verify_multisig(public_keys, signatures, *threshold),
+        }
     }
 
-    /// Maximum fee (`gas_limit` * `gas_price`)
+    /// Verify a [`TransactionAuthenticator::MultiSig`] authorization: the
+    /// threshold must be between 1 and `public_keys.len()`, the derived
+    /// multisig address must match `sender_address`, every signature must
+    /// be tagged with a distinct, in-bounds key index, and at least
+    /// `threshold` of them must verify against `signing_bytes()`.
+    fn verify_multisig(
+        &self,
+        public_keys: &[PublicKey],
+        signatures: &[(u8, Signature)],
+        threshold: u8,
+    ) -> Result<(), crate::crypto::CryptoError> {
+        // A 0 threshold would authorize the transaction with zero
+        // signatures; a threshold above the key count can never be met.
+        // `new_multisig` already rejects both for honestly-built
+        // transactions, but this also runs on authenticators decoded
+        // straight off the wire, so it can't rely on that alone.
+        if threshold == 0 || threshold as usize > public_keys.len() {
+            return Err(crate::crypto::CryptoError::InvalidSignature);
+        }
+
+        if Address::from_multisig(public_keys, threshold) != self.sender_address {
+            return Err(crate::crypto::CryptoError::InvalidSignature);
+        }
+
+        let message = self.signing_bytes();
+        let mut seen_indices = std::collections::HashSet::new();
+        let mut valid_count = 0u8;
+
+        for (index, signature) in signatures {
+            if !seen_indices.insert(*index) {
+                return Err(crate::crypto::CryptoError::InvalidSignature);
+            }
+            let Some(public_key) = public_keys.get(*index as usize) else {
+                return Err(crate::crypto::CryptoError::InvalidSignature);
+            };
+            if crate::crypto::verify(public_key, &message, signature).is_ok() {
+                valid_count = valid_count.saturating_add(1);
+            }
+        }
+
+        if valid_count < threshold {
+            return Err(crate::crypto::CryptoError::InvalidSignature);
+        }
+
+        Ok(())
+    }
+
+    /// Maximum fee the sender could possibly owe: `gas_limit *
+    /// max_fee_per_gas` for EIP-1559 transactions, or `gas_limit *
+    /// gas_price` for legacy ones.
     #[must_use]
     pub fn max_fee(&self) -> HclawAmount {
-        HclawAmount::from_raw(self.gas_price.raw() * self.gas_limit as u128)
+        let price = self.max_fee_per_gas.unwrap_or(self.gas_price);
+        HclawAmount::from_raw(price.raw() * self.gas_limit as u128)
     }
 }
 
+/// How a transaction authorizes itself against `sender_address`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum TransactionAuthenticator {
+    /// Single-key signature. `sender_address` is
+    /// `Address::from_public_key(&tx.sender)`.
+    Single(Signature),
+    /// k-of-n multisig. `sender_address` must equal
+    /// `Address::from_multisig(&public_keys, threshold)`. Each signature is
+    /// tagged with the index (into `public_keys`) of the key that produced
+    /// it, so signers don't need to sign in any particular order.
+    MultiSig {
+        /// The full key set, in the order addressed by signature indices
+        public_keys: Vec<PublicKey>,
+        /// `(signer index, signature)` pairs; must contain at least
+        /// `threshold` entries with distinct, valid indices
+        signatures: Vec<(u8, Signature)>,
+        /// Minimum number of distinct valid signatures required
+        threshold: u8,
+    },
+}
+
 /// Types of contract transactions
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum TransactionKind {
@@ -191,4 +516,315 @@ mod tests {
 
         assert_eq!(tx.max_fee().raw(), 100_000);
     }
+
+    #[test]
+    fn test_eip1559_rejects_priority_fee_above_max_fee() {
+        let kp = Keypair::generate();
+
+        let result = ContractTransaction::new_eip1559(
+            Hash::ZERO,
+            kp.public_key().clone(),
+            b"test".to_vec(),
+            1_000,
+            HclawAmount::from_raw(50),
+            HclawAmount::from_raw(100),
+            1,
+        );
+
+        assert!(matches!(result, Err(ContractError::InvalidTransaction(_))));
+    }
+
+    #[test]
+    fn test_eip1559_max_fee_bounds_on_max_fee_per_gas() {
+        let kp = Keypair::generate();
+        let tx = ContractTransaction::new_eip1559(
+            Hash::ZERO,
+            kp.public_key().clone(),
+            b"test".to_vec(),
+            1_000,
+            HclawAmount::from_raw(100),
+            HclawAmount::from_raw(10),
+            1,
+        )
+        .unwrap();
+
+        assert_eq!(tx.max_fee().raw(), 100_000);
+    }
+
+    #[test]
+    fn test_eip1559_effective_gas_price_caps_at_max_fee() {
+        let kp = Keypair::generate();
+        let tx = ContractTransaction::new_eip1559(
+            Hash::ZERO,
+            kp.public_key().clone(),
+            b"test".to_vec(),
+            1_000,
+            HclawAmount::from_raw(100),
+            HclawAmount::from_raw(10),
+            1,
+        )
+        .unwrap();
+
+        // base_fee + priority (95 + 10 = 105) would exceed max_fee_per_gas
+        // (100), so the price is capped at max_fee_per_gas.
+        assert_eq!(tx.effective_gas_price(HclawAmount::from_raw(95)).raw(), 100);
+        // base_fee + priority (50 + 10 = 60) stays under the cap.
+        assert_eq!(tx.effective_gas_price(HclawAmount::from_raw(50)).raw(), 60);
+    }
+
+    #[test]
+    fn test_legacy_effective_gas_price_ignores_base_fee() {
+        let kp = Keypair::generate();
+        let tx = ContractTransaction::new(
+            Hash::ZERO,
+            kp.public_key().clone(),
+            b"test".to_vec(),
+            1_000,
+            HclawAmount::from_raw(7),
+            1,
+        );
+
+        assert_eq!(
+            tx.effective_gas_price(HclawAmount::from_raw(1_000)).raw(),
+            7
+        );
+    }
+
+    #[test]
+    fn test_legacy_compute_id_unchanged_by_new_fields() {
+        // A legacy transaction's id must hash exactly the bytes it always
+        // has, so ids computed before tx_type/max_fee_per_gas/
+        // max_priority_fee_per_gas existed stay valid.
+        let kp = Keypair::generate();
+        let tx = ContractTransaction::new(
+            Hash::ZERO,
+            kp.public_key().clone(),
+            b"test".to_vec(),
+            1_000,
+            HclawAmount::from_raw(7),
+            1,
+        );
+
+        let mut data = Vec::new();
+        data.extend_from_slice(tx.contract_id.as_bytes());
+        data.extend_from_slice(tx.sender.as_bytes());
+        data.extend_from_slice(&tx.input);
+        data.extend_from_slice(&tx.gas_limit.to_le_bytes());
+        data.extend_from_slice(&tx.gas_price.raw().to_le_bytes());
+        data.extend_from_slice(&tx.nonce.to_le_bytes());
+        data.extend_from_slice(&tx.timestamp.to_le_bytes());
+        let expected = crate::crypto::hash_data(&data);
+
+        assert_eq!(tx.compute_id(), expected);
+    }
+
+    #[test]
+    fn test_eip1559_sign_with_round_trips() {
+        let wallet = crate::wallet::Wallet::generate();
+        let mut tx = ContractTransaction::new_eip1559(
+            Hash::ZERO,
+            wallet.public_key().clone(),
+            b"test".to_vec(),
+            1_000,
+            HclawAmount::from_raw(100),
+            HclawAmount::from_raw(10),
+            1,
+        )
+        .unwrap();
+
+        tx.sign_with(&wallet).unwrap();
+
+        assert!(tx.verify_signature().is_ok());
+    }
+
+    fn multisig_tx(public_keys: Vec<PublicKey>, threshold: u8) -> ContractTransaction {
+        ContractTransaction::new_multisig(
+            Hash::ZERO,
+            public_keys,
+            threshold,
+            b"test".to_vec(),
+            1_000_000,
+            HclawAmount::from_raw(1),
+            1,
+        )
+    }
+
+    #[test]
+    fn test_multisig_accepts_threshold_valid_signatures() {
+        let kp_a = Keypair::generate();
+        let kp_b = Keypair::generate();
+        let kp_c = Keypair::generate();
+        let public_keys = vec![
+            kp_a.public_key().clone(),
+            kp_b.public_key().clone(),
+            kp_c.public_key().clone(),
+        ];
+
+        let mut tx = multisig_tx(public_keys, 2);
+        let message = tx.signing_bytes();
+        tx.authenticator = TransactionAuthenticator::MultiSig {
+            public_keys: vec![
+                kp_a.public_key().clone(),
+                kp_b.public_key().clone(),
+                kp_c.public_key().clone(),
+            ],
+            signatures: vec![(0, kp_a.sign(&message)), (2, kp_c.sign(&message))],
+            threshold: 2,
+        };
+
+        assert!(tx.verify_signature().is_ok());
+    }
+
+    #[test]
+    fn test_multisig_rejects_below_threshold_signatures() {
+        let kp_a = Keypair::generate();
+        let kp_b = Keypair::generate();
+        let public_keys = vec![kp_a.public_key().clone(), kp_b.public_key().clone()];
+
+        let mut tx = multisig_tx(public_keys, 2);
+        let message = tx.signing_bytes();
+        tx.authenticator = TransactionAuthenticator::MultiSig {
+            public_keys: vec![kp_a.public_key().clone(), kp_b.public_key().clone()],
+            signatures: vec![(0, kp_a.sign(&message))],
+            threshold: 2,
+        };
+
+        assert!(tx.verify_signature().is_err());
+    }
+
+    #[test]
+    fn test_multisig_rejects_duplicate_signer_index() {
+        let kp_a = Keypair::generate();
+        let kp_b = Keypair::generate();
+        let public_keys = vec![kp_a.public_key().clone(), kp_b.public_key().clone()];
+
+        let mut tx = multisig_tx(public_keys, 1);
+        let message = tx.signing_bytes();
+        tx.authenticator = TransactionAuthenticator::MultiSig {
+            public_keys: vec![kp_a.public_key().clone(), kp_b.public_key().clone()],
+            signatures: vec![(0, kp_a.sign(&message)), (0, kp_a.sign(&message))],
+            threshold: 1,
+        };
+
+        assert!(tx.verify_signature().is_err());
+    }
+
+    #[test]
+    fn test_multisig_rejects_zero_threshold() {
+        let kp_a = Keypair::generate();
+        let kp_b = Keypair::generate();
+        let public_keys = vec![kp_a.public_key().clone(), kp_b.public_key().clone()];
+
+        // Simulates a transaction decoded off the wire with threshold: 0
+        // baked into sender_address — must be rejected even though an
+        // empty signature set never trips the `valid_count < threshold` check.
+        let mut tx = multisig_tx(public_keys.clone(), 1);
+        tx.sender_address = Address::from_multisig(&public_keys, 0);
+        tx.authenticator = TransactionAuthenticator::MultiSig {
+            public_keys,
+            signatures: Vec::new(),
+            threshold: 0,
+        };
+
+        assert!(tx.verify_signature().is_err());
+    }
+
+    #[test]
+    fn test_multisig_rejects_threshold_above_key_count() {
+        let kp_a = Keypair::generate();
+        let kp_b = Keypair::generate();
+        let public_keys = vec![kp_a.public_key().clone(), kp_b.public_key().clone()];
+
+        let mut tx = multisig_tx(public_keys.clone(), 1);
+        tx.sender_address = Address::from_multisig(&public_keys, 3);
+        let message = tx.signing_bytes();
+        tx.authenticator = TransactionAuthenticator::MultiSig {
+            public_keys: public_keys.clone(),
+            signatures: vec![(0, kp_a.sign(&message)), (1, kp_b.sign(&message))],
+            threshold: 3,
+        };
+
+        assert!(tx.verify_signature().is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "multisig threshold must be between 1")]
+    fn test_new_multisig_panics_on_zero_threshold() {
+        let kp_a = Keypair::generate();
+        let _ = multisig_tx(vec![kp_a.public_key().clone()], 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "multisig threshold must be between 1")]
+    fn test_new_multisig_panics_on_threshold_above_key_count() {
+        let kp_a = Keypair::generate();
+        let _ = multisig_tx(vec![kp_a.public_key().clone()], 2);
+    }
+
+    #[test]
+    fn test_multisig_rejects_mismatched_sender_address() {
+        let kp_a = Keypair::generate();
+        let kp_b = Keypair::generate();
+        let public_keys = vec![kp_a.public_key().clone(), kp_b.public_key().clone()];
+
+        // Built for a 2-of-2 threshold, but the authenticator below claims 1-of-2.
+        let mut tx = multisig_tx(public_keys, 2);
+        let message = tx.signing_bytes();
+        tx.authenticator = TransactionAuthenticator::MultiSig {
+            public_keys: vec![kp_a.public_key().clone(), kp_b.public_key().clone()],
+            signatures: vec![(0, kp_a.sign(&message))],
+            threshold: 1,
+        };
+
+        assert!(tx.verify_signature().is_err());
+    }
+
+    #[test]
+    fn test_sign_with_wallet_produces_verifiable_transaction() {
+        let wallet = crate::wallet::Wallet::generate();
+        let mut tx = ContractTransaction::new(
+            Hash::ZERO,
+            wallet.public_key().clone(),
+            b"test input".to_vec(),
+            1_000_000,
+            HclawAmount::from_raw(1),
+            1,
+        );
+
+        tx.sign_with(&wallet).unwrap();
+
+        assert!(tx.verify_signature().is_ok());
+    }
+
+    #[test]
+    fn test_sign_with_rejects_mismatched_signer() {
+        let sender = Keypair::generate();
+        let wrong_wallet = crate::wallet::Wallet::generate();
+        let mut tx = ContractTransaction::new(
+            Hash::ZERO,
+            sender.public_key().clone(),
+            b"test input".to_vec(),
+            1_000_000,
+            HclawAmount::from_raw(1),
+            1,
+        );
+
+        assert!(matches!(
+            tx.sign_with(&wrong_wallet),
+            Err(crate::crypto::SignerError::PublicKeyMismatch)
+        ));
+    }
+
+    #[test]
+    fn test_multisig_address_independent_of_key_order() {
+        let kp_a = Keypair::generate();
+        let kp_b = Keypair::generate();
+
+        let forward =
+            Address::from_multisig(&[kp_a.public_key().clone(), kp_b.public_key().clone()], 2);
+        let reversed =
+            Address::from_multisig(&[kp_b.public_key().clone(), kp_a.public_key().clone()], 2);
+
+        assert_eq!(forward, reversed);
+    }
 }
@@ -0,0 +1,363 @@
+//! Merkle Patricia trie over contract storage.
+//!
+//! `ContractState::compute_storage_root` used to hash a flat, sorted list
+//! of leaves — a correct commitment, but one that only lets a verifier
+//! check a single key by re-deriving the whole root from every entry. This
+//! module builds a real radix-16 Patricia trie (no RLP/hex-prefix framing
+//! like Ethereum's — nodes are bincode-encoded and content-addressed by
+//! `hash_data`) so a single key/value pair can be proven against the root
+//! with [`prove`]/[`verify_proof`] alone, without the rest of the state.
+//!
+//! The trie is rebuilt from scratch on every call rather than persisted,
+//! matching how `ContractState` already recomputes `compute_storage_root`
+//! from `effective_storage_entries` on demand instead of maintaining a
+//! live tree.
+
+use std::array;
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::crypto::{hash_data, Hash};
+
+/// A trie node. Content-addressed: a node's identity is `hash_data` of its
+/// bincode encoding, which is what parent nodes (and proofs) reference.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+enum Node {
+    /// Terminal node: the remaining nibble path plus the stored value.
+    Leaf { path: Vec<u8>, value: Vec<u8> },
+    /// A single child reached via a shared nibble path (path compression,
+    /// as in Ethereum's Patricia trie).
+    Extension { path: Vec<u8>, child: Hash },
+    /// Up to 16 children, one per next nibble, plus an optional value for
+    /// a key whose path ends exactly at this branch.
+    Branch {
+        children: [Option<Hash>; 16],
+        value: Option<Vec<u8>>,
+    },
+}
+
+impl Node {
+    fn hash(&self) -> Hash {
+        hash_data(&bincode::serialize(self).expect("trie node is always serializable"))
+    }
+}
+
+/// A proof that a single `(key, value)` pair is present under a given
+/// trie root, as the ordered chain of nodes from the root down to the
+/// matching leaf/branch. Self-contained: [`verify_proof`] only needs the
+/// claimed root and this proof, never the rest of the trie.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MerkleProof {
+    nodes: Vec<Node>,
+}
+
+/// Root hash of the trie over zero entries, analogous to the old flat
+/// root's `EMPTY_STORAGE` sentinel.
+fn empty_root() -> Hash {
+    hash_data(b"EMPTY_TRIE")
+}
+
+/// Split a byte key into its nibble path, high nibble first.
+fn key_to_nibbles(key: &[u8]) -> Vec<u8> {
+    let mut nibbles = Vec::with_capacity(key.len() * 2);
+    for byte in key {
+        nibbles.push(byte >> 4);
+        nibbles.push(byte & 0x0f);
+    }
+    nibbles
+}
+
+fn common_prefix_len(a: &[u8], b: &[u8]) -> usize {
+    a.iter().zip(b).take_while(|(x, y)| x == y).count()
+}
+
+/// Build the trie over `entries` and return its root hash plus every node
+/// created, keyed by its own hash (used to walk a path back down for
+/// [`prove`]).
+fn build(entries: &[(Vec<u8>, Vec<u8>)]) -> (Hash, HashMap<Hash, Node>) {
+    let mut store = HashMap::new();
+    if entries.is_empty() {
+        return (empty_root(), store);
+    }
+
+    let nibble_entries: Vec<(Vec<u8>, &Vec<u8>)> = entries
+        .iter()
+        .map(|(key, value)| (key_to_nibbles(key), value))
+        .collect();
+
+    let root = build_node(&nibble_entries, &mut store);
+    (root, store)
+}
+
+/// Recursively build the subtree covering `entries` (already restricted to
+/// nibble paths relative to this subtree's parent), inserting every node
+/// it creates into `store`, and return the subtree's root hash.
+fn build_node(entries: &[(Vec<u8>, &Vec<u8>)], store: &mut HashMap<Hash, Node>) -> Hash {
+    debug_assert!(!entries.is_empty());
+
+    if entries.len() == 1 {
+        let (path, value) = &entries[0];
+        return insert(
+            Node::Leaf {
+                path: path.clone(),
+                value: (*value).clone(),
+            },
+            store,
+        );
+    }
+
+    let mut prefix = entries[0].0.clone();
+    for (path, _) in &entries[1..] {
+        let len = common_prefix_len(&prefix, path);
+        prefix.truncate(len);
+        if prefix.is_empty() {
+            break;
+        }
+    }
+
+    if !prefix.is_empty() {
+        let stripped: Vec<(Vec<u8>, &Vec<u8>)> = entries
+            .iter()
+            .map(|(path, value)| (path[prefix.len()..].to_vec(), *value))
+            .collect();
+        let child = build_node(&stripped, store);
+        return insert(
+            Node::Extension {
+                path: prefix,
+                child,
+            },
+            store,
+        );
+    }
+
+    // No shared prefix left: branch on the first nibble. An entry whose
+    // path is already empty here terminates exactly at this branch.
+    let mut children: [Option<Hash>; 16] = array::from_fn(|_| None);
+    let mut branch_value = None;
+    for nibble in 0u8..16 {
+        let group: Vec<(Vec<u8>, &Vec<u8>)> = entries
+            .iter()
+            .filter(|(path, _)| path.first() == Some(&nibble))
+            .map(|(path, value)| (path[1..].to_vec(), *value))
+            .collect();
+        if !group.is_empty() {
+            children[nibble as usize] = Some(build_node(&group, store));
+        }
+    }
+    for (path, value) in entries {
+        if path.is_empty() {
+            branch_value = Some((*value).clone());
+        }
+    }
+
+    insert(
+        Node::Branch {
+            children,
+            value: branch_value,
+        },
+        store,
+    )
+}
+
+fn insert(node: Node, store: &mut HashMap<Hash, Node>) -> Hash {
+    let hash = node.hash();
+    store.insert(hash, node);
+    hash
+}
+
+/// Walk from `root` down to the node covering `path`, collecting every
+/// node visited in root-to-leaf order. `None` if no entry in the trie has
+/// exactly `path`.
+fn find_path(path: &[u8], root: Hash, store: &HashMap<Hash, Node>) -> Option<Vec<Node>> {
+    let node = store.get(&root)?.clone();
+    match &node {
+        Node::Leaf {
+            path: leaf_path, ..
+        } => {
+            if leaf_path.as_slice() == path {
+                Some(vec![node])
+            } else {
+                None
+            }
+        }
+        Node::Extension {
+            path: ext_path,
+            child,
+        } => {
+            let rest_path = path.strip_prefix(ext_path.as_slice())?;
+            let mut trail = find_path(rest_path, *child, store)?;
+            let mut result = vec![node];
+            result.append(&mut trail);
+            Some(result)
+        }
+        Node::Branch { children, value } => {
+            if path.is_empty() {
+                return value.is_some().then(|| vec![node]);
+            }
+            let child = children[path[0] as usize]?;
+            let mut trail = find_path(&path[1..], child, store)?;
+            let mut result = vec![node];
+            result.append(&mut trail);
+            Some(result)
+        }
+    }
+}
+
+/// Root hash of the trie over `entries`. Equivalent to the old flat
+/// merkle-of-leaves root in what it commits to (every `(key, value)` pair
+/// and nothing else), but now supports [`prove`] for a single key.
+#[must_use]
+pub fn root_hash(entries: &[(Vec<u8>, Vec<u8>)]) -> Hash {
+    build(entries).0
+}
+
+/// Build a proof that `key` is present in the trie over `entries`, or
+/// `None` if `entries` contains no pair with that exact key.
+#[must_use]
+pub fn prove(entries: &[(Vec<u8>, Vec<u8>)], key: &[u8]) -> Option<MerkleProof> {
+    let (root, store) = build(entries);
+    let nodes = find_path(&key_to_nibbles(key), root, &store)?;
+    Some(MerkleProof { nodes })
+}
+
+/// Verify that `(key, value)` is present under `root`, using only `proof`
+/// — no access to the rest of the trie's entries required. This is what
+/// lets a light client or cross-contract verifier confirm a single touched
+/// slot against `ExecutionResult::new_state_root` without replaying the
+/// whole execution.
+#[must_use]
+pub fn verify_proof(root: Hash, key: &[u8], value: &[u8], proof: &MerkleProof) -> bool {
+    let mut remaining: &[u8] = &key_to_nibbles(key);
+    let mut expected_hash = root;
+
+    for node in &proof.nodes {
+        if node.hash() != expected_hash {
+            return false;
+        }
+        match node {
+            Node::Leaf {
+                path,
+                value: leaf_value,
+            } => {
+                return path.as_slice() == remaining && leaf_value == value;
+            }
+            Node::Extension { path, child } => {
+                let Some(rest) = remaining.strip_prefix(path.as_slice()) else {
+                    return false;
+                };
+                remaining = rest;
+                expected_hash = *child;
+            }
+            Node::Branch {
+                children,
+                value: branch_value,
+            } => {
+                if remaining.is_empty() {
+                    return branch_value.as_deref() == Some(value);
+                }
+                match children[remaining[0] as usize] {
+                    Some(child) => {
+                        expected_hash = child;
+                        remaining = &remaining[1..];
+                    }
+                    None => return false,
+                }
+            }
+        }
+    }
+
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entries() -> Vec<(Vec<u8>, Vec<u8>)> {
+        vec![
+            (b"alpha".to_vec(), b"1".to_vec()),
+            (b"alp".to_vec(), b"2".to_vec()),
+            (b"beta".to_vec(), b"3".to_vec()),
+        ]
+    }
+
+    #[test]
+    fn test_empty_trie_has_stable_root() {
+        assert_eq!(root_hash(&[]), root_hash(&[]));
+        assert_eq!(root_hash(&[]), empty_root());
+    }
+
+    #[test]
+    fn test_root_is_order_independent() {
+        let mut shuffled = entries();
+        shuffled.reverse();
+        assert_eq!(root_hash(&entries()), root_hash(&shuffled));
+    }
+
+    #[test]
+    fn test_root_changes_when_a_value_changes() {
+        let mut changed = entries();
+        changed[0].1 = b"different".to_vec();
+        assert_ne!(root_hash(&entries()), root_hash(&changed));
+    }
+
+    #[test]
+    fn test_prove_and_verify_roundtrip_for_every_entry() {
+        let data = entries();
+        let root = root_hash(&data);
+
+        for (key, value) in &data {
+            let proof = prove(&data, key).expect("entry must be provable");
+            assert!(verify_proof(root, key, value, &proof));
+        }
+    }
+
+    #[test]
+    fn test_prove_returns_none_for_missing_key() {
+        let data = entries();
+        assert!(prove(&data, b"nonexistent").is_none());
+    }
+
+    #[test]
+    fn test_verify_rejects_wrong_value() {
+        let data = entries();
+        let root = root_hash(&data);
+        let proof = prove(&data, b"alpha").unwrap();
+        assert!(!verify_proof(root, b"alpha", b"wrong-value", &proof));
+    }
+
+    #[test]
+    fn test_verify_rejects_proof_against_a_different_root() {
+        let data = entries();
+        let proof = prove(&data, b"alpha").unwrap();
+
+        let mut other_data = data.clone();
+        other_data.push((b"gamma".to_vec(), b"4".to_vec()));
+        let other_root = root_hash(&other_data);
+
+        assert!(!verify_proof(other_root, b"alpha", b"1", &proof));
+    }
+
+    #[test]
+    fn test_verify_rejects_proof_for_a_different_key() {
+        let data = entries();
+        let root = root_hash(&data);
+        let proof = prove(&data, b"alpha").unwrap();
+        assert!(!verify_proof(root, b"beta", b"3", &proof));
+    }
+
+    #[test]
+    fn test_prefix_keys_coexist() {
+        // "alp" is a strict prefix of "alpha" in nibble-path terms, which
+        // forces a branch value rather than a plain leaf.
+        let data = entries();
+        let root = root_hash(&data);
+
+        let proof_alp = prove(&data, b"alp").unwrap();
+        assert!(verify_proof(root, b"alp", b"2", &proof_alp));
+
+        let proof_alpha = prove(&data, b"alpha").unwrap();
+        assert!(verify_proof(root, b"alpha", b"1", &proof_alpha));
+    }
+}
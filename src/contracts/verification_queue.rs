@@ -0,0 +1,491 @@
+//! Parallel cross-verifier pool for re-checking claimed contract state
+//! transitions.
+//!
+//! [`ContractState::compute_state_root`](crate::contracts::state::ContractState::compute_state_root)
+//! exists "to verify state transitions match across verifiers," but nothing
+//! drove that comparison and it ran single-threaded. `VerificationQueue`
+//! accepts candidate transitions — a transaction plus its claimed resulting
+//! state root and the account/storage snapshot it ran against — and fans
+//! them out across a fixed pool of worker threads sized to the number of
+//! available CPUs. Each worker re-executes the transaction via
+//! [`TransactionProcessor::verify_execution`] against a cloned snapshot and
+//! records whether the claimed root held up.
+//!
+//! ## Queue design
+//!
+//! Modeled on a standard block-queue: a shared work set guarded by a
+//! [`Mutex`], with idle workers parked on a [`Condvar`] until new candidates
+//! arrive or the queue shuts down. [`QueueInfo`] is a point-in-time snapshot
+//! of how many candidates are unverified, actively being verified, or
+//! already verified — exposed through the HTTP API so operators can watch
+//! backlog depth.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread;
+
+use crate::contracts::processor::TransactionProcessor;
+use crate::contracts::transaction::ContractTransaction;
+use crate::contracts::ExecutionResult;
+use crate::state::AccountState;
+use crate::types::{Address, Id};
+
+/// A candidate state transition awaiting independent re-verification.
+pub struct VerificationTask {
+    /// The transaction that was (allegedly) executed
+    pub tx: ContractTransaction,
+    /// The execution result claimed by whoever ran `tx`
+    pub claimed: ExecutionResult,
+    /// Account snapshot `tx` was executed against
+    pub accounts: HashMap<Address, AccountState>,
+    /// Storage snapshot `tx` was executed against
+    pub storage: HashMap<(Address, Vec<u8>), Vec<u8>>,
+}
+
+/// Outcome of re-verifying one [`VerificationTask`].
+#[derive(Clone, Debug)]
+pub struct VerifyOutcome {
+    /// The transaction this outcome is for
+    pub tx_id: Id,
+    /// Whether the claimed result was confirmed
+    pub accepted: bool,
+    /// Set if re-verification itself errored, as distinct from a rejected
+    /// claim (e.g. the contract wasn't registered)
+    pub error: Option<String>,
+}
+
+/// Point-in-time snapshot of queue depth, broken down by stage.
+#[derive(Clone, Copy, Debug, Default, serde::Serialize)]
+pub struct QueueInfo {
+    /// Candidates submitted but not yet picked up by a worker
+    pub unverified: usize,
+    /// Candidates a worker is actively re-executing
+    pub verifying: usize,
+    /// Candidates whose outcome has been recorded
+    pub verified: usize,
+}
+
+impl QueueInfo {
+    /// Candidates the queue has ever tracked, across all three stages
+    #[must_use]
+    pub const fn total_queue_size(&self) -> usize {
+        self.unverified + self.verifying + self.verified
+    }
+
+    /// Candidates not yet fully verified (queued or in flight)
+    #[must_use]
+    pub const fn incomplete_queue_size(&self) -> usize {
+        self.unverified + self.verifying
+    }
+}
+
+struct QueueState {
+    pending: VecDeque<VerificationTask>,
+    verifying: usize,
+    outcomes: Vec<VerifyOutcome>,
+    shutdown: bool,
+}
+
+impl QueueState {
+    fn info(&self) -> QueueInfo {
+        QueueInfo {
+            unverified: self.pending.len(),
+            verifying: self.verifying,
+            verified: self.outcomes.len(),
+        }
+    }
+}
+
+struct Shared {
+    processor: Arc<TransactionProcessor>,
+    state: Mutex<QueueState>,
+    ready: Condvar,
+}
+
+/// A pool of worker threads that independently re-verify candidate state
+/// transitions against a shared [`TransactionProcessor`].
+///
+/// Workers are spawned once, at construction, and run until the queue is
+/// dropped.
+pub struct VerificationQueue {
+    shared: Arc<Shared>,
+    workers: Vec<thread::JoinHandle<()>>,
+}
+
+impl VerificationQueue {
+    /// Create a queue with one worker per available CPU.
+    #[must_use]
+    pub fn new(processor: Arc<TransactionProcessor>) -> Self {
+        let num_workers = thread::available_parallelism().map_or(1, std::num::NonZeroUsize::get);
+        Self::with_workers(processor, num_workers)
+    }
+
+    /// Create a queue with an explicit worker count (at least 1).
+    #[must_use]
+    pub fn with_workers(processor: Arc<TransactionProcessor>, num_workers: usize) -> Self {
+        let shared = Arc::new(Shared {
+            processor,
+            state: Mutex::new(QueueState {
+                pending: VecDeque::new(),
+                verifying: 0,
+                outcomes: Vec::new(),
+                shutdown: false,
+            }),
+            ready: Condvar::new(),
+        });
+
+        let workers = (0..num_workers.max(1))
+            .map(|i| {
+                let shared = Arc::clone(&shared);
+                thread::Builder::new()
+                    .name(format!("verify-worker-{i}"))
+                    .spawn(move || worker_loop(&shared))
+                    .expect("spawn verification worker")
+            })
+            .collect();
+
+        Self { shared, workers }
+    }
+
+    /// Submit a candidate transition for re-verification.
+    ///
+    /// Wakes one idle worker; the task is picked up in FIFO order.
+    pub fn submit(&self, task: VerificationTask) {
+        let mut state = self.lock();
+        state.pending.push_back(task);
+        self.shared.ready.notify_one();
+    }
+
+    /// Snapshot current queue depth.
+    #[must_use]
+    pub fn queue_info(&self) -> QueueInfo {
+        self.lock().info()
+    }
+
+    /// Drain and return every outcome recorded since the last call.
+    pub fn take_outcomes(&self) -> Vec<VerifyOutcome> {
+        std::mem::take(&mut self.lock().outcomes)
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, QueueState> {
+        self.shared
+            .state
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+impl Drop for VerificationQueue {
+    fn drop(&mut self) {
+        {
+            let mut state = self.lock();
+            state.shutdown = true;
+        }
+        self.shared.ready.notify_all();
+
+        for worker in self.workers.drain(..) {
+            let _ = worker.join();
+        }
+    }
+}
+
+fn worker_loop(shared: &Arc<Shared>) {
+    loop {
+        let task = {
+            let mut state = shared
+                .state
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner);
+            loop {
+                if state.shutdown {
+                    return;
+                }
+                if let Some(task) = state.pending.pop_front() {
+                    state.verifying += 1;
+                    break task;
+                }
+                state = shared
+                    .ready
+                    .wait(state)
+                    .unwrap_or_else(std::sync::PoisonError::into_inner);
+            }
+        };
+
+        let outcome = verify_task(&shared.processor, task);
+
+        let mut state = shared
+            .state
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        state.verifying -= 1;
+        state.outcomes.push(outcome);
+        shared.ready.notify_all();
+    }
+}
+
+/// Re-execute `task.tx` against its snapshot and compare against the
+/// claimed result.
+fn verify_task(processor: &TransactionProcessor, task: VerificationTask) -> VerifyOutcome {
+    let tx_id = task.tx.id;
+
+    let Some(contract) = processor.registry().get(&task.tx.contract_id) else {
+        return VerifyOutcome {
+            tx_id,
+            accepted: false,
+            error: Some(format!("contract {} not registered", task.tx.contract_id)),
+        };
+    };
+
+    match processor.verify_execution(
+        contract,
+        &task.tx,
+        &task.claimed,
+        &task.accounts,
+        &task.storage,
+    ) {
+        Ok(accepted) => VerifyOutcome {
+            tx_id,
+            accepted,
+            error: None,
+        },
+        Err(e) => VerifyOutcome {
+            tx_id,
+            accepted: false,
+            error: Some(e.to_string()),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::contracts::genesis_bounty::{GenesisBountyContract, GenesisDeploymentConfig};
+    use crate::contracts::state::ContractState;
+    use crate::contracts::{Contract, ContractRegistry};
+    use crate::crypto::Keypair;
+    use crate::genesis::DnsBreakGlassConfig;
+    use crate::types::HclawAmount;
+    use std::time::{Duration, Instant};
+
+    /// Chain ID baked into both `deployed_bounty`'s config and every
+    /// envelope `join_tx` builds, so `parse_action`'s chain check passes.
+    const TEST_CHAIN_ID: &str = "verification-queue-test";
+
+    fn deployed_bounty() -> (
+        GenesisBountyContract,
+        HashMap<Address, AccountState>,
+        HashMap<(Address, Vec<u8>), Vec<u8>>,
+    ) {
+        let contract = GenesisBountyContract::new(1000);
+        let mut accounts = HashMap::new();
+        let mut storage = HashMap::new();
+
+        let authority_kp = Keypair::generate();
+        let config = GenesisDeploymentConfig {
+            chain_id: TEST_CHAIN_ID.to_string(),
+            airdrop_amount: HclawAmount::from_hclaw(100),
+            founder_airdrop_amount: HclawAmount::from_hclaw(250_000),
+            max_participants: 5_000,
+            pre_approved: Vec::new(),
+            bootstrap_nodes: Vec::new(),
+            bootstrap_node_tokens: HclawAmount::from_hclaw(500_000),
+            dns_break_glass: DnsBreakGlassConfig {
+                domain: "bootstrap.hardclaw.net".to_string(),
+                max_nodes: 10,
+                tokens_each: HclawAmount::from_hclaw(500),
+                vesting_ms: 86_400_000,
+                authorized_keys: vec![authority_kp.public_key().clone()],
+            },
+            bootstrap_end: 9_999_999_999,
+            transitions: Vec::new(),
+            emission_schedule: None,
+        };
+        let init_data = bincode::serialize(&config).expect("serialize config");
+
+        {
+            let mut state = ContractState::new(&mut accounts, &mut storage);
+            contract
+                .on_deploy(&mut state, &init_data)
+                .expect("on_deploy");
+            state.commit().expect("commit genesis deploy");
+        }
+
+        (contract, accounts, storage)
+    }
+
+    fn processor_with_bounty(contract: GenesisBountyContract) -> Arc<TransactionProcessor> {
+        let mut registry = ContractRegistry::new();
+        registry.register(Box::new(contract));
+        Arc::new(TransactionProcessor::with_registry(10_000_000, registry))
+    }
+
+    fn join_tx(contract_id: Id, sender_kp: &Keypair, stake: HclawAmount) -> ContractTransaction {
+        let action = crate::contracts::genesis_bounty::BountyAction::JoinGenesis { stake };
+        let envelope = crate::contracts::genesis_bounty::ActionEnvelope {
+            version: crate::contracts::genesis_bounty::ACTION_ENVELOPE_VERSION,
+            domain: contract_id,
+            chain_id: TEST_CHAIN_ID.to_string(),
+            action,
+        };
+        let input = bincode::serialize(&envelope).expect("serialize action envelope");
+        ContractTransaction::new(
+            contract_id,
+            sender_kp.public_key().clone(),
+            input,
+            100_000,
+            HclawAmount::ZERO,
+            0,
+        )
+    }
+
+    fn wait_until(mut predicate: impl FnMut() -> bool) -> bool {
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while Instant::now() < deadline {
+            if predicate() {
+                return true;
+            }
+            thread::sleep(Duration::from_millis(5));
+        }
+        predicate()
+    }
+
+    #[test]
+    fn test_queue_info_starts_empty() {
+        let (contract, _accounts, _storage) = deployed_bounty();
+        let processor = processor_with_bounty(contract);
+        let queue = VerificationQueue::with_workers(processor, 2);
+
+        let info = queue.queue_info();
+        assert_eq!(info.total_queue_size(), 0);
+        assert_eq!(info.incomplete_queue_size(), 0);
+    }
+
+    #[test]
+    fn test_accepts_correct_execution() {
+        let (contract, accounts, storage) = deployed_bounty();
+        let contract_id = contract.id();
+        let processor = processor_with_bounty(contract);
+        let queue = VerificationQueue::with_workers(processor.clone(), 2);
+
+        let kp = Keypair::generate();
+        let sender = Address::from_public_key(kp.public_key());
+        let mut accounts = accounts;
+        accounts.insert(sender, AccountState::new(HclawAmount::from_hclaw(1000)));
+
+        let tx = join_tx(contract_id, &kp, HclawAmount::from_hclaw(50));
+
+        let claimed = {
+            let mut scratch_accounts = accounts.clone();
+            let mut scratch_storage = storage.clone();
+            processor
+                .execute_transaction(
+                    processor.registry().get(&contract_id).unwrap(),
+                    &tx,
+                    Address::from_bytes([9; 20]),
+                    &mut scratch_accounts,
+                    &mut scratch_storage,
+                )
+                .expect("execute")
+        };
+
+        queue.submit(VerificationTask {
+            tx,
+            claimed,
+            accounts,
+            storage,
+        });
+
+        assert!(wait_until(|| queue.queue_info().verified == 1));
+        let outcomes = queue.take_outcomes();
+        assert_eq!(outcomes.len(), 1);
+        assert!(outcomes[0].accepted, "{:?}", outcomes[0].error);
+
+        let info = queue.queue_info();
+        assert_eq!(info.verified, 0, "take_outcomes should drain the count");
+    }
+
+    #[test]
+    fn test_rejects_forged_state_root() {
+        let (contract, accounts, storage) = deployed_bounty();
+        let contract_id = contract.id();
+        let processor = processor_with_bounty(contract);
+        let queue = VerificationQueue::with_workers(processor, 2);
+
+        let kp = Keypair::generate();
+        let tx = join_tx(contract_id, &kp, HclawAmount::from_hclaw(50));
+
+        let forged = ExecutionResult {
+            new_state_root: crate::crypto::Hash::ZERO,
+            gas_used: 0,
+            events: Vec::new(),
+            output: Vec::new(),
+        };
+
+        queue.submit(VerificationTask {
+            tx,
+            claimed: forged,
+            accounts,
+            storage,
+        });
+
+        assert!(wait_until(|| queue.queue_info().verified == 1));
+        let outcomes = queue.take_outcomes();
+        assert_eq!(outcomes.len(), 1);
+        assert!(!outcomes[0].accepted);
+    }
+
+    #[test]
+    fn test_unknown_contract_rejected() {
+        let processor = Arc::new(TransactionProcessor::new(10_000_000));
+        let queue = VerificationQueue::with_workers(processor, 1);
+
+        let kp = Keypair::generate();
+        let tx = join_tx(Id::ZERO, &kp, HclawAmount::from_hclaw(50));
+        let claimed = ExecutionResult {
+            new_state_root: crate::crypto::Hash::ZERO,
+            gas_used: 0,
+            events: Vec::new(),
+            output: Vec::new(),
+        };
+
+        queue.submit(VerificationTask {
+            tx,
+            claimed,
+            accounts: HashMap::new(),
+            storage: HashMap::new(),
+        });
+
+        assert!(wait_until(|| queue.queue_info().verified == 1));
+        let outcomes = queue.take_outcomes();
+        assert!(!outcomes[0].accepted);
+        assert!(outcomes[0].error.is_some());
+    }
+
+    #[test]
+    fn test_many_tasks_drain_across_workers() {
+        let (contract, accounts, storage) = deployed_bounty();
+        let contract_id = contract.id();
+        let processor = processor_with_bounty(contract);
+        let queue = VerificationQueue::with_workers(processor, 4);
+
+        for _ in 0..20 {
+            let kp = Keypair::generate();
+            let tx = join_tx(contract_id, &kp, HclawAmount::from_hclaw(50));
+            let claimed = ExecutionResult {
+                new_state_root: crate::crypto::Hash::ZERO,
+                gas_used: 0,
+                events: Vec::new(),
+                output: Vec::new(),
+            };
+            queue.submit(VerificationTask {
+                tx,
+                claimed,
+                accounts: accounts.clone(),
+                storage: storage.clone(),
+            });
+        }
+
+        assert!(wait_until(|| queue.queue_info().total_queue_size() == 20));
+        assert_eq!(queue.queue_info().incomplete_queue_size(), 0);
+    }
+}
@@ -2,33 +2,59 @@
 //!
 //! This module enables execution of WebAssembly smart contracts.
 //! It uses `wasmer` to run sandboxed code.
+//!
+//! Contracts are never instantiated straight from the bytecode a `Deploy`
+//! transaction carries: [`instrument`] first rewrites the module under a
+//! [`ContractSchedule`], so the limits a chain wants enforced (gas, memory,
+//! call depth) live in the bytecode itself rather than in ad hoc checks
+//! scattered through the interpreter loop. This mirrors how a runtime
+//! contract's [`gas::Schedule`](super::gas::Schedule) meters host calls,
+//! just one layer lower — at the instruction level instead of the
+//! host-function level.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::sync::{Arc, Mutex};
 
-use wasmer::{Instance, Module, Store};
+use parity_wasm::elements::{Instruction, MemoryType, Module as PwasmModule};
+use wasm_instrument::gas_metering;
+use wasmer::{
+    AsStoreRef, Function, FunctionEnv, FunctionEnvMut, Instance, Memory, Module, RuntimeError,
+    Store, Value,
+};
 
+use crate::contracts::gas::{ContractSchedule, InstructionCosts};
 use crate::contracts::state::ContractState;
 use crate::contracts::transaction::ContractTransaction;
-use crate::contracts::{Contract, ContractError, ContractResult, ExecutionResult};
-use crate::types::Id;
+use crate::contracts::{Contract, ContractError, ContractEvent, ContractResult, ExecutionResult};
+use crate::types::{Address, HclawAmount, Id};
 
 /// A WebAssembly smart contract
 #[derive(Clone)]
 pub struct WasmContract {
     /// Contract ID
     id: Id,
-    /// WASM Code
+    /// Instrumented WASM code, already rewritten by [`instrument`] under
+    /// `schedule` at load time
     code: Vec<u8>,
+    /// Resource limits this contract's module was instrumented with; also
+    /// supplies the initial gas budget each `execute` call starts from
+    schedule: ContractSchedule,
     /// Compiled Module (cached for performance)
     #[allow(dead_code)] // Will be used for execution
     module: Option<Module>,
 }
 
 impl WasmContract {
-    /// Create new WASM contract
-    pub fn new(id: Id, code: Vec<u8>) -> Self {
-        // In a real implementation, we'd compile here or lazily
+    /// Create a new WASM contract from already-instrumented code. Callers
+    /// load contracts through [`crate::contracts::loader::ContractLoader`],
+    /// which runs [`instrument`] before this constructor ever sees the
+    /// bytecode.
+    pub fn new(id: Id, instrumented_code: Vec<u8>, schedule: ContractSchedule) -> Self {
         Self {
             id,
-            code,
+            code: instrumented_code,
+            schedule,
             module: None,
         }
     }
@@ -43,6 +69,131 @@ impl WasmContract {
         Module::new(store, &self.code)
             .map_err(|e| ContractError::ExecutionFailed(format!("WASM compilation failed: {}", e)))
     }
+
+    /// The address this contract's storage and balance live under — derived
+    /// the same way [`super::governance::GovernanceContract`] derives its
+    /// own, so a WASM contract's `transfer`/`storage_*` host calls land on
+    /// the same address space as every other contract kind.
+    fn contract_address(&self) -> Address {
+        let bytes = self.id.as_bytes();
+        let mut addr = [0u8; 20];
+        addr.copy_from_slice(&bytes[..20]);
+        Address::from_bytes(addr)
+    }
+}
+
+/// Shared mutable state for one `execute` call's host functions.
+///
+/// `Memory` and `Instance` start `None` because the host closures below are
+/// built (and handed to `Instance::new`) before the instance — and
+/// therefore the guest's exported memory and `alloc` function — exist; both
+/// are filled in immediately after instantiation. Everything else is
+/// staged here rather than written straight into `state: &mut ContractState`,
+/// since a host closure must be `'static` (it can outlive the `execute`
+/// call that created it) and so cannot capture a borrow tied to `state`'s
+/// lifetime; `WasmContract::execute` folds the staged writes back into
+/// `state` once the guest call returns.
+struct HostState {
+    /// The guest's exported linear memory, set after instantiation
+    memory: Option<Memory>,
+    /// The guest instance, set after instantiation — used to call back
+    /// into its `alloc` export when a host function needs to hand the
+    /// guest a buffer it didn't already own (e.g. a storage value)
+    instance: Option<Instance>,
+    /// The transaction's input bytes, exposed to the guest via `input_read`
+    input: Vec<u8>,
+    /// This contract's effective storage as of the start of this call, kept
+    /// up to date by `storage_set` so a guest that writes then reads the
+    /// same key within one call sees its own write
+    storage: HashMap<Vec<u8>, Vec<u8>>,
+    /// Keys actually written by `storage_set` this call, so only real
+    /// writes are replayed into `state` afterward
+    written: HashSet<Vec<u8>>,
+    /// Transfers out of this contract's own balance, queued by `transfer`
+    /// and applied in order once the guest call returns
+    transfers: Vec<(Address, u128)>,
+    /// Event payloads queued by `emit_event`
+    events: Vec<Vec<u8>>,
+}
+
+/// Read `len` bytes at `ptr` from `memory`, bounds-checked by
+/// [`wasmer::MemoryView::read`].
+fn memory_read(
+    memory: &Memory,
+    store: &impl AsStoreRef,
+    ptr: i32,
+    len: i32,
+) -> Result<Vec<u8>, RuntimeError> {
+    let view = memory.view(store);
+    let mut buf = vec![0u8; usize::try_from(len).unwrap_or(0)];
+    view.read(u64::from(u32::try_from(ptr).unwrap_or(0)), &mut buf)
+        .map_err(|e| RuntimeError::new(format!("out-of-bounds guest read: {e}")))?;
+    Ok(buf)
+}
+
+/// Write `data` to `ptr` in `memory`, bounds-checked by
+/// [`wasmer::MemoryView::write`].
+fn memory_write(
+    memory: &Memory,
+    store: &impl AsStoreRef,
+    ptr: i32,
+    data: &[u8],
+) -> Result<(), RuntimeError> {
+    let view = memory.view(store);
+    view.write(u64::from(u32::try_from(ptr).unwrap_or(0)), data)
+        .map_err(|e| RuntimeError::new(format!("out-of-bounds guest write: {e}")))
+}
+
+/// The memory a `FunctionEnvMut` host closure reads/writes through, set by
+/// `WasmContract::execute` right after instantiation.
+fn host_memory(ctx: &FunctionEnvMut<'_, Arc<Mutex<HostState>>>) -> Result<Memory, RuntimeError> {
+    ctx.data()
+        .lock()
+        .expect("host state mutex poisoned")
+        .memory
+        .clone()
+        .ok_or_else(|| RuntimeError::new("contract memory not yet initialized"))
+}
+
+/// Ask the guest's exported `alloc(size) -> ptr` for a buffer, for host
+/// functions (`storage_get`, `input_read`) that need to hand the guest data
+/// it doesn't already own a pointer to.
+fn host_alloc(
+    ctx: &mut FunctionEnvMut<'_, Arc<Mutex<HostState>>>,
+    size: i32,
+) -> Result<i32, RuntimeError> {
+    let instance = ctx
+        .data()
+        .lock()
+        .expect("host state mutex poisoned")
+        .instance
+        .clone()
+        .ok_or_else(|| RuntimeError::new("contract instance not yet initialized"))?;
+    let alloc_fn = instance
+        .exports
+        .get_function("alloc")
+        .map_err(|_| RuntimeError::new("contract is missing required 'alloc' export"))?;
+    match alloc_fn.call(ctx, &[Value::I32(size)])?.first() {
+        Some(Value::I32(ptr)) => Ok(*ptr),
+        _ => Err(RuntimeError::new("'alloc' export did not return an i32 pointer")),
+    }
+}
+
+/// Write `data` into a guest buffer the host allocates via `alloc`, then
+/// store that buffer's pointer at `out_ptr` — the "out parameter" half of
+/// `storage_get`/`input_read`'s `(out_ptr) -> len` signature, since the
+/// guest can't pre-allocate a buffer for data whose length it doesn't know
+/// until the host call returns.
+fn host_return_buffer(
+    ctx: &mut FunctionEnvMut<'_, Arc<Mutex<HostState>>>,
+    out_ptr: i32,
+    data: &[u8],
+) -> Result<i32, RuntimeError> {
+    let data_ptr = host_alloc(ctx, i32::try_from(data.len()).unwrap_or(i32::MAX))?;
+    let memory = host_memory(ctx)?;
+    memory_write(&memory, &*ctx, data_ptr, data)?;
+    memory_write(&memory, &*ctx, out_ptr, &data_ptr.to_le_bytes())?;
+    i32::try_from(data.len()).map_err(|_| RuntimeError::new("value too large for guest ABI"))
 }
 
 impl Contract for WasmContract {
@@ -61,40 +212,257 @@ impl Contract for WasmContract {
     fn execute(
         &self,
         state: &mut ContractState<'_>,
-        _tx: &ContractTransaction,
+        tx: &ContractTransaction,
     ) -> ContractResult<ExecutionResult> {
         let mut store = Store::default();
         let module = self.get_module(&store)?;
 
-        // TODO: Import host functions for state access (get, set, transfer)
-        // For now, minimal environment
-        let import_object = wasmer::imports! {};
+        // The gas budget the injected `gas(u64)` trampolines draw down;
+        // shared with the host closure below so it can trap once the
+        // module has spent more than the lesser of `schedule.max_gas` (the
+        // chain-wide ceiling) and `tx.gas_limit` (what the sender is
+        // willing to pay for), so a cheap `gas_limit` still bounds cost
+        // even when `schedule.max_gas` is generous.
+        let gas_budget = self.schedule.max_gas.min(tx.gas_limit);
+        let remaining_gas = Arc::new(AtomicI64::new(gas_budget as i64));
+        let gas_fn = {
+            let remaining_gas = remaining_gas.clone();
+            Function::new_typed(&mut store, move |spent: i64| -> Result<(), wasmer::RuntimeError> {
+                let remaining = remaining_gas.fetch_sub(spent, Ordering::SeqCst) - spent;
+                if remaining < 0 {
+                    return Err(wasmer::RuntimeError::new("out of gas"));
+                }
+                Ok(())
+            })
+        };
+
+        let contract_address = self.contract_address();
+        let existing_storage: HashMap<Vec<u8>, Vec<u8>> = state
+            .effective_storage_entries(&contract_address)
+            .map_err(|e| ContractError::ExecutionFailed(format!("storage read failed: {e}")))?
+            .into_iter()
+            .collect();
+
+        let host_state = Arc::new(Mutex::new(HostState {
+            memory: None,
+            instance: None,
+            input: tx.input.clone(),
+            storage: existing_storage,
+            written: HashSet::new(),
+            transfers: Vec::new(),
+            events: Vec::new(),
+        }));
+        let host_env = FunctionEnv::new(&mut store, host_state.clone());
+
+        // Host functions giving guest code controlled access to
+        // `ContractState`: storage reads/writes, outbound transfers, event
+        // emission, and the transaction's input bytes. `storage_get` and
+        // `input_read` hand back variable-length data the guest couldn't
+        // have pre-allocated a buffer for, so they ask the guest's own
+        // `alloc` export for one (see `host_return_buffer`) and write its
+        // pointer to `out_ptr` rather than returning it directly, keeping
+        // every import here to a plain `(i32...) -> i32` signature.
+        let storage_get_fn = Function::new_typed_with_env(
+            &mut store,
+            &host_env,
+            |mut ctx: FunctionEnvMut<'_, Arc<Mutex<HostState>>>,
+             key_ptr: i32,
+             key_len: i32,
+             out_ptr: i32|
+             -> Result<i32, RuntimeError> {
+                let memory = host_memory(&ctx)?;
+                let key = memory_read(&memory, &ctx, key_ptr, key_len)?;
+                let value = ctx
+                    .data()
+                    .lock()
+                    .expect("host state mutex poisoned")
+                    .storage
+                    .get(&key)
+                    .cloned();
+                let Some(value) = value else {
+                    return Ok(-1);
+                };
+                host_return_buffer(&mut ctx, out_ptr, &value)
+            },
+        );
+        let storage_set_fn = Function::new_typed_with_env(
+            &mut store,
+            &host_env,
+            |ctx: FunctionEnvMut<'_, Arc<Mutex<HostState>>>,
+             key_ptr: i32,
+             key_len: i32,
+             val_ptr: i32,
+             val_len: i32|
+             -> Result<(), RuntimeError> {
+                let memory = host_memory(&ctx)?;
+                let key = memory_read(&memory, &ctx, key_ptr, key_len)?;
+                let value = memory_read(&memory, &ctx, val_ptr, val_len)?;
+                let mut host = ctx.data().lock().expect("host state mutex poisoned");
+                host.written.insert(key.clone());
+                host.storage.insert(key, value);
+                Ok(())
+            },
+        );
+        let transfer_fn = Function::new_typed_with_env(
+            &mut store,
+            &host_env,
+            |ctx: FunctionEnvMut<'_, Arc<Mutex<HostState>>>,
+             to_ptr: i32,
+             amount: u64|
+             -> Result<(), RuntimeError> {
+                let memory = host_memory(&ctx)?;
+                let to_bytes = memory_read(&memory, &ctx, to_ptr, 20)?;
+                let mut addr = [0u8; 20];
+                addr.copy_from_slice(&to_bytes);
+                ctx.data()
+                    .lock()
+                    .expect("host state mutex poisoned")
+                    .transfers
+                    .push((Address::from_bytes(addr), u128::from(amount)));
+                Ok(())
+            },
+        );
+        let emit_event_fn = Function::new_typed_with_env(
+            &mut store,
+            &host_env,
+            |ctx: FunctionEnvMut<'_, Arc<Mutex<HostState>>>,
+             ptr: i32,
+             len: i32|
+             -> Result<(), RuntimeError> {
+                let memory = host_memory(&ctx)?;
+                let data = memory_read(&memory, &ctx, ptr, len)?;
+                ctx.data()
+                    .lock()
+                    .expect("host state mutex poisoned")
+                    .events
+                    .push(data);
+                Ok(())
+            },
+        );
+        let input_read_fn = Function::new_typed_with_env(
+            &mut store,
+            &host_env,
+            |mut ctx: FunctionEnvMut<'_, Arc<Mutex<HostState>>>,
+             out_ptr: i32|
+             -> Result<i32, RuntimeError> {
+                let input = ctx
+                    .data()
+                    .lock()
+                    .expect("host state mutex poisoned")
+                    .input
+                    .clone();
+                host_return_buffer(&mut ctx, out_ptr, &input)
+            },
+        );
+
+        let mut import_object = wasmer::Imports::new();
+        import_object.define("env", "gas", gas_fn);
+        import_object.define("env", "storage_get", storage_get_fn);
+        import_object.define("env", "storage_set", storage_set_fn);
+        import_object.define("env", "transfer", transfer_fn);
+        import_object.define("env", "emit_event", emit_event_fn);
+        import_object.define("env", "input_read", input_read_fn);
+        if self.schedule.enable_debug_print {
+            let debug_print_fn = Function::new_typed(&mut store, |ptr: i32, len: i32| {
+                tracing::debug!(ptr, len, "wasm contract debug_print");
+            });
+            import_object.define("env", "debug_print", debug_print_fn);
+        }
 
         let instance = Instance::new(&mut store, &module, &import_object).map_err(|e| {
             ContractError::ExecutionFailed(format!("WASM instantiation failed: {}", e))
         })?;
 
+        {
+            let memory = instance
+                .exports
+                .get_memory("memory")
+                .map_err(|_| {
+                    ContractError::ExecutionFailed(
+                        "contract is missing required 'memory' export".to_string(),
+                    )
+                })?
+                .clone();
+            let mut host = host_state.lock().expect("host state mutex poisoned");
+            host.memory = Some(memory);
+            host.instance = Some(instance.clone());
+        }
+
         // Locate 'execute' export
         let execute_func = instance
             .exports
             .get_function("execute")
             .map_err(|_| ContractError::ExecutionFailed("Missing 'execute' export".to_string()))?;
 
-        // Pass input data pointer/len (simplified)
-        // In reality, need memory allocation and copying
-        // This is a placeholder for the full host-guest ABI
+        // `execute` returns `(output_ptr: i32, output_len: i32)`, a buffer
+        // it allocated itself via its own `alloc` export (or `(0, 0)` for
+        // no output), mirroring how `storage_get`/`input_read` hand back
+        // guest-owned buffers.
+        let call_result = execute_func.call(&mut store, &[]).map_err(|e| {
+            // The meter already traps deterministically once `remaining_gas`
+            // goes negative; report that case with a fixed string so every
+            // re-executing verifier agrees on the error, rather than
+            // propagating wasmer's own (potentially version-dependent)
+            // trap message.
+            if remaining_gas.load(Ordering::SeqCst) < 0 {
+                ContractError::ExecutionFailed("out of gas".to_string())
+            } else {
+                ContractError::ExecutionFailed(format!("WASM runtime error: {}", e))
+            }
+        })?;
+
+        let output = match (call_result.first(), call_result.get(1)) {
+            (Some(Value::I32(ptr)), Some(Value::I32(len))) if *len > 0 => {
+                let memory = host_state
+                    .lock()
+                    .expect("host state mutex poisoned")
+                    .memory
+                    .clone()
+                    .expect("memory set after instantiation");
+                memory_read(&memory, &store, *ptr, *len)
+                    .map_err(|e| ContractError::ExecutionFailed(e.to_string()))?
+            }
+            _ => Vec::new(),
+        };
+
+        let gas_used = (gas_budget as i64 - remaining_gas.load(Ordering::SeqCst)).max(0) as u64;
 
-        // Execute
-        let _result = execute_func
-            .call(&mut store, &[])
-            .map_err(|e| ContractError::ExecutionFailed(format!("WASM runtime error: {}", e)))?;
+        // Fold the staged writes back into the real state now that the
+        // guest call has returned successfully. `host_state` is still
+        // shared with the `FunctionEnv` owned by `store` at this point, so
+        // this takes the accumulated data out from behind the lock rather
+        // than trying to reclaim sole ownership of the `Arc`.
+        let (written, mut storage, transfers, events) = {
+            let mut host = host_state.lock().expect("host state mutex poisoned");
+            (
+                std::mem::take(&mut host.written),
+                std::mem::take(&mut host.storage),
+                std::mem::take(&mut host.transfers),
+                std::mem::take(&mut host.events),
+            )
+        };
+        for key in written {
+            if let Some(value) = storage.remove(&key) {
+                state.storage_write(contract_address, key, value);
+            }
+        }
+        for (to, amount) in transfers {
+            state.transfer(contract_address, to, HclawAmount::from_raw(amount))?;
+        }
+        let events = events
+            .into_iter()
+            .map(|data| ContractEvent {
+                contract_id: self.id,
+                topic: "WasmEvent".to_string(),
+                data,
+            })
+            .collect();
 
-        // Process result (simplified)
         Ok(ExecutionResult {
-            new_state_root: state.compute_state_root(), // Only changed if host functions called
-            gas_used: 1000,                             // TODO: Metering
-            events: vec![],
-            output: vec![],
+            new_state_root: state.compute_state_root()?,
+            gas_used,
+            events,
+            output,
         })
     }
 
@@ -104,8 +472,120 @@ impl Contract for WasmContract {
         _tx: &ContractTransaction,
         _result: &ExecutionResult,
     ) -> ContractResult<bool> {
-        // Re-execution strategy would be similar to execute()
-        // For now, accept if execution succeeded (proof of execution verification)
+        // This only receives the pre-execution state and the proposer's
+        // already-computed result, not a mutable state to replay the
+        // execution against — that replay, and the gas/state-root/balance
+        // conservation checks that depend on it, live in
+        // `TransactionProcessor::verify_execution`, which has the raw
+        // accounts/storage maps needed to clone a fresh replay state. A
+        // WASM contract's `verify` stays a cheap accept here, same as every
+        // other `Contract` impl in this module tree.
         Ok(true)
     }
 }
+
+/// Adapts [`InstructionCosts`] to [`gas_metering::Rules`], so the gas
+/// injection pass charges the categories a [`ContractSchedule`] actually
+/// configures instead of a single flat per-instruction cost.
+struct ScheduleRules<'a>(&'a InstructionCosts);
+
+impl gas_metering::Rules for ScheduleRules<'_> {
+    fn instruction_cost(&self, instruction: &Instruction) -> Option<u32> {
+        use Instruction::{
+            Block, Br, BrIf, BrTable, Call, CallIndirect, Else, F32Load, F32Store, F64Load,
+            F64Store, I32Load, I32Load16S, I32Load16U, I32Load8S, I32Load8U, I32Store, I32Store16,
+            I32Store8, I64Load, I64Load16S, I64Load16U, I64Load32S, I64Load32U, I64Load8S,
+            I64Load8U, I64Store, I64Store16, I64Store32, I64Store8, If, Loop, Return,
+        };
+
+        let cost = match instruction {
+            Call(_) | CallIndirect(..) => self.0.call,
+            I32Load(..)
+            | I64Load(..)
+            | F32Load(..)
+            | F64Load(..)
+            | I32Load8S(..)
+            | I32Load8U(..)
+            | I32Load16S(..)
+            | I32Load16U(..)
+            | I64Load8S(..)
+            | I64Load8U(..)
+            | I64Load16S(..)
+            | I64Load16U(..)
+            | I64Load32S(..)
+            | I64Load32U(..)
+            | I32Store(..)
+            | I64Store(..)
+            | F32Store(..)
+            | F64Store(..)
+            | I32Store8(..)
+            | I32Store16(..)
+            | I64Store8(..)
+            | I64Store16(..)
+            | I64Store32(..) => self.0.memory_op,
+            Block(_) | Loop(_) | If(_) | Else | Br(_) | BrIf(_) | BrTable(_) | Return => {
+                self.0.control
+            }
+            _ => self.0.default,
+        };
+        Some(u32::try_from(cost).unwrap_or(u32::MAX))
+    }
+
+    fn memory_grow_cost(&self) -> gas_metering::MemoryGrowCost {
+        gas_metering::MemoryGrowCost::Free
+    }
+
+    fn call_per_local_cost(&self) -> u32 {
+        0
+    }
+}
+
+/// Clamp every declared memory's initial/maximum page count to
+/// `max_pages`, regardless of what the module itself asked for.
+fn clamp_memory(module: &mut PwasmModule, max_pages: u32) {
+    let Some(section) = module.memory_section_mut() else {
+        return;
+    };
+    for entry in section.entries_mut() {
+        let limits = entry.limits();
+        let initial = limits.initial().min(max_pages);
+        let maximum = Some(limits.maximum().map_or(max_pages, |m| m.min(max_pages)));
+        *entry = MemoryType::new(initial, maximum);
+    }
+}
+
+/// Instrument `wasm` under `schedule` before it's ever instantiated:
+///
+/// 1. Inject a `gas(u64)` host call at the top of every basic block (split
+///    at `block`/`loop`/`if`/`else`/`end`, branches, calls, and returns),
+///    charging that block's summed [`InstructionCosts`] and trapping once
+///    the running total exceeds `schedule.max_gas`.
+/// 2. Inject a call-depth counter that traps once `schedule.max_stack_height`
+///    is exceeded.
+/// 3. Clamp every memory's initial/maximum page count to
+///    `schedule.max_memory_pages`.
+///
+/// Steps 1 and 2 are handled by the `wasm-instrument` crate, which
+/// implements exactly this basic-block-splitting gas metering and
+/// stack-height limiting technique; step 3 is a direct edit of the memory
+/// section.
+pub fn instrument(wasm: &[u8], schedule: &ContractSchedule) -> ContractResult<Vec<u8>> {
+    let module = parity_wasm::elements::deserialize_buffer::<PwasmModule>(wasm)
+        .map_err(|e| ContractError::ExecutionFailed(format!("WASM decode failed: {e}")))?;
+
+    let rules = ScheduleRules(&schedule.instruction_costs);
+    let backend = gas_metering::Backend::host_function("env", "gas");
+    let module = gas_metering::inject(module, backend, &rules).map_err(|_| {
+        ContractError::ExecutionFailed("gas metering injection failed".to_string())
+    })?;
+
+    let mut module = wasm_instrument::inject_stack_limiter(module, schedule.max_stack_height)
+        .map_err(|_| {
+            ContractError::ExecutionFailed("stack height limiter injection failed".to_string())
+        })?;
+
+    clamp_memory(&mut module, schedule.max_memory_pages);
+
+    parity_wasm::serialize(module)
+        .map_err(|e| ContractError::ExecutionFailed(format!("WASM re-encode failed: {e}")))
+}
@@ -0,0 +1,330 @@
+//! Crypto-agility: a pluggable [`Kem`] trait plus suite negotiation so the
+//! protocol can grow new KEMs (like [`HybridKemKeypair`](super::kem::HybridKemKeypair))
+//! without forking every call site that currently hard-codes HQC-192.
+//!
+//! Two peers each advertise a [`SuitePreferences`] — ordered, strongest-
+//! first lists of key-exchange, KDF, and AEAD algorithms they'll accept,
+//! the same shape a TLS-style config uses for `key_exchanges`/`hkdfs`/
+//! `ciphers`. [`negotiate`] picks the strongest mutually-supported
+//! algorithm in each category and returns a [`SuiteId`] identifying the
+//! result; that [`SuiteId`] is encoded into the wire header
+//! ([`SuiteId::to_wire`]/[`SuiteId::from_wire`]) so a decapsulating peer
+//! knows which [`Kem`] implementation to dispatch to.
+
+use super::kem::{
+    self, HybridKemCiphertext, HybridKemKeypair, HybridKemPublicKey, HybridKemSecretKey,
+    KemCiphertext, KemKeypair, KemPublicKey, KemSecretKey,
+};
+use super::{CryptoResult, SharedSecret};
+
+/// A key-encapsulation mechanism, decoupled from any one implementation so
+/// callers can be generic over which KEM a negotiated [`SuiteId`] selected.
+pub trait Kem {
+    /// This KEM's public key type.
+    type PublicKey;
+    /// This KEM's secret key type.
+    type SecretKey;
+    /// This KEM's ciphertext type.
+    type Ciphertext;
+
+    /// Generate a new random keypair.
+    fn generate() -> (Self::PublicKey, Self::SecretKey);
+
+    /// Encapsulate a fresh shared secret to `public_key`.
+    ///
+    /// # Errors
+    /// Returns error if `public_key` is invalid.
+    fn encapsulate(public_key: &Self::PublicKey) -> CryptoResult<(SharedSecret, Self::Ciphertext)>;
+
+    /// Decapsulate `ciphertext` with `secret_key` to recover the shared secret.
+    ///
+    /// # Errors
+    /// Returns error if `ciphertext` or `secret_key` is invalid.
+    fn decapsulate(ciphertext: &Self::Ciphertext, secret_key: &Self::SecretKey)
+        -> CryptoResult<SharedSecret>;
+}
+
+/// [`Kem`] implementation backed by plain HQC-192.
+#[derive(Clone, Copy, Debug)]
+pub struct Hqc192Kem;
+
+impl Kem for Hqc192Kem {
+    type PublicKey = KemPublicKey;
+    type SecretKey = KemSecretKey;
+    type Ciphertext = KemCiphertext;
+
+    fn generate() -> (KemPublicKey, KemSecretKey) {
+        KemKeypair::generate().into_parts()
+    }
+
+    fn encapsulate(public_key: &KemPublicKey) -> CryptoResult<(SharedSecret, KemCiphertext)> {
+        kem::encapsulate(public_key)
+    }
+
+    fn decapsulate(
+        ciphertext: &KemCiphertext,
+        secret_key: &KemSecretKey,
+    ) -> CryptoResult<SharedSecret> {
+        kem::decapsulate(ciphertext, secret_key)
+    }
+}
+
+/// [`Kem`] implementation backed by the hybrid X25519 + HQC-192 combiner.
+#[derive(Clone, Copy, Debug)]
+pub struct HybridX25519Hqc192Kem;
+
+impl Kem for HybridX25519Hqc192Kem {
+    type PublicKey = HybridKemPublicKey;
+    type SecretKey = HybridKemSecretKey;
+    type Ciphertext = HybridKemCiphertext;
+
+    fn generate() -> (HybridKemPublicKey, HybridKemSecretKey) {
+        HybridKemKeypair::generate().into_parts()
+    }
+
+    fn encapsulate(
+        public_key: &HybridKemPublicKey,
+    ) -> CryptoResult<(SharedSecret, HybridKemCiphertext)> {
+        kem::hybrid_encapsulate(public_key)
+    }
+
+    fn decapsulate(
+        ciphertext: &HybridKemCiphertext,
+        secret_key: &HybridKemSecretKey,
+    ) -> CryptoResult<SharedSecret> {
+        kem::hybrid_decapsulate(ciphertext, secret_key)
+    }
+}
+
+/// A key-exchange algorithm a peer can offer or accept during negotiation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum KemAlgorithm {
+    /// Plain HQC-192, see [`Hqc192Kem`].
+    Hqc192,
+    /// Hybrid X25519 + HQC-192, see [`HybridX25519Hqc192Kem`].
+    HybridX25519Hqc192,
+}
+
+/// A key-derivation algorithm a peer can offer or accept during negotiation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum KdfAlgorithm {
+    /// `HKDF-SHA3-512`, as used by [`HybridX25519Hqc192Kem`]'s secret
+    /// combiner and [`crate::crypto::seal`]'s key/nonce derivation.
+    HkdfSha3_512,
+}
+
+/// An AEAD algorithm a peer can offer or accept during negotiation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum AeadAlgorithm {
+    /// ChaCha20-Poly1305, as used by [`crate::crypto::seal`]/[`crate::crypto::open`].
+    ChaCha20Poly1305,
+}
+
+/// A fully negotiated (KEM, KDF, AEAD) suite. Encoded into the wire header
+/// (see [`SuiteId::to_wire`]/[`SuiteId::from_wire`]) so a decapsulating peer
+/// knows which implementations to dispatch to without re-running negotiation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SuiteId {
+    /// Negotiated key-exchange algorithm.
+    pub kem: KemAlgorithm,
+    /// Negotiated key-derivation algorithm.
+    pub kdf: KdfAlgorithm,
+    /// Negotiated AEAD algorithm.
+    pub aead: AeadAlgorithm,
+}
+
+impl SuiteId {
+    /// Encode as 3 wire bytes: `[kem, kdf, aead]`, one discriminant byte
+    /// per category.
+    #[must_use]
+    pub fn to_wire(self) -> [u8; 3] {
+        [
+            match self.kem {
+                KemAlgorithm::Hqc192 => 0,
+                KemAlgorithm::HybridX25519Hqc192 => 1,
+            },
+            match self.kdf {
+                KdfAlgorithm::HkdfSha3_512 => 0,
+            },
+            match self.aead {
+                AeadAlgorithm::ChaCha20Poly1305 => 0,
+            },
+        ]
+    }
+
+    /// Decode 3 wire bytes produced by [`SuiteId::to_wire`].
+    ///
+    /// # Errors
+    /// Returns [`NegotiationError::UnknownSuiteByte`] if any byte doesn't
+    /// name a known algorithm.
+    pub fn from_wire(bytes: [u8; 3]) -> Result<Self, NegotiationError> {
+        let kem = match bytes[0] {
+            0 => KemAlgorithm::Hqc192,
+            1 => KemAlgorithm::HybridX25519Hqc192,
+            b => return Err(NegotiationError::UnknownSuiteByte("kem", b)),
+        };
+        let kdf = match bytes[1] {
+            0 => KdfAlgorithm::HkdfSha3_512,
+            b => return Err(NegotiationError::UnknownSuiteByte("kdf", b)),
+        };
+        let aead = match bytes[2] {
+            0 => AeadAlgorithm::ChaCha20Poly1305,
+            b => return Err(NegotiationError::UnknownSuiteByte("aead", b)),
+        };
+        Ok(Self { kem, kdf, aead })
+    }
+}
+
+/// Ordered, strongest-first lists of algorithms a peer will accept, one
+/// list per category — mirroring a TLS-style config's `key_exchanges`,
+/// `hkdfs`, and `ciphers`. Advertised by both sides of a handshake and fed
+/// to [`negotiate`].
+#[derive(Clone, Debug)]
+pub struct SuitePreferences {
+    /// Key-exchange algorithms, most preferred first.
+    pub key_exchanges: Vec<KemAlgorithm>,
+    /// KDF algorithms, most preferred first.
+    pub hkdfs: Vec<KdfAlgorithm>,
+    /// AEAD algorithms, most preferred first.
+    pub ciphers: Vec<AeadAlgorithm>,
+}
+
+impl SuitePreferences {
+    /// This crate's default preferences: the hybrid KEM ahead of plain
+    /// HQC-192 (defense-in-depth over raw speed), and the only KDF/AEAD
+    /// this crate currently implements.
+    #[must_use]
+    pub fn default_preferences() -> Self {
+        Self {
+            key_exchanges: vec![KemAlgorithm::HybridX25519Hqc192, KemAlgorithm::Hqc192],
+            hkdfs: vec![KdfAlgorithm::HkdfSha3_512],
+            ciphers: vec![AeadAlgorithm::ChaCha20Poly1305],
+        }
+    }
+}
+
+/// Errors from suite negotiation or [`SuiteId`] wire decoding.
+#[derive(Debug, thiserror::Error)]
+pub enum NegotiationError {
+    /// Neither side's `key_exchanges` list has an algorithm in common.
+    #[error("no common key-exchange algorithm")]
+    NoCommonKem,
+    /// Neither side's `hkdfs` list has an algorithm in common.
+    #[error("no common KDF algorithm")]
+    NoCommonKdf,
+    /// Neither side's `ciphers` list has an algorithm in common.
+    #[error("no common AEAD algorithm")]
+    NoCommonAead,
+    /// A wire byte in the given category didn't name a known algorithm.
+    #[error("unknown {0} suite byte: {1}")]
+    UnknownSuiteByte(&'static str, u8),
+}
+
+/// Negotiate a [`SuiteId`] from two peers' [`SuitePreferences`]: for each of
+/// KEM, KDF, and AEAD independently, pick `local`'s most preferred
+/// algorithm that `remote` also supports.
+///
+/// # Errors
+/// Returns the relevant `NoCommon*` variant if a category has no overlap.
+pub fn negotiate(
+    local: &SuitePreferences,
+    remote: &SuitePreferences,
+) -> Result<SuiteId, NegotiationError> {
+    let kem = local
+        .key_exchanges
+        .iter()
+        .find(|k| remote.key_exchanges.contains(k))
+        .copied()
+        .ok_or(NegotiationError::NoCommonKem)?;
+    let kdf = local
+        .hkdfs
+        .iter()
+        .find(|k| remote.hkdfs.contains(k))
+        .copied()
+        .ok_or(NegotiationError::NoCommonKdf)?;
+    let aead = local
+        .ciphers
+        .iter()
+        .find(|k| remote.ciphers.contains(k))
+        .copied()
+        .ok_or(NegotiationError::NoCommonAead)?;
+    Ok(SuiteId { kem, kdf, aead })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hqc192_kem_trait_round_trips() {
+        let (pk, sk) = Hqc192Kem::generate();
+        let (ss_sender, ct) = Hqc192Kem::encapsulate(&pk).unwrap();
+        let ss_receiver = Hqc192Kem::decapsulate(&ct, &sk).unwrap();
+        assert_eq!(ss_sender.as_bytes(), ss_receiver.as_bytes());
+    }
+
+    #[test]
+    fn test_hybrid_kem_trait_round_trips() {
+        let (pk, sk) = HybridX25519Hqc192Kem::generate();
+        let (ss_sender, ct) = HybridX25519Hqc192Kem::encapsulate(&pk).unwrap();
+        let ss_receiver = HybridX25519Hqc192Kem::decapsulate(&ct, &sk).unwrap();
+        assert_eq!(ss_sender.as_bytes(), ss_receiver.as_bytes());
+    }
+
+    #[test]
+    fn test_negotiate_picks_local_strongest_common() {
+        let local = SuitePreferences::default_preferences();
+        // Remote only supports the weaker plain-HQC192 KEM.
+        let remote = SuitePreferences {
+            key_exchanges: vec![KemAlgorithm::Hqc192],
+            hkdfs: vec![KdfAlgorithm::HkdfSha3_512],
+            ciphers: vec![AeadAlgorithm::ChaCha20Poly1305],
+        };
+
+        let suite = negotiate(&local, &remote).unwrap();
+        assert_eq!(suite.kem, KemAlgorithm::Hqc192);
+    }
+
+    #[test]
+    fn test_negotiate_prefers_hybrid_when_both_support_it() {
+        let local = SuitePreferences::default_preferences();
+        let remote = SuitePreferences::default_preferences();
+
+        let suite = negotiate(&local, &remote).unwrap();
+        assert_eq!(suite.kem, KemAlgorithm::HybridX25519Hqc192);
+    }
+
+    #[test]
+    fn test_negotiate_fails_with_no_common_kem() {
+        let local = SuitePreferences {
+            key_exchanges: vec![KemAlgorithm::HybridX25519Hqc192],
+            hkdfs: vec![KdfAlgorithm::HkdfSha3_512],
+            ciphers: vec![AeadAlgorithm::ChaCha20Poly1305],
+        };
+        let remote = SuitePreferences {
+            key_exchanges: vec![KemAlgorithm::Hqc192],
+            hkdfs: vec![KdfAlgorithm::HkdfSha3_512],
+            ciphers: vec![AeadAlgorithm::ChaCha20Poly1305],
+        };
+
+        let err = negotiate(&local, &remote).unwrap_err();
+        assert!(matches!(err, NegotiationError::NoCommonKem));
+    }
+
+    #[test]
+    fn test_suite_id_wire_round_trip() {
+        let suite = SuiteId {
+            kem: KemAlgorithm::HybridX25519Hqc192,
+            kdf: KdfAlgorithm::HkdfSha3_512,
+            aead: AeadAlgorithm::ChaCha20Poly1305,
+        };
+        let bytes = suite.to_wire();
+        assert_eq!(SuiteId::from_wire(bytes).unwrap(), suite);
+    }
+
+    #[test]
+    fn test_suite_id_from_wire_rejects_unknown_byte() {
+        let err = SuiteId::from_wire([99, 0, 0]).unwrap_err();
+        assert!(matches!(err, NegotiationError::UnknownSuiteByte("kem", 99)));
+    }
+}
@@ -5,13 +5,22 @@
 //!
 //! Used for chain-level encrypted communication between peers,
 //! complementing the Ed25519-based libp2p transport layer.
+//!
+//! [`HybridKemKeypair`] additionally combines HQC-192 with X25519 for
+//! defense-in-depth: since HQC is a comparatively young code-based scheme,
+//! the hybrid construction stays secure as long as *either* component KEM
+//! does.
 
+use hkdf::Hkdf;
 use pqcrypto_hqc::hqc192;
 use pqcrypto_traits::kem::{
     Ciphertext as PqCiphertext, PublicKey as PqKemPublicKey, SecretKey as PqKemSecretKey,
     SharedSecret as PqSharedSecret,
 };
+use rand::rngs::OsRng;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use sha3::Sha3_512;
+use x25519_dalek::{EphemeralSecret, PublicKey as X25519PublicKey, StaticSecret};
 use zeroize::Zeroize;
 
 use super::{CryptoError, CryptoResult};
@@ -215,6 +224,12 @@ impl KemKeypair {
     pub fn decapsulate(&self, ciphertext: &KemCiphertext) -> CryptoResult<SharedSecret> {
         decapsulate(ciphertext, &self.secret)
     }
+
+    /// Split into the owned public/secret key pair.
+    #[must_use]
+    pub fn into_parts(self) -> (KemPublicKey, KemSecretKey) {
+        (self.public, self.secret)
+    }
 }
 
 /// Encapsulate: generate a shared secret and ciphertext for a recipient's public key
@@ -242,6 +257,217 @@ pub fn decapsulate(ciphertext: &KemCiphertext, secret_key: &KemSecretKey) -> Cry
     Ok(SharedSecret(ss.as_bytes().to_vec()))
 }
 
+/// X25519 public key size in bytes
+pub const X25519_PUBKEY_SIZE: usize = 32;
+/// X25519 secret key size in bytes
+pub const X25519_SECRET_KEY_SIZE: usize = 32;
+
+/// Hybrid X25519 + HQC-192 public key (X25519 pubkey ‖ HQC-192 pubkey)
+#[derive(Clone, PartialEq, Eq)]
+pub struct HybridKemPublicKey {
+    x25519: [u8; X25519_PUBKEY_SIZE],
+    hqc: KemPublicKey,
+}
+
+impl HybridKemPublicKey {
+    /// Create from raw bytes (X25519 pubkey ‖ HQC-192 pubkey)
+    ///
+    /// # Errors
+    /// Returns error if bytes are not the correct combined length or the
+    /// HQC half is invalid
+    pub fn from_bytes(bytes: &[u8]) -> CryptoResult<Self> {
+        if bytes.len() != X25519_PUBKEY_SIZE + KEM_PUBKEY_SIZE {
+            return Err(CryptoError::InvalidPublicKey(format!(
+                "hybrid KEM public key must be {} bytes, got {}",
+                X25519_PUBKEY_SIZE + KEM_PUBKEY_SIZE,
+                bytes.len()
+            )));
+        }
+        let mut x25519 = [0u8; X25519_PUBKEY_SIZE];
+        x25519.copy_from_slice(&bytes[..X25519_PUBKEY_SIZE]);
+        let hqc = KemPublicKey::from_bytes(&bytes[X25519_PUBKEY_SIZE..])?;
+        Ok(Self { x25519, hqc })
+    }
+
+    /// Get underlying bytes (X25519 pubkey ‖ HQC-192 pubkey)
+    #[must_use]
+    pub fn as_bytes(&self) -> Vec<u8> {
+        let mut out = self.x25519.to_vec();
+        out.extend_from_slice(self.hqc.as_bytes());
+        out
+    }
+}
+
+impl std::fmt::Debug for HybridKemPublicKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "HybridKemPubKey({}..)", &self.hqc.to_hex()[..16])
+    }
+}
+
+/// Hybrid X25519 + HQC-192 secret key
+///
+/// SECURITY: Memory is zeroized on drop.
+pub struct HybridKemSecretKey {
+    x25519: StaticSecret,
+    hqc: KemSecretKey,
+}
+
+/// Hybrid X25519 + HQC-192 ciphertext (ephemeral X25519 pubkey ‖ HQC-192 ciphertext)
+#[derive(Clone, PartialEq, Eq)]
+pub struct HybridKemCiphertext {
+    x25519: [u8; X25519_PUBKEY_SIZE],
+    hqc: KemCiphertext,
+}
+
+impl HybridKemCiphertext {
+    /// Create from raw bytes (ephemeral X25519 pubkey ‖ HQC-192 ciphertext)
+    ///
+    /// # Errors
+    /// Returns error if bytes are not the correct combined length or the
+    /// HQC half is invalid
+    pub fn from_bytes(bytes: &[u8]) -> CryptoResult<Self> {
+        if bytes.len() != X25519_PUBKEY_SIZE + KEM_CIPHERTEXT_SIZE {
+            return Err(CryptoError::InvalidSignature);
+        }
+        let mut x25519 = [0u8; X25519_PUBKEY_SIZE];
+        x25519.copy_from_slice(&bytes[..X25519_PUBKEY_SIZE]);
+        let hqc = KemCiphertext::from_bytes(&bytes[X25519_PUBKEY_SIZE..])?;
+        Ok(Self { x25519, hqc })
+    }
+
+    /// Get underlying bytes (ephemeral X25519 pubkey ‖ HQC-192 ciphertext)
+    #[must_use]
+    pub fn as_bytes(&self) -> Vec<u8> {
+        let mut out = self.x25519.to_vec();
+        out.extend_from_slice(self.hqc.as_bytes());
+        out
+    }
+}
+
+/// A hybrid X25519 + HQC-192 KEM keypair
+///
+/// Breaking the resulting shared secret requires breaking *both* X25519
+/// and HQC-192.
+pub struct HybridKemKeypair {
+    secret: HybridKemSecretKey,
+    public: HybridKemPublicKey,
+}
+
+impl HybridKemKeypair {
+    /// Generate a new random hybrid KEM keypair
+    #[must_use]
+    pub fn generate() -> Self {
+        let x25519_secret = StaticSecret::random_from_rng(OsRng);
+        let x25519_public = X25519PublicKey::from(&x25519_secret);
+        let (pk, sk) = hqc192::keypair();
+
+        let public = HybridKemPublicKey {
+            x25519: *x25519_public.as_bytes(),
+            hqc: KemPublicKey(pk.as_bytes().to_vec()),
+        };
+        let secret = HybridKemSecretKey {
+            x25519: x25519_secret,
+            hqc: KemSecretKey(sk.as_bytes().to_vec()),
+        };
+        Self { secret, public }
+    }
+
+    /// Get the public key
+    #[must_use]
+    pub fn public_key(&self) -> &HybridKemPublicKey {
+        &self.public
+    }
+
+    /// Decapsulate a ciphertext to recover the shared secret
+    ///
+    /// # Errors
+    /// Returns error if the HQC half of the ciphertext or secret key is invalid
+    pub fn decapsulate(&self, ciphertext: &HybridKemCiphertext) -> CryptoResult<SharedSecret> {
+        hybrid_decapsulate(ciphertext, &self.secret)
+    }
+
+    /// Split into the owned public/secret key pair.
+    #[must_use]
+    pub fn into_parts(self) -> (HybridKemPublicKey, HybridKemSecretKey) {
+        (self.public, self.secret)
+    }
+}
+
+/// Combine the two component shared secrets and both ciphertexts into the
+/// final 64-byte hybrid secret via `HKDF-SHA3-512`.
+///
+/// Both ciphertexts are included in the transcript (not just the shared
+/// secrets) so the combiner stays IND-CCA2 even if one component KEM is
+/// only IND-CPA.
+fn combine(ss_x25519: &[u8], ss_hqc: &[u8], ct_x25519: &[u8], ct_hqc: &[u8]) -> SharedSecret {
+    let mut ikm = Vec::with_capacity(ss_x25519.len() + ss_hqc.len() + ct_x25519.len() + ct_hqc.len());
+    ikm.extend_from_slice(ss_x25519);
+    ikm.extend_from_slice(ss_hqc);
+    ikm.extend_from_slice(ct_x25519);
+    ikm.extend_from_slice(ct_hqc);
+
+    let hk = Hkdf::<Sha3_512>::new(None, &ikm);
+    let mut out = vec![0u8; KEM_SHARED_SECRET_SIZE];
+    hk.expand(b"hardclaw-hybrid-kem-x25519-hqc192", &mut out)
+        .expect("64-byte output is within HKDF-SHA3-512's valid range");
+    ikm.zeroize();
+    SharedSecret(out)
+}
+
+/// Encapsulate: generate a shared secret and ciphertext for a recipient's
+/// hybrid public key
+///
+/// # Errors
+/// Returns error if the HQC half of the public key is invalid
+pub fn hybrid_encapsulate(
+    public_key: &HybridKemPublicKey,
+) -> CryptoResult<(SharedSecret, HybridKemCiphertext)> {
+    let ephemeral = EphemeralSecret::random_from_rng(OsRng);
+    let ephemeral_public = X25519PublicKey::from(&ephemeral);
+    let ss_x25519 = ephemeral.diffie_hellman(&X25519PublicKey::from(public_key.x25519));
+
+    let pq_pk = public_key.hqc.pq_key()?;
+    let (ss_hqc, ct_hqc) = hqc192::encapsulate(&pq_pk);
+
+    let ciphertext = HybridKemCiphertext {
+        x25519: *ephemeral_public.as_bytes(),
+        hqc: KemCiphertext(ct_hqc.as_bytes().to_vec()),
+    };
+    let shared_secret = combine(
+        ss_x25519.as_bytes(),
+        ss_hqc.as_bytes(),
+        &ciphertext.x25519,
+        ct_hqc.as_bytes(),
+    );
+    Ok((shared_secret, ciphertext))
+}
+
+/// Decapsulate: recover the shared secret from a hybrid ciphertext using
+/// the hybrid secret key
+///
+/// # Errors
+/// Returns error if the HQC half of the ciphertext or secret key is invalid
+pub fn hybrid_decapsulate(
+    ciphertext: &HybridKemCiphertext,
+    secret_key: &HybridKemSecretKey,
+) -> CryptoResult<SharedSecret> {
+    let ss_x25519 = secret_key
+        .x25519
+        .diffie_hellman(&X25519PublicKey::from(ciphertext.x25519));
+
+    let pq_sk = secret_key.hqc.pq_key()?;
+    let pq_ct = hqc192::Ciphertext::from_bytes(&ciphertext.hqc.0)
+        .map_err(|_| CryptoError::InvalidSignature)?;
+    let ss_hqc = hqc192::decapsulate(&pq_ct, &pq_sk);
+
+    Ok(combine(
+        ss_x25519.as_bytes(),
+        ss_hqc.as_bytes(),
+        &ciphertext.x25519,
+        ciphertext.hqc.as_bytes(),
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -289,4 +515,53 @@ mod tests {
         let parsed = KemPublicKey::from_bytes(bytes).unwrap();
         assert_eq!(keypair.public_key(), &parsed);
     }
+
+    #[test]
+    fn test_hybrid_encapsulate_decapsulate() {
+        let keypair = HybridKemKeypair::generate();
+
+        let (shared_secret_sender, ciphertext) = hybrid_encapsulate(keypair.public_key()).unwrap();
+        let shared_secret_receiver = keypair.decapsulate(&ciphertext).unwrap();
+
+        assert_eq!(
+            shared_secret_sender.as_bytes(),
+            shared_secret_receiver.as_bytes()
+        );
+        assert_eq!(shared_secret_sender.as_bytes().len(), KEM_SHARED_SECRET_SIZE);
+    }
+
+    #[test]
+    fn test_hybrid_pubkey_bytes_roundtrip() {
+        let keypair = HybridKemKeypair::generate();
+        let bytes = keypair.public_key().as_bytes();
+        let parsed = HybridKemPublicKey::from_bytes(&bytes).unwrap();
+        assert_eq!(keypair.public_key(), &parsed);
+    }
+
+    #[test]
+    fn test_hybrid_tampered_x25519_half_changes_secret() {
+        let keypair = HybridKemKeypair::generate();
+        let (shared_secret, ciphertext) = hybrid_encapsulate(keypair.public_key()).unwrap();
+
+        let mut tampered_bytes = ciphertext.as_bytes();
+        tampered_bytes[0] ^= 0xFF;
+        let tampered = HybridKemCiphertext::from_bytes(&tampered_bytes).unwrap();
+
+        let recovered = keypair.decapsulate(&tampered).unwrap();
+        assert_ne!(shared_secret.as_bytes(), recovered.as_bytes());
+    }
+
+    #[test]
+    fn test_hybrid_tampered_hqc_half_changes_secret() {
+        let keypair = HybridKemKeypair::generate();
+        let (shared_secret, ciphertext) = hybrid_encapsulate(keypair.public_key()).unwrap();
+
+        let mut tampered_bytes = ciphertext.as_bytes();
+        let last = tampered_bytes.len() - 1;
+        tampered_bytes[last] ^= 0xFF;
+        let tampered = HybridKemCiphertext::from_bytes(&tampered_bytes).unwrap();
+
+        let recovered = keypair.decapsulate(&tampered).unwrap();
+        assert_ne!(shared_secret.as_bytes(), recovered.as_bytes());
+    }
 }
@@ -39,6 +39,43 @@ pub fn parse_mnemonic(phrase: &str) -> CryptoResult<Mnemonic> {
         .map_err(|e| CryptoError::InvalidMnemonic(e.to_string()))
 }
 
+/// Lowest entropy BIP39 supports (a 12-word phrase); the floor this crate
+/// accepts for an *imported* mnemonic, e.g. via [`keypair_from_imported_phrase`].
+pub const MIN_IMPORTED_MNEMONIC_ENTROPY_BITS: usize = 128;
+
+/// Check that `phrase` has one of BIP39's standard word counts (12, 15, 18,
+/// 21, or 24 words, i.e. at least [`MIN_IMPORTED_MNEMONIC_ENTROPY_BITS`]
+/// bits of entropy) before attempting to parse it, so a malformed import
+/// gets a precise word-count error instead of `bip39`'s generic one.
+///
+/// # Errors
+/// Returns `CryptoError::InvalidMnemonic` naming the word count found if it
+/// isn't one of the standard lengths.
+pub fn validate_word_count(phrase: &str) -> CryptoResult<()> {
+    let word_count = phrase.split_whitespace().count();
+    match word_count {
+        12 | 15 | 18 | 21 | 24 => Ok(()),
+        other => Err(CryptoError::InvalidMnemonic(format!(
+            "expected a standard BIP39 phrase (12, 15, 18, 21, or 24 words \
+             — at least {MIN_IMPORTED_MNEMONIC_ENTROPY_BITS} bits of entropy), got {other} words"
+        ))),
+    }
+}
+
+/// Parse and derive a keypair from an *imported* mnemonic phrase, enforcing
+/// [`validate_word_count`] first so a phrase with a non-standard word count
+/// (and thus below [`MIN_IMPORTED_MNEMONIC_ENTROPY_BITS`] bits of entropy,
+/// or simply mistyped) is rejected with a precise error before the
+/// checksum is even checked.
+///
+/// # Errors
+/// Returns `CryptoError::InvalidMnemonic` if the word count is
+/// non-standard or the checksum doesn't verify.
+pub fn keypair_from_imported_phrase(phrase: &str, passphrase: &str) -> CryptoResult<Keypair> {
+    validate_word_count(phrase)?;
+    keypair_from_phrase(phrase, passphrase)
+}
+
 /// Derive an Ed25519 keypair from a mnemonic (for libp2p transport identity).
 ///
 /// This returns raw Ed25519 bytes, NOT an ML-DSA keypair. Used only by
@@ -90,12 +127,259 @@ pub fn keypair_from_phrase(phrase: &str, passphrase: &str) -> CryptoResult<Keypa
     Ok(keypair_from_mnemonic(&mnemonic, passphrase))
 }
 
+/// Deterministically derive a child ML-DSA-65 keypair at a hierarchical
+/// derivation path, for multiple independent accounts from one mnemonic.
+///
+/// ML-DSA has no native BIP32/SLIP-0010 analogue, so the path is folded by
+/// hand: starting from the 64-byte BIP39 seed, each path `index` advances
+/// the seed via `seed = BLAKE3(ML_DSA_KDF_DOMAIN || "node" || seed ||
+/// index_le_bytes)`. The final 32 bytes are fed into [`Keypair::from_seed`].
+///
+/// An empty path is equivalent to [`keypair_from_mnemonic`].
+#[must_use]
+pub fn keypair_from_mnemonic_at_path(
+    mnemonic: &Mnemonic,
+    passphrase: &str,
+    path: &[u32],
+) -> Keypair {
+    let bip39_seed = mnemonic.to_seed(passphrase);
+
+    let mut seed: Vec<u8> = bip39_seed.to_vec();
+    for index in path {
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(ML_DSA_KDF_DOMAIN);
+        hasher.update(b"node");
+        hasher.update(&seed);
+        hasher.update(&index.to_le_bytes());
+        seed = hasher.finalize().as_bytes().to_vec();
+    }
+
+    let ml_dsa_seed: [u8; 32] = if path.is_empty() {
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(ML_DSA_KDF_DOMAIN);
+        hasher.update(&seed);
+        *hasher.finalize().as_bytes()
+    } else {
+        seed.try_into().expect("BLAKE3 output is always 32 bytes")
+    };
+
+    Keypair::from_seed(&ml_dsa_seed)
+}
+
+/// Derive the keypair for account `account` from a mnemonic, equivalent to
+/// `keypair_from_mnemonic_at_path(mnemonic, passphrase, &[account])`.
+#[must_use]
+pub fn keypair_from_mnemonic_at_index(
+    mnemonic: &Mnemonic,
+    passphrase: &str,
+    account: u32,
+) -> Keypair {
+    keypair_from_mnemonic_at_path(mnemonic, passphrase, &[account])
+}
+
 /// Convert a mnemonic to its word list.
 #[must_use]
 pub fn mnemonic_to_words(mnemonic: &Mnemonic) -> Vec<&'static str> {
     mnemonic.words().collect()
 }
 
+/// One word swapped for a BIP39 wordlist entry during [`recover_mnemonic`].
+#[derive(Debug, Clone)]
+pub struct WordCorrection {
+    /// Zero-based position of the word within the phrase
+    pub index: usize,
+    /// The word as typed by the user
+    pub original: String,
+    /// The wordlist entry it was corrected to
+    pub corrected: String,
+}
+
+/// Maximum per-word candidates kept (closest edit distance first) before
+/// taking the cartesian product across mistyped words.
+const MAX_CANDIDATES_PER_WORD: usize = 6;
+
+/// Upper bound on how many candidate phrases [`recover_mnemonic`] will test
+/// before giving up. Combinations are walked in an order that tries the
+/// closest per-word corrections first, so single-typo phrases resolve long
+/// before this bound is reached.
+const MAX_RECOVERY_ATTEMPTS: usize = 4096;
+
+/// Attempt to recover a 24-word mnemonic that contains one or more typos.
+///
+/// For every word not found in the BIP39 English wordlist, candidate
+/// corrections within Levenshtein distance 1-2 are gathered (closest first).
+/// Words already in the wordlist are left as-is. The cartesian product of
+/// candidates is then searched, closest corrections first, for a combination
+/// whose BIP39 checksum validates, bounded to [`MAX_RECOVERY_ATTEMPTS`]
+/// combinations.
+///
+/// Returns the recovered mnemonic along with the corrections that were
+/// applied, so the caller can surface them to the user for confirmation
+/// before trusting the derived keypair. Returns `None` if the phrase isn't
+/// 24 words, a word has no candidate within distance 2, or no combination
+/// within the search bound validates.
+#[must_use]
+pub fn recover_mnemonic(phrase: &str) -> Option<(Mnemonic, Vec<WordCorrection>)> {
+    let words: Vec<&str> = phrase.split_whitespace().collect();
+    if words.len() != MNEMONIC_WORD_COUNT {
+        return None;
+    }
+
+    let wordlist = Language::English.word_list();
+
+    // Candidates for each position, sorted by edit distance ascending. A
+    // word already in the list has itself as its only (distance-0) candidate.
+    let mut slots: Vec<Vec<&'static str>> = Vec::with_capacity(words.len());
+    for word in &words {
+        if let Some(&exact) = wordlist.iter().find(|&&w| w == *word) {
+            slots.push(vec![exact]);
+            continue;
+        }
+
+        let mut by_distance: Vec<(usize, &'static str)> = wordlist
+            .iter()
+            .map(|&candidate| (levenshtein_distance(word, candidate), candidate))
+            .filter(|&(dist, _)| dist <= 2)
+            .collect();
+        by_distance.sort_by_key(|&(dist, _)| dist);
+        by_distance.truncate(MAX_CANDIDATES_PER_WORD);
+
+        if by_distance.is_empty() {
+            return None;
+        }
+        slots.push(by_distance.into_iter().map(|(_, w)| w).collect());
+    }
+
+    let total_combinations: usize = slots.iter().map(Vec::len).product();
+    let attempts = total_combinations.min(MAX_RECOVERY_ATTEMPTS);
+
+    let mut indices = vec![0usize; slots.len()];
+    for _ in 0..attempts {
+        let candidate_words: Vec<&str> = indices
+            .iter()
+            .zip(&slots)
+            .map(|(&i, slot)| slot[i])
+            .collect();
+
+        if let Ok(mnemonic) = parse_mnemonic(&candidate_words.join(" ")) {
+            let corrections = words
+                .iter()
+                .zip(&candidate_words)
+                .enumerate()
+                .filter(|(_, (original, corrected))| original != corrected)
+                .map(|(index, (original, corrected))| WordCorrection {
+                    index,
+                    original: (*original).to_string(),
+                    corrected: (*corrected).to_string(),
+                })
+                .collect();
+            return Some((mnemonic, corrections));
+        }
+
+        // Odometer increment across slots, rightmost (least significant) first,
+        // so combinations closest to the typed phrase are tried earliest.
+        for slot in (0..indices.len()).rev() {
+            indices[slot] += 1;
+            if indices[slot] < slots[slot].len() {
+                break;
+            }
+            indices[slot] = 0;
+        }
+    }
+
+    None
+}
+
+/// Upper bound on combinations [`recover_mnemonic_matching`] will test before
+/// giving up — enough to exhaustively search up to two missing words against
+/// the full 2048-word BIP39 wordlist.
+const MAX_PARTIAL_RECOVERY_ATTEMPTS: usize = 2048 * 2048;
+
+/// Recover a 24-word mnemonic from a partial phrase where unknown words are
+/// written as `_`, by brute-forcing every combination of BIP39 wordlist
+/// entries for the blank slots and returning the first combination that
+/// parses (valid checksum) and for which `matches` returns `true` — e.g. a
+/// closure that derives the keypair and compares it against a known target
+/// address.
+///
+/// Known words are trusted as typed, not error-corrected; only `_` slots are
+/// searched, in wordlist order, bounded by [`MAX_PARTIAL_RECOVERY_ATTEMPTS`]
+/// combinations. Returns `None` if the phrase isn't 24 words or no
+/// combination within the search bound both validates and matches.
+#[must_use]
+pub fn recover_mnemonic_matching(
+    partial_phrase: &str,
+    matches: impl Fn(&Keypair) -> bool,
+) -> Option<Mnemonic> {
+    let words: Vec<&str> = partial_phrase.split_whitespace().collect();
+    if words.len() != MNEMONIC_WORD_COUNT {
+        return None;
+    }
+
+    let wordlist = Language::English.word_list();
+    let blank_positions: Vec<usize> = words
+        .iter()
+        .enumerate()
+        .filter(|(_, w)| **w == "_")
+        .map(|(i, _)| i)
+        .collect();
+
+    if blank_positions.is_empty() {
+        let mnemonic = parse_mnemonic(partial_phrase).ok()?;
+        return matches(&keypair_from_mnemonic(&mnemonic, "")).then_some(mnemonic);
+    }
+
+    let total_combinations = wordlist
+        .len()
+        .checked_pow(u32::try_from(blank_positions.len()).unwrap_or(u32::MAX))
+        .unwrap_or(usize::MAX);
+    let attempts = total_combinations.min(MAX_PARTIAL_RECOVERY_ATTEMPTS);
+
+    let mut indices = vec![0usize; blank_positions.len()];
+    for _ in 0..attempts {
+        let mut candidate_words = words.clone();
+        for (slot, &pos) in blank_positions.iter().enumerate() {
+            candidate_words[pos] = wordlist[indices[slot]];
+        }
+
+        if let Ok(mnemonic) = parse_mnemonic(&candidate_words.join(" ")) {
+            if matches(&keypair_from_mnemonic(&mnemonic, "")) {
+                return Some(mnemonic);
+            }
+        }
+
+        for slot in (0..indices.len()).rev() {
+            indices[slot] += 1;
+            if indices[slot] < wordlist.len() {
+                break;
+            }
+            indices[slot] = 0;
+        }
+    }
+
+    None
+}
+
+/// Classic Wagner-Fischer Levenshtein distance between two short ASCII words.
+fn levenshtein_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut curr = vec![0usize; b.len() + 1];
+
+    for (i, &ca) in a.iter().enumerate() {
+        curr[0] = i + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let cost = usize::from(ca != cb);
+            curr[j + 1] = (curr[j] + 1).min(prev[j + 1] + 1).min(prev[j] + cost);
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+
+    prev[b.len()]
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -173,4 +457,75 @@ mod tests {
         let result = parse_mnemonic("invalid mnemonic phrase");
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_empty_path_matches_keypair_from_mnemonic() {
+        let mnemonic = generate_mnemonic();
+        let kp1 = keypair_from_mnemonic(&mnemonic, "");
+        let kp2 = keypair_from_mnemonic_at_path(&mnemonic, "", &[]);
+        assert_eq!(kp1.public_key(), kp2.public_key());
+    }
+
+    #[test]
+    fn test_distinct_indices_yield_distinct_accounts() {
+        let mnemonic = generate_mnemonic();
+        let account0 = keypair_from_mnemonic_at_index(&mnemonic, "", 0);
+        let account1 = keypair_from_mnemonic_at_index(&mnemonic, "", 1);
+        assert_ne!(account0.public_key(), account1.public_key());
+    }
+
+    #[test]
+    fn test_same_index_reproduces_same_wallet() {
+        let mnemonic = generate_mnemonic();
+        let kp1 = keypair_from_mnemonic_at_index(&mnemonic, "", 7);
+        let kp2 = keypair_from_mnemonic_at_index(&mnemonic, "", 7);
+        assert_eq!(kp1.public_key(), kp2.public_key());
+        assert_eq!(kp1.secret_key().to_bytes(), kp2.secret_key().to_bytes());
+    }
+
+    #[test]
+    fn test_account_index_helper_matches_single_element_path() {
+        let mnemonic = generate_mnemonic();
+        let via_index = keypair_from_mnemonic_at_index(&mnemonic, "", 3);
+        let via_path = keypair_from_mnemonic_at_path(&mnemonic, "", &[3]);
+        assert_eq!(via_index.public_key(), via_path.public_key());
+    }
+
+    #[test]
+    fn test_multi_segment_path_differs_from_prefix() {
+        let mnemonic = generate_mnemonic();
+        let account_only = keypair_from_mnemonic_at_path(&mnemonic, "", &[0]);
+        let account_and_change = keypair_from_mnemonic_at_path(&mnemonic, "", &[0, 1]);
+        assert_ne!(account_only.public_key(), account_and_change.public_key());
+    }
+
+    #[test]
+    fn test_validate_word_count_accepts_standard_lengths() {
+        for count in [12, 15, 18, 21, 24] {
+            let phrase = vec!["abandon"; count].join(" ");
+            assert!(validate_word_count(&phrase).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_validate_word_count_rejects_nonstandard_lengths() {
+        let err = validate_word_count("only three words").unwrap_err();
+        assert!(matches!(err, CryptoError::InvalidMnemonic(_)));
+    }
+
+    #[test]
+    fn test_keypair_from_imported_phrase_matches_keypair_from_phrase() {
+        let mnemonic = generate_mnemonic();
+        let phrase = mnemonic.to_string();
+
+        let imported = keypair_from_imported_phrase(&phrase, "").unwrap();
+        let direct = keypair_from_phrase(&phrase, "").unwrap();
+        assert_eq!(imported.public_key(), direct.public_key());
+    }
+
+    #[test]
+    fn test_keypair_from_imported_phrase_rejects_nonstandard_word_count() {
+        let err = keypair_from_imported_phrase("only three words", "").unwrap_err();
+        assert!(matches!(err, CryptoError::InvalidMnemonic(_)));
+    }
 }
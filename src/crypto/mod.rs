@@ -8,26 +8,42 @@
 //! - bip39 for standard mnemonic seed phrases
 //! - Ed25519 retained ONLY for libp2p transport identity
 
+mod agility;
 mod commitment;
 mod hash;
 pub mod kem;
 mod mnemonic;
+mod sealed_box;
+mod shard;
 mod signature;
+mod signer;
 
+pub use agility::{
+    negotiate, AeadAlgorithm, Hqc192Kem, HybridX25519Hqc192Kem, Kem, KdfAlgorithm, KemAlgorithm,
+    NegotiationError, SuiteId, SuitePreferences,
+};
 pub use commitment::{CommitReveal, Commitment};
 pub use hash::{hash_data, merkle_root, Hash, Hasher};
 pub use kem::{
-    decapsulate, encapsulate, KemCiphertext, KemKeypair, KemPublicKey, KemSecretKey, SharedSecret,
-    KEM_CIPHERTEXT_SIZE, KEM_PUBKEY_SIZE, KEM_SECRET_KEY_SIZE, KEM_SHARED_SECRET_SIZE,
+    decapsulate, encapsulate, hybrid_decapsulate, hybrid_encapsulate, HybridKemCiphertext,
+    HybridKemKeypair, HybridKemPublicKey, HybridKemSecretKey, KemCiphertext, KemKeypair,
+    KemPublicKey, KemSecretKey, SharedSecret, KEM_CIPHERTEXT_SIZE, KEM_PUBKEY_SIZE,
+    KEM_SECRET_KEY_SIZE, KEM_SHARED_SECRET_SIZE,
 };
 pub use mnemonic::{
-    generate_mnemonic, keypair_from_mnemonic, keypair_from_phrase, mnemonic_to_words,
-    parse_mnemonic, MNEMONIC_WORD_COUNT,
+    generate_mnemonic, keypair_from_imported_phrase, keypair_from_mnemonic,
+    keypair_from_mnemonic_at_index, keypair_from_mnemonic_at_path, keypair_from_phrase,
+    mnemonic_to_words, parse_mnemonic, recover_mnemonic, recover_mnemonic_matching,
+    validate_word_count, WordCorrection, MIN_IMPORTED_MNEMONIC_ENTROPY_BITS, MNEMONIC_WORD_COUNT,
 };
+pub use sealed_box::{open, seal, SealReceiver, SealSender};
+pub use shard::{recover, shard, Share};
 pub use signature::{
-    sign, verify, Keypair, PublicKey, SecretKey, Signature, PUBKEY_SIZE, SECRET_KEY_SIZE,
-    SEED_SIZE, SIGNATURE_SIZE,
+    sign, sign_randomized, sign_with_context, verify, verify_batch, verify_with_context, Keypair,
+    PublicKey, SecretKey, Signature, MAX_CONTEXT_SIZE, PUBKEY_SIZE, SECRET_KEY_SIZE, SEED_SIZE,
+    SIGNATURE_SIZE,
 };
+pub use signer::{RemoteSigner, Signer, SignerError, SignerTransport};
 
 use thiserror::Error;
 
@@ -52,6 +68,15 @@ pub enum CryptoError {
     /// Invalid mnemonic phrase
     #[error("invalid mnemonic: {0}")]
     InvalidMnemonic(String),
+    /// Signature context string exceeded ML-DSA's 255-byte limit
+    #[error("invalid signature context: {0}")]
+    InvalidContext(String),
+    /// Sealed-box AEAD tag mismatch — wrong key, wrong AAD, or corrupted ciphertext
+    #[error("failed to open sealed box")]
+    OpenFailed,
+    /// Invalid Shamir secret-sharing parameters or share set (see [`shard`](crate::crypto::shard))
+    #[error("shard error: {0}")]
+    Shard(String),
 }
 
 /// Result type for crypto operations
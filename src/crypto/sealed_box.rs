@@ -0,0 +1,292 @@
+//! HPKE-style sealed boxes on top of the HQC-192 KEM.
+//!
+//! [`seal`]/[`open`] give the libp2p peer layer a drop-in post-quantum
+//! sealed box: KEM-encapsulate to the recipient's [`KemPublicKey`], then
+//! derive an AEAD key and base nonce from the shared secret via
+//! `HKDF-Extract`/`HKDF-Expand` (binding in a suite ID and the caller's
+//! `aad`), and seal the plaintext with ChaCha20-Poly1305. [`SealSender`]/
+//! [`SealReceiver`] expose the same derivation for a multi-message session,
+//! incrementing the nonce counter per message instead of always sealing at
+//! sequence number 0.
+
+use chacha20poly1305::aead::Aead;
+use chacha20poly1305::{ChaCha20Poly1305, KeyInit, Nonce};
+use hkdf::Hkdf;
+use sha3::Sha3_512;
+use zeroize::Zeroize;
+
+use super::kem::{decapsulate, encapsulate, KemCiphertext, KemKeypair, KemPublicKey, SharedSecret};
+use super::{CryptoError, CryptoResult};
+
+/// Binds the derived key/nonce to this construction so it can never collide
+/// with an unrelated use of the same shared secret.
+const SUITE_ID: &[u8] = b"hardclaw-sealed-box-hqc192-hkdfsha3512-chacha20poly1305";
+
+/// AEAD key length, matching ChaCha20-Poly1305's key size.
+const KEY_LEN: usize = 32;
+/// AEAD nonce length, matching ChaCha20-Poly1305's nonce size.
+const NONCE_LEN: usize = 12;
+
+/// Derive a 32-byte AEAD key and 12-byte base nonce from a KEM shared
+/// secret: `HKDF-Extract(salt="", ikm=shared_secret)` followed by two
+/// `HKDF-Expand` calls over the same suite ID and `aad`, one per label.
+fn derive_key_nonce(shared_secret: &SharedSecret, aad: &[u8]) -> ([u8; KEY_LEN], [u8; NONCE_LEN]) {
+    let (_prk, hk) = Hkdf::<Sha3_512>::extract(Some(b""), shared_secret.as_bytes());
+
+    let mut info = Vec::with_capacity(SUITE_ID.len() + aad.len() + 4);
+    info.extend_from_slice(SUITE_ID);
+    info.extend_from_slice(aad);
+
+    let mut key = [0u8; KEY_LEN];
+    info.extend_from_slice(b"key");
+    hk.expand(&info, &mut key)
+        .expect("32-byte output is within HKDF-SHA3-512's valid range");
+    info.truncate(SUITE_ID.len() + aad.len());
+
+    let mut nonce = [0u8; NONCE_LEN];
+    info.extend_from_slice(b"nonce");
+    hk.expand(&info, &mut nonce)
+        .expect("12-byte output is within HKDF-SHA3-512's valid range");
+
+    (key, nonce)
+}
+
+/// Encode `sequence` into `base_nonce` by XOR-ing it into the low 8 bytes,
+/// the standard HPKE/TLS nonce-increment scheme.
+fn sequenced_nonce(base_nonce: &[u8; NONCE_LEN], sequence: u64) -> Nonce {
+    let mut nonce_bytes = *base_nonce;
+    for (byte, seq_byte) in nonce_bytes[NONCE_LEN - 8..]
+        .iter_mut()
+        .zip(sequence.to_be_bytes())
+    {
+        *byte ^= seq_byte;
+    }
+    *Nonce::from_slice(&nonce_bytes)
+}
+
+/// Seal `plaintext` to `recipient_pk`: KEM-encapsulate a fresh shared
+/// secret, derive a key/nonce bound to `aad`, and encrypt with
+/// ChaCha20-Poly1305 at sequence number 0.
+///
+/// Returns the KEM ciphertext (needed by the recipient to decapsulate) and
+/// the sealed AEAD ciphertext.
+///
+/// # Errors
+/// Returns error if the recipient's public key is invalid or the AEAD seal
+/// fails.
+pub fn seal(
+    recipient_pk: &KemPublicKey,
+    aad: &[u8],
+    plaintext: &[u8],
+) -> CryptoResult<(KemCiphertext, Vec<u8>)> {
+    let (shared_secret, kem_ciphertext) = encapsulate(recipient_pk)?;
+    let (mut key, base_nonce) = derive_key_nonce(&shared_secret, aad);
+
+    let cipher = ChaCha20Poly1305::new(key.as_slice().into());
+    let nonce = sequenced_nonce(&base_nonce, 0);
+    let sealed = cipher
+        .encrypt(&nonce, chacha20poly1305::aead::Payload { msg: plaintext, aad })
+        .map_err(|_| CryptoError::OpenFailed)?;
+    key.zeroize();
+
+    Ok((kem_ciphertext, sealed))
+}
+
+/// Open a box produced by [`seal`]: decapsulate `kem_ciphertext` with
+/// `keypair`, re-derive the key/nonce bound to `aad`, and authenticate-
+/// decrypt `sealed` at sequence number 0.
+///
+/// # Errors
+/// Returns `CryptoError::OpenFailed` if the AEAD tag doesn't match (wrong
+/// key, wrong `aad`, or corrupted ciphertext), or a KEM error if
+/// `kem_ciphertext` is malformed.
+pub fn open(
+    keypair: &KemKeypair,
+    kem_ciphertext: &KemCiphertext,
+    aad: &[u8],
+    sealed: &[u8],
+) -> CryptoResult<Vec<u8>> {
+    let shared_secret = decapsulate(kem_ciphertext, keypair.secret_key())?;
+    let (mut key, base_nonce) = derive_key_nonce(&shared_secret, aad);
+
+    let cipher = ChaCha20Poly1305::new(key.as_slice().into());
+    let nonce = sequenced_nonce(&base_nonce, 0);
+    let plaintext = cipher
+        .decrypt(&nonce, chacha20poly1305::aead::Payload { msg: sealed, aad })
+        .map_err(|_| CryptoError::OpenFailed)?;
+    key.zeroize();
+
+    Ok(plaintext)
+}
+
+/// Sending half of a multi-message sealed session: seals successive
+/// messages under the same derived key, incrementing the nonce counter
+/// each time instead of re-running the KEM per message.
+pub struct SealSender {
+    key: [u8; KEY_LEN],
+    base_nonce: [u8; NONCE_LEN],
+    sequence: u64,
+}
+
+impl Drop for SealSender {
+    fn drop(&mut self) {
+        self.key.zeroize();
+    }
+}
+
+impl SealSender {
+    /// Start a session by encapsulating to `recipient_pk`, returning the
+    /// KEM ciphertext the receiver needs to build a matching
+    /// [`SealReceiver`].
+    ///
+    /// # Errors
+    /// Returns error if the recipient's public key is invalid.
+    pub fn open_session(recipient_pk: &KemPublicKey, aad: &[u8]) -> CryptoResult<(Self, KemCiphertext)> {
+        let (shared_secret, kem_ciphertext) = encapsulate(recipient_pk)?;
+        let (key, base_nonce) = derive_key_nonce(&shared_secret, aad);
+        Ok((
+            Self {
+                key,
+                base_nonce,
+                sequence: 0,
+            },
+            kem_ciphertext,
+        ))
+    }
+
+    /// Seal the next message in the session, using and then advancing the
+    /// sequence counter.
+    ///
+    /// # Errors
+    /// Returns `CryptoError::OpenFailed` if the AEAD seal fails.
+    pub fn seal_next(&mut self, aad: &[u8], plaintext: &[u8]) -> CryptoResult<Vec<u8>> {
+        let cipher = ChaCha20Poly1305::new(self.key.as_slice().into());
+        let nonce = sequenced_nonce(&self.base_nonce, self.sequence);
+        let sealed = cipher
+            .encrypt(&nonce, chacha20poly1305::aead::Payload { msg: plaintext, aad })
+            .map_err(|_| CryptoError::OpenFailed)?;
+        self.sequence += 1;
+        Ok(sealed)
+    }
+}
+
+/// Receiving half of a multi-message sealed session, mirroring [`SealSender`].
+pub struct SealReceiver {
+    key: [u8; KEY_LEN],
+    base_nonce: [u8; NONCE_LEN],
+    sequence: u64,
+}
+
+impl Drop for SealReceiver {
+    fn drop(&mut self) {
+        self.key.zeroize();
+    }
+}
+
+impl SealReceiver {
+    /// Start a session by decapsulating `kem_ciphertext` with `keypair`.
+    ///
+    /// # Errors
+    /// Returns error if `kem_ciphertext` or the keypair's secret key is
+    /// invalid.
+    pub fn open_session(
+        keypair: &KemKeypair,
+        kem_ciphertext: &KemCiphertext,
+        aad: &[u8],
+    ) -> CryptoResult<Self> {
+        let shared_secret = decapsulate(kem_ciphertext, keypair.secret_key())?;
+        let (key, base_nonce) = derive_key_nonce(&shared_secret, aad);
+        Ok(Self {
+            key,
+            base_nonce,
+            sequence: 0,
+        })
+    }
+
+    /// Open the next message in the session, using and then advancing the
+    /// sequence counter. Messages must be opened in the order they were
+    /// sealed.
+    ///
+    /// # Errors
+    /// Returns `CryptoError::OpenFailed` if the AEAD tag doesn't match.
+    pub fn open_next(&mut self, aad: &[u8], sealed: &[u8]) -> CryptoResult<Vec<u8>> {
+        let cipher = ChaCha20Poly1305::new(self.key.as_slice().into());
+        let nonce = sequenced_nonce(&self.base_nonce, self.sequence);
+        let plaintext = cipher
+            .decrypt(&nonce, chacha20poly1305::aead::Payload { msg: sealed, aad })
+            .map_err(|_| CryptoError::OpenFailed)?;
+        self.sequence += 1;
+        Ok(plaintext)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seal_open_round_trip() {
+        let keypair = KemKeypair::generate();
+        let aad = b"channel-id:42";
+        let plaintext = b"the quick brown fox jumps over the lazy dog";
+
+        let (ciphertext, sealed) = seal(keypair.public_key(), aad, plaintext).unwrap();
+        let opened = open(&keypair, &ciphertext, aad, &sealed).unwrap();
+
+        assert_eq!(opened, plaintext);
+    }
+
+    #[test]
+    fn test_open_fails_with_wrong_aad() {
+        let keypair = KemKeypair::generate();
+        let (ciphertext, sealed) = seal(keypair.public_key(), b"correct aad", b"secret").unwrap();
+
+        let err = open(&keypair, &ciphertext, b"wrong aad", &sealed).unwrap_err();
+        assert!(matches!(err, CryptoError::OpenFailed));
+    }
+
+    #[test]
+    fn test_open_fails_with_wrong_keypair() {
+        let keypair = KemKeypair::generate();
+        let other = KemKeypair::generate();
+        let aad = b"aad";
+        let (ciphertext, sealed) = seal(keypair.public_key(), aad, b"secret").unwrap();
+
+        let err = open(&other, &ciphertext, aad, &sealed).unwrap_err();
+        assert!(matches!(err, CryptoError::OpenFailed));
+    }
+
+    #[test]
+    fn test_streaming_session_round_trips_multiple_messages() {
+        let keypair = KemKeypair::generate();
+        let aad = b"session-aad";
+
+        let (mut sender, kem_ciphertext) = SealSender::open_session(keypair.public_key(), aad).unwrap();
+        let mut receiver = SealReceiver::open_session(&keypair, &kem_ciphertext, aad).unwrap();
+
+        for i in 0..5 {
+            let msg = format!("message {i}");
+            let sealed = sender.seal_next(aad, msg.as_bytes()).unwrap();
+            let opened = receiver.open_next(aad, &sealed).unwrap();
+            assert_eq!(opened, msg.as_bytes());
+        }
+    }
+
+    #[test]
+    fn test_streaming_session_rejects_replayed_message() {
+        let keypair = KemKeypair::generate();
+        let aad = b"session-aad";
+
+        let (mut sender, kem_ciphertext) = SealSender::open_session(keypair.public_key(), aad).unwrap();
+        let mut receiver = SealReceiver::open_session(&keypair, &kem_ciphertext, aad).unwrap();
+
+        let first = sender.seal_next(aad, b"first").unwrap();
+        let _second = sender.seal_next(aad, b"second").unwrap();
+
+        receiver.open_next(aad, &first).unwrap();
+        // Receiver's sequence counter has advanced past the first message's
+        // nonce, so replaying it now fails to authenticate.
+        let err = receiver.open_next(aad, &first).unwrap_err();
+        assert!(matches!(err, CryptoError::OpenFailed));
+    }
+}
@@ -0,0 +1,295 @@
+//! Shamir secret sharing over GF(256) for backing up a [`KemSecretKey`]
+//! across multiple custodians instead of trusting a single copy.
+//!
+//! [`shard`] evaluates a random degree-`t-1` polynomial per secret byte
+//! (the byte itself as the constant term) and hands each of `n` custodians
+//! one point `(x, f(x))` per byte as their [`Share`]. [`recover`]
+//! reconstructs the secret via Lagrange interpolation at `x = 0` from any
+//! `t` of those shares; fewer than `t` shares are information-theoretically
+//! independent of the secret.
+
+use rand::RngCore;
+use zeroize::Zeroize;
+
+use super::kem::KemSecretKey;
+use super::{CryptoError, CryptoResult};
+
+/// AES's GF(2^8) reduction polynomial (`x^8 + x^4 + x^3 + x + 1`), used so
+/// field arithmetic here matches the textbook description of Shamir
+/// sharing over "the" byte field.
+const REDUCTION: u8 = 0x1B;
+
+/// Multiply two GF(256) field elements.
+fn gf_mul(mut a: u8, mut b: u8) -> u8 {
+    let mut product = 0u8;
+    for _ in 0..8 {
+        if b & 1 != 0 {
+            product ^= a;
+        }
+        let carry = a & 0x80;
+        a <<= 1;
+        if carry != 0 {
+            a ^= REDUCTION;
+        }
+        b >>= 1;
+    }
+    product
+}
+
+/// Multiplicative inverse of a nonzero GF(256) element (every nonzero
+/// element satisfies `a^255 = 1`, so `a^254 = a^-1`).
+fn gf_inv(a: u8) -> u8 {
+    debug_assert!(a != 0, "zero has no multiplicative inverse");
+    let mut result = 1u8;
+    let mut base = a;
+    let mut exp = 254u8;
+    while exp > 0 {
+        if exp & 1 != 0 {
+            result = gf_mul(result, base);
+        }
+        base = gf_mul(base, base);
+        exp >>= 1;
+    }
+    result
+}
+
+/// Evaluate a polynomial (lowest-degree coefficient first) at `x` via
+/// Horner's method in GF(256).
+fn eval_poly(coefficients: &[u8], x: u8) -> u8 {
+    coefficients
+        .iter()
+        .rev()
+        .fold(0u8, |acc, &c| gf_mul(acc, x) ^ c)
+}
+
+/// One custodian's share of a sharded secret: an x-coordinate (`index`,
+/// never 0) and the corresponding y-coordinate for every byte of the
+/// secret.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Share {
+    /// The x-coordinate this share was evaluated at (1..=255).
+    pub index: u8,
+    /// One y-coordinate per secret byte.
+    pub bytes: Vec<u8>,
+}
+
+impl Share {
+    /// Encode as hex: the index byte followed by the share bytes.
+    #[must_use]
+    pub fn to_hex(&self) -> String {
+        let mut out = Vec::with_capacity(1 + self.bytes.len());
+        out.push(self.index);
+        out.extend_from_slice(&self.bytes);
+        hex::encode(out)
+    }
+
+    /// Decode a share previously produced by [`Share::to_hex`].
+    ///
+    /// # Errors
+    /// Returns [`CryptoError::Shard`] if `s` isn't valid hex or is empty.
+    pub fn from_hex(s: &str) -> CryptoResult<Self> {
+        let bytes = hex::decode(s.trim())
+            .map_err(|e| CryptoError::Shard(format!("invalid share hex: {e}")))?;
+        let (index, rest) = bytes
+            .split_first()
+            .ok_or_else(|| CryptoError::Shard("share is empty".to_string()))?;
+        Ok(Self {
+            index: *index,
+            bytes: rest.to_vec(),
+        })
+    }
+}
+
+/// Split `secret` into `total_shares` [`Share`]s such that any `threshold`
+/// of them reconstruct it via [`recover`], but fewer than `threshold`
+/// reveal nothing about it.
+///
+/// # Errors
+/// Returns [`CryptoError::Shard`] if `threshold < 2`, `threshold >
+/// total_shares`, or `total_shares` is 0 or greater than 255 (the number of
+/// nonzero GF(256) x-coordinates).
+pub fn shard(secret: &KemSecretKey, threshold: u8, total_shares: u8) -> CryptoResult<Vec<Share>> {
+    if threshold < 2 {
+        return Err(CryptoError::Shard(
+            "threshold must be at least 2".to_string(),
+        ));
+    }
+    if total_shares == 0 || (total_shares as u16) < threshold as u16 {
+        return Err(CryptoError::Shard(
+            "total_shares must be at least threshold".to_string(),
+        ));
+    }
+
+    let mut secret_bytes = secret.to_bytes();
+    let mut shares: Vec<Share> = (1..=total_shares)
+        .map(|index| Share {
+            index,
+            bytes: vec![0u8; secret_bytes.len()],
+        })
+        .collect();
+
+    let mut rng = rand::thread_rng();
+    let mut coefficients = vec![0u8; threshold as usize];
+    for (byte_idx, &secret_byte) in secret_bytes.iter().enumerate() {
+        coefficients[0] = secret_byte;
+        rng.fill_bytes(&mut coefficients[1..]);
+        for share in &mut shares {
+            share.bytes[byte_idx] = eval_poly(&coefficients, share.index);
+        }
+    }
+    coefficients.zeroize();
+    secret_bytes.zeroize();
+
+    Ok(shares)
+}
+
+/// Reconstruct a [`KemSecretKey`] from `shares` via Lagrange interpolation
+/// at `x = 0`. Any `threshold` (or more) of the shares [`shard`] produced
+/// reconstruct the original key; fewer reveal nothing.
+///
+/// # Errors
+/// Returns [`CryptoError::Shard`] if `shares` is empty, contains a
+/// duplicate or zero index, or the shares have mismatched lengths, or
+/// propagates [`KemSecretKey::from_bytes`]'s error if the reconstructed
+/// bytes aren't a valid secret key (almost always meaning too few, or the
+/// wrong, shares were supplied).
+pub fn recover(shares: &[Share]) -> CryptoResult<KemSecretKey> {
+    let Some(first) = shares.first() else {
+        return Err(CryptoError::Shard("no shares provided".to_string()));
+    };
+    let len = first.bytes.len();
+
+    let mut seen_indices = std::collections::HashSet::new();
+    for share in shares {
+        if share.index == 0 {
+            return Err(CryptoError::Shard(
+                "share index 0 is not a valid x-coordinate".to_string(),
+            ));
+        }
+        if share.bytes.len() != len {
+            return Err(CryptoError::Shard(
+                "shares have mismatched lengths".to_string(),
+            ));
+        }
+        if !seen_indices.insert(share.index) {
+            return Err(CryptoError::Shard(format!(
+                "duplicate share index {}",
+                share.index
+            )));
+        }
+    }
+
+    let mut secret = vec![0u8; len];
+    for byte_idx in 0..len {
+        let mut acc = 0u8;
+        for (i, share_i) in shares.iter().enumerate() {
+            let mut basis = 1u8;
+            for (j, share_j) in shares.iter().enumerate() {
+                if i == j {
+                    continue;
+                }
+                // Lagrange basis at x=0: product of x_j / (x_j - x_i); in
+                // GF(256), subtraction is XOR.
+                let denom = share_j.index ^ share_i.index;
+                basis = gf_mul(basis, gf_mul(share_j.index, gf_inv(denom)));
+            }
+            acc ^= gf_mul(share_i.bytes[byte_idx], basis);
+        }
+        secret[byte_idx] = acc;
+    }
+
+    let result = KemSecretKey::from_bytes(&secret);
+    secret.zeroize();
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto::kem::KemKeypair;
+
+    #[test]
+    fn test_shard_and_recover_round_trip() {
+        let keypair = KemKeypair::generate();
+        let secret = keypair.secret_key();
+
+        let shares = shard(secret, 3, 5).unwrap();
+        assert_eq!(shares.len(), 5);
+
+        let recovered = recover(&shares[..3]).unwrap();
+        assert_eq!(recovered.to_bytes(), secret.to_bytes());
+    }
+
+    #[test]
+    fn test_recover_accepts_any_threshold_subset() {
+        let keypair = KemKeypair::generate();
+        let secret = keypair.secret_key();
+        let shares = shard(secret, 3, 5).unwrap();
+
+        // Any 3 of the 5 shares should reconstruct the same secret.
+        let subset_a = vec![shares[0].clone(), shares[2].clone(), shares[4].clone()];
+        let subset_b = vec![shares[1].clone(), shares[2].clone(), shares[3].clone()];
+
+        assert_eq!(
+            recover(&subset_a).unwrap().to_bytes(),
+            secret.to_bytes()
+        );
+        assert_eq!(
+            recover(&subset_b).unwrap().to_bytes(),
+            secret.to_bytes()
+        );
+    }
+
+    #[test]
+    fn test_fewer_than_threshold_shares_reveal_nothing() {
+        let keypair = KemKeypair::generate();
+        let secret = keypair.secret_key();
+        let shares = shard(secret, 3, 5).unwrap();
+
+        // Two shares is one short of the threshold of three: recovery
+        // either fails to parse as a valid secret key, or (on the rare
+        // chance it parses) produces different bytes than the original.
+        if let Ok(wrong) = recover(&shares[..2]) {
+            assert_ne!(wrong.to_bytes(), secret.to_bytes());
+        }
+    }
+
+    #[test]
+    fn test_recover_rejects_duplicate_indices() {
+        let keypair = KemKeypair::generate();
+        let secret = keypair.secret_key();
+        let shares = shard(secret, 3, 5).unwrap();
+
+        let duplicated = vec![shares[0].clone(), shares[0].clone(), shares[1].clone()];
+        let err = recover(&duplicated).unwrap_err();
+        assert!(matches!(err, CryptoError::Shard(_)));
+    }
+
+    #[test]
+    fn test_shard_rejects_invalid_parameters() {
+        let keypair = KemKeypair::generate();
+        let secret = keypair.secret_key();
+
+        assert!(shard(secret, 1, 5).is_err());
+        assert!(shard(secret, 6, 5).is_err());
+        assert!(shard(secret, 0, 0).is_err());
+    }
+
+    #[test]
+    fn test_share_hex_round_trip() {
+        let keypair = KemKeypair::generate();
+        let secret = keypair.secret_key();
+        let shares = shard(secret, 2, 3).unwrap();
+
+        let encoded = shares[0].to_hex();
+        let decoded = Share::from_hex(&encoded).unwrap();
+        assert_eq!(decoded, shares[0]);
+    }
+
+    #[test]
+    fn test_gf_inverse_round_trips() {
+        for a in 1..=255u8 {
+            assert_eq!(gf_mul(a, gf_inv(a)), 1);
+        }
+    }
+}
@@ -11,7 +11,8 @@ use ml_dsa::{
     Signature as MlDsaSignature, SigningKey as MlDsaSigningKey,
     VerifyingKey as MlDsaVerifyingKey, B32,
 };
-use rand::RngCore;
+use rand::{CryptoRng, RngCore};
+use rayon::prelude::*;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::fmt;
 use std::hash::{Hash, Hasher as StdHasher};
@@ -27,6 +28,8 @@ pub const SIGNATURE_SIZE: usize = 3309;
 pub const SEED_SIZE: usize = 32;
 /// ML-DSA-65 secret key size in bytes (32-byte seed format)
 pub const SECRET_KEY_SIZE: usize = SEED_SIZE;
+/// Maximum length of an ML-DSA-65 context string (FIPS 204)
+pub const MAX_CONTEXT_SIZE: usize = 255;
 
 /// An ML-DSA-65 digital signature
 #[derive(Clone, PartialEq, Eq)]
@@ -105,6 +108,15 @@ impl Signature {
     pub fn to_hex(&self) -> String {
         hex::encode(&self.0)
     }
+
+    /// Parse from hex string
+    ///
+    /// # Errors
+    /// Returns error if hex is invalid or not a valid signature
+    pub fn from_hex(s: &str) -> CryptoResult<Self> {
+        let bytes = hex::decode(s).map_err(|_| CryptoError::InvalidSignature)?;
+        Self::from_bytes(&bytes)
+    }
 }
 
 impl fmt::Debug for Signature {
@@ -265,12 +277,52 @@ impl SecretKey {
         self.0.clone()
     }
 
-    /// Sign a message
+    /// Sign a message. Equivalent to `sign_with_context(message, &[])` — the
+    /// empty context, kept as the default since most callers only ever
+    /// produce one type of signed message.
     #[must_use]
     pub fn sign(&self, message: &[u8]) -> Signature {
+        self.sign_with_context(message, &[])
+            .expect("empty context never exceeds MAX_CONTEXT_SIZE")
+    }
+
+    /// Sign a message bound to `ctx`, FIPS 204's context-string domain
+    /// separation: a signature produced with one context string can never
+    /// be replayed as valid under a different one (e.g. a transaction
+    /// signature reused as a block-header or vote signature), since `ctx`
+    /// is mixed into the signing transcript alongside `message`.
+    ///
+    /// # Errors
+    /// Returns `CryptoError::InvalidContext` if `ctx` exceeds
+    /// [`MAX_CONTEXT_SIZE`] bytes.
+    pub fn sign_with_context(&self, message: &[u8], ctx: &[u8]) -> CryptoResult<Signature> {
+        if ctx.len() > MAX_CONTEXT_SIZE {
+            return Err(CryptoError::InvalidContext(format!(
+                "context must be at most {} bytes, got {}",
+                MAX_CONTEXT_SIZE,
+                ctx.len()
+            )));
+        }
+        let sk = restore_signing_key(&self.0);
+        let sig = sk
+            .sign_deterministic(message, ctx)
+            .expect("signing should not fail with valid key");
+        let encoded = sig.encode();
+        Ok(Signature(AsRef::<[u8]>::as_ref(&encoded).to_vec()))
+    }
+
+    /// Sign a message using FIPS 204's "hedged" (randomized) variant: a
+    /// fresh 32-byte `rnd` drawn from `rng` is mixed into the signing
+    /// transcript in place of the all-zero value `sign` uses, so a single
+    /// faulted or side-channel-leaky signing operation doesn't expose the
+    /// same deterministic transcript every time. Signatures produced this
+    /// way verify identically to deterministic ones via `verify` — no
+    /// verifier change needed.
+    #[must_use]
+    pub fn sign_randomized(&self, message: &[u8], mut rng: impl RngCore + CryptoRng) -> Signature {
         let sk = restore_signing_key(&self.0);
         let sig = sk
-            .sign_deterministic(message, &[])
+            .sign_randomized(message, &[], &mut rng)
             .expect("signing should not fail with valid key");
         let encoded = sig.encode();
         Signature(AsRef::<[u8]>::as_ref(&encoded).to_vec())
@@ -326,6 +378,23 @@ impl Keypair {
         self.secret.sign(message)
     }
 
+    /// Sign a message bound to a context string (see
+    /// `SecretKey::sign_with_context`).
+    ///
+    /// # Errors
+    /// Returns `CryptoError::InvalidContext` if `ctx` exceeds
+    /// [`MAX_CONTEXT_SIZE`] bytes.
+    pub fn sign_with_context(&self, message: &[u8], ctx: &[u8]) -> CryptoResult<Signature> {
+        self.secret.sign_with_context(message, ctx)
+    }
+
+    /// Sign a message using hedged (randomized) signing (see
+    /// `SecretKey::sign_randomized`).
+    #[must_use]
+    pub fn sign_randomized(&self, message: &[u8], rng: impl RngCore + CryptoRng) -> Signature {
+        self.secret.sign_randomized(message, rng)
+    }
+
     /// Get the secret key (for persistence)
     #[must_use]
     pub fn secret_key(&self) -> &SecretKey {
@@ -339,11 +408,56 @@ pub fn sign(secret: &SecretKey, message: &[u8]) -> Signature {
     secret.sign(message)
 }
 
-/// Verify a signature against a public key and message
+/// Sign a message with a secret key, bound to a context string (convenience
+/// function; see `SecretKey::sign_with_context`)
+///
+/// # Errors
+/// Returns `CryptoError::InvalidContext` if `ctx` exceeds
+/// [`MAX_CONTEXT_SIZE`] bytes.
+pub fn sign_with_context(secret: &SecretKey, message: &[u8], ctx: &[u8]) -> CryptoResult<Signature> {
+    secret.sign_with_context(message, ctx)
+}
+
+/// Sign a message with a secret key using hedged (randomized) signing
+/// (convenience function; see `SecretKey::sign_randomized`)
+#[must_use]
+pub fn sign_randomized(secret: &SecretKey, message: &[u8], rng: impl RngCore + CryptoRng) -> Signature {
+    secret.sign_randomized(message, rng)
+}
+
+/// Verify a signature against a public key and message. Equivalent to
+/// `verify_with_context(public_key, message, &[], signature)` — the empty
+/// context, matching `sign`'s default.
 ///
 /// # Errors
 /// Returns error if signature is invalid
 pub fn verify(public_key: &PublicKey, message: &[u8], signature: &Signature) -> CryptoResult<()> {
+    verify_with_context(public_key, message, &[], signature)
+}
+
+/// Verify a signature produced with `sign_with_context`/
+/// `Keypair::sign_with_context` against the same context string. A
+/// signature bound to a different `ctx` (or no context at all) will not
+/// verify, even if it's otherwise valid for `public_key` and `message`.
+///
+/// # Errors
+/// Returns `CryptoError::InvalidContext` if `ctx` exceeds
+/// [`MAX_CONTEXT_SIZE`] bytes, or `CryptoError::InvalidSignature` /
+/// `CryptoError::InvalidPublicKey` if verification fails.
+pub fn verify_with_context(
+    public_key: &PublicKey,
+    message: &[u8],
+    ctx: &[u8],
+    signature: &Signature,
+) -> CryptoResult<()> {
+    if ctx.len() > MAX_CONTEXT_SIZE {
+        return Err(CryptoError::InvalidContext(format!(
+            "context must be at most {} bytes, got {}",
+            MAX_CONTEXT_SIZE,
+            ctx.len()
+        )));
+    }
+
     let vk_encoded = EncodedVerifyingKey::<MlDsa65>::try_from(public_key.0.as_slice())
         .map_err(|_| CryptoError::InvalidPublicKey("wrong length".into()))?;
     let vk = MlDsaVerifyingKey::<MlDsa65>::decode(&vk_encoded);
@@ -353,11 +467,38 @@ pub fn verify(public_key: &PublicKey, message: &[u8], signature: &Signature) ->
     let sig = MlDsaSignature::<MlDsa65>::decode(&sig_encoded)
         .ok_or(CryptoError::InvalidSignature)?;
 
-    use ml_dsa::signature::Verifier;
-    vk.verify(message, &sig)
+    vk.verify_with_context(ctx, message, &sig)
         .map_err(|_| CryptoError::InvalidSignature)
 }
 
+/// Verify many `(public_key, message, signature)` tuples at once, spreading
+/// the ML-DSA verification work (the expensive part of each `verify` call)
+/// across a thread pool instead of running items one at a time. Useful for
+/// validating every transaction signature in a block or mempool without
+/// serializing on a single core.
+///
+/// # Errors
+/// Returns `Ok(())` if every item verifies, or `Err` with the indices (into
+/// `items`, ascending) of the items that failed to verify.
+pub fn verify_batch(items: &[(&PublicKey, &[u8], &Signature)]) -> Result<(), Vec<usize>> {
+    let failures: Vec<usize> = items
+        .par_iter()
+        .enumerate()
+        .filter_map(|(i, (public_key, message, signature))| {
+            match verify(public_key, message, signature) {
+                Ok(()) => None,
+                Err(_) => Some(i),
+            }
+        })
+        .collect();
+
+    if failures.is_empty() {
+        Ok(())
+    } else {
+        Err(failures)
+    }
+}
+
 /// Restore a SigningKey from 32-byte seed format
 fn restore_signing_key(bytes: &[u8]) -> MlDsaSigningKey<MlDsa65> {
     let seed: [u8; 32] = bytes.try_into()
@@ -389,6 +530,96 @@ mod tests {
         assert!(verify(keypair.public_key(), message, &sig).is_ok());
     }
 
+    #[test]
+    fn test_sign_randomized_verifies() {
+        let keypair = Keypair::generate();
+        let message = b"test message";
+
+        let sig = keypair.sign_randomized(message, rand::thread_rng());
+        assert!(verify(keypair.public_key(), message, &sig).is_ok());
+    }
+
+    #[test]
+    fn test_sign_randomized_is_hedged_not_reproducible() {
+        let keypair = Keypair::generate();
+        let message = b"test message";
+
+        let sig_a = keypair.sign_randomized(message, rand::thread_rng());
+        let sig_b = keypair.sign_randomized(message, rand::thread_rng());
+        assert_ne!(sig_a.as_bytes(), sig_b.as_bytes());
+    }
+
+    #[test]
+    fn test_sign_with_context_verifies_under_same_context() {
+        let keypair = Keypair::generate();
+        let message = b"transaction payload";
+
+        let sig = keypair.sign_with_context(message, b"hardclaw-tx").unwrap();
+        assert!(verify_with_context(keypair.public_key(), message, b"hardclaw-tx", &sig).is_ok());
+    }
+
+    #[test]
+    fn test_sign_with_context_rejects_under_different_context() {
+        let keypair = Keypair::generate();
+        let message = b"transaction payload";
+
+        let sig = keypair.sign_with_context(message, b"hardclaw-tx").unwrap();
+        assert!(verify_with_context(keypair.public_key(), message, b"hardclaw-block", &sig).is_err());
+        assert!(verify(keypair.public_key(), message, &sig).is_err());
+    }
+
+    #[test]
+    fn test_sign_with_context_rejects_oversized_context() {
+        let keypair = Keypair::generate();
+        let ctx = vec![0u8; MAX_CONTEXT_SIZE + 1];
+
+        assert!(keypair.sign_with_context(b"msg", &ctx).is_err());
+    }
+
+    #[test]
+    fn test_verify_batch_all_valid() {
+        let keypairs: Vec<Keypair> = (0..5).map(|_| Keypair::generate()).collect();
+        let messages: Vec<Vec<u8>> = (0..5).map(|i| format!("message {i}").into_bytes()).collect();
+        let signatures: Vec<Signature> = keypairs
+            .iter()
+            .zip(&messages)
+            .map(|(kp, msg)| kp.sign(msg))
+            .collect();
+
+        let items: Vec<(&PublicKey, &[u8], &Signature)> = keypairs
+            .iter()
+            .zip(&messages)
+            .zip(&signatures)
+            .map(|((kp, msg), sig)| (kp.public_key(), msg.as_slice(), sig))
+            .collect();
+
+        assert!(verify_batch(&items).is_ok());
+    }
+
+    #[test]
+    fn test_verify_batch_reports_failing_indices() {
+        let keypairs: Vec<Keypair> = (0..4).map(|_| Keypair::generate()).collect();
+        let messages: Vec<Vec<u8>> = (0..4).map(|i| format!("message {i}").into_bytes()).collect();
+        let mut signatures: Vec<Signature> = keypairs
+            .iter()
+            .zip(&messages)
+            .map(|(kp, msg)| kp.sign(msg))
+            .collect();
+
+        // Corrupt the signatures at indices 1 and 3 by swapping them in.
+        signatures.swap(1, 3);
+
+        let items: Vec<(&PublicKey, &[u8], &Signature)> = keypairs
+            .iter()
+            .zip(&messages)
+            .zip(&signatures)
+            .map(|((kp, msg), sig)| (kp.public_key(), msg.as_slice(), sig))
+            .collect();
+
+        let failures = verify_batch(&items).unwrap_err();
+        assert_eq!(failures, vec![1, 3]);
+    }
+
     #[test]
     fn test_wrong_message_fails() {
         let keypair = Keypair::generate();
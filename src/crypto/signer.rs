@@ -0,0 +1,148 @@
+//! Abstraction over "something that can sign `ContractTransaction`s",
+//! decoupling signing from holding a local [`Keypair`] in memory.
+//!
+//! ML-DSA-65 secret keys are large (4032 bytes) and some deployments don't
+//! want them in application memory at all — an HSM, an air-gapped signing
+//! machine, or a threshold-signing backend should be able to produce the
+//! same [`Signature`] a local [`Keypair`] would, without the transaction
+//! type knowing the difference. [`ContractTransaction::sign_with`] accepts
+//! anything implementing [`Signer`]; [`RemoteSigner`] is the pluggable-
+//! transport implementation for signers that live outside this process.
+
+use super::{PublicKey, Signature};
+
+/// Something that can produce an ML-DSA-65 [`Signature`] over arbitrary
+/// signing bytes for a fixed [`PublicKey`], without necessarily holding the
+/// corresponding secret key in local memory.
+pub trait Signer {
+    /// The public key this signer signs on behalf of.
+    fn public_key(&self) -> &PublicKey;
+
+    /// Sign `signing_bytes` (typically [`ContractTransaction::signing_bytes`](crate::contracts::ContractTransaction::signing_bytes)).
+    ///
+    /// # Errors
+    /// Returns [`SignerError`] if the signer is unreachable, refuses, or
+    /// otherwise fails to produce a signature.
+    fn sign(&self, signing_bytes: &[u8]) -> Result<Signature, SignerError>;
+}
+
+/// Errors produced by a [`Signer`].
+#[derive(Debug, thiserror::Error)]
+pub enum SignerError {
+    /// The signer's public key doesn't match the key the caller expected to
+    /// sign for (e.g. `ContractTransaction::sign_with` checking against
+    /// `sender`).
+    #[error("signer's public key does not match the expected signer")]
+    PublicKeyMismatch,
+    /// The transport to a remote/hardware signer failed, or it returned
+    /// something other than a usable signature.
+    #[error("remote signer error: {0}")]
+    Transport(String),
+}
+
+/// How a [`RemoteSigner`] actually gets bytes to, and a signature back from,
+/// a signer living outside this process — a USB HSM, an air-gapped machine
+/// read over serial/QR, a threshold-signing coordinator, etc. Implement
+/// this once per transport and hand it to [`RemoteSigner::new`].
+pub trait SignerTransport {
+    /// Send `signing_bytes` to the remote signer for `public_key` and
+    /// return the signature it produces.
+    ///
+    /// # Errors
+    /// Returns [`SignerError::Transport`] if the round trip fails.
+    fn request_signature(
+        &self,
+        public_key: &PublicKey,
+        signing_bytes: &[u8],
+    ) -> Result<Signature, SignerError>;
+}
+
+/// A [`Signer`] backed by a remote or hardware-resident key, reached via a
+/// pluggable [`SignerTransport`]. Covers HSMs, air-gapped signing machines,
+/// and threshold-signing backends alike — they all reduce to "ship bytes
+/// out, get a signature back."
+pub struct RemoteSigner<T: SignerTransport> {
+    public_key: PublicKey,
+    transport: T,
+}
+
+impl<T: SignerTransport> RemoteSigner<T> {
+    /// Wrap `transport` as a [`Signer`] for `public_key`.
+    #[must_use]
+    pub fn new(public_key: PublicKey, transport: T) -> Self {
+        Self {
+            public_key,
+            transport,
+        }
+    }
+}
+
+impl<T: SignerTransport> Signer for RemoteSigner<T> {
+    fn public_key(&self) -> &PublicKey {
+        &self.public_key
+    }
+
+    fn sign(&self, signing_bytes: &[u8]) -> Result<Signature, SignerError> {
+        let signature = self
+            .transport
+            .request_signature(&self.public_key, signing_bytes)?;
+        Ok(signature)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto::Keypair;
+
+    /// A transport that just signs locally, standing in for a real
+    /// HSM/air-gapped round trip in tests.
+    struct LoopbackTransport {
+        keypair: Keypair,
+    }
+
+    impl SignerTransport for LoopbackTransport {
+        fn request_signature(
+            &self,
+            public_key: &PublicKey,
+            signing_bytes: &[u8],
+        ) -> Result<Signature, SignerError> {
+            if public_key != self.keypair.public_key() {
+                return Err(SignerError::PublicKeyMismatch);
+            }
+            Ok(self.keypair.sign(signing_bytes))
+        }
+    }
+
+    #[test]
+    fn test_remote_signer_produces_verifiable_signature() {
+        let keypair = Keypair::generate();
+        let public_key = keypair.public_key().clone();
+        let remote = RemoteSigner::new(public_key.clone(), LoopbackTransport { keypair });
+
+        let message = b"sign me";
+        let signature = remote.sign(message).unwrap();
+
+        assert!(crate::crypto::verify(&public_key, message, &signature).is_ok());
+        assert_eq!(remote.public_key(), &public_key);
+    }
+
+    #[test]
+    fn test_remote_signer_propagates_transport_error() {
+        let keypair = Keypair::generate();
+        let other_keypair = Keypair::generate();
+        // Transport holds a different key than the RemoteSigner claims to
+        // be signing for, simulating a misconfigured remote signer.
+        let remote = RemoteSigner::new(
+            keypair.public_key().clone(),
+            LoopbackTransport {
+                keypair: other_keypair,
+            },
+        );
+
+        assert!(matches!(
+            remote.sign(b"sign me"),
+            Err(SignerError::PublicKeyMismatch)
+        ));
+    }
+}
@@ -0,0 +1,188 @@
+//! Pluggable wire encodings for blocks and state chunks.
+//!
+//! Borrowing Solana's `UiAccount` encoding menu: the canonical
+//! representation of a [`Block`] or a snapshot state chunk is always
+//! `bincode` (that's what [`BlockHeader::compute_hash`](crate::types::block::BlockHeader::compute_hash)
+//! and [`crate::snapshot`] hash over), but the bytes handed to a peer or
+//! dropped in a log don't have to be. [`Encoding`] names the framings
+//! this crate knows how to produce; [`encode_block`]/[`decode_block`]
+//! and [`encode_state_chunk`]/[`decode_state_chunk`] convert between a
+//! value and its framed bytes without ever changing what gets hashed.
+//!
+//! `Base64Zstd` is the one worth reaching for over the wire: blocks
+//! carry many [`VerificationResult`](crate::types::VerificationResult)s
+//! and genesis allocs, and that repetition compresses well. `Base58` and
+//! `Base64` exist for ASCII-safe transport — logs, JSON — where raw
+//! bincode bytes aren't welcome.
+
+use base64::Engine;
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::types::Block;
+
+/// A wire framing for an already-`bincode`-serializable value.
+///
+/// The hash of a [`Block`] is always computed over canonical bincode
+/// bytes (see [`crate::types::block::BlockHeader::compute_hash`]);
+/// `Encoding` only governs how those bytes (or the bytes of a snapshot
+/// state chunk) are framed for transport, and never changes the hash.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum Encoding {
+    /// Raw `bincode` bytes, no framing.
+    #[default]
+    Bincode,
+    /// `bincode` bytes, Base58-encoded.
+    Base58,
+    /// `bincode` bytes, Base64-encoded.
+    Base64,
+    /// `bincode` bytes, zstd-compressed then Base64-encoded.
+    Base64Zstd,
+}
+
+/// Errors raised encoding or decoding a value under an [`Encoding`].
+#[derive(Debug, thiserror::Error)]
+pub enum EncodingError {
+    /// `bincode` failed to serialize the value.
+    #[error("bincode serialization failed: {0}")]
+    Serialize(#[from] Box<bincode::ErrorKind>),
+    /// The framed bytes didn't decode cleanly as Base58.
+    #[error("invalid base58: {0}")]
+    InvalidBase58(#[from] bs58::decode::Error),
+    /// The framed bytes didn't decode cleanly as Base64.
+    #[error("invalid base64: {0}")]
+    InvalidBase64(#[from] base64::DecodeError),
+    /// zstd (de)compression of the bincode bytes failed.
+    #[error("zstd (de)compression failed: {0}")]
+    Zstd(#[from] std::io::Error),
+}
+
+/// Encode `bytes` (already-canonical `bincode`) under `encoding`.
+fn frame(bytes: Vec<u8>, encoding: Encoding) -> Result<Vec<u8>, EncodingError> {
+    Ok(match encoding {
+        Encoding::Bincode => bytes,
+        Encoding::Base58 => bs58::encode(bytes).into_string().into_bytes(),
+        Encoding::Base64 => base64::engine::general_purpose::STANDARD
+            .encode(bytes)
+            .into_bytes(),
+        Encoding::Base64Zstd => {
+            let compressed = zstd::encode_all(bytes.as_slice(), 0)?;
+            base64::engine::general_purpose::STANDARD
+                .encode(compressed)
+                .into_bytes()
+        }
+    })
+}
+
+/// Recover canonical `bincode` bytes from `framed`, reversing `frame`.
+fn unframe(framed: &[u8], encoding: Encoding) -> Result<Vec<u8>, EncodingError> {
+    Ok(match encoding {
+        Encoding::Bincode => framed.to_vec(),
+        Encoding::Base58 => bs58::decode(framed).into_vec()?,
+        Encoding::Base64 => base64::engine::general_purpose::STANDARD.decode(framed)?,
+        Encoding::Base64Zstd => {
+            let compressed = base64::engine::general_purpose::STANDARD.decode(framed)?;
+            zstd::decode_all(compressed.as_slice())?
+        }
+    })
+}
+
+/// Serialize `block` to canonical `bincode`, then frame it under
+/// `encoding`. The block's hash is unaffected: it was already fixed by
+/// [`BlockHeader::compute_hash`](crate::types::block::BlockHeader::compute_hash)
+/// before this ever runs.
+pub fn encode_block(block: &Block, encoding: Encoding) -> Result<Vec<u8>, EncodingError> {
+    encode_value(block, encoding)
+}
+
+/// Reverse [`encode_block`]: unframe `bytes` under `encoding`, then
+/// `bincode`-deserialize the recovered bytes back into a [`Block`].
+pub fn decode_block(bytes: &[u8], encoding: Encoding) -> Result<Block, EncodingError> {
+    decode_value(bytes, encoding)
+}
+
+/// Frame a raw snapshot state chunk (see [`crate::snapshot`]) under
+/// `encoding`. Chunk bytes are already the canonical, already-serialized
+/// state bytes `SnapshotManifest` hashes over, so unlike [`encode_block`]
+/// there's no bincode wrapping step here — only the transport framing.
+pub fn encode_state_chunk(chunk: &[u8], encoding: Encoding) -> Result<Vec<u8>, EncodingError> {
+    frame(chunk.to_vec(), encoding)
+}
+
+/// Reverse [`encode_state_chunk`].
+pub fn decode_state_chunk(bytes: &[u8], encoding: Encoding) -> Result<Vec<u8>, EncodingError> {
+    unframe(bytes, encoding)
+}
+
+/// Shared bincode-then-frame path for any `Serialize` value.
+fn encode_value<T: Serialize>(value: &T, encoding: Encoding) -> Result<Vec<u8>, EncodingError> {
+    let canonical = bincode::serialize(value)?;
+    frame(canonical, encoding)
+}
+
+/// Shared unframe-then-bincode path for any `DeserializeOwned` value.
+fn decode_value<T: DeserializeOwned>(
+    bytes: &[u8],
+    encoding: Encoding,
+) -> Result<T, EncodingError> {
+    let canonical = unframe(bytes, encoding)?;
+    Ok(bincode::deserialize(&canonical)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto::Keypair;
+
+    fn sample_block() -> Block {
+        let kp = Keypair::generate();
+        Block::genesis(kp.public_key().clone())
+    }
+
+    #[test]
+    fn test_bincode_roundtrip_preserves_hash() {
+        let block = sample_block();
+        let encoded = encode_block(&block, Encoding::Bincode).unwrap();
+        let decoded = decode_block(&encoded, Encoding::Bincode).unwrap();
+        assert_eq!(decoded.hash, block.hash);
+    }
+
+    #[test]
+    fn test_base58_roundtrip_preserves_hash() {
+        let block = sample_block();
+        let encoded = encode_block(&block, Encoding::Base58).unwrap();
+        let decoded = decode_block(&encoded, Encoding::Base58).unwrap();
+        assert_eq!(decoded.hash, block.hash);
+    }
+
+    #[test]
+    fn test_base64_roundtrip_preserves_hash() {
+        let block = sample_block();
+        let encoded = encode_block(&block, Encoding::Base64).unwrap();
+        let decoded = decode_block(&encoded, Encoding::Base64).unwrap();
+        assert_eq!(decoded.hash, block.hash);
+    }
+
+    #[test]
+    fn test_base64_zstd_roundtrip_preserves_hash() {
+        let block = sample_block();
+        let encoded = encode_block(&block, Encoding::Base64Zstd).unwrap();
+        let decoded = decode_block(&encoded, Encoding::Base64Zstd).unwrap();
+        assert_eq!(decoded.hash, block.hash);
+    }
+
+    #[test]
+    fn test_encodings_disagree_on_framed_bytes() {
+        let block = sample_block();
+        let bincode_bytes = encode_block(&block, Encoding::Bincode).unwrap();
+        let base64_bytes = encode_block(&block, Encoding::Base64).unwrap();
+        assert_ne!(bincode_bytes, base64_bytes);
+    }
+
+    #[test]
+    fn test_state_chunk_roundtrip() {
+        let chunk = vec![1u8, 2, 3, 4, 5, 6, 7, 8];
+        let encoded = encode_state_chunk(&chunk, Encoding::Base64Zstd).unwrap();
+        let decoded = decode_state_chunk(&encoded, Encoding::Base64Zstd).unwrap();
+        assert_eq!(decoded, chunk);
+    }
+}
@@ -7,6 +7,7 @@ use std::collections::HashMap;
 use serde::{Deserialize, Serialize};
 
 use super::{GenesisError, GENESIS_AIRDROP_AMOUNT, MAX_GENESIS_PARTICIPANTS, MINIMUM_STAKE_HCLAW};
+use crate::crypto::{hash_data, Hash};
 use crate::types::{Address, HclawAmount, Timestamp};
 
 /// Simplified airdrop configuration
@@ -147,6 +148,16 @@ impl AirdropTracker {
         self.claims.get(address)
     }
 
+    /// All claims ordered by airdrop position, for deterministic state
+    /// commitments (see `BootstrapState::state_root`) — iterating `claims`
+    /// directly would be `HashMap`-ordered and non-reproducible.
+    #[must_use]
+    pub fn claims_by_position(&self) -> Vec<&AirdropClaim> {
+        let mut claims: Vec<&AirdropClaim> = self.claims.values().collect();
+        claims.sort_by_key(|c| c.position);
+        claims
+    }
+
     /// Next position to be assigned
     #[must_use]
     pub fn next_position(&self) -> u32 {
@@ -203,6 +214,183 @@ impl AirdropTracker {
     }
 }
 
+/// Merkle-distributor airdrop config.
+///
+/// `AirdropTracker` forces every one of up to `max_participants` claims
+/// into consensus state as a first-come-first-served `HashMap` entry,
+/// which can't represent a precomputed, audited eligibility list. This is
+/// the alternative: the allocation list lives off-chain, and only its
+/// 32-byte Merkle root (plus leaf count) is committed here — the same
+/// shape as a chain-spec's `genesis_alloc`, but for lists too large to
+/// embed directly.
+///
+/// Leaves are `hash_data(index_le_bytes ++ address_bytes ++
+/// amount_raw_le_bytes)`; see [`verify_merkle_proof`] for how a proof
+/// folds up to `root`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MerkleAirdropConfig {
+    /// Root of the allocation Merkle tree
+    pub root: Hash,
+    /// Number of leaves (allocation list length) `root` commits to
+    pub total_leaves: u32,
+}
+
+/// One allocation list entry, submitted by a claimant alongside a Merkle
+/// proof against [`MerkleAirdropConfig::root`].
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MerkleAirdropClaim {
+    /// Leaf index in the allocation list (0-indexed)
+    pub index: u32,
+    /// Address being credited
+    pub address: Address,
+    /// Amount this leaf allocates
+    pub amount: HclawAmount,
+}
+
+impl MerkleAirdropClaim {
+    /// The leaf hash this claim must match: `hash_data(index_le_bytes ++
+    /// address_bytes ++ amount_raw_le_bytes)`.
+    #[must_use]
+    fn leaf_hash(&self) -> Hash {
+        let mut data = Vec::new();
+        data.extend_from_slice(&self.index.to_le_bytes());
+        data.extend_from_slice(self.address.as_bytes());
+        data.extend_from_slice(&self.amount.raw().to_le_bytes());
+        hash_data(&data)
+    }
+}
+
+/// Fold `leaf` up through `proof` and check the result matches `root`.
+///
+/// Sibling pairing is order-independent (`hash_data(min(node, sibling) ++
+/// max(node, sibling))` at each level), so proof generation doesn't need
+/// to track which side of the tree each sibling came from.
+#[must_use]
+fn verify_merkle_proof(leaf: Hash, proof: &[Hash], root: Hash) -> bool {
+    let mut node = leaf;
+    for sibling in proof {
+        let (lo, hi) = if node.as_bytes() <= sibling.as_bytes() {
+            (node.clone(), sibling.clone())
+        } else {
+            (sibling.clone(), node.clone())
+        };
+        let mut data = Vec::new();
+        data.extend_from_slice(lo.as_bytes());
+        data.extend_from_slice(hi.as_bytes());
+        node = hash_data(&data);
+    }
+    node == root
+}
+
+/// Tracks claims against a [`MerkleAirdropConfig`] using a compact claimed
+/// bitmap keyed by leaf index, instead of a full per-address claims map —
+/// the allocation list itself lives off-chain, pinned by `config.root`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MerkleAirdropTracker {
+    /// Merkle-distributor configuration
+    config: MerkleAirdropConfig,
+    /// Claimed bitmap, one bit per leaf index, packed 64 per word
+    claimed: Vec<u64>,
+    /// Total tokens distributed so far
+    total_distributed: HclawAmount,
+}
+
+impl MerkleAirdropTracker {
+    /// Create a new tracker for `config`
+    #[must_use]
+    pub fn new(config: MerkleAirdropConfig) -> Self {
+        let words = (config.total_leaves as usize).div_ceil(64);
+        Self {
+            config,
+            claimed: vec![0u64; words],
+            total_distributed: HclawAmount::ZERO,
+        }
+    }
+
+    /// Verify a claimant's proof against `config.root`.
+    ///
+    /// This only checks tree membership and index range — it is
+    /// read-only and does not mark the index claimed. Eligibility is
+    /// established purely by being in the tree, so there is nothing to
+    /// reserve against a double-claim until [`Self::activate_claim`]
+    /// flips the bit (mirroring `AirdropTracker::reserve_position`
+    /// verifying first and `activate_claim` distributing after the
+    /// competency check).
+    ///
+    /// # Errors
+    /// Returns [`GenesisError::InvalidConfig`] if `claim.index` is out of
+    /// range or the proof doesn't fold up to `config.root`.
+    pub fn reserve_position(
+        &self,
+        claim: &MerkleAirdropClaim,
+        proof: &[Hash],
+    ) -> Result<(), GenesisError> {
+        if claim.index >= self.config.total_leaves {
+            return Err(GenesisError::InvalidConfig(format!(
+                "index {} out of range for {} leaves",
+                claim.index, self.config.total_leaves
+            )));
+        }
+        if !verify_merkle_proof(claim.leaf_hash(), proof, self.config.root) {
+            return Err(GenesisError::InvalidConfig(
+                "merkle proof does not verify against root".into(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Activate a claim after the competency check passes, flipping its
+    /// bit in the claimed bitmap and returning the amount to credit.
+    ///
+    /// # Errors
+    /// Returns [`GenesisError::InvalidConfig`] if `claim.index` is out of
+    /// range, or [`GenesisError::AlreadyClaimed`] if it was already
+    /// activated.
+    pub fn activate_claim(
+        &mut self,
+        claim: &MerkleAirdropClaim,
+    ) -> Result<HclawAmount, GenesisError> {
+        if claim.index >= self.config.total_leaves {
+            return Err(GenesisError::InvalidConfig(format!(
+                "index {} out of range for {} leaves",
+                claim.index, self.config.total_leaves
+            )));
+        }
+        if self.is_claimed(claim.index) {
+            return Err(GenesisError::AlreadyClaimed);
+        }
+
+        let (word, bit) = Self::bit_location(claim.index);
+        self.claimed[word] |= 1 << bit;
+        self.total_distributed = self.total_distributed.saturating_add(claim.amount);
+
+        Ok(claim.amount)
+    }
+
+    /// Whether `index` has already been activated
+    #[must_use]
+    pub fn is_claimed(&self, index: u32) -> bool {
+        let (word, bit) = Self::bit_location(index);
+        self.claimed.get(word).is_some_and(|w| w & (1 << bit) != 0)
+    }
+
+    /// Total tokens distributed so far
+    #[must_use]
+    pub fn total_distributed(&self) -> HclawAmount {
+        self.total_distributed
+    }
+
+    /// Get the config
+    #[must_use]
+    pub fn config(&self) -> &MerkleAirdropConfig {
+        &self.config
+    }
+
+    fn bit_location(index: u32) -> (usize, u32) {
+        ((index / 64) as usize, index % 64)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -291,4 +479,123 @@ mod tests {
         assert_eq!(tracker.total_distributed().whole_hclaw(), 10_000);
         assert_eq!(tracker.unclaimed_pool().whole_hclaw(), 490_000);
     }
+
+    /// Build a 2-leaf tree's root and each leaf's sibling proof.
+    fn two_leaf_tree(leaf0: Hash, leaf1: Hash) -> (Hash, Vec<Hash>, Vec<Hash>) {
+        let (lo, hi) = if leaf0.as_bytes() <= leaf1.as_bytes() {
+            (leaf0.clone(), leaf1.clone())
+        } else {
+            (leaf1.clone(), leaf0.clone())
+        };
+        let mut data = Vec::new();
+        data.extend_from_slice(lo.as_bytes());
+        data.extend_from_slice(hi.as_bytes());
+        let root = hash_data(&data);
+        (root, vec![leaf1], vec![leaf0])
+    }
+
+    #[test]
+    fn test_merkle_reserve_and_activate() {
+        let addr0 = test_address(1);
+        let addr1 = test_address(2);
+        let claim0 = MerkleAirdropClaim {
+            index: 0,
+            address: addr0,
+            amount: HclawAmount::from_hclaw(100),
+        };
+        let claim1 = MerkleAirdropClaim {
+            index: 1,
+            address: addr1,
+            amount: HclawAmount::from_hclaw(250),
+        };
+        let (root, proof0, proof1) = two_leaf_tree(claim0.leaf_hash(), claim1.leaf_hash());
+        let mut tracker = MerkleAirdropTracker::new(MerkleAirdropConfig {
+            root,
+            total_leaves: 2,
+        });
+
+        tracker.reserve_position(&claim0, &proof0).unwrap();
+        let amount = tracker.activate_claim(&claim0).unwrap();
+        assert_eq!(amount.whole_hclaw(), 100);
+        assert!(tracker.is_claimed(0));
+        assert!(!tracker.is_claimed(1));
+
+        tracker.reserve_position(&claim1, &proof1).unwrap();
+        tracker.activate_claim(&claim1).unwrap();
+        assert_eq!(tracker.total_distributed().whole_hclaw(), 350);
+    }
+
+    #[test]
+    fn test_merkle_rejects_wrong_proof() {
+        let addr0 = test_address(1);
+        let addr1 = test_address(2);
+        let claim0 = MerkleAirdropClaim {
+            index: 0,
+            address: addr0,
+            amount: HclawAmount::from_hclaw(100),
+        };
+        let claim1 = MerkleAirdropClaim {
+            index: 1,
+            address: addr1,
+            amount: HclawAmount::from_hclaw(250),
+        };
+        let (root, _, _) = two_leaf_tree(claim0.leaf_hash(), claim1.leaf_hash());
+        let tracker = MerkleAirdropTracker::new(MerkleAirdropConfig {
+            root,
+            total_leaves: 2,
+        });
+
+        // Using claim1's proof (claim0's leaf) against claim0 doesn't fold up
+        // to the root.
+        let wrong_proof = vec![claim0.leaf_hash()];
+        assert!(matches!(
+            tracker.reserve_position(&claim0, &wrong_proof),
+            Err(GenesisError::InvalidConfig(_))
+        ));
+    }
+
+    #[test]
+    fn test_merkle_rejects_double_activate() {
+        let addr0 = test_address(1);
+        let addr1 = test_address(2);
+        let claim0 = MerkleAirdropClaim {
+            index: 0,
+            address: addr0,
+            amount: HclawAmount::from_hclaw(100),
+        };
+        let claim1 = MerkleAirdropClaim {
+            index: 1,
+            address: addr1,
+            amount: HclawAmount::from_hclaw(250),
+        };
+        let (root, _, _) = two_leaf_tree(claim0.leaf_hash(), claim1.leaf_hash());
+        let mut tracker = MerkleAirdropTracker::new(MerkleAirdropConfig {
+            root,
+            total_leaves: 2,
+        });
+
+        tracker.activate_claim(&claim0).unwrap();
+        assert!(matches!(
+            tracker.activate_claim(&claim0),
+            Err(GenesisError::AlreadyClaimed)
+        ));
+    }
+
+    #[test]
+    fn test_merkle_rejects_out_of_range_index() {
+        let tracker = MerkleAirdropTracker::new(MerkleAirdropConfig {
+            root: Hash::ZERO,
+            total_leaves: 2,
+        });
+        let claim = MerkleAirdropClaim {
+            index: 5,
+            address: test_address(1),
+            amount: HclawAmount::from_hclaw(100),
+        };
+
+        assert!(matches!(
+            tracker.reserve_position(&claim, &[]),
+            Err(GenesisError::InvalidConfig(_))
+        ));
+    }
 }
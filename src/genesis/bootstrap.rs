@@ -13,6 +13,7 @@ use super::competency::CompetencyManager;
 use super::liveness::LivenessTracker;
 use super::vesting::VestingSchedule;
 use super::{DnsBootstrapClaim, GenesisConfig, GenesisError};
+use crate::crypto::{hash_data, verify, Hash, PublicKey, Signature};
 use crate::types::{Address, HclawAmount, SystemJobKind, Timestamp};
 
 /// Current phase of the bootstrap period
@@ -27,6 +28,82 @@ pub enum BootstrapPhase {
     },
 }
 
+/// One unit of pending bootstrap work, as reported by
+/// [`BootstrapState::pending_work`] without mutating any state — the
+/// "workable" half of a keeper's workable/work query pair.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PendingJob {
+    /// `beneficiary`'s vesting schedule hasn't been credited its graduated
+    /// liveness participation for `day` yet; drive it via
+    /// [`BootstrapState::process_day_end`] (or [`BootstrapState::advance_to`]).
+    DayEnd {
+        /// The bootstrap day that needs processing
+        day: u32,
+        /// The vesting schedule this day-end credit applies to
+        beneficiary: Address,
+    },
+    /// Bootstrap has crossed its completion condition and needs
+    /// [`BootstrapState::check_completion`] called.
+    Completion,
+}
+
+impl PendingJob {
+    /// Deterministic `hash(job) % num_keepers` bucket this job is assigned
+    /// to, so several keepers cranking the same [`BootstrapState`] can each
+    /// filter [`BootstrapState::pending_work`] down to the jobs they own
+    /// instead of every keeper emitting the same `SystemJobKind`. Returns
+    /// `0` for `num_keepers == 0` (single-keeper / no partitioning).
+    #[must_use]
+    pub fn assignment_bucket(&self, num_keepers: u32) -> u32 {
+        if num_keepers == 0 {
+            return 0;
+        }
+        let mut data = Vec::new();
+        match self {
+            Self::DayEnd { day, beneficiary } => {
+                data.extend_from_slice(&day.to_le_bytes());
+                data.extend_from_slice(beneficiary.as_bytes());
+            }
+            Self::Completion => data.extend_from_slice(b"bootstrap-completion"),
+        }
+        let hash = hash_data(&data);
+        let bucket = u32::from_le_bytes(hash.as_bytes()[..4].try_into().expect("4 bytes"));
+        bucket % num_keepers
+    }
+}
+
+/// Evidence that `verifier` signed two different timestamps within the
+/// same bootstrap day — equivalent to a validator double-attestation /
+/// equivocation. Verified against `verifier_key` before
+/// [`BootstrapState::detect_double_attestation`] slashes anyone.
+#[derive(Clone, Debug)]
+pub struct DoubleAttestationEvidence {
+    /// The verifier accused of equivocating
+    pub verifier: Address,
+    /// The verifier's signing key, used to check both signatures below
+    pub verifier_key: PublicKey,
+    /// The bootstrap day both timestamps fall within
+    pub day: u32,
+    /// First signed timestamp
+    pub timestamp_a: Timestamp,
+    /// Signature over `(day, timestamp_a)`
+    pub signature_a: Signature,
+    /// Second, distinct signed timestamp
+    pub timestamp_b: Timestamp,
+    /// Signature over `(day, timestamp_b)`
+    pub signature_b: Signature,
+}
+
+impl DoubleAttestationEvidence {
+    /// The exact byte layout each signature must cover
+    fn signed_message(day: u32, timestamp: Timestamp) -> Vec<u8> {
+        let mut data = Vec::new();
+        data.extend_from_slice(&day.to_le_bytes());
+        data.extend_from_slice(&timestamp.to_le_bytes());
+        data
+    }
+}
+
 /// The bootstrap state machine
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct BootstrapState {
@@ -119,7 +196,7 @@ impl BootstrapState {
             amount,
             self.airdrop.config().min_stake,
             self.config.bootstrap_start,
-            self.config.bootstrap_end,
+            0, // no cliff: tokens are gated by liveness, not a lockup period
             join_day,
         );
 
@@ -151,18 +228,15 @@ impl BootstrapState {
 
         let mut jobs = Vec::new();
 
-        // For each vesting schedule, check if the verifier was active on this day
-        let active_verifiers: Vec<Address> = self
-            .vesting_schedules
-            .keys()
-            .copied()
-            .filter(|addr| self.liveness.was_active_on_day(addr, day))
-            .collect();
+        // Credit each vesting schedule with its verifier's graduated
+        // participation for this day, rather than an all-or-nothing check.
+        let addresses: Vec<Address> = self.vesting_schedules.keys().copied().collect();
 
-        for address in active_verifiers {
+        for address in addresses {
+            let ratio = self.liveness.participation_ratio(&address, day);
             if let Some(schedule) = self.vesting_schedules.get_mut(&address) {
                 let before = schedule.vested_amount();
-                schedule.mark_day_active(day);
+                schedule.mark_period_participation(day, ratio);
                 let after = schedule.vested_amount();
 
                 let unlocked = after.saturating_sub(before);
@@ -173,13 +247,156 @@ impl BootstrapState {
                     });
                 }
             }
+
+            if let Some(job) = self.detect_downtime(&address, day) {
+                jobs.push(job);
+            }
+        }
+
+        jobs
+    }
+
+    /// Check `address` for prolonged downtime: more than
+    /// `config.max_missed_days` consecutive inactive days ending at `day`.
+    /// Fires only once per streak — the day the
+    /// `max_missed_days + 1`-day window first goes fully inactive — rather
+    /// than re-slashing on every subsequent day the verifier stays down.
+    /// Slashes one period's vesting portion from the offender's still-locked
+    /// balance.
+    pub fn detect_downtime(&mut self, address: &Address, day: u32) -> Option<SystemJobKind> {
+        let threshold = self.config.max_missed_days;
+        if day < threshold {
+            return None;
+        }
+        let window_start = day - threshold;
+        let all_missed = (window_start..=day)
+            .all(|d| !self.liveness.was_active_on_day(address, d));
+        if !all_missed {
+            return None;
+        }
+        // The streak must have *just* reached the threshold this day, or
+        // we'd emit a fresh slash every day the verifier stays offline.
+        if window_start > 0 && !self.liveness.was_active_on_day(address, window_start - 1) {
+            return None;
+        }
+
+        let schedule = self.vesting_schedules.get_mut(address)?;
+        let penalty = schedule.per_period;
+        let amount = schedule.slash(penalty);
+        if amount.raw() == 0 {
+            return None;
+        }
+
+        Some(SystemJobKind::Slash {
+            offender: *address,
+            amount,
+            reason: "prolonged downtime".to_string(),
+        })
+    }
+
+    /// Verify [`DoubleAttestationEvidence`] and, on success, slash the
+    /// offender's entire still-locked vesting balance. Two distinct
+    /// timestamps signed by the same verifier key for the same bootstrap
+    /// day is unambiguous equivocation — there's no partial credit the way
+    /// there is for downtime.
+    pub fn detect_double_attestation(
+        &mut self,
+        evidence: &DoubleAttestationEvidence,
+    ) -> Result<SystemJobKind, GenesisError> {
+        if evidence.timestamp_a == evidence.timestamp_b {
+            return Err(GenesisError::InvalidSlashEvidence(
+                "evidence must cover two distinct timestamps".into(),
+            ));
+        }
+
+        for (timestamp, signature) in [
+            (evidence.timestamp_a, &evidence.signature_a),
+            (evidence.timestamp_b, &evidence.signature_b),
+        ] {
+            let message = DoubleAttestationEvidence::signed_message(evidence.day, timestamp);
+            verify(&evidence.verifier_key, &message, signature).map_err(|e| {
+                GenesisError::InvalidSlashEvidence(format!("signature verification failed: {e}"))
+            })?;
+        }
+
+        let schedule = self
+            .vesting_schedules
+            .get_mut(&evidence.verifier)
+            .ok_or_else(|| {
+                GenesisError::InvalidSlashEvidence("no vesting schedule for verifier".into())
+            })?;
+
+        let locked = schedule.total_amount.saturating_sub(schedule.vested_amount());
+        let amount = schedule.slash(locked);
+
+        Ok(SystemJobKind::Slash {
+            offender: evidence.verifier,
+            amount,
+            reason: "double-attestation".to_string(),
+        })
+    }
+
+    /// Enumerate every [`PendingJob`] outstanding as of `now`, without
+    /// mutating any state: one [`PendingJob::DayEnd`] per vesting schedule
+    /// for each day boundary crossed since `last_processed_day`, plus a
+    /// [`PendingJob::Completion`] if bootstrap has crossed its completion
+    /// condition. Callers filter this list by
+    /// [`PendingJob::assignment_bucket`] to decide which jobs they, rather
+    /// than some other keeper, should drive.
+    #[must_use]
+    pub fn pending_work(&self, now: Timestamp) -> Vec<PendingJob> {
+        let mut jobs = Vec::new();
+        if !self.is_active() {
+            return jobs;
+        }
+
+        let current_day = self.liveness.day_for_timestamp(now).unwrap_or(0);
+        let start_day = self.last_processed_day.map_or(0, |day| day + 1);
+        for day in start_day..=current_day {
+            for beneficiary in self.vesting_schedules.keys() {
+                jobs.push(PendingJob::DayEnd {
+                    day,
+                    beneficiary: *beneficiary,
+                });
+            }
+        }
+
+        let should_complete = now >= self.config.bootstrap_end || self.airdrop.is_exhausted();
+        if should_complete {
+            jobs.push(PendingJob::Completion);
+        }
+
+        jobs
+    }
+
+    /// Idempotently catch up every missed day-end in order up to `now`,
+    /// then run completion. Safe to call repeatedly (including from
+    /// several keepers racing on the same state) — [`Self::process_day_end`]
+    /// already no-ops once a day has been processed, and
+    /// [`Self::check_completion`] is a no-op once bootstrap is no longer
+    /// active.
+    pub fn advance_to(&mut self, now: Timestamp) -> Vec<SystemJobKind> {
+        let mut jobs = Vec::new();
+        if !self.is_active() {
+            return jobs;
+        }
+
+        let current_day = self.liveness.day_for_timestamp(now).unwrap_or(0);
+        let start_day = self.last_processed_day.map_or(0, |day| day + 1);
+        for day in start_day..=current_day {
+            jobs.extend(self.process_day_end(day));
+        }
+
+        if let Some(job) = self.check_completion(now) {
+            jobs.push(job);
         }
 
         jobs
     }
 
     /// Process a DNS break-glass claim.
-    /// Requires a signature from the authority key over the node's public key.
+    /// Requires a signature from one of `authorized_keys` over the claim's
+    /// canonical message (see [`DnsBootstrapClaim::signing_message`]).
     pub fn process_dns_claim(
         &mut self,
         claim: DnsBootstrapClaim,
@@ -211,6 +428,21 @@ impl BootstrapState {
             ));
         }
 
+        if !self
+            .config
+            .dns_break_glass
+            .authorized_keys
+            .contains(&claim.authorized_by)
+        {
+            return Err(GenesisError::DnsBreakGlassUnauthorized);
+        }
+        verify(
+            &claim.authorized_by,
+            &claim.signing_message(),
+            &claim.authority_sig,
+        )
+        .map_err(|_| GenesisError::DnsBreakGlassUnauthorized)?;
+
         let job = SystemJobKind::DnsBootstrapClaim {
             node: claim.address,
             hostname: claim.hostname.clone(),
@@ -278,6 +510,128 @@ impl BootstrapState {
             .max_nodes
             .saturating_sub(self.dns_claims.len() as u32)
     }
+
+    /// Deterministic commitment over every field a restarting or
+    /// late-joining node needs to have reconstructed correctly: airdrop
+    /// claims (ordered by position), vesting schedules (ordered by
+    /// address), `dns_claims`, `liveness`, and `last_processed_day`.
+    /// `config` itself isn't included — it's frozen and distributed
+    /// out-of-band, already covered by
+    /// [`GenesisConfig::config_hash`](super::GenesisConfig::config_hash).
+    /// See [`Self::catch_up`] for the other half of this check.
+    #[must_use]
+    pub fn state_root(&self) -> Hash {
+        let mut data = Vec::new();
+
+        for claim in self.airdrop.claims_by_position() {
+            data.extend_from_slice(&bincode::serialize(claim).expect("serialize airdrop claim"));
+        }
+
+        let mut schedules: Vec<(&Address, &VestingSchedule)> =
+            self.vesting_schedules.iter().collect();
+        schedules.sort_by_key(|(address, _)| **address);
+        for (address, schedule) in schedules {
+            data.extend_from_slice(address.as_bytes());
+            data.extend_from_slice(
+                &bincode::serialize(schedule).expect("serialize vesting schedule"),
+            );
+        }
+
+        data.extend_from_slice(
+            &bincode::serialize(&self.dns_claims).expect("serialize dns claims"),
+        );
+        data.extend_from_slice(&bincode::serialize(&self.liveness).expect("serialize liveness"));
+        data.extend_from_slice(&bincode::serialize(&self.last_processed_day).expect("serialize last_processed_day"));
+
+        hash_data(&data)
+    }
+
+    /// Rebuild a [`BootstrapState`] from scratch by replaying `events`
+    /// against a frozen `config`, in the sequencer catch-up style: apply
+    /// everything in order, then check the result against a peer-supplied
+    /// `trusted_root` rather than trusting local storage. An event that
+    /// fails to apply (e.g. a join past airdrop exhaustion) is skipped
+    /// exactly as it would have been live — only the final root matters.
+    pub fn catch_up(
+        config: GenesisConfig,
+        events: &[BootstrapEvent],
+        trusted_root: Hash,
+    ) -> Result<Self, GenesisError> {
+        let mut state = Self::new(config);
+
+        for event in events {
+            match event {
+                BootstrapEvent::VerifierJoin { address, now } => {
+                    let _ = state.process_verifier_join(*address, *now);
+                }
+                BootstrapEvent::VerifierActivate { address, now } => {
+                    let _ = state.activate_verifier(address, *now);
+                }
+                BootstrapEvent::Attestation {
+                    verifier,
+                    block_timestamp,
+                } => {
+                    state.record_attestation(verifier, *block_timestamp);
+                }
+                BootstrapEvent::DayEnd { day } => {
+                    state.process_day_end(*day);
+                }
+                BootstrapEvent::DnsClaim { claim } => {
+                    let _ = state.process_dns_claim(claim.clone());
+                }
+            }
+        }
+
+        let actual = state.state_root();
+        if actual != trusted_root {
+            return Err(GenesisError::StateRootMismatch {
+                expected: trusted_root,
+                actual,
+            });
+        }
+
+        Ok(state)
+    }
+}
+
+/// One entry in the canonical bootstrap event log — everything that
+/// mutates a [`BootstrapState`], in the order it originally happened.
+/// Replayed by [`BootstrapState::catch_up`] to let a late-joining or
+/// restarting node rebuild genesis state from peers instead of trusting
+/// local storage.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum BootstrapEvent {
+    /// A verifier reserved an airdrop position
+    VerifierJoin {
+        /// The joining address
+        address: Address,
+        /// When they joined
+        now: Timestamp,
+    },
+    /// A verifier passed their competency challenge and was activated
+    VerifierActivate {
+        /// The activated address
+        address: Address,
+        /// When they were activated
+        now: Timestamp,
+    },
+    /// A block attestation was recorded for liveness tracking
+    Attestation {
+        /// The attesting verifier
+        verifier: Address,
+        /// The block's timestamp
+        block_timestamp: Timestamp,
+    },
+    /// A bootstrap day boundary was processed
+    DayEnd {
+        /// The day that ended
+        day: u32,
+    },
+    /// A DNS break-glass bootstrap node claim was processed
+    DnsClaim {
+        /// The claim that was submitted
+        claim: DnsBootstrapClaim,
+    },
 }
 
 #[cfg(test)]
@@ -291,9 +645,42 @@ mod tests {
     }
 
     fn test_config() -> GenesisConfig {
+        test_config_with_authority().0
+    }
+
+    /// Like [`test_config`], but also returns the authority keypair so
+    /// callers can sign DNS break-glass claims.
+    fn test_config_with_authority() -> (GenesisConfig, Keypair) {
         let addrs: Vec<Address> = (0..7).map(|_| test_addr()).collect();
         let authority = Keypair::generate();
-        GenesisConfig::new("test".into(), addrs, authority.public_key().clone(), 0)
+        let config = GenesisConfig::new("test".into(), addrs, authority.public_key().clone(), 0);
+        (config, authority)
+    }
+
+    fn signed_dns_claim(
+        authority: &Keypair,
+        node: &Keypair,
+        hostname: &str,
+        amount: HclawAmount,
+        claimed_at: Timestamp,
+    ) -> DnsBootstrapClaim {
+        let address = Address::from_public_key(node.public_key());
+        let message = crate::genesis::dns_claim_signing_message(
+            node.public_key(),
+            &address,
+            hostname,
+            amount,
+        );
+        DnsBootstrapClaim {
+            address,
+            node_key: node.public_key().clone(),
+            hostname: hostname.to_string(),
+            amount,
+            claimed_at,
+            vests_at: claimed_at + DAY_MS,
+            authorized_by: authority.public_key().clone(),
+            authority_sig: authority.sign(&message),
+        }
     }
 
     #[test]
@@ -339,6 +726,154 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_pending_work_enumerates_missed_days_without_mutating() {
+        let config = test_config();
+        let pre_approved = config.pre_approved.clone();
+        let mut state = BootstrapState::new(config);
+        state.process_verifier_join(pre_approved[0], 0).unwrap();
+
+        let jobs = state.pending_work(2 * DAY_MS);
+        let day_ends: Vec<u32> = jobs
+            .iter()
+            .filter_map(|j| match j {
+                PendingJob::DayEnd { day, .. } => Some(*day),
+                PendingJob::Completion => None,
+            })
+            .collect();
+        assert_eq!(day_ends, vec![0, 1, 2]);
+        // Read-only: last_processed_day must still be untouched.
+        assert_eq!(state.last_processed_day, None);
+    }
+
+    #[test]
+    fn test_advance_to_catches_up_multiple_missed_days() {
+        let config = test_config();
+        let pre_approved = config.pre_approved.clone();
+        let mut state = BootstrapState::new(config);
+        state.process_verifier_join(pre_approved[0], 0).unwrap();
+
+        for _ in 0..100 {
+            state.record_attestation(&pre_approved[0], 1000);
+        }
+
+        let jobs = state.advance_to(2 * DAY_MS);
+        assert_eq!(state.last_processed_day, Some(2));
+        assert!(!jobs.is_empty());
+
+        // Idempotent: calling again at the same `now` is a no-op.
+        let jobs_again = state.advance_to(2 * DAY_MS);
+        assert!(jobs_again.is_empty());
+    }
+
+    #[test]
+    fn test_assignment_bucket_is_deterministic_and_partitions() {
+        let addr = test_addr();
+        let job = PendingJob::DayEnd {
+            day: 5,
+            beneficiary: addr,
+        };
+        let bucket = job.assignment_bucket(4);
+        assert_eq!(job.assignment_bucket(4), bucket, "must be deterministic");
+        assert!(bucket < 4);
+        assert_eq!(job.assignment_bucket(0), 0);
+    }
+
+    #[test]
+    fn test_downtime_slashes_after_max_missed_days() {
+        let config = test_config();
+        let pre_approved = config.pre_approved.clone();
+        let mut state = BootstrapState::new(config);
+        state.process_verifier_join(pre_approved[0], 0).unwrap();
+
+        // Active on day 0, then silent. max_missed_days defaults to 3, so
+        // the verifier must go missed on days 1,2,3,4 (a 4-day window)
+        // before detect_downtime slashes — it fires exactly once, the day
+        // that window first closes.
+        for _ in 0..100 {
+            state.record_attestation(&pre_approved[0], 1000);
+        }
+
+        assert!(state.detect_downtime(&pre_approved[0], 1).is_none());
+        assert!(state.detect_downtime(&pre_approved[0], 2).is_none());
+        assert!(state.detect_downtime(&pre_approved[0], 3).is_none());
+        let job = state.detect_downtime(&pre_approved[0], 4);
+        assert!(matches!(job, Some(SystemJobKind::Slash { .. })));
+
+        // Staying down on day 5 must not slash again.
+        assert!(state.detect_downtime(&pre_approved[0], 5).is_none());
+    }
+
+    #[test]
+    fn test_double_attestation_slashes_entire_locked_balance() {
+        let config = test_config();
+        let mut state = BootstrapState::new(config);
+
+        let offender_key = Keypair::generate();
+        let address = Address::from_public_key(offender_key.public_key());
+        state.process_verifier_join(address, 0).unwrap();
+        state.activate_verifier(&address, 0).unwrap();
+
+        let day = 0u32;
+        let timestamp_a = 1_000;
+        let timestamp_b = 2_000;
+        let msg_a = DoubleAttestationEvidence::signed_message(day, timestamp_a);
+        let msg_b = DoubleAttestationEvidence::signed_message(day, timestamp_b);
+
+        let evidence = DoubleAttestationEvidence {
+            verifier: address,
+            verifier_key: offender_key.public_key().clone(),
+            day,
+            timestamp_a,
+            signature_a: offender_key.sign(&msg_a),
+            timestamp_b,
+            signature_b: offender_key.sign(&msg_b),
+        };
+
+        let job = state.detect_double_attestation(&evidence).unwrap();
+        match job {
+            SystemJobKind::Slash { offender, amount, .. } => {
+                assert_eq!(offender, address);
+                assert!(amount.raw() > 0);
+            }
+            _ => panic!("expected Slash job"),
+        }
+        assert_eq!(
+            state.get_vesting(&address).unwrap().vested_amount(),
+            state.get_vesting(&address).unwrap().total_amount
+        );
+    }
+
+    #[test]
+    fn test_double_attestation_rejects_bad_signature() {
+        let config = test_config();
+        let mut state = BootstrapState::new(config);
+
+        let offender_key = Keypair::generate();
+        let address = Address::from_public_key(offender_key.public_key());
+        state.process_verifier_join(address, 0).unwrap();
+        state.activate_verifier(&address, 0).unwrap();
+
+        let wrong_key = Keypair::generate();
+        let day = 0u32;
+        let timestamp_a = 1_000;
+        let timestamp_b = 2_000;
+        let msg_a = DoubleAttestationEvidence::signed_message(day, timestamp_a);
+        let msg_b = DoubleAttestationEvidence::signed_message(day, timestamp_b);
+
+        let evidence = DoubleAttestationEvidence {
+            verifier: address,
+            verifier_key: offender_key.public_key().clone(),
+            day,
+            timestamp_a,
+            signature_a: wrong_key.sign(&msg_a),
+            timestamp_b,
+            signature_b: offender_key.sign(&msg_b),
+        };
+
+        assert!(state.detect_double_attestation(&evidence).is_err());
+    }
+
     #[test]
     fn test_completion() {
         let config = test_config();
@@ -355,20 +890,17 @@ mod tests {
 
     #[test]
     fn test_dns_break_glass() {
-        let config = test_config();
+        let (config, authority) = test_config_with_authority();
         let mut state = BootstrapState::new(config);
 
         let node_kp = Keypair::generate();
-        let addr = Address::from_public_key(node_kp.public_key());
-
-        let claim = DnsBootstrapClaim {
-            address: addr,
-            node_key: node_kp.public_key().clone(),
-            hostname: "bootstrap-new.clawpaper.com".to_string(),
-            amount: HclawAmount::from_hclaw(250_000),
-            claimed_at: 1000,
-            vests_at: 1000 + DAY_MS,
-        };
+        let claim = signed_dns_claim(
+            &authority,
+            &node_kp,
+            "bootstrap-new.clawpaper.com",
+            HclawAmount::from_hclaw(250_000),
+            1000,
+        );
 
         let job = state.process_dns_claim(claim).unwrap();
         assert!(matches!(job, SystemJobKind::DnsBootstrapClaim { .. }));
@@ -377,48 +909,145 @@ mod tests {
 
     #[test]
     fn test_dns_wrong_domain_rejected() {
-        let config = test_config();
+        let (config, authority) = test_config_with_authority();
         let mut state = BootstrapState::new(config);
 
         let node_kp = Keypair::generate();
-        let claim = DnsBootstrapClaim {
-            address: Address::from_public_key(node_kp.public_key()),
-            node_key: node_kp.public_key().clone(),
-            hostname: "evil.attacker.com".to_string(),
-            amount: HclawAmount::from_hclaw(250_000),
-            claimed_at: 1000,
-            vests_at: 1000 + DAY_MS,
-        };
+        let claim = signed_dns_claim(
+            &authority,
+            &node_kp,
+            "evil.attacker.com",
+            HclawAmount::from_hclaw(250_000),
+            1000,
+        );
 
         assert!(state.process_dns_claim(claim).is_err());
     }
 
     #[test]
     fn test_dns_no_duplicate_address() {
-        let config = test_config();
+        let (config, authority) = test_config_with_authority();
         let mut state = BootstrapState::new(config);
 
         let node_kp = Keypair::generate();
-        let addr = Address::from_public_key(node_kp.public_key());
-
-        let claim1 = DnsBootstrapClaim {
-            address: addr,
-            node_key: node_kp.public_key().clone(),
-            hostname: "node1.clawpaper.com".to_string(),
-            amount: HclawAmount::from_hclaw(250_000),
-            claimed_at: 1000,
-            vests_at: 1000 + DAY_MS,
-        };
+
+        let claim1 = signed_dns_claim(
+            &authority,
+            &node_kp,
+            "node1.clawpaper.com",
+            HclawAmount::from_hclaw(250_000),
+            1000,
+        );
         state.process_dns_claim(claim1).unwrap();
 
-        let claim2 = DnsBootstrapClaim {
-            address: addr,
-            node_key: node_kp.public_key().clone(),
-            hostname: "node2.clawpaper.com".to_string(),
-            amount: HclawAmount::from_hclaw(250_000),
-            claimed_at: 2000,
-            vests_at: 2000 + DAY_MS,
-        };
+        let claim2 = signed_dns_claim(
+            &authority,
+            &node_kp,
+            "node2.clawpaper.com",
+            HclawAmount::from_hclaw(250_000),
+            2000,
+        );
         assert!(state.process_dns_claim(claim2).is_err());
     }
+
+    #[test]
+    fn test_dns_rejects_unsigned_claim() {
+        let (config, authority) = test_config_with_authority();
+        let mut state = BootstrapState::new(config);
+
+        let node_kp = Keypair::generate();
+        let impostor = Keypair::generate();
+        let mut claim = signed_dns_claim(
+            &authority,
+            &node_kp,
+            "node1.clawpaper.com",
+            HclawAmount::from_hclaw(250_000),
+            1000,
+        );
+        // Swap in a signature from a key that isn't in `authorized_keys`.
+        claim.authority_sig = impostor.sign(&claim.signing_message());
+
+        assert!(matches!(
+            state.process_dns_claim(claim),
+            Err(GenesisError::DnsBreakGlassUnauthorized)
+        ));
+    }
+
+    #[test]
+    fn test_state_root_is_deterministic() {
+        let config = test_config();
+        let pre_approved = config.pre_approved.clone();
+        let mut state1 = BootstrapState::new(config.clone());
+        let mut state2 = BootstrapState::new(config);
+
+        for state in [&mut state1, &mut state2] {
+            state.process_verifier_join(pre_approved[0], 0).unwrap();
+            for _ in 0..100 {
+                state.record_attestation(&pre_approved[0], 1000);
+            }
+            state.process_day_end(0);
+        }
+
+        assert_eq!(state1.state_root(), state2.state_root());
+    }
+
+    #[test]
+    fn test_state_root_changes_with_state() {
+        let config = test_config();
+        let pre_approved = config.pre_approved.clone();
+        let mut state = BootstrapState::new(config);
+        let root_before = state.state_root();
+
+        state.process_verifier_join(pre_approved[0], 0).unwrap();
+        let root_after = state.state_root();
+
+        assert_ne!(root_before, root_after);
+    }
+
+    #[test]
+    fn test_catch_up_replays_matching_root() {
+        let config = test_config();
+        let pre_approved = config.pre_approved.clone();
+
+        let mut live = BootstrapState::new(config.clone());
+        live.process_verifier_join(pre_approved[0], 0).unwrap();
+        for _ in 0..100 {
+            live.record_attestation(&pre_approved[0], 1000);
+        }
+        live.process_day_end(0);
+        let trusted_root = live.state_root();
+
+        let mut replay_events = vec![BootstrapEvent::VerifierJoin {
+            address: pre_approved[0],
+            now: 0,
+        }];
+        for _ in 0..100 {
+            replay_events.push(BootstrapEvent::Attestation {
+                verifier: pre_approved[0],
+                block_timestamp: 1000,
+            });
+        }
+        replay_events.push(BootstrapEvent::DayEnd { day: 0 });
+
+        let rebuilt = BootstrapState::catch_up(config, &replay_events, trusted_root).unwrap();
+        assert_eq!(rebuilt.state_root(), trusted_root);
+    }
+
+    #[test]
+    fn test_catch_up_rejects_state_root_mismatch() {
+        let config = test_config();
+        let pre_approved = config.pre_approved.clone();
+
+        let events = vec![BootstrapEvent::VerifierJoin {
+            address: pre_approved[0],
+            now: 0,
+        }];
+
+        let bogus_root = hash_data(b"not the real root");
+        let result = BootstrapState::catch_up(config, &events, bogus_root);
+        assert!(matches!(
+            result,
+            Err(GenesisError::StateRootMismatch { .. })
+        ));
+    }
 }
@@ -3,6 +3,17 @@
 //! Every hour, 1/24th of the day's budget is distributed evenly among
 //! eligible staked verifiers who attested to blocks in the prior hour.
 //! The daily budget follows a parabolic curve: day² × (90 - day).
+//!
+//! An hour's budget can alternatively be folded into a lazy
+//! reward-per-weight accumulator (`reward_per_weight_cumulative`) instead
+//! of being pushed out immediately — see `accrue_epoch`/`claimable_reward`
+//! — letting verifiers claim accumulated reward whenever they like.
+//!
+//! Once the 90-day parabolic period itself is exhausted (`last_distributed_epoch
+//! == TOTAL_EPOCHS - 1`), a follow-on [`EmissionSchedule`] can take over and
+//! continue paying indefinitely on a halving curve, tracked separately via
+//! `BountyTracker::total_emitted` rather than mixed into the fixed-pool
+//! accounting above.
 
 use serde::{Deserialize, Serialize};
 
@@ -17,6 +28,19 @@ pub const BOUNTY_DAYS: u8 = 90;
 /// Minimum active nodes before bounties are paid
 pub const MIN_PUBLIC_NODES: u32 = 5;
 
+/// Safety ceiling on [`BountyTracker::carry_forward`], in whole HCLAW.
+/// Carry-forward is only ever dust and skipped/ineligible-hour budgets, so
+/// it can never legitimately exceed the entire pool; this exists purely as
+/// a sanity check against a rounding bug silently inflating later payouts.
+pub const MAX_CARRY: u64 = BOUNTY_POOL;
+
+/// Fixed-point scale for [`BountyTracker::reward_per_weight_cumulative`].
+/// Chosen to match the precision staking reward-per-token accumulators
+/// conventionally use, which keeps the per-epoch increment
+/// (`hourly_budget_scaled / total_active_stake`) from rounding to zero
+/// even when a single hour's budget is small relative to total stake.
+pub const REWARD_SCALE: u128 = 1_000_000_000_000_000_000;
+
 /// Sum of all weights: Σw(day) for day ∈ [0, 89]
 /// Calculated as: Σ(day² × (90 - day)) = 5,466,825
 pub const TOTAL_WEIGHT: u128 = 5_466_825;
@@ -30,6 +54,18 @@ pub const HOURS_PER_DAY: u64 = 24;
 /// Total epochs in the bounty period (90 days × 24 hours)
 pub const TOTAL_EPOCHS: u64 = BOUNTY_DAYS as u64 * HOURS_PER_DAY;
 
+/// Total HCLAW emitted over [`ERA_DAYS`]'s worth of post-genesis days,
+/// before any halvening — the starting point `calculate_emission_for_day`
+/// halves every era. Sized independently of [`BOUNTY_POOL`]; the 90-day
+/// bounty is a fixed, one-time allocation, while this seeds an indefinite
+/// follow-on supply.
+pub const YEAR_ONE_EMISSION: u64 = 1_000_000; // HCLAW
+
+/// Era length, in days, for the emission halvening schedule: `year_emission`
+/// halves every `ERA_DAYS` days (so era 0 covers days `[0, ERA_DAYS)`, era 1
+/// covers `[ERA_DAYS, 2 * ERA_DAYS)`, and so on).
+pub const ERA_DAYS: u16 = 365;
+
 /// Parabolic weight function: w(day) = day² × (90 - day)
 ///
 /// Properties:
@@ -105,6 +141,102 @@ pub fn distribute_evenly(
         .collect()
 }
 
+/// Distribute `total` among `recipients` proportional to each one's stake:
+/// `total * stake_i / sum(stake_j)`. Recipients with zero stake receive
+/// nothing and are omitted from the result.
+///
+/// Integer division leaves a remainder of at most `recipients.len() - 1`
+/// raw units; like `distribute_evenly`'s dust, it's left undistributed
+/// here for the caller to route into the same carry-forward/burn path
+/// rather than handled specially. Multiplying by `stake_i` before dividing
+/// by the sum (instead of pre-dividing each stake into a fraction) already
+/// avoids the precision loss a fixed scale factor would otherwise be
+/// needed to paper over, even for small per-hour budgets.
+pub fn distribute_weighted(
+    recipients: &[(Address, HclawAmount)],
+    total: HclawAmount,
+) -> Vec<(Address, HclawAmount)> {
+    if recipients.is_empty() {
+        return Vec::new();
+    }
+    let total_stake: u128 = recipients.iter().map(|(_, stake)| stake.raw()).sum();
+    if total_stake == 0 {
+        return Vec::new();
+    }
+
+    recipients
+        .iter()
+        .filter(|(_, stake)| stake.raw() > 0)
+        .map(|(addr, stake)| {
+            let share = total.raw() * stake.raw() / total_stake;
+            (*addr, HclawAmount::from_raw(share))
+        })
+        .collect()
+}
+
+/// Whether `era` (an index into the emission halvening schedule, not a
+/// calendar year) lands on a Gregorian leap year when treated as a proxy
+/// year count — used by [`EmissionSchedule::calculate_emission_for_day`] to
+/// pick a 365- vs 366-day divisor.
+fn is_leap_era(era: u64) -> bool {
+    (era % 4 == 0 && era % 100 != 0) || era % 400 == 0
+}
+
+/// Post-genesis continuous emission schedule — the follow-on to the 90-day
+/// parabolic bounty. Once `BountyTracker::last_distributed_epoch` passes
+/// `TOTAL_EPOCHS - 1`, subsequent epochs can draw from here instead of going
+/// unpaid: [`YEAR_ONE_EMISSION`] halves every [`ERA_DAYS`] days and is split
+/// evenly across that era's days, via `calculate_emission_for_day`.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct EmissionSchedule {
+    /// When set, `calculate_emission_for_day` divides an era's emission by
+    /// 366 instead of 365 on eras that land on a Gregorian leap year (see
+    /// [`is_leap_era`]), instead of always assuming a flat 365-day year.
+    leap_aware: bool,
+}
+
+impl EmissionSchedule {
+    /// Default schedule: every era divides by a flat 365 days.
+    #[must_use]
+    pub fn new() -> Self {
+        Self { leap_aware: false }
+    }
+
+    /// Schedule that divides leap eras by 366 days instead of 365 — see
+    /// [`is_leap_era`].
+    #[must_use]
+    pub fn is_leap_aware() -> Self {
+        Self { leap_aware: true }
+    }
+
+    /// Emission for a single day, `days_since_genesis` days after the
+    /// genesis bounty's start. Selects the era via `era =
+    /// days_since_genesis / ERA_DAYS`, halves `YEAR_ONE_EMISSION` that many
+    /// times, and divides the result across the era's days (365, or 366 if
+    /// `leap_aware` and the era lands on a leap year).
+    #[must_use]
+    pub fn calculate_emission_for_day(&self, days_since_genesis: u64) -> HclawAmount {
+        let era = days_since_genesis / u64::from(ERA_DAYS);
+        let shift = u32::try_from(era).unwrap_or(u32::MAX);
+        let era_total = HclawAmount::from_hclaw(YEAR_ONE_EMISSION)
+            .raw()
+            .checked_shr(shift)
+            .unwrap_or(0);
+        let days_in_era: u128 = if self.leap_aware && is_leap_era(era) {
+            366
+        } else {
+            365
+        };
+        HclawAmount::from_raw(era_total / days_in_era)
+    }
+}
+
+impl Default for EmissionSchedule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Tracks bounty distributions over the 90-day period.
 ///
 /// Epochs are distributed sequentially (0, 1, 2, ..., 2159).
@@ -123,6 +255,40 @@ pub struct BountyTracker {
     pub start_time: u64,
     /// Number of public (non-bootstrap) nodes
     pub public_node_count: u32,
+    /// Undistributed balance retained for reallocation rather than burned:
+    /// `distribute_evenly` dust, the full budget of hours skipped while
+    /// inactive, and budgets for hours with zero eligible verifiers. Folded
+    /// back into the next hour's budget by the caller and drained on the
+    /// next successful distribution; only burned once the bounty period
+    /// itself ends (epoch `TOTAL_EPOCHS - 1` distributed), since there's no
+    /// later hour left to carry it into.
+    pub carry_forward: HclawAmount,
+    /// Reward-per-unit-stake accumulator for the lazy accrual path (see
+    /// `AccrueEpoch`/`ClaimReward`), scaled by [`REWARD_SCALE`]. Increases
+    /// once per accrued epoch by `hourly_budget_scaled /
+    /// total_active_stake`; a verifier's claimable share is computed from
+    /// the delta between this value and their own last checkpoint — see
+    /// `claimable_reward`.
+    pub reward_per_weight_cumulative: u128,
+    /// Sum of `stake` across every participant currently eligible to
+    /// accrue lazy rewards — the divisor for
+    /// `reward_per_weight_cumulative`'s per-epoch increment. Maintained
+    /// incrementally (bumped in `execute_join`, reduced in
+    /// `execute_report_misbehavior`) rather than recomputed by summing
+    /// every participant, so `accrue_epoch` stays O(1).
+    pub total_active_stake: HclawAmount,
+    /// Budget folded into `reward_per_weight_cumulative` via `accrue_epoch`
+    /// but not yet claimed via `ClaimReward`. Distinct from `total_paid`,
+    /// which only counts HCLAW actually credited to an address; a claim
+    /// moves its amount from here into `total_paid` (see `record_claim`).
+    pub total_accrued: HclawAmount,
+    /// Total HCLAW paid out by the post-genesis [`EmissionSchedule`], once
+    /// the parabolic period is exhausted. A separate pool from `total_paid`
+    /// and deliberately excluded from the `BOUNTY_POOL` invariant
+    /// `total_paid + total_burned + carry_forward + total_accrued +
+    /// total_remaining == BOUNTY_POOL` — the emission schedule supply is
+    /// indefinite, not drawn from the fixed 90-day pool.
+    pub total_emitted: HclawAmount,
 }
 
 impl BountyTracker {
@@ -135,6 +301,11 @@ impl BountyTracker {
             total_burned: HclawAmount::ZERO,
             start_time,
             public_node_count: 0,
+            carry_forward: HclawAmount::ZERO,
+            reward_per_weight_cumulative: 0,
+            total_active_stake: HclawAmount::ZERO,
+            total_accrued: HclawAmount::ZERO,
+            total_emitted: HclawAmount::ZERO,
         }
     }
 
@@ -165,20 +336,103 @@ impl BountyTracker {
         self.total_burned = self.total_burned.saturating_add(amount);
     }
 
+    /// Add undistributed balance to the carry-forward pool instead of
+    /// burning it. Debug-asserts the [`MAX_CARRY`] ceiling — if that ever
+    /// trips, accounting has already gone wrong somewhere upstream.
+    pub fn add_carry(&mut self, amount: HclawAmount) {
+        self.carry_forward = self.carry_forward.saturating_add(amount);
+        debug_assert!(
+            self.carry_forward.raw() <= HclawAmount::from_hclaw(MAX_CARRY).raw(),
+            "carry_forward exceeded the MAX_CARRY ceiling"
+        );
+    }
+
+    /// Drain and return the full carry-forward balance — used to fold it
+    /// into the next hour's effective budget, or to sweep it to the burn
+    /// sink at genuine period end.
+    pub fn take_carry(&mut self) -> HclawAmount {
+        let carry = self.carry_forward;
+        self.carry_forward = HclawAmount::ZERO;
+        carry
+    }
+
     /// Update public node count
     pub fn update_node_count(&mut self, count: u32) {
         self.public_node_count = count;
     }
 
-    /// Get total remaining (unpaid, unburned) bounty
+    /// Get total remaining (unpaid, unburned, uncarried, unaccrued) bounty.
+    ///
+    /// Maintains the hard invariant `total_paid + total_burned +
+    /// carry_forward + total_accrued + total_remaining == BOUNTY_POOL`.
     #[must_use]
     pub fn total_remaining(&self) -> HclawAmount {
         let pool = HclawAmount::from_hclaw(BOUNTY_POOL);
         pool.saturating_sub(self.total_paid)
             .saturating_sub(self.total_burned)
+            .saturating_sub(self.carry_forward)
+            .saturating_sub(self.total_accrued)
+    }
+
+    /// Fold `hourly_budget` into `reward_per_weight_cumulative`, the lazy
+    /// accrual path's alternative to crediting a push-distributed list of
+    /// verifiers immediately. Returns whatever integer-division dust is
+    /// left over (or the whole budget, if nobody has active stake yet) for
+    /// the caller to route into `add_carry` exactly like
+    /// `distribute_hourly_checked` does with its own dust.
+    pub fn accrue_epoch(&mut self, hourly_budget: HclawAmount) -> HclawAmount {
+        if self.total_active_stake.raw() == 0 {
+            return hourly_budget;
+        }
+        let scaled = hourly_budget.raw().saturating_mul(REWARD_SCALE);
+        let per_weight = scaled / self.total_active_stake.raw();
+        self.reward_per_weight_cumulative =
+            self.reward_per_weight_cumulative.saturating_add(per_weight);
+
+        let used = HclawAmount::from_raw(per_weight * self.total_active_stake.raw() / REWARD_SCALE);
+        self.total_accrued = self.total_accrued.saturating_add(used);
+        HclawAmount::from_raw(hourly_budget.raw() - used.raw())
+    }
+
+    /// Move a successfully claimed amount from `total_accrued` (reserved
+    /// but unclaimed) into `total_paid` (actually credited) — the
+    /// lazy-accrual counterpart to `record_distribution`.
+    pub fn record_claim(&mut self, amount: HclawAmount) {
+        self.total_accrued = self.total_accrued.saturating_sub(amount);
+        self.total_paid = self.total_paid.saturating_add(amount);
+    }
+
+    /// Record a distribution paid from the post-genesis [`EmissionSchedule`]
+    /// rather than the 90-day parabolic pool — the emission-schedule
+    /// counterpart to `record_distribution`, advancing
+    /// `last_distributed_epoch` the same way but accumulating into
+    /// `total_emitted` instead of `total_paid`.
+    pub fn record_emission_distribution(&mut self, epoch: u64, amount: HclawAmount) {
+        self.last_distributed_epoch = epoch;
+        self.total_emitted = self.total_emitted.saturating_add(amount);
     }
 }
 
+/// Claimable lazy-accrual balance for a verifier whose weight was last
+/// checkpointed at `last_checkpoint`, given the tracker's current
+/// `reward_per_weight_cumulative`, the verifier's active `weight` (their
+/// stake), and whatever was already folded into `pending` by an earlier
+/// checkpoint. `weight` must be the verifier's stake as of
+/// `last_checkpoint` — callers checkpoint (fold the delta into `pending`,
+/// advance `last_checkpoint`) before any stake change for exactly this
+/// reason.
+#[must_use]
+pub fn claimable_reward(
+    cumulative_now: u128,
+    last_checkpoint: u128,
+    weight: HclawAmount,
+    pending: HclawAmount,
+) -> HclawAmount {
+    let delta = cumulative_now.saturating_sub(last_checkpoint);
+    let accrued = HclawAmount::from_raw(delta * weight.raw() / REWARD_SCALE);
+    pending.saturating_add(accrued)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -339,6 +593,109 @@ mod tests {
         assert!(result.is_empty());
     }
 
+    #[test]
+    fn test_distribute_weighted_proportional_to_stake() {
+        let recipients = vec![
+            (Address::from_bytes([1; 20]), HclawAmount::from_hclaw(100)),
+            (Address::from_bytes([2; 20]), HclawAmount::from_hclaw(300)),
+        ];
+        let total = HclawAmount::from_hclaw(800);
+
+        let result = distribute_weighted(&recipients, total);
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].1.whole_hclaw(), 200);
+        assert_eq!(result[1].1.whole_hclaw(), 600);
+    }
+
+    #[test]
+    fn test_distribute_weighted_remainder_is_left_for_caller_to_carry() {
+        let recipients = vec![
+            (Address::from_bytes([1; 20]), HclawAmount::from_hclaw(1)),
+            (Address::from_bytes([2; 20]), HclawAmount::from_hclaw(2)),
+            (Address::from_bytes([3; 20]), HclawAmount::from_hclaw(100)),
+        ];
+        let total = HclawAmount::from_raw(1_000);
+
+        let result = distribute_weighted(&recipients, total);
+        let distributed: u128 = result.iter().map(|(_, a)| a.raw()).sum();
+        // Like distribute_evenly's dust, the remainder is never assigned to
+        // any one recipient — it's the caller's job to carry it forward or
+        // burn it.
+        assert!(
+            distributed <= total.raw(),
+            "weighted sum must never exceed total"
+        );
+        for (addr, amount) in &result {
+            let stake = recipients.iter().find(|(a, _)| a == addr).unwrap().1;
+            let exact_share =
+                total.raw() * stake.raw() / recipients.iter().map(|(_, s)| s.raw()).sum::<u128>();
+            assert_eq!(amount.raw(), exact_share);
+        }
+    }
+
+    #[test]
+    fn test_distribute_weighted_never_exceeds_total() {
+        let recipients = vec![
+            (Address::from_bytes([1; 20]), HclawAmount::from_hclaw(7)),
+            (Address::from_bytes([2; 20]), HclawAmount::from_hclaw(11)),
+            (Address::from_bytes([3; 20]), HclawAmount::from_hclaw(13)),
+        ];
+        let total = HclawAmount::from_hclaw(999);
+
+        let result = distribute_weighted(&recipients, total);
+        let distributed: u128 = result.iter().map(|(_, a)| a.raw()).sum();
+        assert!(distributed <= total.raw());
+    }
+
+    #[test]
+    fn test_distribute_weighted_equal_weights_matches_distribute_evenly() {
+        let addrs = vec![
+            Address::from_bytes([1; 20]),
+            Address::from_bytes([2; 20]),
+            Address::from_bytes([3; 20]),
+        ];
+        let total = HclawAmount::from_hclaw(1000);
+        let stake = HclawAmount::from_hclaw(50);
+
+        let weighted_recipients: Vec<(Address, HclawAmount)> =
+            addrs.iter().map(|addr| (*addr, stake)).collect();
+        let weighted = distribute_weighted(&weighted_recipients, total);
+        let evenly = distribute_evenly(&addrs, total);
+
+        assert_eq!(weighted.len(), evenly.len());
+        for ((w_addr, w_amt), (e_addr, e_amt)) in weighted.iter().zip(evenly.iter()) {
+            assert_eq!(w_addr, e_addr);
+            assert_eq!(w_amt, e_amt);
+        }
+    }
+
+    #[test]
+    fn test_distribute_weighted_skips_zero_weight_recipients() {
+        let recipients = vec![
+            (Address::from_bytes([1; 20]), HclawAmount::from_hclaw(100)),
+            (Address::from_bytes([2; 20]), HclawAmount::ZERO),
+        ];
+        let result = distribute_weighted(&recipients, HclawAmount::from_hclaw(500));
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].0, recipients[0].0);
+    }
+
+    #[test]
+    fn test_distribute_weighted_empty() {
+        let result = distribute_weighted(&[], HclawAmount::from_hclaw(1000));
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    fn test_distribute_weighted_zero_total_stake() {
+        let recipients = vec![
+            (Address::from_bytes([1; 20]), HclawAmount::ZERO),
+            (Address::from_bytes([2; 20]), HclawAmount::ZERO),
+        ];
+        let result = distribute_weighted(&recipients, HclawAmount::from_hclaw(1000));
+        assert!(result.is_empty());
+    }
+
     #[test]
     fn test_bounty_tracker_epoch_sequencing() {
         let mut tracker = BountyTracker::new(0);
@@ -367,6 +724,170 @@ mod tests {
         assert!(tracker.is_active());
     }
 
+    #[test]
+    fn test_carry_forward_accumulates_and_drains() {
+        let mut tracker = BountyTracker::new(0);
+        assert_eq!(tracker.carry_forward, HclawAmount::ZERO);
+
+        tracker.add_carry(HclawAmount::from_raw(10));
+        tracker.add_carry(HclawAmount::from_raw(5));
+        assert_eq!(tracker.carry_forward.raw(), 15);
+
+        let drained = tracker.take_carry();
+        assert_eq!(drained.raw(), 15);
+        assert_eq!(tracker.carry_forward, HclawAmount::ZERO);
+    }
+
+    #[test]
+    fn test_total_remaining_accounts_for_carry() {
+        let mut tracker = BountyTracker::new(0);
+        let pool = HclawAmount::from_hclaw(BOUNTY_POOL);
+
+        tracker.record_distribution(0, HclawAmount::from_hclaw(100));
+        tracker.record_burn(HclawAmount::from_hclaw(50));
+        tracker.add_carry(HclawAmount::from_hclaw(25));
+
+        assert_eq!(
+            tracker.total_paid.raw()
+                + tracker.total_burned.raw()
+                + tracker.carry_forward.raw()
+                + tracker.total_remaining().raw(),
+            pool.raw()
+        );
+    }
+
+    #[test]
+    fn test_accrue_epoch_splits_evenly_by_weight() {
+        let mut tracker = BountyTracker::new(0);
+        tracker.total_active_stake = HclawAmount::from_hclaw(100);
+
+        let dust = tracker.accrue_epoch(HclawAmount::from_hclaw(10));
+        assert_eq!(dust, HclawAmount::ZERO);
+        assert_eq!(tracker.total_accrued, HclawAmount::from_hclaw(10));
+
+        // A verifier holding half the active stake should be able to claim
+        // half of what's been accrued so far.
+        let claimable = claimable_reward(
+            tracker.reward_per_weight_cumulative,
+            0,
+            HclawAmount::from_hclaw(50),
+            HclawAmount::ZERO,
+        );
+        assert_eq!(claimable, HclawAmount::from_hclaw(5));
+    }
+
+    #[test]
+    fn test_accrue_epoch_with_no_active_stake_returns_whole_budget_as_dust() {
+        let mut tracker = BountyTracker::new(0);
+        let budget = HclawAmount::from_hclaw(10);
+        let dust = tracker.accrue_epoch(budget);
+        assert_eq!(dust, budget);
+        assert_eq!(tracker.total_accrued, HclawAmount::ZERO);
+        assert_eq!(tracker.reward_per_weight_cumulative, 0);
+    }
+
+    #[test]
+    fn test_claimable_reward_checkpoint_round_trips_to_zero() {
+        let mut tracker = BountyTracker::new(0);
+        tracker.total_active_stake = HclawAmount::from_hclaw(100);
+        tracker.accrue_epoch(HclawAmount::from_hclaw(10));
+
+        let weight = HclawAmount::from_hclaw(100);
+        let claimable = claimable_reward(tracker.reward_per_weight_cumulative, 0, weight, HclawAmount::ZERO);
+        assert_eq!(claimable, HclawAmount::from_hclaw(10));
+
+        // Checkpointing at the current cumulative leaves nothing further
+        // claimable until the next accrual.
+        let after_checkpoint =
+            claimable_reward(tracker.reward_per_weight_cumulative, tracker.reward_per_weight_cumulative, weight, claimable);
+        assert_eq!(after_checkpoint, claimable);
+    }
+
+    #[test]
+    fn test_record_claim_moves_accrued_to_paid() {
+        let mut tracker = BountyTracker::new(0);
+        tracker.total_accrued = HclawAmount::from_hclaw(10);
+
+        tracker.record_claim(HclawAmount::from_hclaw(4));
+        assert_eq!(tracker.total_accrued, HclawAmount::from_hclaw(6));
+        assert_eq!(tracker.total_paid, HclawAmount::from_hclaw(4));
+    }
+
+    #[test]
+    fn test_total_remaining_accounts_for_accrued() {
+        let mut tracker = BountyTracker::new(0);
+        let pool = HclawAmount::from_hclaw(BOUNTY_POOL);
+        tracker.total_active_stake = HclawAmount::from_hclaw(100);
+
+        tracker.accrue_epoch(HclawAmount::from_hclaw(10));
+        assert_eq!(
+            tracker.total_paid.raw()
+                + tracker.total_burned.raw()
+                + tracker.carry_forward.raw()
+                + tracker.total_accrued.raw()
+                + tracker.total_remaining().raw(),
+            pool.raw()
+        );
+    }
+
+    #[test]
+    fn test_calculate_emission_for_day_halves_each_era() {
+        let schedule = EmissionSchedule::new();
+        let year_total = HclawAmount::from_hclaw(YEAR_ONE_EMISSION).raw();
+
+        let era0 = schedule.calculate_emission_for_day(0);
+        assert_eq!(era0.raw(), year_total / 365);
+
+        let era1 = schedule.calculate_emission_for_day(u64::from(ERA_DAYS));
+        assert_eq!(era1.raw(), (year_total / 2) / 365);
+
+        let era2 = schedule.calculate_emission_for_day(u64::from(ERA_DAYS) * 2);
+        assert_eq!(era2.raw(), (year_total / 4) / 365);
+    }
+
+    #[test]
+    fn test_calculate_emission_for_day_is_flat_within_an_era() {
+        let schedule = EmissionSchedule::new();
+        let first_day = schedule.calculate_emission_for_day(0);
+        let last_day_of_era = schedule.calculate_emission_for_day(u64::from(ERA_DAYS) - 1);
+        assert_eq!(first_day, last_day_of_era);
+    }
+
+    #[test]
+    fn test_leap_aware_uses_366_day_divisor_on_leap_eras() {
+        let leap_aware = EmissionSchedule::is_leap_aware();
+        let not_leap_aware = EmissionSchedule::new();
+        assert!(is_leap_era(0));
+
+        let year_total = HclawAmount::from_hclaw(YEAR_ONE_EMISSION).raw();
+        assert_eq!(leap_aware.calculate_emission_for_day(0).raw(), year_total / 366);
+        assert_eq!(not_leap_aware.calculate_emission_for_day(0).raw(), year_total / 365);
+    }
+
+    #[test]
+    fn test_leap_aware_matches_default_on_non_leap_eras() {
+        // Era 1 (days [365, 730)) isn't a leap era, so leap-awareness
+        // shouldn't change anything relative to the flat-365 default.
+        assert!(!is_leap_era(1));
+        let leap_aware = EmissionSchedule::is_leap_aware();
+        let not_leap_aware = EmissionSchedule::new();
+        let day = u64::from(ERA_DAYS);
+        assert_eq!(
+            leap_aware.calculate_emission_for_day(day),
+            not_leap_aware.calculate_emission_for_day(day)
+        );
+    }
+
+    #[test]
+    fn test_record_emission_distribution_advances_epoch_and_total_emitted() {
+        let mut tracker = BountyTracker::new(0);
+        tracker.record_emission_distribution(TOTAL_EPOCHS, HclawAmount::from_hclaw(5));
+        assert_eq!(tracker.last_distributed_epoch, TOTAL_EPOCHS);
+        assert_eq!(tracker.total_emitted, HclawAmount::from_hclaw(5));
+        // Kept entirely separate from the fixed-pool accounting.
+        assert_eq!(tracker.total_paid, HclawAmount::ZERO);
+    }
+
     #[test]
     fn test_total_pool_accounted() {
         // Sum all 2160 hourly budgets and verify they account for the pool
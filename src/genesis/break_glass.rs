@@ -0,0 +1,361 @@
+//! Resolution and authority-verification for the DNS break-glass mechanism.
+//!
+//! [`DnsBreakGlassConfig`]/[`DnsBootstrapClaim`] describe the data; this
+//! module does the actual work: resolve TXT records under
+//! `config.domain`, parse each one as `hostname => base64(node_public_key
+//! || authority_signature)`, and verify `authority_signature` is a valid
+//! ML-DSA-65 signature by one of `config.authorized_keys` over the
+//! claim's canonical message (see
+//! [`DnsBootstrapClaim::signing_message`](super::DnsBootstrapClaim::signing_message))
+//! before trusting it — this is what stops plain DNS hijacking from
+//! minting break-glass tokens (see the module docs on
+//! [`DnsBreakGlassConfig`]).
+//!
+//! DNS resolution itself goes through the injectable [`TxtResolver`] trait
+//! rather than a concrete resolver, so tests can supply canned records
+//! without touching the network. This tree has no real DNS client
+//! dependency to build a production resolver on top of, so only the
+//! trait and the verification/claim-construction logic live here; wiring
+//! a real resolver (e.g. one backed by the system's DNS libraries) is left
+//! to whatever binary embeds this crate.
+
+use base64::Engine;
+
+use super::{dns_claim_signing_message, DnsBootstrapClaim, DnsBreakGlassConfig};
+use crate::crypto::{PublicKey, Signature, PUBKEY_SIZE, SIGNATURE_SIZE};
+use crate::types::{Address, Timestamp};
+
+/// Resolves the TXT records published under a domain, so
+/// [`resolve_break_glass_nodes`] can be tested against canned records
+/// instead of making real DNS queries.
+pub trait TxtResolver {
+    /// Return every `(hostname, txt_record_value)` pair published under
+    /// `domain`.
+    ///
+    /// # Errors
+    /// Returns [`BreakGlassError::Resolution`] if the lookup itself fails.
+    fn resolve_txt_records(&self, domain: &str) -> Result<Vec<(String, String)>, BreakGlassError>;
+}
+
+/// Errors raised while resolving and validating DNS break-glass nodes.
+#[derive(Debug, thiserror::Error)]
+pub enum BreakGlassError {
+    /// The underlying TXT lookup failed.
+    #[error("DNS resolution failed: {0}")]
+    Resolution(String),
+    /// A TXT record's value wasn't valid base64, or didn't decode to
+    /// `node_public_key || authority_signature` of the expected length.
+    #[error("malformed TXT record for hostname {hostname}: {reason}")]
+    MalformedRecord {
+        /// Hostname the bad record was published under
+        hostname: String,
+        /// What was wrong with it
+        reason: String,
+    },
+    /// The record decoded fine, but `authority_signature` doesn't verify
+    /// against any of `config.authorized_keys`.
+    #[error("invalid authority signature for hostname {0}")]
+    InvalidSignature(String),
+    /// The node (by derived address) has already been authorized by an
+    /// earlier break-glass claim.
+    #[error("node {0} has already been authorized")]
+    AlreadyAuthorized(Address),
+    /// Authorizing this node would exceed `config.max_nodes`.
+    #[error("DNS break-glass node cap of {0} reached")]
+    CapExceeded(u32),
+}
+
+/// Resolve `config.domain`'s TXT records via `resolver`, verify each one's
+/// authority signature, and return a [`DnsBootstrapClaim`] per valid,
+/// newly-authorized node.
+///
+/// `already_claimed` lists the addresses of nodes authorized by prior
+/// claims (e.g. [`BootstrapState::dns_claims`](super::bootstrap::BootstrapState::dns_claims)
+/// mapped to addresses) — both these and duplicates resolved more than
+/// once in this same batch are rejected as
+/// [`BreakGlassError::AlreadyAuthorized`]. Records are processed in the
+/// order `resolver` returns them; once `config.max_nodes` total claims
+/// would be exceeded, the first record over the cap fails with
+/// [`BreakGlassError::CapExceeded`] rather than being silently dropped.
+///
+/// # Errors
+/// Returns whatever [`BreakGlassError`] the first invalid record produces
+/// — a malformed record, a bad signature, a duplicate node, or exceeding
+/// `max_nodes` — so callers know exactly which hostname to investigate
+/// instead of only learning that *something* in the batch failed.
+pub fn resolve_break_glass_nodes(
+    config: &DnsBreakGlassConfig,
+    resolver: &impl TxtResolver,
+    already_claimed: &[Address],
+    now: Timestamp,
+) -> Result<Vec<DnsBootstrapClaim>, BreakGlassError> {
+    let records = resolver.resolve_txt_records(&config.domain)?;
+
+    let mut seen: Vec<Address> = already_claimed.to_vec();
+    let mut remaining = config.max_nodes.saturating_sub(seen.len() as u32);
+    let mut claims = Vec::new();
+
+    for (hostname, value) in records {
+        if remaining == 0 {
+            return Err(BreakGlassError::CapExceeded(config.max_nodes));
+        }
+
+        let (node_key, signature) = decode_record(&hostname, &value)?;
+        let address = Address::from_public_key(&node_key);
+
+        if seen.contains(&address) {
+            return Err(BreakGlassError::AlreadyAuthorized(address));
+        }
+
+        let message = dns_claim_signing_message(&node_key, &address, &hostname, config.tokens_each);
+        let authorized_by = config
+            .authorized_keys
+            .iter()
+            .find(|key| crate::crypto::verify(key, &message, &signature).is_ok())
+            .ok_or_else(|| BreakGlassError::InvalidSignature(hostname.clone()))?
+            .clone();
+
+        claims.push(DnsBootstrapClaim {
+            address,
+            node_key,
+            hostname,
+            amount: config.tokens_each,
+            claimed_at: now,
+            vests_at: now + config.vesting_ms,
+            authorized_by,
+            authority_sig: signature,
+        });
+        seen.push(address);
+        remaining -= 1;
+    }
+
+    Ok(claims)
+}
+
+/// Decode one TXT record's value as `node_public_key || authority_signature`.
+/// The signature isn't checked here — verification needs the full claim
+/// message (see [`DnsBootstrapClaim::signing_message`]), which isn't
+/// assembled until the caller knows `address`, `hostname`, and `amount`.
+fn decode_record(hostname: &str, value: &str) -> Result<(PublicKey, Signature), BreakGlassError> {
+    let decoded = base64::engine::general_purpose::STANDARD
+        .decode(value)
+        .map_err(|e| BreakGlassError::MalformedRecord {
+            hostname: hostname.to_string(),
+            reason: format!("not valid base64: {e}"),
+        })?;
+
+    if decoded.len() != PUBKEY_SIZE + SIGNATURE_SIZE {
+        return Err(BreakGlassError::MalformedRecord {
+            hostname: hostname.to_string(),
+            reason: format!(
+                "expected {} bytes (node_public_key || authority_signature), got {}",
+                PUBKEY_SIZE + SIGNATURE_SIZE,
+                decoded.len()
+            ),
+        });
+    }
+
+    let (key_bytes, sig_bytes) = decoded.split_at(PUBKEY_SIZE);
+
+    let node_key =
+        PublicKey::from_bytes(key_bytes).map_err(|e| BreakGlassError::MalformedRecord {
+            hostname: hostname.to_string(),
+            reason: format!("invalid node public key: {e}"),
+        })?;
+    let signature =
+        Signature::from_bytes(sig_bytes).map_err(|e| BreakGlassError::MalformedRecord {
+            hostname: hostname.to_string(),
+            reason: format!("invalid signature: {e}"),
+        })?;
+
+    Ok((node_key, signature))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto::Keypair;
+
+    struct CannedResolver {
+        records: Vec<(String, String)>,
+    }
+
+    impl TxtResolver for CannedResolver {
+        fn resolve_txt_records(
+            &self,
+            _domain: &str,
+        ) -> Result<Vec<(String, String)>, BreakGlassError> {
+            Ok(self.records.clone())
+        }
+    }
+
+    fn test_config(authority: &Keypair, max_nodes: u32) -> DnsBreakGlassConfig {
+        DnsBreakGlassConfig {
+            domain: "clawpaper.com".to_string(),
+            max_nodes,
+            tokens_each: crate::types::HclawAmount::from_hclaw(250_000),
+            vesting_ms: super::super::DAY_MS,
+            authorized_keys: vec![authority.public_key().clone()],
+        }
+    }
+
+    fn signed_record(authority: &Keypair, node: &Keypair, hostname: &str, config: &DnsBreakGlassConfig) -> String {
+        let node_key = node.public_key();
+        let address = Address::from_public_key(node_key);
+        let message = dns_claim_signing_message(node_key, &address, hostname, config.tokens_each);
+        let signature = authority.sign(&message);
+        let mut blob = node_key.as_bytes().to_vec();
+        blob.extend_from_slice(signature.as_bytes());
+        base64::engine::general_purpose::STANDARD.encode(blob)
+    }
+
+    #[test]
+    fn test_resolves_valid_signed_record() {
+        let authority = Keypair::generate();
+        let node = Keypair::generate();
+        let config = test_config(&authority, 10);
+        let resolver = CannedResolver {
+            records: vec![(
+                "node1.clawpaper.com".to_string(),
+                signed_record(&authority, &node, "node1.clawpaper.com", &config),
+            )],
+        };
+
+        let claims = resolve_break_glass_nodes(&config, &resolver, &[], 1000).unwrap();
+
+        assert_eq!(claims.len(), 1);
+        assert_eq!(
+            claims[0].address,
+            Address::from_public_key(node.public_key())
+        );
+        assert_eq!(claims[0].amount, config.tokens_each);
+        assert_eq!(claims[0].claimed_at, 1000);
+        assert_eq!(claims[0].vests_at, 1000 + config.vesting_ms);
+        assert_eq!(claims[0].authorized_by, *authority.public_key());
+    }
+
+    #[test]
+    fn test_resolves_record_signed_by_rotated_key() {
+        let old_authority = Keypair::generate();
+        let new_authority = Keypair::generate();
+        let node = Keypair::generate();
+        let mut config = test_config(&old_authority, 10);
+        config.authorized_keys.push(new_authority.public_key().clone());
+
+        let resolver = CannedResolver {
+            records: vec![(
+                "node1.clawpaper.com".to_string(),
+                signed_record(&new_authority, &node, "node1.clawpaper.com", &config),
+            )],
+        };
+
+        let claims = resolve_break_glass_nodes(&config, &resolver, &[], 1000).unwrap();
+
+        assert_eq!(claims.len(), 1);
+        assert_eq!(claims[0].authorized_by, *new_authority.public_key());
+    }
+
+    #[test]
+    fn test_rejects_signature_from_wrong_authority() {
+        let authority = Keypair::generate();
+        let impostor = Keypair::generate();
+        let node = Keypair::generate();
+        let config = test_config(&authority, 10);
+        let resolver = CannedResolver {
+            records: vec![(
+                "node1.clawpaper.com".to_string(),
+                signed_record(&impostor, &node, "node1.clawpaper.com", &config),
+            )],
+        };
+
+        assert!(matches!(
+            resolve_break_glass_nodes(&config, &resolver, &[], 1000),
+            Err(BreakGlassError::InvalidSignature(_))
+        ));
+    }
+
+    #[test]
+    fn test_rejects_malformed_record() {
+        let authority = Keypair::generate();
+        let config = test_config(&authority, 10);
+        let resolver = CannedResolver {
+            records: vec![(
+                "node1.clawpaper.com".to_string(),
+                "not-base64!!!".to_string(),
+            )],
+        };
+
+        assert!(matches!(
+            resolve_break_glass_nodes(&config, &resolver, &[], 1000),
+            Err(BreakGlassError::MalformedRecord { .. })
+        ));
+    }
+
+    #[test]
+    fn test_rejects_already_claimed_node() {
+        let authority = Keypair::generate();
+        let node = Keypair::generate();
+        let config = test_config(&authority, 10);
+        let resolver = CannedResolver {
+            records: vec![(
+                "node1.clawpaper.com".to_string(),
+                signed_record(&authority, &node, "node1.clawpaper.com", &config),
+            )],
+        };
+        let already = vec![Address::from_public_key(node.public_key())];
+
+        assert!(matches!(
+            resolve_break_glass_nodes(&config, &resolver, &already, 1000),
+            Err(BreakGlassError::AlreadyAuthorized(_))
+        ));
+    }
+
+    #[test]
+    fn test_rejects_duplicate_within_same_batch() {
+        let authority = Keypair::generate();
+        let node = Keypair::generate();
+        let config = test_config(&authority, 10);
+        let resolver = CannedResolver {
+            records: vec![
+                (
+                    "node1.clawpaper.com".to_string(),
+                    signed_record(&authority, &node, "node1.clawpaper.com", &config),
+                ),
+                (
+                    "node2.clawpaper.com".to_string(),
+                    signed_record(&authority, &node, "node2.clawpaper.com", &config),
+                ),
+            ],
+        };
+
+        assert!(matches!(
+            resolve_break_glass_nodes(&config, &resolver, &[], 1000),
+            Err(BreakGlassError::AlreadyAuthorized(_))
+        ));
+    }
+
+    #[test]
+    fn test_enforces_max_nodes_cap() {
+        let authority = Keypair::generate();
+        let node_a = Keypair::generate();
+        let node_b = Keypair::generate();
+        let config = test_config(&authority, 1);
+        let resolver = CannedResolver {
+            records: vec![
+                (
+                    "node1.clawpaper.com".to_string(),
+                    signed_record(&authority, &node_a, "node1.clawpaper.com", &config),
+                ),
+                (
+                    "node2.clawpaper.com".to_string(),
+                    signed_record(&authority, &node_b, "node2.clawpaper.com", &config),
+                ),
+            ],
+        };
+
+        assert!(matches!(
+            resolve_break_glass_nodes(&config, &resolver, &[], 1000),
+            Err(BreakGlassError::CapExceeded(1))
+        ));
+    }
+}
@@ -2,8 +2,10 @@
 //!
 //! Before a verifier can be activated and receive their airdrop,
 //! they must prove they can actually verify solutions correctly.
-//! The challenge presents a known-good and known-bad solution;
-//! the verifier must accept the good one and reject the bad one.
+//! The challenge presents `K` deterministically-seeded solution hashes,
+//! each secretly labeled valid or invalid; the verifier must classify
+//! every one of them correctly to pass, so a verifier that merely
+//! guesses has only a `2^-K` chance of getting through.
 //!
 //! Pre-approved addresses (bootstrap nodes, founder machines) skip this check.
 
@@ -14,6 +16,10 @@ use serde::{Deserialize, Serialize};
 use crate::crypto::{hash_data, Hash};
 use crate::types::{Address, Timestamp};
 
+/// Default number of solution vectors per challenge (guess-through
+/// probability `2^-8` = 1/256).
+const DEFAULT_CHALLENGE_DIFFICULTY: u32 = 8;
+
 /// A competency challenge for a new verifier
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct CompetencyChallenge {
@@ -21,10 +27,12 @@ pub struct CompetencyChallenge {
     pub id: Hash,
     /// The verifier being tested
     pub verifier: Address,
-    /// Hash of the valid test solution (verifier should accept this)
-    pub valid_solution_hash: Hash,
-    /// Hash of the invalid test solution (verifier should reject this)
-    pub invalid_solution_hash: Hash,
+    /// Hash of each solution vector the verifier must classify, in order
+    pub solution_hashes: Vec<Hash>,
+    /// The hidden expected classification for each entry in
+    /// `solution_hashes` (`true` = valid, `false` = invalid); not revealed
+    /// to the verifier until after they submit their own classifications
+    pub expected_labels: Vec<bool>,
     /// When the challenge was issued
     pub issued_at: Timestamp,
     /// Challenge expiry
@@ -33,6 +41,16 @@ pub struct CompetencyChallenge {
     pub status: ChallengeStatus,
 }
 
+impl CompetencyChallenge {
+    /// Number of solution vectors in this challenge
+    #[must_use]
+    pub fn difficulty(&self) -> u32 {
+        #[allow(clippy::cast_possible_truncation)]
+        let len = self.solution_hashes.len() as u32;
+        len
+    }
+}
+
 /// Status of a competency challenge
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ChallengeStatus {
@@ -106,12 +124,27 @@ impl CompetencyManager {
         self.pre_approved.contains(address)
     }
 
-    /// Generate a challenge for a verifier.
-    /// Returns `None` if already verified, or error if rate-limited.
+    /// Generate a challenge for a verifier with the default difficulty
+    /// (see [`DEFAULT_CHALLENGE_DIFFICULTY`]).
+    /// Returns an error if already verified or rate-limited.
     pub fn generate_challenge(
         &mut self,
         verifier: Address,
         now: Timestamp,
+    ) -> Result<CompetencyChallenge, CompetencyError> {
+        self.generate_challenge_with_difficulty(verifier, now, DEFAULT_CHALLENGE_DIFFICULTY)
+    }
+
+    /// Generate a challenge carrying `difficulty` solution vectors, each
+    /// with a hidden valid/invalid label. A verifier that merely guesses
+    /// every label has a `2^-difficulty` chance of passing.
+    ///
+    /// Returns an error if already verified or rate-limited.
+    pub fn generate_challenge_with_difficulty(
+        &mut self,
+        verifier: Address,
+        now: Timestamp,
+        difficulty: u32,
     ) -> Result<CompetencyChallenge, CompetencyError> {
         if self.verified.contains(&verifier) {
             return Err(CompetencyError::AlreadyVerified);
@@ -133,19 +166,26 @@ impl CompetencyManager {
         }
 
         // Generate deterministic challenge seeded from verifier address + timestamp
-        let seed = hash_data(
-            &[verifier.as_bytes().as_slice(), &now.to_le_bytes()].concat(),
-        );
-
-        // Create deterministic valid/invalid solution hashes from the seed
-        let valid_hash = hash_data(&[seed.as_bytes().as_slice(), b"valid"].concat());
-        let invalid_hash = hash_data(&[seed.as_bytes().as_slice(), b"invalid"].concat());
+        let seed = hash_data(&[verifier.as_bytes().as_slice(), &now.to_le_bytes()].concat());
+
+        // Derive `difficulty` solution hashes and labels from the seed. The
+        // label for vector `i` comes from a bit of its own hash so it can't
+        // be predicted without recomputing the hash, same as the hash itself.
+        let mut solution_hashes = Vec::with_capacity(difficulty as usize);
+        let mut expected_labels = Vec::with_capacity(difficulty as usize);
+        for i in 0..difficulty {
+            let vector_hash =
+                hash_data(&[seed.as_bytes().as_slice(), b"vector", &i.to_le_bytes()].concat());
+            let label = vector_hash.as_bytes()[0] & 1 == 0;
+            solution_hashes.push(vector_hash);
+            expected_labels.push(label);
+        }
 
         let challenge = CompetencyChallenge {
             id: seed,
             verifier,
-            valid_solution_hash: valid_hash,
-            invalid_solution_hash: invalid_hash,
+            solution_hashes,
+            expected_labels,
             issued_at: now,
             expires_at: now + CHALLENGE_TIMEOUT_MS,
             status: ChallengeStatus::Pending,
@@ -157,15 +197,16 @@ impl CompetencyManager {
         Ok(challenge)
     }
 
-    /// Submit challenge results.
+    /// Submit the verifier's classification of every solution vector in
+    /// their pending challenge, in the same order the challenge listed them.
     ///
-    /// `accepted_valid`: did the verifier accept the valid solution?
-    /// `rejected_invalid`: did the verifier reject the invalid solution?
+    /// Passes only if every classification matches the hidden expected
+    /// label; on failure, `reason` reports how many were misclassified
+    /// without revealing which ones.
     pub fn submit_result(
         &mut self,
         verifier: &Address,
-        accepted_valid: bool,
-        rejected_invalid: bool,
+        classifications: &[bool],
         now: Timestamp,
     ) -> Result<ChallengeStatus, CompetencyError> {
         let challenge = self
@@ -182,28 +223,35 @@ impl CompetencyManager {
             return Err(CompetencyError::ChallengeExpired);
         }
 
-        if accepted_valid && rejected_invalid {
+        if classifications.len() != challenge.expected_labels.len() {
+            return Err(CompetencyError::WrongVectorCount {
+                expected: challenge.expected_labels.len(),
+                got: classifications.len(),
+            });
+        }
+
+        let misclassified = classifications
+            .iter()
+            .zip(&challenge.expected_labels)
+            .filter(|(got, expected)| got != expected)
+            .count();
+
+        if misclassified == 0 {
             challenge.status = ChallengeStatus::Passed;
             self.verified.insert(*verifier);
             Ok(ChallengeStatus::Passed)
         } else {
-            let reason = match (accepted_valid, rejected_invalid) {
-                (false, false) => {
-                    "failed to accept valid solution AND failed to reject invalid solution"
-                }
-                (false, true) => "failed to accept the valid solution",
-                (true, false) => "failed to reject the invalid solution",
-                _ => unreachable!(),
-            };
+            let reason = format!(
+                "misclassified {misclassified} of {} solution vectors",
+                challenge.expected_labels.len()
+            );
 
             challenge.status = ChallengeStatus::Failed {
-                reason: reason.to_string(),
+                reason: reason.clone(),
             };
             *self.fail_counts.entry(*verifier).or_insert(0) += 1;
 
-            Ok(ChallengeStatus::Failed {
-                reason: reason.to_string(),
-            })
+            Ok(ChallengeStatus::Failed { reason })
         }
     }
 
@@ -241,6 +289,14 @@ pub enum CompetencyError {
         /// When the cooldown expires
         retry_after: Timestamp,
     },
+    /// Submitted the wrong number of classifications for the challenge
+    #[error("expected {expected} classifications, got {got}")]
+    WrongVectorCount {
+        /// Number of solution vectors in the challenge
+        expected: usize,
+        /// Number of classifications submitted
+        got: usize,
+    },
 }
 
 #[cfg(test)]
@@ -272,9 +328,12 @@ mod tests {
         // Generate challenge
         let challenge = manager.generate_challenge(verifier, 1000).unwrap();
         assert_eq!(challenge.status, ChallengeStatus::Pending);
+        assert_eq!(challenge.difficulty(), DEFAULT_CHALLENGE_DIFFICULTY);
 
-        // Submit correct results
-        let status = manager.submit_result(&verifier, true, true, 2000).unwrap();
+        // Submit correct results (the expected labels themselves)
+        let status = manager
+            .submit_result(&verifier, &challenge.expected_labels, 2000)
+            .unwrap();
         assert_eq!(status, ChallengeStatus::Passed);
         assert!(manager.is_verified(&verifier));
     }
@@ -284,14 +343,43 @@ mod tests {
         let mut manager = CompetencyManager::new(&[]);
         let verifier = test_addr();
 
-        manager.generate_challenge(verifier, 1000).unwrap();
+        let challenge = manager.generate_challenge(verifier, 1000).unwrap();
 
-        // Failed: accepted invalid solution
-        let status = manager.submit_result(&verifier, true, false, 2000).unwrap();
+        // Failed: flip the first classification
+        let mut guesses = challenge.expected_labels.clone();
+        guesses[0] = !guesses[0];
+        let status = manager.submit_result(&verifier, &guesses, 2000).unwrap();
         assert!(matches!(status, ChallengeStatus::Failed { .. }));
         assert!(!manager.is_verified(&verifier));
     }
 
+    #[test]
+    fn test_wrong_vector_count_rejected() {
+        let mut manager = CompetencyManager::new(&[]);
+        let verifier = test_addr();
+
+        manager.generate_challenge(verifier, 1000).unwrap();
+
+        let result = manager.submit_result(&verifier, &[true, false], 2000);
+        assert!(matches!(
+            result,
+            Err(CompetencyError::WrongVectorCount { .. })
+        ));
+    }
+
+    #[test]
+    fn test_custom_difficulty() {
+        let mut manager = CompetencyManager::new(&[]);
+        let verifier = test_addr();
+
+        let challenge = manager
+            .generate_challenge_with_difficulty(verifier, 1000, 3)
+            .unwrap();
+        assert_eq!(challenge.difficulty(), 3);
+        assert_eq!(challenge.solution_hashes.len(), 3);
+        assert_eq!(challenge.expected_labels.len(), 3);
+    }
+
     #[test]
     fn test_max_attempts() {
         let mut manager = CompetencyManager::new(&[]);
@@ -299,9 +387,11 @@ mod tests {
 
         for i in 0..MAX_ATTEMPTS {
             let time = (i as i64) * (RETRY_COOLDOWN_MS + 1000);
-            manager.generate_challenge(verifier, time).unwrap();
+            let challenge = manager.generate_challenge(verifier, time).unwrap();
+            let mut guesses = challenge.expected_labels.clone();
+            guesses[0] = !guesses[0];
             manager
-                .submit_result(&verifier, false, false, time + 1000)
+                .submit_result(&verifier, &guesses, time + 1000)
                 .unwrap();
         }
 
@@ -316,14 +406,17 @@ mod tests {
         let mut manager = CompetencyManager::new(&[]);
         let verifier = test_addr();
 
-        manager.generate_challenge(verifier, 1000).unwrap();
-        manager
-            .submit_result(&verifier, false, false, 2000)
-            .unwrap();
+        let challenge = manager.generate_challenge(verifier, 1000).unwrap();
+        let mut guesses = challenge.expected_labels.clone();
+        guesses[0] = !guesses[0];
+        manager.submit_result(&verifier, &guesses, 2000).unwrap();
 
         // Try again too soon
         let result = manager.generate_challenge(verifier, 3000);
-        assert!(matches!(result, Err(CompetencyError::CooldownActive { .. })));
+        assert!(matches!(
+            result,
+            Err(CompetencyError::CooldownActive { .. })
+        ));
 
         // After cooldown
         let result = manager.generate_challenge(verifier, 1000 + RETRY_COOLDOWN_MS + 1);
@@ -335,8 +428,10 @@ mod tests {
         let mut manager = CompetencyManager::new(&[]);
         let verifier = test_addr();
 
-        manager.generate_challenge(verifier, 1000).unwrap();
-        manager.submit_result(&verifier, true, true, 2000).unwrap();
+        let challenge = manager.generate_challenge(verifier, 1000).unwrap();
+        manager
+            .submit_result(&verifier, &challenge.expected_labels, 2000)
+            .unwrap();
 
         let result = manager.generate_challenge(verifier, 3000);
         assert!(matches!(result, Err(CompetencyError::AlreadyVerified)));
@@ -4,15 +4,19 @@
 //! configurations for testnet vs mainnet. The TOML format mirrors the
 //! `GenesisConfig` struct.
 
+use std::collections::HashSet;
 use std::path::Path;
 
 use serde::{Deserialize, Serialize};
 
 use super::{
-    GenesisError, BOOTSTRAP_DNS_DOMAIN, BOOTSTRAP_DURATION_MS, BOOTSTRAP_NODE_TOKENS,
-    DNS_BOOTSTRAP_TOKENS, FOUNDER_AIRDROP_AMOUNT, GENESIS_AIRDROP_AMOUNT, MAX_DNS_BOOTSTRAP_NODES,
-    MAX_GENESIS_PARTICIPANTS,
+    DnsBreakGlassConfig, GenesisAccount, GenesisBuiltin, GenesisConfig, GenesisError,
+    BOOTSTRAP_DNS_DOMAIN, BOOTSTRAP_DURATION_MS, BOOTSTRAP_NODE_TOKENS, DEFAULT_MAX_MISSED_DAYS,
+    DNS_BOOTSTRAP_TOKENS, FOUNDER_AIRDROP_AMOUNT, GENESIS_AIRDROP_AMOUNT,
+    MAX_DNS_BOOTSTRAP_NODES, MAX_GENESIS_PARTICIPANTS,
 };
+use crate::crypto::{hash_data, Hash, PublicKey};
+use crate::types::{Address, GenesisAlloc, HclawAmount, Timestamp};
 
 /// TOML-serializable genesis config
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -43,6 +47,62 @@ pub struct GenesisConfigToml {
     pub authority_key: String,
     /// DNS break-glass config (optional, uses defaults if absent)
     pub dns_break_glass: Option<DnsBreakGlassToml>,
+    /// Contract execution gas schedule (optional, uses
+    /// `gas::Schedule::standard()` if absent) — lets testnet and mainnet
+    /// charge different per-operation costs
+    pub gas_schedule: Option<GasScheduleToml>,
+    /// Ordered parameter fork schedule (default: empty, i.e. no overrides).
+    /// Lets operators phase in stricter consensus-relevant parameters
+    /// partway through bootstrap instead of only at compile time.
+    #[serde(default)]
+    pub fork_schedule: Vec<ForkScheduleEntry>,
+    /// Contracts to deploy at genesis (default: empty). Lets every node
+    /// derive an identical built-in contract set purely from this file; see
+    /// [`crate::contracts::ContractRegistry::from_genesis`].
+    #[serde(default)]
+    pub predeployed: Vec<PredeployedContractToml>,
+    /// Directly-seeded genesis accounts (default: empty). See
+    /// [`GenesisConfig::prealloc`].
+    #[serde(default)]
+    pub prealloc: Vec<PreallocEntryToml>,
+    /// Built-in/precompile contracts mounted at fixed addresses (default:
+    /// empty). See [`GenesisConfig::builtins`].
+    #[serde(default)]
+    pub builtins: Vec<GenesisBuiltinToml>,
+    /// Consecutive inactive days before a verifier is slashed (default: 3).
+    /// See [`GenesisConfig::max_missed_days`].
+    #[serde(default = "default_max_missed_days")]
+    pub max_missed_days: u32,
+}
+
+/// TOML-serializable [`GenesisAccount`] — the hex-address equivalent keyed
+/// by address string instead of a parsed [`Address`], so a prealloc entry
+/// can live in a text config file.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PreallocEntryToml {
+    /// Address to seed (hex-encoded)
+    pub address: String,
+    /// Starting balance, in whole HCLAW
+    pub balance: u64,
+    /// Starting nonce (default: 0)
+    #[serde(default)]
+    pub nonce: u64,
+    /// Contract bytecode, hex-encoded (default: none, i.e. a plain wallet)
+    #[serde(default)]
+    pub code: Option<String>,
+}
+
+/// TOML-serializable [`GenesisBuiltin`] — the hex-address equivalent of a
+/// builtin mount point.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct GenesisBuiltinToml {
+    /// Fixed address this builtin is mounted at (hex-encoded)
+    pub address: String,
+    /// `native:`/WASM code reference, passed to `ContractLoader::load`
+    pub code_ref: String,
+    /// Block height at which the builtin starts accepting calls (default: 0)
+    #[serde(default)]
+    pub activate_at_height: u64,
 }
 
 /// TOML-serializable DNS break-glass config
@@ -60,6 +120,135 @@ pub struct DnsBreakGlassToml {
     /// Vesting period in hours (default: 24)
     #[serde(default = "default_dns_vesting_hours")]
     pub vesting_hours: u32,
+    /// Extra authority public keys (hex-encoded), on top of the top-level
+    /// `authority_key`, that may also sign DNS break-glass claims. Lets an
+    /// operator rotate in a new authority key without invalidating claims
+    /// already signed by the old one (default: none).
+    #[serde(default)]
+    pub additional_authority_keys: Vec<String>,
+}
+
+/// TOML-serializable contract execution gas schedule. Mirrors the
+/// per-operation costs of [`crate::contracts::gas::Schedule`]; defaults match
+/// [`crate::contracts::gas::Schedule::standard`] so an operator only needs
+/// to override the costs they actually care about. The deploy costs
+/// (`deploy_base_cost`, `deploy_code_byte`) are not read from TOML and always
+/// take their `Schedule::standard` values.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct GasScheduleToml {
+    /// Flat cost charged once per transaction (default: 21,000)
+    #[serde(default = "default_gas_base_tx_cost")]
+    pub base_tx_cost: u64,
+    /// Flat cost of a storage read (default: 200)
+    #[serde(default = "default_gas_storage_read")]
+    pub storage_read: u64,
+    /// Flat cost of a storage write/delete, before the per-byte surcharge (default: 5,000)
+    #[serde(default = "default_gas_storage_write")]
+    pub storage_write: u64,
+    /// Extra cost per byte of a storage write's value (default: 3)
+    #[serde(default = "default_gas_storage_write_byte")]
+    pub storage_write_byte: u64,
+    /// Flat cost of a credit (default: 100)
+    #[serde(default = "default_gas_credit")]
+    pub credit: u64,
+    /// Flat cost of a debit (default: 100)
+    #[serde(default = "default_gas_debit")]
+    pub debit: u64,
+    /// Flat cost of an emitted event (default: 375)
+    #[serde(default = "default_gas_emit_event")]
+    pub emit_event: u64,
+    /// Cost per byte of an execution result's output (default: 8)
+    #[serde(default = "default_gas_output_byte")]
+    pub output_byte: u64,
+}
+
+impl GasScheduleToml {
+    /// Convert into the runtime [`crate::contracts::gas::Schedule`] used by
+    /// `ContractState`/`TransactionProcessor`.
+    #[must_use]
+    pub const fn to_schedule(&self) -> crate::contracts::gas::Schedule {
+        crate::contracts::gas::Schedule {
+            base_tx_cost: self.base_tx_cost,
+            storage_read: self.storage_read,
+            storage_write: self.storage_write,
+            storage_write_byte: self.storage_write_byte,
+            credit: self.credit,
+            debit: self.debit,
+            emit_event: self.emit_event,
+            output_byte: self.output_byte,
+            ..crate::contracts::gas::Schedule::standard()
+        }
+    }
+}
+
+fn default_gas_base_tx_cost() -> u64 {
+    crate::contracts::gas::Schedule::standard().base_tx_cost
+}
+
+fn default_gas_storage_read() -> u64 {
+    crate::contracts::gas::Schedule::standard().storage_read
+}
+
+fn default_gas_storage_write() -> u64 {
+    crate::contracts::gas::Schedule::standard().storage_write
+}
+
+fn default_gas_storage_write_byte() -> u64 {
+    crate::contracts::gas::Schedule::standard().storage_write_byte
+}
+
+fn default_gas_credit() -> u64 {
+    crate::contracts::gas::Schedule::standard().credit
+}
+
+fn default_gas_debit() -> u64 {
+    crate::contracts::gas::Schedule::standard().debit
+}
+
+fn default_gas_emit_event() -> u64 {
+    crate::contracts::gas::Schedule::standard().emit_event
+}
+
+fn default_gas_output_byte() -> u64 {
+    crate::contracts::gas::Schedule::standard().output_byte
+}
+
+/// A single parameter override that activates on `activation_day` and holds
+/// until a later entry's `activation_day` supersedes it. Modeled on how
+/// chain specs gate parameter changes on block numbers — each field is
+/// independently optional, so an entry can override just one parameter
+/// without having to repeat the others.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct ForkScheduleEntry {
+    /// Bootstrap day (0-indexed) this override takes effect
+    pub activation_day: u32,
+    /// New `MIN_ATTESTATIONS_PER_DAY` threshold from this day onward
+    /// (leaves the prior value in force if absent)
+    pub min_attestations_per_day: Option<u32>,
+}
+
+/// A single contract to deploy at genesis, declared rather than registered
+/// imperatively. Mirrors the `[[predeployed]]` entries in a `chain-spec`
+/// file listing built-in accounts: every node that loads this config ends
+/// up with the same contract set, or refuses to start.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PredeployedContractToml {
+    /// Hex-encoded contract ID — must match what the loaded contract
+    /// reports via `Contract::id()`
+    pub id: String,
+    /// Native loader marker (e.g. `"native:genesis_bounty_v1"`), passed
+    /// verbatim to `ContractLoader::load` as the contract's "code"
+    pub name: String,
+    /// Contract version — must match what the loaded contract reports via
+    /// `Contract::version()`
+    pub version: u32,
+    /// Hex-encoded deploy-time init data, passed to `Contract::on_deploy`
+    /// (default: empty, for contracts that ignore it)
+    #[serde(default)]
+    pub init_state: String,
+    /// Hex-encoded expected state root after `on_deploy` runs against a
+    /// fresh `ContractState`, pinning this contract's initial storage
+    pub expected_state_root: String,
 }
 
 fn default_bootstrap_days() -> u32 {
@@ -98,7 +287,27 @@ fn default_dns_vesting_hours() -> u32 {
     24
 }
 
+fn default_max_missed_days() -> u32 {
+    super::DEFAULT_MAX_MISSED_DAYS
+}
+
 impl GenesisConfigToml {
+    /// Resolve `fork_schedule` into `(activation_day,
+    /// min_attestations_per_day)` overrides ready for
+    /// [`crate::genesis::liveness::LivenessTracker::with_fork_schedule`],
+    /// dropping entries that don't override this particular parameter.
+    #[must_use]
+    pub fn liveness_fork_schedule(&self) -> Vec<(u32, u32)> {
+        self.fork_schedule
+            .iter()
+            .filter_map(|entry| {
+                entry
+                    .min_attestations_per_day
+                    .map(|threshold| (entry.activation_day, threshold))
+            })
+            .collect()
+    }
+
     /// Load from a TOML file
     pub fn load_from_file(path: &Path) -> Result<Self, GenesisError> {
         let content = std::fs::read_to_string(path)?;
@@ -112,6 +321,97 @@ impl GenesisConfigToml {
         std::fs::write(path, content)?;
         Ok(())
     }
+
+    /// Convert into the runtime [`GenesisConfig`] embedded in block 0,
+    /// parsing every hex-encoded field and layering this file's overrides
+    /// on top of [`GenesisConfig::new`]'s defaults. `now` becomes
+    /// `bootstrap_start`, same as a direct `GenesisConfig::new` call.
+    ///
+    /// # Errors
+    /// Returns [`GenesisError::InvalidConfig`] if `authority_key`,
+    /// `pre_approved`, `prealloc`, or `builtins` contain malformed hex.
+    pub fn to_genesis_config(&self, now: Timestamp) -> Result<GenesisConfig, GenesisError> {
+        let authority_key = PublicKey::from_hex(&self.authority_key).map_err(|e| {
+            GenesisError::InvalidConfig(format!("invalid authority_key: {e}"))
+        })?;
+        let pre_approved = self
+            .pre_approved
+            .iter()
+            .map(|hex| {
+                Address::from_hex(hex).map_err(|e| {
+                    GenesisError::InvalidConfig(format!("invalid pre_approved address {hex}: {e}"))
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut config = GenesisConfig::new(self.chain_id.clone(), pre_approved, authority_key, now);
+        config.airdrop_amount = HclawAmount::from_hclaw(self.airdrop_amount);
+        config.max_participants = self.max_participants;
+        config.bootstrap_end = now + bootstrap_duration_from_days(self.bootstrap_duration_days);
+        config.max_missed_days = self.max_missed_days;
+
+        if let Some(dns) = &self.dns_break_glass {
+            let mut authorized_keys = config.dns_break_glass.authorized_keys.clone();
+            for hex in &dns.additional_authority_keys {
+                let key = PublicKey::from_hex(hex).map_err(|e| {
+                    GenesisError::InvalidConfig(format!(
+                        "invalid additional_authority_keys entry {hex}: {e}"
+                    ))
+                })?;
+                authorized_keys.push(key);
+            }
+
+            config.dns_break_glass = DnsBreakGlassConfig {
+                domain: dns.domain.clone(),
+                max_nodes: dns.max_nodes,
+                tokens_each: HclawAmount::from_hclaw(dns.tokens_each),
+                vesting_ms: i64::from(dns.vesting_hours) * 60 * 60 * 1000,
+                authorized_keys,
+            };
+        }
+
+        for entry in &self.prealloc {
+            let address = Address::from_hex(&entry.address).map_err(|e| {
+                GenesisError::InvalidConfig(format!(
+                    "invalid prealloc address {}: {e}",
+                    entry.address
+                ))
+            })?;
+            let code = entry
+                .code
+                .as_ref()
+                .map(|hex| {
+                    hex::decode(hex).map_err(|e| {
+                        GenesisError::InvalidConfig(format!("invalid prealloc code for {address}: {e}"))
+                    })
+                })
+                .transpose()?;
+            config.prealloc.insert(
+                address,
+                GenesisAccount {
+                    balance: HclawAmount::from_hclaw(entry.balance),
+                    nonce: entry.nonce,
+                    code,
+                },
+            );
+        }
+
+        for builtin in &self.builtins {
+            let address = Address::from_hex(&builtin.address).map_err(|e| {
+                GenesisError::InvalidConfig(format!(
+                    "invalid builtin address {}: {e}",
+                    builtin.address
+                ))
+            })?;
+            config.builtins.push(GenesisBuiltin {
+                address,
+                code_ref: builtin.code_ref.clone(),
+                activate_at_height: builtin.activate_at_height,
+            });
+        }
+
+        Ok(config)
+    }
 }
 
 /// Create a default testnet TOML config (for quick local testing)
@@ -128,6 +428,12 @@ pub fn default_testnet_toml() -> GenesisConfigToml {
         bootstrap_node_tokens: BOOTSTRAP_NODE_TOKENS,
         authority_key: "<authority-pubkey-hex>".to_string(),
         dns_break_glass: None,
+        gas_schedule: None,
+        fork_schedule: Vec::new(),
+        predeployed: Vec::new(),
+        prealloc: Vec::new(),
+        builtins: Vec::new(),
+        max_missed_days: DEFAULT_MAX_MISSED_DAYS,
     }
 }
 
@@ -141,9 +447,175 @@ pub fn bootstrap_duration_from_days(days: u32) -> i64 {
     }
 }
 
+/// TOML-serializable genesis balance allocation — the hex-address
+/// equivalent of [`crate::types::GenesisAlloc`], which holds a parsed
+/// [`Address`] unsuited to a text config file. See
+/// [`ChainSpec::to_genesis_alloc`] for the conversion.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ChainSpecAlloc {
+    /// Address to credit (hex-encoded)
+    pub address: String,
+    /// Amount to credit, in whole HCLAW
+    pub amount: u64,
+    /// Label for this allocation (e.g., "bootstrap-us", "founder-1")
+    #[serde(default)]
+    pub label: String,
+}
+
+fn default_protocol_version() -> u32 {
+    1
+}
+
+fn default_consensus_threshold() -> f64 {
+    crate::CONSENSUS_THRESHOLD
+}
+
+/// A fully declarative chain specification — the analogue of Substrate's
+/// `chain_spec.rs` or OpenEthereum's chainspec files. Wraps
+/// [`GenesisConfigToml`] rather than duplicating it: `genesis_alloc`,
+/// `consensus_threshold`, and `protocol_version` are the only pieces that
+/// genesis's imperative assembly (`Block::genesis_with_job`,
+/// `AirdropConfig::new`, and the hardcoded `CONSENSUS_THRESHOLD` constant)
+/// didn't already expose as loadable config.
+///
+/// Every node that loads the same `ChainSpec` computes the identical
+/// genesis block via [`ChainSpec::genesis_hash`] — nodes should refuse to
+/// peer with one that reports a different hash for the spec they think
+/// they're both running.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ChainSpec {
+    /// Protocol version new blocks are stamped with (default: 1)
+    #[serde(default = "default_protocol_version")]
+    pub protocol_version: u32,
+    /// Fraction of the validator set required for block consensus
+    /// (default: [`crate::CONSENSUS_THRESHOLD`], i.e. 0.66)
+    #[serde(default = "default_consensus_threshold")]
+    pub consensus_threshold: f64,
+    /// Direct genesis balance credits (Ethereum-style `alloc`), applied on
+    /// top of whatever the airdrop/bootstrap pools in `genesis` mint
+    #[serde(default)]
+    pub genesis_alloc: Vec<ChainSpecAlloc>,
+    /// The rest of the declarative genesis configuration: airdrop amounts,
+    /// pre-approved/bootstrap addresses, DNS break-glass, gas schedule,
+    /// fork schedule, and predeployed contracts
+    pub genesis: GenesisConfigToml,
+}
+
+impl ChainSpec {
+    /// Load a chain spec from a TOML file
+    pub fn load(path: &Path) -> Result<Self, GenesisError> {
+        let content = std::fs::read_to_string(path)?;
+        toml::from_str(&content).map_err(|e| GenesisError::ParseError(e.to_string()))
+    }
+
+    /// Save a chain spec to a TOML file
+    pub fn save_to_file(&self, path: &Path) -> Result<(), GenesisError> {
+        let content =
+            toml::to_string_pretty(self).map_err(|e| GenesisError::ParseError(e.to_string()))?;
+        std::fs::write(path, content)?;
+        Ok(())
+    }
+
+    /// Reject specs that are internally inconsistent: empty `chain_id`,
+    /// an out-of-range `consensus_threshold`, duplicate `genesis_alloc`
+    /// addresses, or a total supply (alloc + airdrop + founder + bootstrap
+    /// pools) that overflows `u128`.
+    ///
+    /// # Errors
+    /// Returns [`GenesisError::InvalidConfig`] describing the first
+    /// inconsistency found.
+    pub fn validate(&self) -> Result<(), GenesisError> {
+        if self.genesis.chain_id.is_empty() {
+            return Err(GenesisError::InvalidConfig("chain_id is empty".into()));
+        }
+        if !(self.consensus_threshold > 0.0 && self.consensus_threshold <= 1.0) {
+            return Err(GenesisError::InvalidConfig(
+                "consensus_threshold must be in (0.0, 1.0]".into(),
+            ));
+        }
+
+        let mut seen = HashSet::new();
+        let mut total: u128 = 0;
+        for entry in &self.genesis_alloc {
+            let address = Address::from_hex(&entry.address).map_err(|e| {
+                GenesisError::InvalidConfig(format!(
+                    "invalid genesis_alloc address {}: {e}",
+                    entry.address
+                ))
+            })?;
+            if !seen.insert(address) {
+                return Err(GenesisError::InvalidConfig(format!(
+                    "duplicate genesis_alloc address: {address}"
+                )));
+            }
+            total = total
+                .checked_add(HclawAmount::from_hclaw(entry.amount).raw())
+                .ok_or_else(|| {
+                    GenesisError::InvalidConfig("genesis_alloc total overflows".into())
+                })?;
+        }
+
+        let pools = [
+            HclawAmount::from_hclaw(self.genesis.airdrop_amount)
+                .raw()
+                .checked_mul(self.genesis.max_participants as u128),
+            HclawAmount::from_hclaw(self.genesis.founder_airdrop_amount)
+                .raw()
+                .checked_mul(self.genesis.pre_approved.len() as u128),
+            HclawAmount::from_hclaw(self.genesis.bootstrap_node_tokens)
+                .raw()
+                .checked_mul(self.genesis.bootstrap_nodes.len() as u128),
+        ];
+        for pool in pools {
+            let pool = pool.ok_or_else(|| {
+                GenesisError::InvalidConfig("genesis allocation pool overflows".into())
+            })?;
+            total = total.checked_add(pool).ok_or_else(|| {
+                GenesisError::InvalidConfig("genesis allocation total overflows".into())
+            })?;
+        }
+
+        Ok(())
+    }
+
+    /// Convert `genesis_alloc` into the [`GenesisAlloc`] entries
+    /// [`crate::types::Block::genesis_with_job`] expects.
+    ///
+    /// # Errors
+    /// Returns [`GenesisError::InvalidConfig`] if any entry's address
+    /// isn't valid hex.
+    pub fn to_genesis_alloc(&self) -> Result<Vec<GenesisAlloc>, GenesisError> {
+        self.genesis_alloc
+            .iter()
+            .map(|entry| {
+                let address = Address::from_hex(&entry.address).map_err(|e| {
+                    GenesisError::InvalidConfig(format!(
+                        "invalid genesis_alloc address {}: {e}",
+                        entry.address
+                    ))
+                })?;
+                Ok(GenesisAlloc {
+                    address,
+                    amount: HclawAmount::from_hclaw(entry.amount),
+                    label: entry.label.clone(),
+                })
+            })
+            .collect()
+    }
+
+    /// Deterministic hash over every field of the spec, so two nodes that
+    /// loaded the same chain spec can confirm they agree without comparing
+    /// the whole file — and refuse to peer if they don't.
+    #[must_use]
+    pub fn genesis_hash(&self) -> Hash {
+        hash_data(&bincode::serialize(self).unwrap_or_default())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::contracts::gas::Schedule;
 
     #[test]
     fn test_toml_roundtrip() {
@@ -168,4 +640,235 @@ mod tests {
         assert_eq!(config.pre_approved.len(), 8);
         assert_eq!(config.bootstrap_nodes.len(), 4);
     }
+
+    #[test]
+    fn test_gas_schedule_roundtrip_uses_standard_defaults_when_absent() {
+        let config = default_testnet_toml();
+        assert!(config.gas_schedule.is_none());
+
+        let serialized = toml::to_string_pretty(&config).unwrap();
+        let deserialized: GenesisConfigToml = toml::from_str(&serialized).unwrap();
+        assert!(deserialized.gas_schedule.is_none());
+
+        let mut with_override = config;
+        with_override.gas_schedule = Some(GasScheduleToml {
+            base_tx_cost: 10_000,
+            ..toml::from_str("").unwrap()
+        });
+        let serialized = toml::to_string_pretty(&with_override).unwrap();
+        let deserialized: GenesisConfigToml = toml::from_str(&serialized).unwrap();
+        let schedule = deserialized.gas_schedule.unwrap().to_schedule();
+        assert_eq!(schedule.base_tx_cost, 10_000);
+        assert_eq!(schedule.storage_read, Schedule::standard().storage_read);
+    }
+
+    #[test]
+    fn test_fork_schedule_roundtrip_and_liveness_conversion() {
+        let mut config = default_testnet_toml();
+        assert!(config.fork_schedule.is_empty());
+
+        config.fork_schedule = vec![
+            ForkScheduleEntry {
+                activation_day: 10,
+                min_attestations_per_day: Some(200),
+            },
+            ForkScheduleEntry {
+                activation_day: 5,
+                // An entry that doesn't override this parameter should be
+                // dropped by `liveness_fork_schedule`, not turned into a
+                // bogus (day, 0) pair.
+                min_attestations_per_day: None,
+            },
+        ];
+
+        let serialized = toml::to_string_pretty(&config).unwrap();
+        let deserialized: GenesisConfigToml = toml::from_str(&serialized).unwrap();
+        assert_eq!(deserialized.fork_schedule.len(), 2);
+
+        let overrides = deserialized.liveness_fork_schedule();
+        assert_eq!(overrides, vec![(10, 200)]);
+    }
+
+    #[test]
+    fn test_predeployed_roundtrip() {
+        let mut config = default_testnet_toml();
+        assert!(config.predeployed.is_empty());
+
+        config.predeployed.push(PredeployedContractToml {
+            id: "00".repeat(32),
+            name: "native:governance_v1".to_string(),
+            version: 1,
+            init_state: String::new(),
+            expected_state_root: "11".repeat(32),
+        });
+
+        let serialized = toml::to_string_pretty(&config).unwrap();
+        let deserialized: GenesisConfigToml = toml::from_str(&serialized).unwrap();
+
+        assert_eq!(deserialized.predeployed.len(), 1);
+        assert_eq!(deserialized.predeployed[0].name, "native:governance_v1");
+        assert_eq!(deserialized.predeployed[0].version, 1);
+    }
+
+    fn test_chain_spec() -> ChainSpec {
+        ChainSpec {
+            protocol_version: 1,
+            consensus_threshold: crate::CONSENSUS_THRESHOLD,
+            genesis_alloc: vec![ChainSpecAlloc {
+                address: "11".repeat(20),
+                amount: 1_000,
+                label: "treasury".to_string(),
+            }],
+            genesis: default_testnet_toml(),
+        }
+    }
+
+    #[test]
+    fn test_chain_spec_toml_roundtrip() {
+        let spec = test_chain_spec();
+        let serialized = toml::to_string_pretty(&spec).unwrap();
+        let deserialized: ChainSpec = toml::from_str(&serialized).unwrap();
+
+        assert_eq!(deserialized.protocol_version, spec.protocol_version);
+        assert_eq!(deserialized.consensus_threshold, spec.consensus_threshold);
+        assert_eq!(deserialized.genesis_alloc.len(), 1);
+        assert_eq!(deserialized.genesis.chain_id, spec.genesis.chain_id);
+        assert!(deserialized.validate().is_ok());
+    }
+
+    #[test]
+    fn test_chain_spec_defaults_when_absent() {
+        // Only `genesis` is required; `protocol_version`, `consensus_threshold`,
+        // and `genesis_alloc` should all fall back to their defaults.
+        let toml_str = format!(
+            "[genesis]\nchain_id = \"hardclaw-testnet-1\"\nauthority_key = \"{}\"\npre_approved = []\n",
+            "00".repeat(64)
+        );
+        let deserialized: ChainSpec = toml::from_str(&toml_str).unwrap();
+
+        assert_eq!(deserialized.protocol_version, 1);
+        assert_eq!(deserialized.consensus_threshold, crate::CONSENSUS_THRESHOLD);
+        assert!(deserialized.genesis_alloc.is_empty());
+    }
+
+    #[test]
+    fn test_chain_spec_rejects_duplicate_alloc_address() {
+        let mut spec = test_chain_spec();
+        spec.genesis_alloc.push(ChainSpecAlloc {
+            address: "11".repeat(20),
+            amount: 1,
+            label: "dup".to_string(),
+        });
+
+        assert!(matches!(
+            spec.validate(),
+            Err(GenesisError::InvalidConfig(_))
+        ));
+    }
+
+    #[test]
+    fn test_chain_spec_rejects_invalid_threshold() {
+        let mut spec = test_chain_spec();
+        spec.consensus_threshold = 0.0;
+        assert!(spec.validate().is_err());
+
+        spec.consensus_threshold = 1.5;
+        assert!(spec.validate().is_err());
+    }
+
+    #[test]
+    fn test_chain_spec_rejects_overflowing_alloc_total() {
+        let mut spec = test_chain_spec();
+        spec.genesis_alloc[0].amount = u64::MAX;
+        spec.genesis.max_participants = u32::MAX;
+
+        assert!(matches!(
+            spec.validate(),
+            Err(GenesisError::InvalidConfig(_))
+        ));
+    }
+
+    #[test]
+    fn test_chain_spec_to_genesis_alloc() {
+        let spec = test_chain_spec();
+        let alloc = spec.to_genesis_alloc().unwrap();
+
+        assert_eq!(alloc.len(), 1);
+        assert_eq!(alloc[0].amount.whole_hclaw(), 1_000);
+        assert_eq!(alloc[0].label, "treasury");
+    }
+
+    #[test]
+    fn test_chain_spec_genesis_hash_deterministic_and_sensitive_to_input() {
+        let spec = test_chain_spec();
+        let mut other = spec.clone();
+
+        assert_eq!(spec.genesis_hash(), spec.clone().genesis_hash());
+
+        other.protocol_version += 1;
+        assert_ne!(spec.genesis_hash(), other.genesis_hash());
+    }
+
+    fn test_authority_key_hex() -> String {
+        "aa".repeat(crate::crypto::PUBKEY_SIZE)
+    }
+
+    #[test]
+    fn test_to_genesis_config_applies_overrides() {
+        let mut config = default_testnet_toml();
+        config.pre_approved = Vec::new();
+        config.authority_key = test_authority_key_hex();
+        config.airdrop_amount = 42;
+        config.max_participants = 7;
+        config.bootstrap_duration_days = 3;
+
+        let genesis = config.to_genesis_config(1000).expect("valid config converts");
+        assert_eq!(genesis.chain_id, config.chain_id);
+        assert_eq!(genesis.airdrop_amount, HclawAmount::from_hclaw(42));
+        assert_eq!(genesis.max_participants, 7);
+        assert_eq!(
+            genesis.bootstrap_end,
+            1000 + bootstrap_duration_from_days(3)
+        );
+    }
+
+    #[test]
+    fn test_to_genesis_config_populates_prealloc_and_builtins() {
+        let mut config = default_testnet_toml();
+        config.pre_approved = Vec::new();
+        config.authority_key = test_authority_key_hex();
+        config.prealloc.push(PreallocEntryToml {
+            address: "22".repeat(20),
+            balance: 1_000,
+            nonce: 3,
+            code: Some("cafe".to_string()),
+        });
+        config.builtins.push(GenesisBuiltinToml {
+            address: "33".repeat(20),
+            code_ref: "native:governance_v1".to_string(),
+            activate_at_height: 10,
+        });
+
+        let genesis = config.to_genesis_config(1000).expect("valid config converts");
+        let treasury = Address::from_hex(&"22".repeat(20)).unwrap();
+        let account = genesis.prealloc.get(&treasury).expect("prealloc entry present");
+        assert_eq!(account.balance, HclawAmount::from_hclaw(1_000));
+        assert_eq!(account.nonce, 3);
+        assert_eq!(account.code, Some(vec![0xca, 0xfe]));
+
+        assert_eq!(genesis.builtins.len(), 1);
+        assert_eq!(genesis.builtins[0].activate_at_height, 10);
+    }
+
+    #[test]
+    fn test_to_genesis_config_rejects_invalid_authority_key() {
+        let mut config = default_testnet_toml();
+        config.pre_approved = Vec::new();
+        config.authority_key = "not-hex".to_string();
+
+        assert!(matches!(
+            config.to_genesis_config(1000),
+            Err(GenesisError::InvalidConfig(_))
+        ));
+    }
 }
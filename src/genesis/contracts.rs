@@ -3,10 +3,102 @@
 //! This module handles the deployment and registration of genesis contracts
 //! in the genesis block.
 
-use crate::contracts::genesis_bounty::GenesisBountyContract;
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::contracts::gas::ContractSchedule;
+use crate::contracts::genesis_bounty::{GenesisBountyContract, GenesisDeploymentConfig};
 use crate::contracts::governance::GovernanceContract;
+use crate::contracts::loader::{ContractLoader, UniversalLoader};
 use crate::contracts::processor::TransactionProcessor;
-use crate::contracts::ContractRegistry;
+use crate::contracts::state::ContractState;
+use crate::contracts::{Contract, ContractError, ContractRegistry, ContractResult};
+use crate::genesis::config::{GenesisConfigToml, PredeployedContractToml};
+use crate::state::AccountState;
+use crate::types::Address;
+
+/// Data-driven description of a single contract to deploy at genesis.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum ContractDeploySpec {
+    /// Deploy `GenesisBountyContract` with the given init config
+    Bounty(GenesisDeploymentConfig),
+    /// Deploy `GovernanceContract` (no per-contract config needed)
+    Governance,
+}
+
+/// Declarative genesis deployment spec, loaded from a chain-spec file
+/// (JSON/TOML) rather than compiled in. This lets operators launch
+/// alternate networks/testnets without recompiling.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct GenesisDeploySpec {
+    /// Timestamp when the bounty period starts (typically genesis timestamp)
+    pub bootstrap_start_time: i64,
+    /// Total voting power to seed the governance contract with at deploy time
+    pub initial_voting_power: u128,
+    /// Maximum gas per transaction
+    pub max_block_gas: u64,
+    /// Contracts to deploy, in order
+    pub contracts: Vec<ContractDeploySpec>,
+}
+
+impl GenesisDeploySpec {
+    /// Build the default spec: bounty + governance contracts, 10M max gas.
+    ///
+    /// The DNS break-glass authority key is freshly generated since this
+    /// constructor has no key material to draw from — operators who need a
+    /// real authority key should build a `GenesisDeploySpec` from a loaded
+    /// chain-spec file instead.
+    #[must_use]
+    pub fn default_for(bootstrap_start_time: i64, initial_voting_power: u128) -> Self {
+        let authority_key = crate::crypto::Keypair::generate().public_key().clone();
+
+        Self {
+            bootstrap_start_time,
+            initial_voting_power,
+            max_block_gas: 10_000_000,
+            contracts: vec![
+                ContractDeploySpec::Bounty(GenesisDeploymentConfig {
+                    // Generic default, same spirit as the freshly-generated
+                    // authority key below — operators who need a real chain
+                    // identity should build from a loaded chain-spec file.
+                    chain_id: "hardclaw-default".to_string(),
+                    airdrop_amount: crate::types::HclawAmount::from_hclaw(100),
+                    founder_airdrop_amount: crate::types::HclawAmount::from_hclaw(250_000),
+                    max_participants: 5_000,
+                    pre_approved: Vec::new(),
+                    bootstrap_nodes: Vec::new(),
+                    bootstrap_node_tokens: crate::types::HclawAmount::from_hclaw(500_000),
+                    dns_break_glass: crate::genesis::DnsBreakGlassConfig {
+                        domain: crate::genesis::BOOTSTRAP_DNS_DOMAIN.to_string(),
+                        max_nodes: crate::genesis::MAX_DNS_BOOTSTRAP_NODES,
+                        tokens_each: crate::types::HclawAmount::from_hclaw(
+                            crate::genesis::DNS_BOOTSTRAP_TOKENS,
+                        ),
+                        vesting_ms: crate::genesis::DNS_BOOTSTRAP_VESTING_MS,
+                        authorized_keys: vec![authority_key],
+                    },
+                    bootstrap_end: 0,
+                    transitions: Vec::new(),
+                    emission_schedule: None,
+                }),
+                ContractDeploySpec::Governance,
+            ],
+        }
+    }
+}
+
+/// Result of deploying genesis contracts from a `GenesisDeploySpec`: the
+/// configured processor plus the account/storage state produced by running
+/// each contract's `on_deploy` hook (e.g. governance's seeded voting power).
+pub struct GenesisDeployment {
+    /// Transaction processor with all spec'd contracts registered
+    pub processor: TransactionProcessor,
+    /// Account state after deploy-time effects were applied
+    pub accounts: HashMap<Address, AccountState>,
+    /// Contract storage after deploy-time effects were applied
+    pub storage: HashMap<(Address, Vec<u8>), Vec<u8>>,
+}
 
 /// Initialize genesis contracts and return a configured transaction processor.
 ///
@@ -16,29 +108,172 @@ use crate::contracts::ContractRegistry;
 ///
 /// # Arguments
 /// * `bounty_start_time` - Timestamp when bounty period starts (typically genesis timestamp)
-/// * `_initial_voting_power` - Total voting power (set via `UpdateVotingPower` transaction after deploy)
+/// * `initial_voting_power` - Total voting power, applied via the `UpdateVotingPower` effect
 ///
 /// # Returns
 /// A `TransactionProcessor` with both contracts registered and ready for execution
+///
+/// This is a thin wrapper around [`initialize_genesis_contracts_from_config`]
+/// that builds the default spec; operators who need alternate contract sets
+/// or parameters should load a `GenesisDeploySpec` from a file instead.
+#[must_use]
 pub fn initialize_genesis_contracts(
     bounty_start_time: i64,
-    _initial_voting_power: u128,
+    initial_voting_power: u128,
 ) -> TransactionProcessor {
+    let spec = GenesisDeploySpec::default_for(bounty_start_time, initial_voting_power);
+    initialize_genesis_contracts_from_config(&spec).processor
+}
+
+/// Deploy genesis contracts according to a data-driven spec.
+///
+/// Registers each contract listed in `spec.contracts`, running its
+/// `on_deploy` hook against scratch state, then applies the
+/// `UpdateVotingPower` effect so `spec.initial_voting_power` is actually in
+/// effect by the time the genesis block is sealed (rather than sitting
+/// unused, as the flat-parameter version did).
+#[must_use]
+pub fn initialize_genesis_contracts_from_config(spec: &GenesisDeploySpec) -> GenesisDeployment {
     let mut registry = ContractRegistry::new();
+    let mut accounts: HashMap<Address, AccountState> = HashMap::new();
+    let mut storage: HashMap<(Address, Vec<u8>), Vec<u8>> = HashMap::new();
+
+    {
+        let mut state = ContractState::new(&mut accounts, &mut storage);
+
+        for entry in &spec.contracts {
+            match entry {
+                ContractDeploySpec::Bounty(config) => {
+                    let contract = GenesisBountyContract::new(spec.bootstrap_start_time);
+                    let init_data = bincode::serialize(config).expect("config serialization");
+                    contract
+                        .on_deploy(&mut state, &init_data)
+                        .expect("genesis bounty on_deploy");
+                    registry.register(Box::new(contract));
+                }
+                ContractDeploySpec::Governance => {
+                    let contract = GovernanceContract::new();
+                    contract
+                        .on_deploy(&mut state, &[])
+                        .expect("governance on_deploy");
+                    contract.set_initial_voting_power(&mut state, spec.initial_voting_power);
+                    registry.register(Box::new(contract));
+                }
+            }
+        }
+
+        state.commit().expect("genesis deploy commit");
+    }
+
+    let processor = TransactionProcessor::with_registry(spec.max_block_gas, registry);
+
+    GenesisDeployment {
+        processor,
+        accounts,
+        storage,
+    }
+}
+
+impl ContractRegistry {
+    /// Build a registry from a `GenesisConfigToml`'s `[[predeployed]]` list.
+    ///
+    /// Each entry is loaded via the universal contract loader (`name` is
+    /// the native marker, e.g. `"native:governance_v1"`), its `on_deploy`
+    /// hook is run against a fresh, isolated `ContractState`, and the
+    /// resulting storage root is checked against `expected_state_root`
+    /// before the contract is registered. This gives every node that
+    /// starts from the same genesis config an identical initial contract
+    /// set — analogous to how chain specs list built-in/predeployed
+    /// accounts — instead of relying on imperative `register` calls to
+    /// stay in sync across nodes.
+    ///
+    /// # Errors
+    /// Returns an error if an entry's hex fields don't parse, its
+    /// declared `id`/`version` don't match the loaded contract's own, or
+    /// the post-`on_deploy` state root doesn't match
+    /// `expected_state_root`.
+    pub fn from_genesis(config: &GenesisConfigToml) -> ContractResult<Self> {
+        let mut registry = Self::new();
+        let loader = UniversalLoader::new();
+
+        for entry in &config.predeployed {
+            registry.register(deploy_predeployed_entry(&loader, entry)?);
+        }
+
+        Ok(registry)
+    }
+}
+
+/// Load, deploy, and verify a single `[[predeployed]]` entry, returning the
+/// ready-to-register contract. Split out of
+/// [`ContractRegistry::from_genesis`] so each entry gets its own scratch
+/// `ContractState` rather than sharing one across the whole loop.
+fn deploy_predeployed_entry(
+    loader: &dyn ContractLoader,
+    entry: &PredeployedContractToml,
+) -> ContractResult<Box<dyn Contract>> {
+    let expected_id = parse_hash_hex(&entry.id, &entry.name)?;
+    let expected_root = parse_hash_hex(&entry.expected_state_root, &entry.name)?;
+    let init_data = hex::decode(&entry.init_state).map_err(|e| {
+        ContractError::InvalidTransaction(format!(
+            "predeployed '{}': invalid init_state hex: {e}",
+            entry.name
+        ))
+    })?;
+
+    // Predeployed genesis entries are native contracts (see
+    // `GenesisConfigToml::predeployed`), so a WASM resource schedule never
+    // applies here; the mainnet defaults are a placeholder `NativeLoader`
+    // ignores outright.
+    let contract = loader.load(expected_id, entry.name.as_bytes(), &ContractSchedule::mainnet())?;
 
-    // Create and register genesis bounty contract
-    let bounty_contract = GenesisBountyContract::new(bounty_start_time);
-    registry.register(Box::new(bounty_contract));
+    if contract.id() != expected_id {
+        return Err(ContractError::InvalidTransaction(format!(
+            "predeployed '{}': declared id {} does not match loaded contract id {}",
+            entry.name,
+            expected_id,
+            contract.id()
+        )));
+    }
+    if contract.version() != entry.version {
+        return Err(ContractError::InvalidTransaction(format!(
+            "predeployed '{}': declared version {} does not match loaded contract version {}",
+            entry.name,
+            entry.version,
+            contract.version()
+        )));
+    }
+
+    let mut accounts: HashMap<Address, AccountState> = HashMap::new();
+    let mut storage: HashMap<(Address, Vec<u8>), Vec<u8>> = HashMap::new();
+    let mut state = ContractState::new(&mut accounts, &mut storage);
+    contract.on_deploy(&mut state, &init_data)?;
+    let computed_root = state.compute_state_root()?;
+
+    if computed_root != expected_root {
+        return Err(ContractError::StateRootMismatch {
+            expected: expected_root,
+            got: computed_root,
+        });
+    }
 
-    // Create and register governance contract
-    // Voting power is storage-backed and initialized to 0 via on_deploy.
-    // Use an UpdateVotingPower transaction to set initial voting power.
-    let governance_contract = GovernanceContract::new();
-    registry.register(Box::new(governance_contract));
+    Ok(contract)
+}
 
-    // Create transaction processor with initialized registry
-    // Use default max gas (10M)
-    TransactionProcessor::with_registry(10_000_000, registry)
+/// Parse a hex-encoded 32-byte hash field from a `[[predeployed]]` entry,
+/// naming `context` (the entry's `name`) in the error so a config with
+/// several entries doesn't leave the operator guessing which one failed.
+fn parse_hash_hex(hex_str: &str, context: &str) -> ContractResult<crate::crypto::Hash> {
+    let bytes = hex::decode(hex_str).map_err(|e| {
+        ContractError::InvalidTransaction(format!("predeployed '{context}': invalid hex: {e}"))
+    })?;
+    let arr: [u8; 32] = bytes.try_into().map_err(|bytes: Vec<u8>| {
+        ContractError::InvalidTransaction(format!(
+            "predeployed '{context}': expected a 32-byte hash, got {} bytes",
+            bytes.len()
+        ))
+    })?;
+    Ok(crate::crypto::Hash::from_bytes(arr))
 }
 
 #[cfg(test)]
@@ -68,4 +303,93 @@ mod tests {
         assert!(processor.registry().get_contract(&bounty_id).is_some());
         assert!(processor.registry().get_contract(&governance_id).is_some());
     }
+
+    #[test]
+    fn test_from_config_applies_initial_voting_power() {
+        let spec = GenesisDeploySpec::default_for(1_000_000, 42_000);
+        let deployment = initialize_genesis_contracts_from_config(&spec);
+
+        assert_eq!(deployment.processor.registry().contract_count(), 2);
+
+        let governance = GovernanceContract::new();
+        let mut accounts = deployment.accounts.clone();
+        let mut storage = deployment.storage.clone();
+        let state = ContractState::new(&mut accounts, &mut storage);
+        assert_eq!(governance.total_voting_power(&state).unwrap(), 42_000);
+    }
+
+    #[test]
+    fn test_from_config_respects_custom_contract_list() {
+        let mut spec = GenesisDeploySpec::default_for(0, 0);
+        spec.contracts = vec![ContractDeploySpec::Governance];
+
+        let deployment = initialize_genesis_contracts_from_config(&spec);
+        assert_eq!(deployment.processor.registry().contract_count(), 1);
+        assert!(deployment
+            .processor
+            .registry()
+            .get_contract(&crate::contracts::governance::GOVERNANCE_CONTRACT_ID)
+            .is_some());
+    }
+
+    #[test]
+    fn test_from_genesis_deploys_and_verifies_predeployed_contracts() {
+        use crate::contracts::governance::GOVERNANCE_CONTRACT_ID;
+
+        // Compute the expected post-deploy root the same way `on_deploy`
+        // will, so this test documents the verification step without
+        // hardcoding a tree-specific hash literal.
+        let mut accounts = HashMap::new();
+        let mut storage = HashMap::new();
+        let mut state = ContractState::new(&mut accounts, &mut storage);
+        GovernanceContract::new()
+            .on_deploy(&mut state, &[])
+            .unwrap();
+        let expected_root = state.compute_state_root().unwrap();
+
+        let mut config = crate::genesis::config::default_testnet_toml();
+        config.predeployed.push(PredeployedContractToml {
+            id: hex::encode(GOVERNANCE_CONTRACT_ID.as_bytes()),
+            name: "native:governance_v1".to_string(),
+            version: 1,
+            init_state: String::new(),
+            expected_state_root: hex::encode(expected_root.as_bytes()),
+        });
+
+        let registry = ContractRegistry::from_genesis(&config).unwrap();
+        assert_eq!(registry.contract_count(), 1);
+        assert!(registry.get_contract(&GOVERNANCE_CONTRACT_ID).is_some());
+    }
+
+    #[test]
+    fn test_from_genesis_rejects_mismatched_state_root() {
+        use crate::contracts::governance::GOVERNANCE_CONTRACT_ID;
+
+        let mut config = crate::genesis::config::default_testnet_toml();
+        config.predeployed.push(PredeployedContractToml {
+            id: hex::encode(GOVERNANCE_CONTRACT_ID.as_bytes()),
+            name: "native:governance_v1".to_string(),
+            version: 1,
+            init_state: String::new(),
+            expected_state_root: "00".repeat(32),
+        });
+
+        assert!(ContractRegistry::from_genesis(&config).is_err());
+    }
+
+    #[test]
+    fn test_from_genesis_rejects_version_mismatch() {
+        use crate::contracts::governance::GOVERNANCE_CONTRACT_ID;
+
+        let mut config = crate::genesis::config::default_testnet_toml();
+        config.predeployed.push(PredeployedContractToml {
+            id: hex::encode(GOVERNANCE_CONTRACT_ID.as_bytes()),
+            name: "native:governance_v1".to_string(),
+            version: 99,
+            init_state: String::new(),
+            expected_state_root: "00".repeat(32),
+        });
+
+        assert!(ContractRegistry::from_genesis(&config).is_err());
+    }
 }
@@ -8,8 +8,17 @@
 //!
 //! A verifier is considered "active" on a given day if they signed at least
 //! `min_attestations_per_day` block attestations during that day's window.
+//!
+//! [`LivenessTracker`] only keeps per-day detail for a sliding `window` of
+//! the most recent days — old days are evicted as the chain advances rather
+//! than growing an ever-larger `Vec`, so the same tracker serves both the
+//! fixed 30-day genesis bootstrap and an indefinite steady-state liveness
+//! requirement. Evicted days aren't forgotten entirely: each verifier's
+//! lifetime active-day count and active-day streak keep accumulating past
+//! eviction, via [`Self::active_days`], [`Self::streak`] and
+//! [`Self::longest_streak`].
 
-use std::collections::{HashMap, HashSet};
+use std::collections::{HashMap, HashSet, VecDeque};
 
 use serde::{Deserialize, Serialize};
 
@@ -71,86 +80,210 @@ impl DailyLiveness {
     }
 }
 
-/// Tracks liveness across the full bootstrap period (30 days)
+/// Tracks liveness over a rolling window of days, with lifetime totals and
+/// streaks surviving past the window so the tracker never needs to retain
+/// every day's detail forever.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct LivenessTracker {
     /// Bootstrap start timestamp (for computing day boundaries)
     bootstrap_start: Timestamp,
-    /// Daily records (index = day number)
-    days: Vec<DailyLiveness>,
-    /// Minimum attestations per day to count as active
+    /// Per-day records still within the retention window, oldest first.
+    /// Always a contiguous run of day numbers ending at `current_day`.
+    days: VecDeque<DailyLiveness>,
+    /// How many of the most recent days to keep full per-day detail for,
+    /// before a day is finalized into the lifetime counters and evicted.
+    window: u32,
+    /// Minimum attestations per day to count as active, before any fork
+    /// schedule override takes effect
     min_attestations: u32,
     /// Current day index (which day are we in)
     current_day: u32,
+    /// Ordered `(activation_day, min_attestations_per_day)` overrides from
+    /// the genesis fork schedule, sorted ascending by `activation_day`. See
+    /// [`Self::min_attestations_for_day`].
+    fork_schedule: Vec<(u32, u32)>,
+    /// Per-verifier count of active days that have already scrolled out of
+    /// the window, so [`Self::active_days`] still reflects the full
+    /// history rather than just what's left in `days`.
+    lifetime_active_days: HashMap<Address, u32>,
+    /// Per-verifier consecutive-active-day streak as of the most recently
+    /// evicted day, i.e. the baseline that the still-in-window days in
+    /// `days` extend or reset. See [`Self::streak`].
+    current_streak: HashMap<Address, u32>,
+    /// Per-verifier longest streak ever observed, including streaks that
+    /// have since scrolled out of the window. See [`Self::longest_streak`].
+    longest_streak: HashMap<Address, u32>,
 }
 
 impl LivenessTracker {
-    /// Create a new tracker
+    /// Create a new tracker with the default (bootstrap-length) retention
+    /// window. Use [`Self::with_window`] to track a different window, e.g.
+    /// an indefinite post-bootstrap liveness requirement.
     #[must_use]
     pub fn new(bootstrap_start: Timestamp) -> Self {
-        let mut days = Vec::with_capacity(BOOTSTRAP_DAYS as usize);
-        for day in 0..BOOTSTRAP_DAYS {
-            days.push(DailyLiveness::new(day, bootstrap_start));
-        }
+        let mut days = VecDeque::with_capacity(BOOTSTRAP_DAYS as usize);
+        days.push_back(DailyLiveness::new(0, bootstrap_start));
 
         Self {
             bootstrap_start,
             days,
+            window: BOOTSTRAP_DAYS,
             min_attestations: MIN_ATTESTATIONS_PER_DAY,
             current_day: 0,
+            fork_schedule: Vec::new(),
+            lifetime_active_days: HashMap::new(),
+            current_streak: HashMap::new(),
+            longest_streak: HashMap::new(),
         }
     }
 
-    /// Record an attestation at a given timestamp.
-    /// Determines which day the timestamp falls in and records it.
-    pub fn record_attestation(&mut self, verifier: &Address, block_timestamp: Timestamp) {
-        if let Some(day) = self.day_for_timestamp(block_timestamp) {
-            if (day as usize) < self.days.len() {
-                self.days[day as usize].record_attestation(verifier);
-                if day > self.current_day {
-                    self.current_day = day;
+    /// Set how many of the most recent days to retain full per-day detail
+    /// for. Days older than this are finalized into the lifetime active-day
+    /// and streak counters and evicted from memory.
+    #[must_use]
+    pub fn with_window(mut self, window: u32) -> Self {
+        self.window = window.max(1);
+        self
+    }
+
+    /// Apply a parameter fork schedule: an ordered list of
+    /// `(activation_day, min_attestations_per_day)` overrides, letting
+    /// operators tighten the liveness threshold partway through bootstrap
+    /// while days scored before an override's `activation_day` keep using
+    /// the rules in force at the time. Entries are sorted by
+    /// `activation_day` internally, so callers may pass them in any order.
+    #[must_use]
+    pub fn with_fork_schedule(mut self, mut schedule: Vec<(u32, u32)>) -> Self {
+        schedule.sort_by_key(|(activation_day, _)| *activation_day);
+        self.fork_schedule = schedule;
+        self
+    }
+
+    /// The `min_attestations_per_day` threshold in force on `day`: the most
+    /// recent fork schedule override with `activation_day <= day`, or the
+    /// tracker's base threshold if none have activated yet.
+    #[must_use]
+    pub fn min_attestations_for_day(&self, day: u32) -> u32 {
+        self.fork_schedule
+            .iter()
+            .rev()
+            .find(|(activation_day, _)| *activation_day <= day)
+            .map_or(self.min_attestations, |(_, threshold)| *threshold)
+    }
+
+    /// Advance the tracker's notion of "now" to `day`, pushing a fresh
+    /// empty record for every day between the current one and `day`
+    /// inclusive, and finalizing+evicting whichever days fall outside the
+    /// retention window as a result. A no-op if `day` is not after the
+    /// current day.
+    pub fn advance_to(&mut self, day: u32) {
+        while self.current_day < day {
+            self.current_day += 1;
+            self.days
+                .push_back(DailyLiveness::new(self.current_day, self.bootstrap_start));
+            if self.days.len() > self.window as usize {
+                if let Some(evicted) = self.days.pop_front() {
+                    self.finalize_day(&evicted);
                 }
             }
         }
     }
 
-    /// Get the day number (0-indexed) for a timestamp
+    /// Fold a day that's about to leave the window into the lifetime
+    /// counters: every verifier ever seen (whether active on this day or
+    /// not) has their streak extended or reset, and active verifiers get
+    /// their lifetime active-day count bumped.
+    fn finalize_day(&mut self, day: &DailyLiveness) {
+        let threshold = self.min_attestations_for_day(day.day);
+
+        let mut verifiers: HashSet<Address> = self.current_streak.keys().copied().collect();
+        verifiers.extend(day.attestations.keys().copied());
+
+        for verifier in verifiers {
+            if day.is_active(&verifier, threshold) {
+                *self.lifetime_active_days.entry(verifier).or_insert(0) += 1;
+                let streak = self.current_streak.entry(verifier).or_insert(0);
+                *streak += 1;
+                let longest = self.longest_streak.entry(verifier).or_insert(0);
+                *longest = (*longest).max(*streak);
+            } else {
+                self.current_streak.insert(verifier, 0);
+            }
+        }
+    }
+
+    /// Record an attestation at a given timestamp.
+    /// Determines which day the timestamp falls in and records it,
+    /// advancing the window if the timestamp is for a day later than any
+    /// seen so far. Attestations for a day that has already scrolled out
+    /// of the window are dropped — that day's outcome is already final.
+    pub fn record_attestation(&mut self, verifier: &Address, block_timestamp: Timestamp) {
+        let Some(day) = self.day_for_timestamp(block_timestamp) else {
+            return;
+        };
+        if day > self.current_day {
+            self.advance_to(day);
+        }
+
+        let Some(oldest) = self.days.front().map(|d| d.day) else {
+            return;
+        };
+        if day < oldest {
+            return;
+        }
+        if let Some(record) = self.days.get_mut((day - oldest) as usize) {
+            record.record_attestation(verifier);
+        }
+    }
+
+    /// Get the day number (0-indexed) for a timestamp, or `None` if it's
+    /// before `bootstrap_start`. Unbounded above — the tracker's window
+    /// slides forward indefinitely rather than capping at a fixed horizon.
     #[must_use]
     pub fn day_for_timestamp(&self, timestamp: Timestamp) -> Option<u32> {
         if timestamp < self.bootstrap_start {
             return None;
         }
-        let day = ((timestamp - self.bootstrap_start) / DAY_MS) as u32;
-        if day >= BOOTSTRAP_DAYS {
-            None
-        } else {
-            Some(day)
-        }
+        Some(((timestamp - self.bootstrap_start) / DAY_MS) as u32)
     }
 
-    /// Count how many days a verifier has been active (met liveness threshold)
+    /// Total days a verifier has been active, across both days already
+    /// evicted from the window (counted via the lifetime counter) and
+    /// days still in the window.
     #[must_use]
     pub fn active_days(&self, verifier: &Address) -> u32 {
-        self.days
+        let lifetime = self
+            .lifetime_active_days
+            .get(verifier)
+            .copied()
+            .unwrap_or(0);
+        let in_window = self
+            .days
             .iter()
-            .filter(|d| d.is_active(verifier, self.min_attestations))
-            .count() as u32
+            .filter(|d| d.is_active(verifier, self.min_attestations_for_day(d.day)))
+            .count() as u32;
+        lifetime + in_window
     }
 
-    /// Check if a verifier was active on a specific day
+    /// Check if a verifier was active on a specific day. Only answers for
+    /// days still in the retention window — an evicted day's per-day
+    /// detail is gone, folded into [`Self::active_days`] and the streak
+    /// counters instead.
     #[must_use]
     pub fn was_active_on_day(&self, verifier: &Address, day: u32) -> bool {
         self.days
-            .get(day as usize)
-            .is_some_and(|d| d.is_active(verifier, self.min_attestations))
+            .iter()
+            .find(|d| d.day == day)
+            .is_some_and(|d| d.is_active(verifier, self.min_attestations_for_day(day)))
     }
 
-    /// Get the list of days a verifier was active (for vesting calculation)
+    /// Get the list of days (within the retention window) a verifier was
+    /// active on, for vesting calculation.
     #[must_use]
     pub fn active_day_list(&self, verifier: &Address) -> Vec<u32> {
         self.days
             .iter()
-            .filter(|d| d.is_active(verifier, self.min_attestations))
+            .filter(|d| d.is_active(verifier, self.min_attestations_for_day(d.day)))
             .map(|d| d.day)
             .collect()
     }
@@ -161,15 +294,71 @@ impl LivenessTracker {
         self.current_day
     }
 
-    /// Get attestation count for a verifier on a specific day
+    /// Get attestation count for a verifier on a specific day. Zero for a
+    /// day that's scrolled out of the retention window.
     #[must_use]
     pub fn attestation_count(&self, verifier: &Address, day: u32) -> u32 {
         self.days
-            .get(day as usize)
+            .iter()
+            .find(|d| d.day == day)
             .and_then(|d| d.attestations.get(verifier))
             .copied()
             .unwrap_or(0)
     }
+
+    /// Graduated participation for a verifier on a specific day, as a
+    /// fraction of `min_attestations` in `[0.0, 1.0]`.
+    ///
+    /// Unlike [`Self::was_active_on_day`] (a hard pass/fail threshold),
+    /// this lets vesting credit partial effort: a verifier who attested
+    /// half the required count gets half that day's vesting portion
+    /// rather than none.
+    #[must_use]
+    pub fn participation_ratio(&self, verifier: &Address, day: u32) -> f64 {
+        let threshold = self.min_attestations_for_day(day);
+        if threshold == 0 {
+            return 1.0;
+        }
+        let count = self.attestation_count(verifier, day);
+        (f64::from(count) / f64::from(threshold)).min(1.0)
+    }
+
+    /// A verifier's current consecutive-active-day streak, ending at the
+    /// most recent day known to the tracker (whether or not that day has
+    /// scrolled out of the window yet).
+    #[must_use]
+    pub fn streak(&self, verifier: &Address) -> u32 {
+        let mut streak = self.current_streak.get(verifier).copied().unwrap_or(0);
+        for day in &self.days {
+            let threshold = self.min_attestations_for_day(day.day);
+            if day.is_active(verifier, threshold) {
+                streak += 1;
+            } else {
+                streak = 0;
+            }
+        }
+        streak
+    }
+
+    /// The longest consecutive-active-day streak a verifier has ever had,
+    /// including streaks that have since scrolled out of the window.
+    #[must_use]
+    pub fn longest_streak(&self, verifier: &Address) -> u32 {
+        let mut longest = self.longest_streak.get(verifier).copied().unwrap_or(0);
+        let mut streak = self.current_streak.get(verifier).copied().unwrap_or(0);
+        longest = longest.max(streak);
+
+        for day in &self.days {
+            let threshold = self.min_attestations_for_day(day.day);
+            if day.is_active(verifier, threshold) {
+                streak += 1;
+                longest = longest.max(streak);
+            } else {
+                streak = 0;
+            }
+        }
+        longest
+    }
 }
 
 #[cfg(test)]
@@ -190,7 +379,9 @@ mod tests {
         assert_eq!(tracker.day_for_timestamp(DAY_MS - 1), Some(0));
         assert_eq!(tracker.day_for_timestamp(DAY_MS), Some(1));
         assert_eq!(tracker.day_for_timestamp(29 * DAY_MS), Some(29));
-        assert_eq!(tracker.day_for_timestamp(30 * DAY_MS), None); // Past bootstrap
+        // Unlike the old fixed-length bootstrap array, the window slides
+        // forward indefinitely rather than running out at day 30.
+        assert_eq!(tracker.day_for_timestamp(30 * DAY_MS), Some(30));
     }
 
     #[test]
@@ -237,6 +428,35 @@ mod tests {
         assert_eq!(days, vec![0, 1, 5]);
     }
 
+    #[test]
+    fn test_participation_ratio_graduated() {
+        let start = 0i64;
+        let mut tracker = LivenessTracker::new(start);
+        let verifier = test_addr();
+
+        // Half the required attestations => half credit
+        for _ in 0..(MIN_ATTESTATIONS_PER_DAY / 2) {
+            tracker.record_attestation(&verifier, 1000);
+        }
+        assert!((tracker.participation_ratio(&verifier, 0) - 0.5).abs() < f64::EPSILON);
+        assert!(!tracker.was_active_on_day(&verifier, 0));
+
+        // No attestations on a day => zero credit
+        assert_eq!(tracker.participation_ratio(&verifier, 1), 0.0);
+    }
+
+    #[test]
+    fn test_participation_ratio_caps_at_one() {
+        let start = 0i64;
+        let mut tracker = LivenessTracker::new(start);
+        let verifier = test_addr();
+
+        for _ in 0..(MIN_ATTESTATIONS_PER_DAY * 3) {
+            tracker.record_attestation(&verifier, 1000);
+        }
+        assert_eq!(tracker.participation_ratio(&verifier, 0), 1.0);
+    }
+
     #[test]
     fn test_multiple_verifiers_same_day() {
         let start = 0i64;
@@ -255,4 +475,107 @@ mod tests {
         assert!(tracker.was_active_on_day(&v1, 0));
         assert!(!tracker.was_active_on_day(&v2, 0));
     }
+
+    #[test]
+    fn test_fork_schedule_tightens_threshold_from_activation_day() {
+        let start = 0i64;
+        // Threshold doubles starting day 2, regardless of insertion order.
+        let tracker = LivenessTracker::new(start).with_fork_schedule(vec![(2, 200)]);
+        let verifier = test_addr();
+
+        assert_eq!(
+            tracker.min_attestations_for_day(0),
+            MIN_ATTESTATIONS_PER_DAY
+        );
+        assert_eq!(
+            tracker.min_attestations_for_day(1),
+            MIN_ATTESTATIONS_PER_DAY
+        );
+        assert_eq!(tracker.min_attestations_for_day(2), 200);
+        assert_eq!(tracker.min_attestations_for_day(10), 200);
+
+        let mut tracker = tracker;
+        // 100 attestations meets the pre-fork threshold on day 0...
+        for _ in 0..MIN_ATTESTATIONS_PER_DAY {
+            tracker.record_attestation(&verifier, 1000);
+        }
+        assert!(tracker.was_active_on_day(&verifier, 0));
+
+        // ...but the same 100 falls short of the tightened threshold on day 2.
+        for _ in 0..MIN_ATTESTATIONS_PER_DAY {
+            tracker.record_attestation(&verifier, 2 * DAY_MS + 1000);
+        }
+        assert!(!tracker.was_active_on_day(&verifier, 2));
+        assert!((tracker.participation_ratio(&verifier, 2) - 0.5).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_fork_schedule_sorts_out_of_order_entries() {
+        let tracker =
+            LivenessTracker::new(0).with_fork_schedule(vec![(10, 300), (5, 150), (0, 50)]);
+
+        assert_eq!(tracker.min_attestations_for_day(0), 50);
+        assert_eq!(tracker.min_attestations_for_day(4), 50);
+        assert_eq!(tracker.min_attestations_for_day(5), 150);
+        assert_eq!(tracker.min_attestations_for_day(9), 150);
+        assert_eq!(tracker.min_attestations_for_day(10), 300);
+    }
+
+    #[test]
+    fn test_window_eviction_preserves_lifetime_active_days() {
+        let start = 0i64;
+        let mut tracker = LivenessTracker::new(start).with_window(3);
+        let verifier = test_addr();
+
+        // Active every day for 5 days, with only a 3-day window retained.
+        for day in 0..5u32 {
+            let ts = i64::from(day) * DAY_MS + 1000;
+            for _ in 0..MIN_ATTESTATIONS_PER_DAY {
+                tracker.record_attestation(&verifier, ts);
+            }
+        }
+
+        // Days 0 and 1 have scrolled out of the 3-day window (2, 3, 4
+        // remain), but the lifetime total still counts all 5.
+        assert_eq!(tracker.active_days(&verifier), 5);
+        assert!(!tracker.was_active_on_day(&verifier, 0));
+        assert!(tracker.was_active_on_day(&verifier, 4));
+    }
+
+    #[test]
+    fn test_streak_resets_on_gap_and_tracks_across_eviction() {
+        let start = 0i64;
+        let mut tracker = LivenessTracker::new(start).with_window(2);
+        let verifier = test_addr();
+
+        // Active on days 0 and 1, idle on day 2, active again on day 3.
+        for day in [0u32, 1, 3] {
+            let ts = i64::from(day) * DAY_MS + 1000;
+            for _ in 0..MIN_ATTESTATIONS_PER_DAY {
+                tracker.record_attestation(&verifier, ts);
+            }
+        }
+        tracker.advance_to(3);
+
+        assert_eq!(tracker.streak(&verifier), 1);
+        assert_eq!(tracker.longest_streak(&verifier), 2);
+        assert_eq!(tracker.active_days(&verifier), 3);
+    }
+
+    #[test]
+    fn test_longest_streak_survives_a_later_gap() {
+        let start = 0i64;
+        let mut tracker = LivenessTracker::new(start).with_window(10);
+        let verifier = test_addr();
+
+        for day in [0u32, 1, 2, 4] {
+            let ts = i64::from(day) * DAY_MS + 1000;
+            for _ in 0..MIN_ATTESTATIONS_PER_DAY {
+                tracker.record_attestation(&verifier, ts);
+            }
+        }
+
+        assert_eq!(tracker.streak(&verifier), 1);
+        assert_eq!(tracker.longest_streak(&verifier), 3);
+    }
 }
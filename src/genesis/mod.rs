@@ -8,15 +8,29 @@
 pub mod airdrop;
 pub mod bootstrap;
 pub mod bounty;
+pub mod break_glass;
 pub mod competency;
 pub mod config;
 pub mod contracts;
 pub mod liveness;
 pub mod vesting;
 
+/// Bundled named network specs, embedded at compile time so
+/// [`GenesisConfig::from_named`] never needs an external file.
+mod presets {
+    /// Production `HardClaw` mainnet genesis spec
+    pub const MAINNET: &str = include_str!("presets/mainnet.toml");
+    /// Public testnet genesis spec
+    pub const TESTNET: &str = include_str!("presets/testnet.toml");
+    /// Single-node local development genesis spec
+    pub const DEV: &str = include_str!("presets/dev.toml");
+}
+
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
 
-use crate::crypto::{hash_data, Hash, PublicKey};
+use crate::crypto::{hash_data, Hash, PublicKey, Signature};
 use crate::types::{Address, HclawAmount, Timestamp};
 
 /// Duration of the bootstrap period (30 days in milliseconds)
@@ -52,6 +66,10 @@ pub const DNS_BOOTSTRAP_VESTING_MS: i64 = DAY_MS;
 /// DNS domain that authorizes bootstrap nodes
 pub const BOOTSTRAP_DNS_DOMAIN: &str = "clawpaper.com";
 
+/// Consecutive inactive days before a verifier is slashed for prolonged
+/// downtime, absent an override in [`GenesisConfig::max_missed_days`]
+pub const DEFAULT_MAX_MISSED_DAYS: u32 = 3;
+
 /// The genesis bootstrap job — the chain's first task
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct BootstrapJob {
@@ -104,8 +122,9 @@ pub struct BootstrapCompletionCriteria {
 /// bringing authoritative nodes online or injecting liquidity.
 ///
 /// Security: DNS resolution alone is NOT sufficient. The DNS TXT record
-/// must contain a signature over the node's public key, signed by the
-/// genesis authority key. This prevents DNS hijacking from claiming tokens.
+/// must contain a signature over the claim's canonical fields (see
+/// [`DnsBootstrapClaim::signing_message`]), signed by one of
+/// `authorized_keys`. This prevents DNS hijacking from claiming tokens.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct DnsBreakGlassConfig {
     /// Domain to resolve bootstrap nodes from
@@ -116,10 +135,12 @@ pub struct DnsBreakGlassConfig {
     pub tokens_each: HclawAmount,
     /// Vesting period (24 hours)
     pub vesting_ms: i64,
-    /// Authority public key — DNS TXT records must contain a signature
-    /// from this key over the node's public key to be valid.
-    /// This protects against DNS hijacking.
-    pub authority_key: PublicKey,
+    /// Authority public keys — a DNS TXT record is only valid if it
+    /// carries a signature from one of these, over the claim's canonical
+    /// message. A small set rather than a single key supports
+    /// ACME-style key rotation: a new key can be added and old records
+    /// keep verifying against the previous one until they're replaced.
+    pub authorized_keys: Vec<PublicKey>,
 }
 
 /// A DNS break-glass claim
@@ -137,6 +158,78 @@ pub struct DnsBootstrapClaim {
     pub claimed_at: Timestamp,
     /// When fully vested (`claimed_at` + 24h)
     pub vests_at: Timestamp,
+    /// Which authority key signed `authority_sig`, recorded so audits can
+    /// attribute each accepted break-glass grant to the key that approved
+    /// it — useful once `authorized_keys` holds more than one entry.
+    pub authorized_by: PublicKey,
+    /// Signature by `authorized_by` over [`Self::signing_message`],
+    /// checked in [`BootstrapState::process_dns_claim`](super::bootstrap::BootstrapState::process_dns_claim).
+    pub authority_sig: Signature,
+}
+
+impl DnsBootstrapClaim {
+    /// Canonical message an authority key signs to authorize this claim:
+    /// `node_key`, `address`, `hostname`, and `amount`, in that order.
+    /// `claimed_at` is deliberately excluded — it's submission-time
+    /// bookkeeping set by whoever calls `process_dns_claim`, not something
+    /// an authority key can attest to ahead of publishing a DNS record.
+    #[must_use]
+    pub fn signing_message(&self) -> Vec<u8> {
+        dns_claim_signing_message(&self.node_key, &self.address, &self.hostname, self.amount)
+    }
+}
+
+/// Builds [`DnsBootstrapClaim::signing_message`]'s byte encoding from its
+/// constituent fields directly, for callers (like DNS TXT record
+/// verification) that need to find the authorizing key before a full
+/// [`DnsBootstrapClaim`] exists to call the method on.
+#[must_use]
+pub fn dns_claim_signing_message(
+    node_key: &PublicKey,
+    address: &Address,
+    hostname: &str,
+    amount: HclawAmount,
+) -> Vec<u8> {
+    let mut data = Vec::new();
+    data.extend_from_slice(node_key.as_bytes());
+    data.extend_from_slice(address.as_bytes());
+    data.extend_from_slice(hostname.as_bytes());
+    data.extend_from_slice(&amount.raw().to_le_bytes());
+    data
+}
+
+/// A single directly-seeded genesis account: an Ethereum-chain-spec-style
+/// `prealloc` entry carrying a balance, a starting nonce, and optional
+/// contract code, at an address chosen by whoever assembled the config
+/// rather than one derived from the airdrop/DNS-break-glass pools.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct GenesisAccount {
+    /// Balance this address starts with
+    pub balance: HclawAmount,
+    /// Starting nonce (non-zero lets a prealloc entry model an account
+    /// that has already "transacted" before genesis, e.g. a migrated one)
+    pub nonce: u64,
+    /// Contract bytecode, if this prealloc entry is a contract account
+    /// rather than a plain wallet
+    #[serde(default)]
+    pub code: Option<Vec<u8>>,
+}
+
+/// A built-in/precompile contract mounted at a fixed address, the way a
+/// chain spec lists builtins rather than deploying them through an
+/// ordinary transaction. `code_ref` is the same `native:`/WASM code
+/// reference `ContractLoader::load` already accepts for
+/// [`crate::genesis::config::PredeployedContractToml`] entries;
+/// `activate_at_height` lets a builtin be declared at genesis but only
+/// start accepting calls once the chain reaches that height.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct GenesisBuiltin {
+    /// Fixed address this builtin is mounted at
+    pub address: Address,
+    /// `native:`/WASM code reference, passed to `ContractLoader::load`
+    pub code_ref: String,
+    /// Block height at which the builtin starts accepting calls
+    pub activate_at_height: u64,
 }
 
 /// The full genesis configuration embedded in block 0
@@ -167,6 +260,23 @@ pub struct GenesisConfig {
     /// Only used if `deploy_contracts` is true
     #[serde(default)]
     pub initial_voting_power: u128,
+    /// Directly-seeded accounts (system accounts, treasury balances,
+    /// migrated wallets), keyed by address. Sorted iteration order makes
+    /// [`Self::genesis_state_root`] deterministic without re-sorting.
+    #[serde(default)]
+    pub prealloc: BTreeMap<Address, GenesisAccount>,
+    /// Built-in/precompile contracts mounted at fixed addresses
+    #[serde(default)]
+    pub builtins: Vec<GenesisBuiltin>,
+    /// Consecutive inactive days after which a verifier's still-locked
+    /// vesting is slashed for prolonged downtime. See
+    /// [`BootstrapState::detect_downtime`](super::bootstrap::BootstrapState::detect_downtime).
+    #[serde(default = "default_max_missed_days")]
+    pub max_missed_days: u32,
+}
+
+fn default_max_missed_days() -> u32 {
+    DEFAULT_MAX_MISSED_DAYS
 }
 
 impl GenesisConfig {
@@ -192,13 +302,16 @@ impl GenesisConfig {
                 max_nodes: MAX_DNS_BOOTSTRAP_NODES,
                 tokens_each: HclawAmount::from_hclaw(DNS_BOOTSTRAP_TOKENS),
                 vesting_ms: DNS_BOOTSTRAP_VESTING_MS,
-                authority_key,
+                authorized_keys: vec![authority_key],
             },
             bootstrap_start: now,
             bootstrap_end: now + BOOTSTRAP_DURATION_MS,
             protocol_version: 1,
             deploy_contracts: false, // default to opt-in
             initial_voting_power: 0,
+            prealloc: BTreeMap::new(),
+            builtins: Vec::new(),
+            max_missed_days: DEFAULT_MAX_MISSED_DAYS,
         }
     }
 
@@ -215,11 +328,89 @@ impl GenesisConfig {
         for addr in &self.pre_approved {
             data.extend_from_slice(addr.as_bytes());
         }
-        data.extend_from_slice(self.dns_break_glass.authority_key.as_bytes());
+        for key in &self.dns_break_glass.authorized_keys {
+            data.extend_from_slice(key.as_bytes());
+        }
+        data.extend_from_slice(self.genesis_state_root().as_bytes());
+        data.extend_from_slice(self.builtins_hash().as_bytes());
+        hash_data(&data)
+    }
+
+    /// Every genesis account this config fixes a balance for at block 0:
+    /// `pre_approved` addresses (credited `airdrop_amount` at nonce 0) plus
+    /// every `prealloc` entry. Ordinary airdrop claims and DNS break-glass
+    /// grants are decided during the bootstrap window, after genesis, so
+    /// — like Ethereum's genesis `alloc` — they aren't part of this set.
+    #[must_use]
+    fn genesis_accounts(&self) -> Vec<(Address, HclawAmount, u64)> {
+        let mut accounts: Vec<(Address, HclawAmount, u64)> = self
+            .pre_approved
+            .iter()
+            .map(|addr| (*addr, self.airdrop_amount.clone(), 0))
+            .collect();
+        accounts.extend(
+            self.prealloc
+                .iter()
+                .map(|(addr, account)| (*addr, account.balance.clone(), account.nonce)),
+        );
+        accounts
+    }
+
+    /// Merkle root over every [`Self::genesis_accounts`] entry, so a light
+    /// client can prove a single genesis allocation is part of this
+    /// config without the whole account list. See [`genesis_leaf_hash`]
+    /// for the leaf encoding and [`fold_genesis_leaf`] for how a sibling
+    /// path folds up to this root.
+    #[must_use]
+    pub fn genesis_state_root(&self) -> Hash {
+        let leaves = sorted_genesis_leaves(&self.genesis_accounts());
+        build_genesis_tree(&leaves)
+            .last()
+            .and_then(|level| level.first())
+            .cloned()
+            .unwrap_or_else(|| hash_data(b""))
+    }
+
+    /// Build `address`'s inclusion proof against [`Self::genesis_state_root`]:
+    /// the sibling hash at every level from its leaf up to the root.
+    /// Returns `None` if `address` isn't a [`Self::genesis_accounts`] entry.
+    #[must_use]
+    pub fn genesis_proof(&self, address: &Address) -> Option<Vec<Hash>> {
+        let accounts = self.genesis_accounts();
+        let (amount, nonce) = accounts
+            .iter()
+            .find(|(addr, _, _)| addr == address)
+            .map(|(_, amount, nonce)| (amount.clone(), *nonce))?;
+        let leaf = genesis_leaf_hash(address, &amount, nonce);
+        let leaves = sorted_genesis_leaves(&accounts);
+        let index = leaves.iter().position(|l| l == &leaf)?;
+        Some(build_genesis_proof(&leaves, index))
+    }
+
+    /// Deterministic hash over `builtins`, folded into [`Self::config_hash`]
+    /// directly rather than through [`Self::genesis_state_root`] — a
+    /// builtin has no balance/nonce, so it doesn't fit that tree's leaf
+    /// shape, but every node loading this config must still agree on the
+    /// exact builtin set.
+    #[must_use]
+    fn builtins_hash(&self) -> Hash {
+        let mut data = Vec::new();
+        for builtin in &self.builtins {
+            data.extend_from_slice(builtin.address.as_bytes());
+            data.extend_from_slice(builtin.code_ref.as_bytes());
+            data.extend_from_slice(&builtin.activate_at_height.to_le_bytes());
+        }
         hash_data(&data)
     }
 
     /// Validate the config
+    ///
+    /// # Errors
+    /// Returns [`GenesisError::InvalidConfig`] if `chain_id`,
+    /// `max_participants`, or `airdrop_amount` are unset, if DNS
+    /// break-glass allows more than 10 nodes, if a `prealloc` address
+    /// collides with an airdrop slot (`pre_approved`), or if `builtins`
+    /// declare a duplicate address or collide with a `prealloc` entry.
     pub fn validate(&self) -> Result<(), GenesisError> {
         if self.chain_id.is_empty() {
             return Err(GenesisError::InvalidConfig("chain_id is empty".into()));
@@ -237,11 +428,78 @@ impl GenesisConfig {
                 "DNS break-glass max_nodes cannot exceed 10".into(),
             ));
         }
+        for addr in self.prealloc.keys() {
+            if self.pre_approved.contains(addr) {
+                return Err(GenesisError::InvalidConfig(format!(
+                    "prealloc address {addr} overlaps an airdrop (pre_approved) slot"
+                )));
+            }
+        }
+        let mut builtin_addresses = std::collections::HashSet::new();
+        for builtin in &self.builtins {
+            if !builtin_addresses.insert(builtin.address) {
+                return Err(GenesisError::InvalidConfig(format!(
+                    "duplicate builtin address {}",
+                    builtin.address
+                )));
+            }
+            if self.prealloc.contains_key(&builtin.address) {
+                return Err(GenesisError::InvalidConfig(format!(
+                    "builtin address {} overlaps a prealloc entry",
+                    builtin.address
+                )));
+            }
+        }
         Ok(())
     }
 
+    /// Load a genesis config from a TOML spec file, parsing every field
+    /// via [`config::GenesisConfigToml::to_genesis_config`] and running
+    /// [`Self::validate`] before returning it — a malformed or
+    /// inconsistent spec never reaches a running node.
+    ///
+    /// # Errors
+    /// Returns [`GenesisError::Io`] if `path` can't be read,
+    /// [`GenesisError::ParseError`] if it isn't valid TOML, or
+    /// [`GenesisError::InvalidConfig`] if it parses but fails validation.
+    pub fn load_from_spec(path: &std::path::Path, now: Timestamp) -> Result<Self, GenesisError> {
+        let toml_config = config::GenesisConfigToml::load_from_file(path)?;
+        let genesis_config = toml_config.to_genesis_config(now)?;
+        genesis_config.validate()?;
+        Ok(genesis_config)
+    }
+
+    /// Load one of the bundled named presets (`"mainnet"`, `"testnet"`, or
+    /// `"dev"`), embedded at compile time so this works with no external
+    /// files — the same role Ethereum clients' bundled `Spec` JSON plays
+    /// for `--chain mainnet`/`--chain dev`.
+    ///
+    /// # Errors
+    /// Returns [`GenesisError::InvalidConfig`] for an unknown preset name,
+    /// [`GenesisError::ParseError`] if a bundled preset's TOML is
+    /// malformed, or [`GenesisError::InvalidConfig`] if it fails
+    /// validation.
+    pub fn from_named(name: &str, now: Timestamp) -> Result<Self, GenesisError> {
+        let raw = match name {
+            "mainnet" => presets::MAINNET,
+            "testnet" => presets::TESTNET,
+            "dev" => presets::DEV,
+            other => {
+                return Err(GenesisError::InvalidConfig(format!(
+                    "unknown genesis preset '{other}' (expected mainnet, testnet, or dev)"
+                )))
+            }
+        };
+        let toml_config: config::GenesisConfigToml =
+            toml::from_str(raw).map_err(|e| GenesisError::ParseError(e.to_string()))?;
+        let genesis_config = toml_config.to_genesis_config(now)?;
+        genesis_config.validate()?;
+        Ok(genesis_config)
+    }
+
     /// Total maximum supply that could be minted at genesis
-    /// (airdrop pool + full DNS break-glass reserve)
+    /// (airdrop pool + full DNS break-glass reserve + every `prealloc`
+    /// balance)
     #[must_use]
     pub fn max_genesis_supply(&self) -> HclawAmount {
         let airdrop_total =
@@ -249,8 +507,108 @@ impl GenesisConfig {
         let dns_reserve = HclawAmount::from_raw(
             self.dns_break_glass.tokens_each.raw() * self.dns_break_glass.max_nodes as u128,
         );
-        airdrop_total.saturating_add(dns_reserve)
+        let prealloc_total: u128 = self.prealloc.values().map(|account| account.balance.raw()).sum();
+        airdrop_total
+            .saturating_add(dns_reserve)
+            .saturating_add(HclawAmount::from_raw(prealloc_total))
+    }
+}
+
+/// A genesis account leaf: `hash_data(address_bytes ++ amount_raw_le_bytes
+/// ++ nonce_le_bytes)`. `nonce` is `0` for every plain `pre_approved`
+/// airdrop slot, but a `prealloc` entry can declare a nonzero starting
+/// nonce, so the leaf shape matches
+/// [`crate::contracts::state::ContractState::compute_state_root`]'s per-account
+/// hash, which folds in a live `nonce`.
+fn genesis_leaf_hash(address: &Address, amount: &HclawAmount, nonce: u64) -> Hash {
+    let mut data = Vec::new();
+    data.extend_from_slice(address.as_bytes());
+    data.extend_from_slice(&amount.raw().to_le_bytes());
+    data.extend_from_slice(&nonce.to_le_bytes());
+    hash_data(&data)
+}
+
+/// Leaf hashes for every `accounts` entry, sorted by address bytes for a
+/// deterministic tree regardless of input order.
+fn sorted_genesis_leaves(accounts: &[(Address, HclawAmount, u64)]) -> Vec<Hash> {
+    let mut sorted = accounts.to_vec();
+    sorted.sort_by(|(a, _, _), (b, _, _)| a.as_bytes().cmp(b.as_bytes()));
+    sorted
+        .iter()
+        .map(|(addr, amount, nonce)| genesis_leaf_hash(addr, amount, *nonce))
+        .collect()
+}
+
+/// Fold two sibling nodes into their parent. Pairing is order-independent
+/// (`hash_data(min(left, right) ++ max(left, right))`), so a proof
+/// doesn't need to track which side of the tree each sibling came from —
+/// the same convention the Merkle-distributor airdrop's proof folding
+/// (`genesis::airdrop`) uses.
+fn fold_genesis_leaf(left: &Hash, right: &Hash) -> Hash {
+    let (lo, hi) = if left.as_bytes() <= right.as_bytes() {
+        (left, right)
+    } else {
+        (right, left)
+    };
+    let mut data = Vec::new();
+    data.extend_from_slice(lo.as_bytes());
+    data.extend_from_slice(hi.as_bytes());
+    hash_data(&data)
+}
+
+/// Build every level of the binary Merkle tree over `leaves`, root last.
+/// An odd level duplicates its last node to pair with itself; an empty
+/// tree's "root" is `hash_data(b"")`.
+fn build_genesis_tree(leaves: &[Hash]) -> Vec<Vec<Hash>> {
+    if leaves.is_empty() {
+        return vec![vec![hash_data(b"")]];
+    }
+
+    let mut levels = vec![leaves.to_vec()];
+    while levels.last().map(Vec::len).unwrap_or(0) > 1 {
+        let current = levels.last().expect("just checked non-empty");
+        let mut next = Vec::with_capacity(current.len().div_ceil(2));
+        for pair in current.chunks(2) {
+            let right = pair.get(1).unwrap_or(&pair[0]);
+            next.push(fold_genesis_leaf(&pair[0], right));
+        }
+        levels.push(next);
+    }
+    levels
+}
+
+/// Build `leaves[index]`'s sibling path up to the tree's root.
+fn build_genesis_proof(leaves: &[Hash], mut index: usize) -> Vec<Hash> {
+    let levels = build_genesis_tree(leaves);
+    let mut proof = Vec::with_capacity(levels.len().saturating_sub(1));
+    for level in &levels[..levels.len() - 1] {
+        let sibling_index = if index % 2 == 0 { index + 1 } else { index - 1 };
+        let sibling = level.get(sibling_index).unwrap_or(&level[index]);
+        proof.push(sibling.clone());
+        index /= 2;
+    }
+    proof
+}
+
+/// Verify that `(address, amount, nonce)` is a genesis account committed
+/// under `root`, by folding its leaf hash up through `proof`. `nonce` is
+/// `0` for an ordinary airdrop slot; pass the declared starting nonce
+/// when checking a `prealloc` entry. Use this to check a wallet's own
+/// genesis allocation (or any other node's) against block 0's hash
+/// without fetching the whole [`GenesisConfig`].
+#[must_use]
+pub fn verify_genesis_inclusion(
+    root: &Hash,
+    address: &Address,
+    amount: &HclawAmount,
+    nonce: u64,
+    proof: &[Hash],
+) -> bool {
+    let mut node = genesis_leaf_hash(address, amount, nonce);
+    for sibling in proof {
+        node = fold_genesis_leaf(&node, sibling);
     }
+    &node == root
 }
 
 /// Genesis-related errors
@@ -277,6 +635,10 @@ pub enum GenesisError {
     /// Invalid DNS break-glass claim
     #[error("DNS break-glass: {0}")]
     DnsBreakGlassInvalid(String),
+    /// DNS break-glass claim's signature didn't verify against any
+    /// authorized authority key
+    #[error("DNS break-glass claim is not authorized by a known authority key")]
+    DnsBreakGlassUnauthorized,
     /// Liveness requirement not met
     #[error("liveness requirement not met for day {day}")]
     LivenessNotMet {
@@ -289,6 +651,18 @@ pub enum GenesisError {
     /// TOML parse error
     #[error("config parse error: {0}")]
     ParseError(String),
+    /// Slashing evidence failed verification
+    #[error("invalid slash evidence: {0}")]
+    InvalidSlashEvidence(String),
+    /// Replaying a bootstrap event log reconstructed a different state
+    /// than the peer-supplied root
+    #[error("bootstrap catch-up state root mismatch")]
+    StateRootMismatch {
+        /// Root the caller expected to reach
+        expected: Hash,
+        /// Root actually reconstructed by replay
+        actual: Hash,
+    },
 }
 
 #[cfg(test)]
@@ -325,6 +699,144 @@ mod tests {
         assert_eq!(cfg1.config_hash(), cfg2.config_hash());
     }
 
+    #[test]
+    fn test_genesis_proof_verifies_against_state_root() {
+        let addrs = test_addresses(5);
+        let authority = Keypair::generate();
+        let cfg = GenesisConfig::new("test".into(), addrs.clone(), authority.public_key().clone(), 1000);
+        let root = cfg.genesis_state_root();
+
+        for addr in &addrs {
+            let proof = cfg.genesis_proof(addr).expect("pre-approved address has a proof");
+            assert!(verify_genesis_inclusion(
+                &root,
+                addr,
+                &cfg.airdrop_amount,
+                0,
+                &proof
+            ));
+        }
+    }
+
+    #[test]
+    fn test_genesis_proof_rejects_wrong_amount() {
+        let addrs = test_addresses(3);
+        let authority = Keypair::generate();
+        let cfg = GenesisConfig::new("test".into(), addrs.clone(), authority.public_key().clone(), 1000);
+        let root = cfg.genesis_state_root();
+        let proof = cfg.genesis_proof(&addrs[0]).unwrap();
+
+        assert!(!verify_genesis_inclusion(
+            &root,
+            &addrs[0],
+            &HclawAmount::from_raw(cfg.airdrop_amount.raw() + 1),
+            0,
+            &proof
+        ));
+    }
+
+    #[test]
+    fn test_prealloc_account_covered_by_genesis_proof() {
+        let authority = Keypair::generate();
+        let mut cfg = GenesisConfig::new("test".into(), Vec::new(), authority.public_key().clone(), 1000);
+        let treasury = Address::from_public_key(Keypair::generate().public_key());
+        cfg.prealloc.insert(
+            treasury,
+            GenesisAccount {
+                balance: HclawAmount::from_hclaw(1_000_000),
+                nonce: 7,
+                code: None,
+            },
+        );
+        let root = cfg.genesis_state_root();
+        let proof = cfg.genesis_proof(&treasury).expect("prealloc address has a proof");
+
+        assert!(verify_genesis_inclusion(
+            &root,
+            &treasury,
+            &HclawAmount::from_hclaw(1_000_000),
+            7,
+            &proof
+        ));
+    }
+
+    #[test]
+    fn test_max_genesis_supply_includes_prealloc() {
+        let authority = Keypair::generate();
+        let mut cfg = GenesisConfig::new("test".into(), Vec::new(), authority.public_key().clone(), 1000);
+        let without_prealloc = cfg.max_genesis_supply();
+
+        let treasury = Address::from_public_key(Keypair::generate().public_key());
+        cfg.prealloc.insert(
+            treasury,
+            GenesisAccount {
+                balance: HclawAmount::from_hclaw(1_000_000),
+                nonce: 0,
+                code: None,
+            },
+        );
+        assert_eq!(
+            cfg.max_genesis_supply().raw(),
+            without_prealloc.raw() + HclawAmount::from_hclaw(1_000_000).raw()
+        );
+    }
+
+    #[test]
+    fn test_validate_rejects_prealloc_overlapping_pre_approved() {
+        let addrs = test_addresses(1);
+        let authority = Keypair::generate();
+        let mut cfg = GenesisConfig::new("test".into(), addrs.clone(), authority.public_key().clone(), 1000);
+        cfg.prealloc.insert(
+            addrs[0],
+            GenesisAccount {
+                balance: HclawAmount::from_hclaw(1),
+                nonce: 0,
+                code: None,
+            },
+        );
+        assert!(matches!(
+            cfg.validate(),
+            Err(GenesisError::InvalidConfig(_))
+        ));
+    }
+
+    #[test]
+    fn test_validate_rejects_duplicate_builtin_addresses() {
+        let authority = Keypair::generate();
+        let mut cfg = GenesisConfig::new("test".into(), Vec::new(), authority.public_key().clone(), 1000);
+        let addr = Address::from_public_key(Keypair::generate().public_key());
+        cfg.builtins.push(GenesisBuiltin {
+            address: addr,
+            code_ref: "native:registry_v1".into(),
+            activate_at_height: 0,
+        });
+        cfg.builtins.push(GenesisBuiltin {
+            address: addr,
+            code_ref: "native:registry_v2".into(),
+            activate_at_height: 100,
+        });
+        assert!(matches!(
+            cfg.validate(),
+            Err(GenesisError::InvalidConfig(_))
+        ));
+    }
+
+    #[test]
+    fn test_genesis_proof_none_for_unknown_address() {
+        let addrs = test_addresses(2);
+        let authority = Keypair::generate();
+        let cfg = GenesisConfig::new("test".into(), addrs, authority.public_key().clone(), 1000);
+        let outsider = Address::from_public_key(Keypair::generate().public_key());
+        assert!(cfg.genesis_proof(&outsider).is_none());
+    }
+
+    #[test]
+    fn test_empty_genesis_accounts_root_is_empty_hash() {
+        let authority = Keypair::generate();
+        let cfg = GenesisConfig::new("test".into(), Vec::new(), authority.public_key().clone(), 1000);
+        assert_eq!(cfg.genesis_state_root(), hash_data(b""));
+    }
+
     #[test]
     fn test_genesis_config_validation() {
         let addrs = test_addresses(5);
@@ -355,4 +867,48 @@ mod tests {
         assert_eq!(MAX_GENESIS_PARTICIPANTS, 5_000);
         assert_eq!(MINIMUM_STAKE_HCLAW, 50);
     }
+
+    #[test]
+    fn test_from_named_loads_every_bundled_preset() {
+        for name in ["mainnet", "testnet", "dev"] {
+            let cfg = GenesisConfig::from_named(name, 1000)
+                .unwrap_or_else(|e| panic!("preset {name} failed to load: {e}"));
+            assert!(cfg.validate().is_ok());
+        }
+    }
+
+    #[test]
+    fn test_from_named_rejects_unknown_preset() {
+        assert!(matches!(
+            GenesisConfig::from_named("does-not-exist", 1000),
+            Err(GenesisError::InvalidConfig(_))
+        ));
+    }
+
+    #[test]
+    fn test_load_from_spec_roundtrips_a_written_file() {
+        let mut toml_config = config::default_testnet_toml();
+        toml_config.pre_approved = Vec::new();
+        toml_config.authority_key = presets::DEV
+            .lines()
+            .find_map(|line| line.strip_prefix("authority_key = \""))
+            .and_then(|rest| rest.strip_suffix('"'))
+            .expect("dev preset has an authority_key line")
+            .to_string();
+
+        let path = std::env::temp_dir().join("hardclaw_test_genesis_spec.toml");
+        toml_config.save_to_file(&path).unwrap();
+
+        let loaded = GenesisConfig::load_from_spec(&path, 1000).expect("spec loads");
+        assert_eq!(loaded.chain_id, toml_config.chain_id);
+
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn test_config_hash_stable_across_equivalent_spec_loads() {
+        let a = GenesisConfig::from_named("dev", 1000).unwrap();
+        let b = GenesisConfig::from_named("dev", 1000).unwrap();
+        assert_eq!(a.config_hash(), b.config_hash());
+    }
 }
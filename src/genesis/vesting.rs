@@ -1,15 +1,26 @@
-//! Liveness-gated daily vesting for airdrop tokens.
+//! Liveness-gated graded vesting for airdrop tokens.
 //!
 //! Vesting is NOT purely time-based. Each verifier must actively operate
-//! their node each day (proven by block attestations) to unlock that day's
-//! vesting portion. If a verifier is offline on day 15, they don't get
-//! day 15's tokens — those tokens are burned at bootstrap end.
+//! their node each period (proven by block attestations) to unlock that
+//! period's vesting portion. Unlock is graduated rather than all-or-nothing:
+//! a verifier who attests half the required count for a period earns half
+//! that period's portion. If a verifier is fully offline for period 15,
+//! they get none of period 15's tokens — the unearned fraction is burned
+//! at bootstrap end.
 //!
-//! Structure:
+//! Structure, modeled on a standard cliff + graded-period grant:
 //! - Immediate unlock: enough to meet min_stake (so they can participate)
-//! - Remaining tokens: divided into 30 daily portions
-//! - Each daily portion unlocks ONLY if the verifier was active that day
-//!   (met the minimum attestation threshold via the LivenessTracker)
+//! - Remaining tokens: divided into `period_count` portions of `per_period`
+//!   each, starting once `start + cliff_ms` has passed
+//! - Each period's portion unlocks in proportion to the verifier's
+//!   attestation participation that period (via
+//!   `LivenessTracker::participation_ratio`)
+//!
+//! [`VestingSchedule::unlocked_at`] computes the unlock for any timestamp
+//! directly from `(immediate_amount, period_participation)` — a
+//! late-joining node that has this schedule and its recorded participation
+//! can recompute the correct vested amount without replaying every
+//! intermediate `process_day_end` call.
 
 use serde::{Deserialize, Serialize};
 
@@ -23,44 +34,60 @@ pub struct VestingSchedule {
     pub total_amount: HclawAmount,
     /// Amount immediately available (enough to meet min_stake)
     pub immediate_amount: HclawAmount,
-    /// Amount subject to daily vesting (total - immediate)
+    /// Amount subject to graded vesting (total - immediate)
     pub vesting_amount: HclawAmount,
-    /// Per-day vesting amount (vesting_amount / 30)
-    pub daily_amount: HclawAmount,
-    /// Bootstrap start timestamp (for day alignment)
-    pub bootstrap_start: Timestamp,
-    /// Bootstrap end timestamp
-    pub bootstrap_end: Timestamp,
-    /// The day this verifier joined (0-indexed from bootstrap start)
+    /// Per-period vesting amount (`vesting_amount / period_count`)
+    pub per_period: HclawAmount,
+    /// When this grant starts (genesis block timestamp)
+    pub start: Timestamp,
+    /// Cliff duration: no tokens unlock via [`Self::unlocked_at`] before
+    /// `start + cliff_ms`, regardless of recorded participation
+    pub cliff_ms: i64,
+    /// Length of one vesting period in ms (one bootstrap day)
+    pub period_ms: i64,
+    /// Number of graded periods from `join_day` to the end of bootstrap —
+    /// stored explicitly so the schedule is self-contained rather than
+    /// needing `BOOTSTRAP_DAYS` re-derived from elsewhere
+    pub period_count: u32,
+    /// The period this verifier joined (0-indexed from bootstrap start)
     pub join_day: u32,
-    /// Which days the verifier was active (updated by liveness tracker).
-    /// Index = day number (0-29), value = whether they were active.
-    pub daily_active: Vec<bool>,
+    /// Graduated participation credit per period (updated by liveness
+    /// tracker). Index = period number (0-29), value in `[0.0, 1.0]` — the
+    /// fraction of that period's vesting portion the verifier earned.
+    pub period_participation: Vec<f64>,
     /// Total amount withdrawn so far
     pub withdrawn: HclawAmount,
+    /// Total amount slashed from this schedule's still-locked balance for
+    /// liveness violations (double-attestation, prolonged downtime). Already
+    /// deducted from `total_amount`; tracked separately so
+    /// [`Self::forfeited_amount`] can be attributed rather than lumped in
+    /// with ordinary unearned-liveness burn.
+    #[serde(default)]
+    pub slashed: HclawAmount,
 }
 
 impl VestingSchedule {
     /// Create a new liveness-gated vesting schedule.
     ///
-    /// `min_stake` tokens are immediately available for staking.
-    /// The remainder is divided into daily portions, one per day of the
-    /// bootstrap period, starting from the day they joined.
+    /// `min_stake` tokens are immediately available for staking. The
+    /// remainder is divided into `period_count` graded portions, one per
+    /// bootstrap day remaining from `join_day` to `BOOTSTRAP_DAYS`, each
+    /// gated by [`Self::cliff_ms`] and the verifier's attestation
+    /// participation for that period.
     #[must_use]
     pub fn new(
         total_amount: HclawAmount,
         min_stake: HclawAmount,
-        bootstrap_start: Timestamp,
-        bootstrap_end: Timestamp,
+        start: Timestamp,
+        cliff_ms: i64,
         join_day: u32,
     ) -> Self {
         let immediate = min_stake.min(total_amount);
         let vesting = total_amount.saturating_sub(immediate);
 
-        // Days remaining from join_day to end of bootstrap
-        let days_remaining = BOOTSTRAP_DAYS.saturating_sub(join_day);
-        let daily = if days_remaining > 0 && vesting.raw() > 0 {
-            HclawAmount::from_raw(vesting.raw() / days_remaining as u128)
+        let period_count = BOOTSTRAP_DAYS.saturating_sub(join_day);
+        let per_period = if period_count > 0 && vesting.raw() > 0 {
+            HclawAmount::from_raw(vesting.raw() / period_count as u128)
         } else {
             HclawAmount::ZERO
         };
@@ -69,48 +96,84 @@ impl VestingSchedule {
             total_amount,
             immediate_amount: immediate,
             vesting_amount: vesting,
-            daily_amount: daily,
-            bootstrap_start,
-            bootstrap_end,
+            per_period,
+            start,
+            cliff_ms,
+            period_ms: super::DAY_MS,
+            period_count,
             join_day,
-            daily_active: vec![false; BOOTSTRAP_DAYS as usize],
+            period_participation: vec![0.0; BOOTSTRAP_DAYS as usize],
             withdrawn: HclawAmount::ZERO,
+            slashed: HclawAmount::ZERO,
         }
     }
 
-    /// Mark a day as active (called by bootstrap state machine when
-    /// liveness tracker confirms the verifier met the threshold).
-    pub fn mark_day_active(&mut self, day: u32) {
-        if let Some(active) = self.daily_active.get_mut(day as usize) {
-            *active = true;
+    /// Slash `amount` from this schedule's still-locked balance (never
+    /// clawing back tokens already vested or withdrawn). Reduces
+    /// `total_amount` so future [`Self::vested_amount`]/[`Self::unlocked_at`]
+    /// calls reflect the loss, and records it in `slashed` for reporting.
+    /// Returns the amount actually slashed, which is less than `amount` if
+    /// the locked balance is smaller.
+    pub fn slash(&mut self, amount: HclawAmount) -> HclawAmount {
+        let locked = self.total_amount.saturating_sub(self.vested_amount());
+        let actual = amount.min(locked);
+        self.total_amount = self.total_amount.saturating_sub(actual);
+        self.slashed = self.slashed.saturating_add(actual);
+        actual
+    }
+
+    /// Record graduated participation credit for a period (called by the
+    /// bootstrap state machine with the verifier's attestation ratio for
+    /// that period, from `LivenessTracker::participation_ratio`). `ratio`
+    /// is clamped to `[0.0, 1.0]`.
+    pub fn mark_period_participation(&mut self, period: u32, ratio: f64) {
+        if let Some(slot) = self.period_participation.get_mut(period as usize) {
+            *slot = ratio.clamp(0.0, 1.0);
         }
     }
 
-    /// Count of active days since joining
+    /// Count of fully active periods (participation == 1.0) since joining
     #[must_use]
-    pub fn active_days_count(&self) -> u32 {
-        self.daily_active
+    pub fn active_period_count(&self) -> u32 {
+        self.period_participation
             .iter()
             .enumerate()
-            .filter(|(i, active)| **active && *i as u32 >= self.join_day)
+            .filter(|(i, &ratio)| ratio >= 1.0 && *i as u32 >= self.join_day)
             .count() as u32
     }
 
-    /// Calculate total vested amount based on liveness.
-    /// Only days where the verifier was active contribute to vesting.
+    /// Calculate total vested amount based on graduated liveness:
+    /// `immediate_amount + (liveness_weighted_periods_elapsed * per_period)`,
+    /// capped at `total_amount`. Each period contributes
+    /// `per_period * participation_ratio`, so partial effort earns partial
+    /// credit rather than all-or-nothing.
     #[must_use]
     pub fn vested_amount(&self) -> HclawAmount {
-        let mut vested = self.immediate_amount;
+        let weighted_periods_elapsed: f64 = self
+            .period_participation
+            .iter()
+            .skip(self.join_day as usize)
+            .sum();
 
-        // Add daily_amount for each active day from join_day onward
-        for day in self.join_day..BOOTSTRAP_DAYS {
-            if self.daily_active.get(day as usize).copied().unwrap_or(false) {
-                vested = vested.saturating_add(self.daily_amount);
-            }
-        }
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let portion = (self.per_period.raw() as f64 * weighted_periods_elapsed) as u128;
 
         // Cap at total (handles rounding from integer division)
-        vested.min(self.total_amount)
+        self.immediate_amount
+            .saturating_add(HclawAmount::from_raw(portion))
+            .min(self.total_amount)
+    }
+
+    /// Unlocked amount as of `now`: `0` before `start + cliff_ms`, else
+    /// [`Self::vested_amount`]. Reconstructs the unlock for any timestamp
+    /// directly from this schedule's own fields — no need to replay every
+    /// `process_day_end` call between genesis and `now`.
+    #[must_use]
+    pub fn unlocked_at(&self, now: Timestamp) -> HclawAmount {
+        if now < self.start.saturating_add(self.cliff_ms) {
+            return HclawAmount::ZERO;
+        }
+        self.vested_amount()
     }
 
     /// Calculate withdrawable amount (vested minus already withdrawn)
@@ -132,22 +195,27 @@ impl VestingSchedule {
         Ok(())
     }
 
-    /// Calculate tokens that will be burned (days the verifier missed).
-    /// Only meaningful after bootstrap ends.
+    /// Calculate tokens that will be burned: the unearned fraction of each
+    /// day's portion, summed over the vesting window, plus anything already
+    /// removed via [`Self::slash`] (slashing reduces `total_amount`
+    /// directly, so it's already reflected here — `slashed` itself is kept
+    /// only for reporting). Only meaningful after bootstrap ends.
     #[must_use]
     pub fn forfeited_amount(&self) -> HclawAmount {
-        let missed_days = (self.join_day..BOOTSTRAP_DAYS)
-            .filter(|&day| !self.daily_active.get(day as usize).copied().unwrap_or(false))
-            .count() as u128;
-
-        HclawAmount::from_raw(self.daily_amount.raw() * missed_days)
+        self.total_amount.saturating_sub(self.vested_amount())
     }
 
-    /// Whether the schedule is fully vested (all eligible days were active)
+    /// Whether the schedule is fully vested (every eligible day earned
+    /// full participation credit)
     #[must_use]
     pub fn is_fully_vested(&self) -> bool {
-        (self.join_day..BOOTSTRAP_DAYS)
-            .all(|day| self.daily_active.get(day as usize).copied().unwrap_or(false))
+        (self.join_day..BOOTSTRAP_DAYS).all(|day| {
+            self.period_participation
+                .get(day as usize)
+                .copied()
+                .unwrap_or(0.0)
+                >= 1.0
+        })
     }
 }
 
@@ -167,10 +235,8 @@ pub enum VestingError {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::genesis::DAY_MS;
 
     const START: Timestamp = 0;
-    const END: Timestamp = 30 * DAY_MS;
 
     #[test]
     fn test_immediate_unlock_covers_min_stake() {
@@ -179,7 +245,7 @@ mod tests {
             HclawAmount::from_hclaw(3_000),
             HclawAmount::from_hclaw(3_000),
             START,
-            END,
+            0,
             0,
         );
 
@@ -189,14 +255,14 @@ mod tests {
     }
 
     #[test]
-    fn test_daily_vesting_with_liveness() {
+    fn test_graded_vesting_with_liveness() {
         // 100K tokens, 250K min_stake — wait, min_stake > total here.
         // Let's use tier 1: 250K tokens, 250K min_stake — all immediate
         let schedule = VestingSchedule::new(
             HclawAmount::from_hclaw(250_000),
             HclawAmount::from_hclaw(250_000),
             START,
-            END,
+            0,
             0,
         );
         assert_eq!(schedule.vested_amount().whole_hclaw(), 250_000);
@@ -206,37 +272,37 @@ mod tests {
             HclawAmount::from_hclaw(10_000),
             HclawAmount::from_hclaw(1_000),
             START,
-            END,
+            0,
             0,
         );
 
-        // Immediate: 1000, vesting: 9000 over 30 days = 300/day
+        // Immediate: 1000, vesting: 9000 over 30 periods = 300/period
         assert_eq!(schedule.immediate_amount.whole_hclaw(), 1_000);
-        assert_eq!(schedule.daily_amount.whole_hclaw(), 300);
+        assert_eq!(schedule.per_period.whole_hclaw(), 300);
 
-        // No days active yet — only immediate available
+        // No periods active yet — only immediate available
         assert_eq!(schedule.vested_amount().whole_hclaw(), 1_000);
 
-        // Active on days 0 and 1
-        schedule.mark_day_active(0);
-        schedule.mark_day_active(1);
+        // Active on periods 0 and 1
+        schedule.mark_period_participation(0, 1.0);
+        schedule.mark_period_participation(1, 1.0);
         // 1000 immediate + 300 * 2 = 1600
         assert_eq!(schedule.vested_amount().whole_hclaw(), 1_600);
     }
 
     #[test]
-    fn test_missed_days_dont_vest() {
+    fn test_missed_periods_dont_vest() {
         let mut schedule = VestingSchedule::new(
             HclawAmount::from_hclaw(10_000),
             HclawAmount::from_hclaw(1_000),
             START,
-            END,
+            0,
             0,
         );
 
-        // Active every other day for 30 days = 15 active days
-        for day in (0..30).step_by(2) {
-            schedule.mark_day_active(day);
+        // Active every other period for 30 periods = 15 active periods
+        for period in (0..30).step_by(2) {
+            schedule.mark_period_participation(period, 1.0);
         }
 
         // 1000 immediate + 300 * 15 = 5500
@@ -248,22 +314,23 @@ mod tests {
     }
 
     #[test]
-    fn test_late_joiner_fewer_vesting_days() {
-        // Join on day 20 — only 10 days of vesting
+    fn test_late_joiner_fewer_vesting_periods() {
+        // Join on day 20 — only 10 periods of vesting, stored explicitly
         let mut schedule = VestingSchedule::new(
             HclawAmount::from_hclaw(10_000),
             HclawAmount::from_hclaw(1_000),
             START,
-            END,
+            0,
             20,
         );
 
-        // Vesting: 9000 / 10 days = 900 per day
-        assert_eq!(schedule.daily_amount.whole_hclaw(), 900);
+        assert_eq!(schedule.period_count, 10);
+        // Vesting: 9000 / 10 periods = 900 per period
+        assert_eq!(schedule.per_period.whole_hclaw(), 900);
 
-        // Active all 10 days
-        for day in 20..30 {
-            schedule.mark_day_active(day);
+        // Active all 10 periods
+        for period in 20..30 {
+            schedule.mark_period_participation(period, 1.0);
         }
 
         // 1000 immediate + 900 * 10 = 10000
@@ -277,11 +344,11 @@ mod tests {
             HclawAmount::from_hclaw(10_000),
             HclawAmount::from_hclaw(1_000),
             START,
-            END,
+            0,
             0,
         );
 
-        schedule.mark_day_active(0);
+        schedule.mark_period_participation(0, 1.0);
         // 1000 + 300 = 1300 available
         assert_eq!(schedule.withdrawable().whole_hclaw(), 1_300);
 
@@ -292,6 +359,28 @@ mod tests {
         assert!(schedule.withdraw(HclawAmount::from_hclaw(500)).is_err());
     }
 
+    #[test]
+    fn test_partial_participation_earns_partial_credit() {
+        let mut schedule = VestingSchedule::new(
+            HclawAmount::from_hclaw(10_000),
+            HclawAmount::from_hclaw(1_000),
+            START,
+            0,
+            0,
+        );
+
+        // Per-period amount is 300 — half participation earns 150
+        schedule.mark_period_participation(0, 0.5);
+        assert_eq!(schedule.vested_amount().whole_hclaw(), 1_150);
+        assert!(!schedule.is_fully_vested());
+        assert_eq!(schedule.active_period_count(), 0);
+
+        // Full participation on a second period earns the full portion
+        schedule.mark_period_participation(1, 1.0);
+        assert_eq!(schedule.vested_amount().whole_hclaw(), 1_450);
+        assert_eq!(schedule.active_period_count(), 1);
+    }
+
     #[test]
     fn test_tier7_all_immediate() {
         // Tier 7: 100 tokens, min_stake 100 — everything immediate
@@ -299,7 +388,7 @@ mod tests {
             HclawAmount::from_hclaw(100),
             HclawAmount::from_hclaw(100),
             START,
-            END,
+            0,
             0,
         );
 
@@ -307,4 +396,73 @@ mod tests {
         assert_eq!(schedule.vested_amount().whole_hclaw(), 100);
         assert_eq!(schedule.forfeited_amount().whole_hclaw(), 0);
     }
+
+    #[test]
+    fn test_slash_reduces_locked_balance_not_vested() {
+        let mut schedule = VestingSchedule::new(
+            HclawAmount::from_hclaw(10_000),
+            HclawAmount::from_hclaw(1_000),
+            START,
+            0,
+            0,
+        );
+        schedule.mark_period_participation(0, 1.0);
+        let vested_before = schedule.vested_amount();
+
+        let slashed = schedule.slash(HclawAmount::from_hclaw(2_000));
+        assert_eq!(slashed.whole_hclaw(), 2_000);
+        assert_eq!(schedule.slashed.whole_hclaw(), 2_000);
+        // Already-vested tokens aren't clawed back.
+        assert_eq!(schedule.vested_amount(), vested_before);
+        // Total shrank, so the remaining locked balance shrank with it.
+        assert_eq!(schedule.total_amount.whole_hclaw(), 8_000);
+    }
+
+    #[test]
+    fn test_slash_caps_at_locked_balance() {
+        let mut schedule = VestingSchedule::new(
+            HclawAmount::from_hclaw(10_000),
+            HclawAmount::from_hclaw(1_000),
+            START,
+            0,
+            0,
+        );
+        // Only 9,000 is still locked (1,000 immediate already "vested").
+        let slashed = schedule.slash(HclawAmount::from_hclaw(50_000));
+        assert_eq!(slashed.whole_hclaw(), 9_000);
+        assert_eq!(schedule.total_amount.whole_hclaw(), 1_000);
+    }
+
+    #[test]
+    fn test_unlocked_at_is_zero_before_cliff() {
+        let mut schedule = VestingSchedule::new(
+            HclawAmount::from_hclaw(10_000),
+            HclawAmount::from_hclaw(1_000),
+            START,
+            7 * super::super::DAY_MS,
+            0,
+        );
+        schedule.mark_period_participation(0, 1.0);
+
+        assert_eq!(schedule.unlocked_at(0).whole_hclaw(), 0);
+        assert_eq!(
+            schedule.unlocked_at(7 * super::super::DAY_MS).whole_hclaw(),
+            1_300
+        );
+    }
+
+    #[test]
+    fn test_unlocked_at_matches_vested_amount_after_cliff() {
+        let mut schedule = VestingSchedule::new(
+            HclawAmount::from_hclaw(10_000),
+            HclawAmount::from_hclaw(1_000),
+            START,
+            0,
+            0,
+        );
+        schedule.mark_period_participation(0, 1.0);
+        schedule.mark_period_participation(1, 0.5);
+
+        assert_eq!(schedule.unlocked_at(100), schedule.vested_amount());
+    }
 }
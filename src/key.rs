@@ -0,0 +1,217 @@
+//! `hardclaw key` — offline crypto primitives for scripting custody
+//! operations and checking transaction payloads without booting a node.
+//!
+//! Usage:
+//!   hardclaw key generate
+//!   hardclaw key address <phrase-or-file>
+//!   hardclaw key sign <message> [--phrase <words> | --phrase-file <path> | --account <name>]
+//!   hardclaw key verify <pubkey> <message> <sig>
+//!   hardclaw key recover --address <addr> <partial phrase, '_' for unknown words>
+//!
+//! `sign` (and any command taking a key source) reads from, in order:
+//! `--phrase <words>`, `--phrase-file <path>`, or an encrypted keystore
+//! account (`--account <name>`, default "node", same format as `hardclaw
+//! node`'s `--keystore-path`/`--account`).
+
+use hardclaw::crypto::{
+    keypair_from_phrase, mnemonic_to_words, recover_mnemonic_matching, verify,
+};
+use hardclaw::{generate_mnemonic, keypair_from_mnemonic, Address, Keypair, PublicKey, Signature};
+
+pub fn run(args: &[String]) {
+    match args.first().map(String::as_str) {
+        Some("generate") => run_generate(),
+        Some("address") => run_address(&args[1..]),
+        Some("sign") => run_sign(&args[1..]),
+        Some("verify") => run_verify(&args[1..]),
+        Some("recover") => run_recover(&args[1..]),
+        _ => {
+            eprintln!("Usage: hardclaw key <generate|address|sign|verify|recover> ...");
+            std::process::exit(1);
+        }
+    }
+}
+
+/// `key generate` — print a new mnemonic and its derived address.
+fn run_generate() {
+    let mnemonic = generate_mnemonic();
+    let keypair = keypair_from_mnemonic(&mnemonic, "");
+
+    println!("Seed Phrase (KEEP THIS SAFE — loss = loss of funds):");
+    println!("{}", mnemonic_to_words(&mnemonic).join(" "));
+    println!();
+    println!("Public Key (Hex):");
+    println!("{}", keypair.public_key().to_hex());
+    println!();
+    println!("Address:");
+    println!("{}", Address::from_public_key(keypair.public_key()));
+}
+
+/// `key address <phrase-or-file>` — derive the public key and address from
+/// a seed phrase, given either directly or as a path to a file containing it.
+fn run_address(args: &[String]) {
+    let Some(phrase_or_file) = args.first() else {
+        eprintln!("Usage: hardclaw key address <phrase-or-file>");
+        std::process::exit(1);
+    };
+
+    let keypair = keypair_from_phrase(&resolve_phrase_or_file(phrase_or_file), "").unwrap_or_else(|e| {
+        eprintln!("Invalid seed phrase: {}", e);
+        std::process::exit(1);
+    });
+
+    println!("Public Key (Hex):");
+    println!("{}", keypair.public_key().to_hex());
+    println!("Address:");
+    println!("{}", Address::from_public_key(keypair.public_key()));
+}
+
+/// `key sign <message>` — sign a message with a key resolved via
+/// [`resolve_keypair`].
+fn run_sign(args: &[String]) {
+    let Some(message) = args.first() else {
+        eprintln!(
+            "Usage: hardclaw key sign <message> [--phrase <words> | --phrase-file <path> | --account <name>]"
+        );
+        std::process::exit(1);
+    };
+
+    let keypair = resolve_keypair(&args[1..]);
+    let signature = keypair.sign(message.as_bytes());
+
+    println!("Signature (Hex):");
+    println!("{}", signature.to_hex());
+}
+
+/// `key verify <pubkey> <message> <sig>` — check a signature against a raw
+/// public key, with no key loading involved.
+fn run_verify(args: &[String]) {
+    let (pubkey_hex, message, sig_hex) = match args {
+        [pubkey_hex, message, sig_hex] => (pubkey_hex, message, sig_hex),
+        _ => {
+            eprintln!("Usage: hardclaw key verify <pubkey> <message> <sig>");
+            std::process::exit(1);
+        }
+    };
+
+    let public_key = PublicKey::from_hex(pubkey_hex).unwrap_or_else(|e| {
+        eprintln!("Invalid public key: {}", e);
+        std::process::exit(1);
+    });
+    let signature = Signature::from_hex(sig_hex).unwrap_or_else(|e| {
+        eprintln!("Invalid signature: {}", e);
+        std::process::exit(1);
+    });
+
+    let valid = verify(&public_key, message.as_bytes(), &signature).is_ok();
+    println!("Signature valid:");
+    println!("{}", valid);
+
+    if !valid {
+        std::process::exit(1);
+    }
+}
+
+/// `key recover --address <addr> <partial phrase>` — brute-force the `_`
+/// slots of a partially remembered 24-word phrase against a known target
+/// address via [`recover_mnemonic_matching`].
+fn run_recover(args: &[String]) {
+    let usage = "Usage: hardclaw key recover --address <addr> <partial phrase, '_' for unknown words>";
+
+    let Some(address_hex) = arg_value(args, "--address") else {
+        eprintln!("{}", usage);
+        std::process::exit(1);
+    };
+    let target = Address::from_hex(address_hex).unwrap_or_else(|e| {
+        eprintln!("Invalid address: {}", e);
+        std::process::exit(1);
+    });
+
+    let Some(partial_phrase) = positional_args(args, &["--address"]).into_iter().next() else {
+        eprintln!("{}", usage);
+        std::process::exit(1);
+    };
+
+    println!("Brute-forcing unknown words against address {}...", target);
+    match recover_mnemonic_matching(partial_phrase, |keypair| {
+        Address::from_public_key(keypair.public_key()) == target
+    }) {
+        Some(mnemonic) => {
+            println!("Recovered seed phrase:");
+            println!("{}", mnemonic_to_words(&mnemonic).join(" "));
+        }
+        None => {
+            eprintln!("No matching phrase found within the search bound");
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Resolve a keypair from `--phrase <words>`, `--phrase-file <path>`, or an
+/// encrypted keystore account (`--account <name>`, default "node"), prompting
+/// for the keystore passphrase when a keystore account is used.
+fn resolve_keypair(args: &[String]) -> Keypair {
+    if let Some(phrase) = arg_value(args, "--phrase") {
+        return keypair_from_phrase(phrase, "").unwrap_or_else(|e| {
+            eprintln!("Invalid seed phrase: {}", e);
+            std::process::exit(1);
+        });
+    }
+
+    if let Some(path) = arg_value(args, "--phrase-file") {
+        let phrase = resolve_phrase_or_file(path);
+        return keypair_from_phrase(&phrase, "").unwrap_or_else(|e| {
+            eprintln!("Invalid seed phrase: {}", e);
+            std::process::exit(1);
+        });
+    }
+
+    let account = arg_value(args, "--account").map_or("node", String::as_str);
+    let keystore_path = arg_value(args, "--keystore-path").map(std::path::PathBuf::from);
+    let path = crate::node::keystore_account_path(keystore_path.as_deref(), account);
+    if !path.exists() {
+        eprintln!(
+            "No --phrase/--phrase-file given and no keystore account '{}' found at {}",
+            account,
+            path.display()
+        );
+        std::process::exit(1);
+    }
+
+    crate::node::unlock_keystore_account(&path, None).into_keypair()
+}
+
+/// Read `arg` as a seed phrase directly, or as a path to a file containing one.
+fn resolve_phrase_or_file(arg: &str) -> String {
+    let path = std::path::Path::new(arg);
+    if path.is_file() {
+        std::fs::read_to_string(path)
+            .unwrap_or_else(|e| {
+                eprintln!("Failed to read {}: {}", arg, e);
+                std::process::exit(1);
+            })
+            .trim()
+            .to_string()
+    } else {
+        arg.to_string()
+    }
+}
+
+fn arg_value<'a>(args: &'a [String], flag: &str) -> Option<&'a String> {
+    args.iter().position(|a| a == flag).and_then(|i| args.get(i + 1))
+}
+
+/// Every arg that isn't one of `flags_with_value` or its value.
+fn positional_args<'a>(args: &'a [String], flags_with_value: &[&str]) -> Vec<&'a String> {
+    let mut out = Vec::new();
+    let mut i = 0;
+    while i < args.len() {
+        if flags_with_value.contains(&args[i].as_str()) {
+            i += 2;
+        } else {
+            out.push(&args[i]);
+            i += 1;
+        }
+    }
+    out
+}
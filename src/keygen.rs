@@ -5,25 +5,72 @@
 //! Usage:
 //!   hardclaw keygen                  Generate a new wallet
 //!   hardclaw keygen --seed           Derive wallet from existing seed phrase
+//!   hardclaw keygen --seed --account <N>  Derive account N from that seed phrase
 //!   hardclaw keygen --authority      Generate authority keypair (requires --seed)
+//!   hardclaw keygen --vanity <PAT>   Brute-force an address matching PAT
+//!   hardclaw keygen --encrypt        (with any of the above except --authority) passphrase-protect the saved wallet
+//!   hardclaw keygen encrypt          Encrypt an existing wallet file in place
+//!   hardclaw keygen decrypt          Decrypt an existing wallet file in place
+//!   hardclaw keygen unlock           Print a temporarily-decrypted copy of a wallet
+//!   hardclaw keygen migrate          Upgrade every wallet file to the current schema version
+
+use std::io::Write;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
 
 use hardclaw::wallet::Wallet;
+use hardclaw::Address;
 
 pub fn run(args: &[String]) {
+    if args.first().is_some_and(|a| a == "encrypt") {
+        return run_encrypt_subcommand(&args[1..]);
+    }
+    if args.first().is_some_and(|a| a == "decrypt") {
+        return run_decrypt_subcommand(&args[1..]);
+    }
+    if args.first().is_some_and(|a| a == "unlock") {
+        return run_unlock_subcommand(&args[1..]);
+    }
+    if args.first().is_some_and(|a| a == "migrate") {
+        return run_migrate_subcommand();
+    }
+
     let has_seed = args.iter().any(|a| a == "--seed");
     let has_authority = args.iter().any(|a| a == "--authority");
+    let encrypt = args.iter().any(|a| a == "--encrypt");
+    let vanity_pattern = args
+        .iter()
+        .position(|a| a == "--vanity")
+        .and_then(|i| args.get(i + 1));
+    let account_index: Option<u32> = args
+        .iter()
+        .position(|a| a == "--account")
+        .and_then(|i| args.get(i + 1))
+        .map(|s| {
+            s.parse().unwrap_or_else(|_| {
+                eprintln!(
+                    "Error: --account expects a non-negative integer, got '{}'",
+                    s
+                );
+                std::process::exit(1);
+            })
+        });
 
-    if has_authority {
+    if let Some(pattern) = vanity_pattern {
+        run_vanity(pattern, encrypt);
+    } else if has_authority {
         run_authority(has_seed);
     } else if has_seed {
-        run_from_seed();
+        run_from_seed(encrypt, account_index);
     } else {
-        run_generate();
+        run_generate(encrypt);
     }
 }
 
 /// Generate a fresh wallet with a new mnemonic
-fn run_generate() {
+fn run_generate(encrypt: bool) {
     println!("Generating new HardClaw wallet...");
     println!("----------------------------------------------------------------");
 
@@ -44,11 +91,13 @@ fn run_generate() {
     println!("{}", address);
     println!("----------------------------------------------------------------");
 
-    save_wallet(&mut wallet);
+    save_wallet(&mut wallet, encrypt);
 }
 
-/// Derive wallet from an existing seed phrase
-fn run_from_seed() {
+/// Derive wallet from an existing seed phrase. When `account_index` is set,
+/// derives that HD account from the phrase instead of the default (account
+/// 0) keypair — see [`Wallet::derive_account`].
+fn run_from_seed(encrypt: bool, account_index: Option<u32>) {
     println!("Restore wallet from seed phrase");
     println!("----------------------------------------------------------------");
     println!("Enter your 24-word seed phrase:");
@@ -64,15 +113,20 @@ fn run_from_seed() {
         std::process::exit(1);
     }
 
-    let keypair = match hardclaw::keypair_from_phrase(phrase, "") {
-        Ok(kp) => kp,
-        Err(e) => {
-            eprintln!("Error: invalid seed phrase: {}", e);
-            std::process::exit(1);
-        }
+    let mut wallet = if let Some(index) = account_index {
+        let mnemonic = match hardclaw::crypto::parse_mnemonic(phrase) {
+            Ok(mnemonic) => mnemonic,
+            Err(e) => {
+                eprintln!("Error: invalid seed phrase: {}", e);
+                std::process::exit(1);
+            }
+        };
+        println!("Deriving account {} from this seed phrase...", index);
+        Wallet::derive_account(&mnemonic, "", index)
+    } else {
+        let (phrase, keypair) = resolve_seed_phrase(phrase);
+        Wallet::from_keypair_and_mnemonic(keypair, phrase)
     };
-
-    let mut wallet = Wallet::from_keypair_and_mnemonic(keypair, phrase.to_string());
     let address = wallet.address();
 
     println!();
@@ -83,7 +137,7 @@ fn run_from_seed() {
     println!("{}", address);
     println!("----------------------------------------------------------------");
 
-    save_wallet(&mut wallet);
+    save_wallet(&mut wallet, encrypt);
 }
 
 /// Generate an authority keypair (for signing genesis config).
@@ -110,15 +164,9 @@ fn run_authority(has_seed: bool) {
         std::process::exit(1);
     }
 
-    let keypair = match hardclaw::keypair_from_phrase(phrase, "") {
-        Ok(kp) => kp,
-        Err(e) => {
-            eprintln!("Error: invalid seed phrase: {}", e);
-            std::process::exit(1);
-        }
-    };
+    let (phrase, keypair) = resolve_seed_phrase(phrase);
 
-    let mut wallet = Wallet::from_keypair_and_mnemonic(keypair, phrase.to_string());
+    let mut wallet = Wallet::from_keypair_and_mnemonic(keypair, phrase);
     wallet.name = Some("authority".to_string());
     let address = wallet.address();
 
@@ -152,13 +200,227 @@ fn run_authority(has_seed: bool) {
     }
 }
 
-/// Save wallet as <address>.json and set as default if none exists
-fn save_wallet(wallet: &mut Wallet) {
+/// Parse a typed seed phrase into `(phrase, keypair)`, falling back to
+/// Levenshtein-distance typo recovery (see
+/// [`hardclaw::crypto::recover_mnemonic`]) when the phrase as typed doesn't
+/// validate. Any recovered correction is shown to the user, who must
+/// confirm it before the corrected phrase is trusted.
+fn resolve_seed_phrase(typed: &str) -> (String, hardclaw::Keypair) {
+    if let Ok(keypair) = hardclaw::keypair_from_phrase(typed, "") {
+        return (typed.to_string(), keypair);
+    }
+
+    println!("Seed phrase did not validate as typed; searching for a typo correction...");
+    match hardclaw::crypto::recover_mnemonic(typed) {
+        Some((mnemonic, corrections)) if !corrections.is_empty() => {
+            println!(
+                "Found a candidate phrase with {} correction(s):",
+                corrections.len()
+            );
+            for c in &corrections {
+                println!(
+                    "  word {}: '{}' -> '{}'",
+                    c.index + 1,
+                    c.original,
+                    c.corrected
+                );
+            }
+            if !confirm("Use this corrected phrase? [y/N]: ") {
+                eprintln!("Aborted: correction not confirmed");
+                std::process::exit(1);
+            }
+            let phrase = mnemonic.to_string();
+            let keypair = hardclaw::keypair_from_mnemonic(&mnemonic, "");
+            (phrase, keypair)
+        }
+        _ => {
+            eprintln!("Error: invalid seed phrase and no typo correction found");
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Print `message` and read a yes/no answer from stdin.
+fn confirm(message: &str) -> bool {
+    print!("{}", message);
+    let _ = std::io::stdout().flush();
+    let mut line = String::new();
+    std::io::stdin()
+        .read_line(&mut line)
+        .expect("failed to read input");
+    matches!(line.trim().to_ascii_lowercase().as_str(), "y" | "yes")
+}
+
+/// A prefix or suffix match target for vanity address search.
+///
+/// `*pattern` matches `pattern` against the tail of the address; anything
+/// else matches against the head. A pattern containing any uppercase hex
+/// digit is matched case-sensitively (against the EIP-55-style checksummed
+/// hex); an all-lowercase pattern matches case-insensitively.
+#[derive(Clone)]
+struct VanityPattern {
+    needle: String,
+    suffix: bool,
+    case_sensitive: bool,
+}
+
+impl VanityPattern {
+    fn parse(raw: &str) -> Self {
+        let (suffix, needle) = match raw.strip_prefix('*') {
+            Some(rest) => (true, rest),
+            None => (false, raw),
+        };
+        let case_sensitive = needle.chars().any(|c| c.is_ascii_uppercase());
+
+        Self {
+            needle: needle.to_string(),
+            suffix,
+            case_sensitive,
+        }
+    }
+
+    fn matches(&self, address: &Address) -> bool {
+        let haystack = if self.case_sensitive {
+            address.to_checksummed_hex()
+        } else {
+            address.to_hex().to_ascii_lowercase()
+        };
+        let haystack = haystack.trim_start_matches("0x");
+
+        if self.suffix {
+            haystack.ends_with(&self.needle)
+        } else {
+            haystack.starts_with(&self.needle)
+        }
+    }
+
+    /// Expected attempts before a random match: `16^N` for an N-character
+    /// case-sensitive pattern, halved when case doesn't matter.
+    fn expected_attempts(&self) -> f64 {
+        #[allow(clippy::cast_possible_wrap)]
+        let attempts = 16f64.powi(self.needle.len() as i32);
+        if self.case_sensitive {
+            attempts
+        } else {
+            attempts / 2.0
+        }
+    }
+}
+
+/// Brute-force ML-DSA-65 keypairs across worker threads until one derives
+/// an `Address` matching `raw_pattern`, then save it like any other wallet.
+fn run_vanity(raw_pattern: &str, encrypt: bool) {
+    let pattern = VanityPattern::parse(raw_pattern);
+    let num_workers = thread::available_parallelism().map_or(1, std::num::NonZeroUsize::get);
+
+    println!(
+        "Searching for vanity address ({} '{}')",
+        if pattern.suffix { "suffix" } else { "prefix" },
+        raw_pattern
+    );
+    println!(
+        "Case-{}sensitive match across {} worker thread{}",
+        if pattern.case_sensitive { "" } else { "in" },
+        num_workers,
+        if num_workers == 1 { "" } else { "s" }
+    );
+    println!(
+        "Expected attempts: ~{:.0} (16^{} possibilities{})",
+        pattern.expected_attempts(),
+        pattern.needle.len(),
+        if pattern.case_sensitive {
+            ""
+        } else {
+            ", halved since case doesn't matter"
+        }
+    );
+    println!("----------------------------------------------------------------");
+
+    let found = Arc::new(AtomicBool::new(false));
+    let attempts = Arc::new(AtomicU64::new(0));
+    let winner: Arc<Mutex<Option<Wallet>>> = Arc::new(Mutex::new(None));
+    let start = Instant::now();
+
+    let workers: Vec<_> = (0..num_workers)
+        .map(|_| {
+            let found = Arc::clone(&found);
+            let attempts = Arc::clone(&attempts);
+            let winner = Arc::clone(&winner);
+            let pattern = pattern.clone();
+
+            thread::spawn(move || {
+                while !found.load(Ordering::Relaxed) {
+                    let wallet = Wallet::generate();
+                    attempts.fetch_add(1, Ordering::Relaxed);
+
+                    if pattern.matches(&wallet.address()) && !found.swap(true, Ordering::SeqCst) {
+                        *winner.lock().expect("vanity result mutex poisoned") = Some(wallet);
+                        return;
+                    }
+                }
+            })
+        })
+        .collect();
+
+    while !found.load(Ordering::Relaxed) {
+        thread::sleep(Duration::from_millis(500));
+        let n = attempts.load(Ordering::Relaxed);
+        let rate = n as f64 / start.elapsed().as_secs_f64().max(0.001);
+        print!("\rSearched {n} addresses ({rate:.0}/s)...");
+        let _ = std::io::stdout().flush();
+    }
+    println!();
+
+    for worker in workers {
+        let _ = worker.join();
+    }
+
+    let mut wallet = winner
+        .lock()
+        .expect("vanity result mutex poisoned")
+        .take()
+        .expect("a worker recorded the matching wallet before setting found");
+
+    let address = wallet.address();
+    let phrase = wallet
+        .mnemonic
+        .as_ref()
+        .expect("generated wallet has mnemonic");
+
+    println!(
+        "Found matching address after {} attempts in {:.1}s!",
+        attempts.load(Ordering::Relaxed),
+        start.elapsed().as_secs_f64()
+    );
+    println!();
+    println!("Seed Phrase (KEEP THIS SAFE — loss = loss of funds):");
+    println!("{}", phrase);
+    println!();
+    println!("Public Key (Hex):");
+    println!("{}", wallet.public_key().to_hex());
+    println!();
+    println!("Address:");
+    println!("{}", address);
+    println!("----------------------------------------------------------------");
+
+    save_wallet(&mut wallet, encrypt);
+}
+
+/// Save wallet as <address>.json and set as default if none exists. When
+/// `encrypt` is set, prompts for a passphrase and seals the file with it.
+fn save_wallet(wallet: &mut Wallet, encrypt: bool) {
     let address = wallet.address();
     let wallets_dir = Wallet::default_dir();
     let path = wallets_dir.join(format!("{}.json", address));
 
-    match wallet.save(&path) {
+    let result = if encrypt {
+        let passphrase = prompt_new_passphrase();
+        wallet.save_encrypted(&path, &passphrase)
+    } else {
+        wallet.save(&path)
+    };
+
+    match result {
         Ok(()) => {
             println!("Wallet saved to: {}", path.display());
 
@@ -178,3 +440,170 @@ fn save_wallet(wallet: &mut Wallet) {
         }
     }
 }
+
+/// Resolve a `--wallet <path>` argument, falling back to the default wallet path.
+pub(crate) fn wallet_path_arg(args: &[String]) -> std::path::PathBuf {
+    args.iter()
+        .position(|a| a == "--wallet")
+        .and_then(|i| args.get(i + 1))
+        .map(std::path::PathBuf::from)
+        .unwrap_or_else(Wallet::default_path)
+}
+
+/// Read a passphrase from stdin as a trimmed line. Terminal echo suppression
+/// would need a real TTY dependency, which this zero-dependency CLI avoids.
+pub(crate) fn prompt_passphrase(prompt: &str) -> String {
+    print!("{}", prompt);
+    let _ = std::io::stdout().flush();
+    let mut line = String::new();
+    std::io::stdin()
+        .read_line(&mut line)
+        .expect("failed to read input");
+    line.trim().to_string()
+}
+
+/// Prompt for a new passphrase twice and confirm the two entries match.
+fn prompt_new_passphrase() -> String {
+    loop {
+        let first = prompt_passphrase("Enter a new passphrase: ");
+        let second = prompt_passphrase("Confirm passphrase: ");
+        if first.is_empty() {
+            eprintln!("Error: passphrase cannot be empty");
+            continue;
+        }
+        if first == second {
+            return first;
+        }
+        eprintln!("Error: passphrases did not match, try again");
+    }
+}
+
+/// `hardclaw keygen encrypt [--wallet <path>]` — seal an existing cleartext
+/// wallet file with a new passphrase.
+fn run_encrypt_subcommand(args: &[String]) {
+    let path = wallet_path_arg(args);
+
+    let mut wallet = match Wallet::load(&path) {
+        Ok(w) => w,
+        Err(e) => {
+            eprintln!("Failed to load wallet at {}: {}", path.display(), e);
+            std::process::exit(1);
+        }
+    };
+
+    let passphrase = prompt_new_passphrase();
+    match wallet.save_encrypted(&path, &passphrase) {
+        Ok(()) => println!("Wallet at {} is now passphrase-protected.", path.display()),
+        Err(e) => {
+            eprintln!("Failed to encrypt wallet: {}", e);
+            std::process::exit(1);
+        }
+    }
+}
+
+/// `hardclaw keygen decrypt [--wallet <path>]` — unseal an encrypted wallet
+/// file back to cleartext on disk.
+fn run_decrypt_subcommand(args: &[String]) {
+    let path = wallet_path_arg(args);
+    let passphrase = prompt_passphrase("Enter passphrase: ");
+
+    let mut wallet = match Wallet::load_with_passphrase(&path, &passphrase) {
+        Ok(w) => w,
+        Err(e) => {
+            eprintln!("Failed to unlock wallet at {}: {}", path.display(), e);
+            std::process::exit(1);
+        }
+    };
+
+    match wallet.save(&path) {
+        Ok(()) => println!("Wallet at {} is now stored in cleartext.", path.display()),
+        Err(e) => {
+            eprintln!("Failed to decrypt wallet: {}", e);
+            std::process::exit(1);
+        }
+    }
+}
+
+/// `hardclaw keygen unlock [--wallet <path>] [--ttl <seconds>]` — decrypt a
+/// wallet, print its secret key/mnemonic for the given TTL, then zeroize and
+/// forget them. The passphrase-protected file on disk is left untouched.
+fn run_unlock_subcommand(args: &[String]) {
+    let path = wallet_path_arg(args);
+    let ttl_secs: u64 = args
+        .iter()
+        .position(|a| a == "--ttl")
+        .and_then(|i| args.get(i + 1))
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(60);
+
+    let passphrase = prompt_passphrase("Enter passphrase: ");
+    let wallet = match Wallet::load_with_passphrase(&path, &passphrase) {
+        Ok(w) => w,
+        Err(e) => {
+            eprintln!("Failed to unlock wallet at {}: {}", path.display(), e);
+            std::process::exit(1);
+        }
+    };
+
+    println!("Unlocked for {} seconds:", ttl_secs);
+    println!();
+    println!("Address:");
+    println!("{}", wallet.address());
+    println!("Secret Key (Hex):");
+    println!("{}", hex::encode(wallet.keypair().secret_key().to_bytes()));
+    if let Some(phrase) = wallet.mnemonic.as_ref() {
+        println!("Seed Phrase:");
+        println!("{}", phrase);
+    }
+    println!();
+    println!(
+        "Re-lock in {} seconds (key material is zeroized on drop)...",
+        ttl_secs
+    );
+    thread::sleep(Duration::from_secs(ttl_secs));
+    drop(wallet);
+    println!("Locked.");
+}
+
+/// `hardclaw keygen migrate` — scan `Wallet::default_dir()` and upgrade
+/// every wallet file to the current schema version in place, backing up
+/// each one before rewriting it.
+fn run_migrate_subcommand() {
+    let dir = Wallet::default_dir();
+    let entries = match std::fs::read_dir(&dir) {
+        Ok(entries) => entries,
+        Err(e) => {
+            eprintln!("Failed to read wallet directory {}: {}", dir.display(), e);
+            std::process::exit(1);
+        }
+    };
+
+    let (mut migrated, mut up_to_date, mut failed) = (0u32, 0u32, 0u32);
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if !path.extension().is_some_and(|e| e == "json") {
+            continue;
+        }
+
+        match Wallet::migrate_file(&path) {
+            Ok(Some((from, to))) => {
+                println!("Migrated {} (v{} -> v{})", path.display(), from, to);
+                migrated += 1;
+            }
+            Ok(None) => up_to_date += 1,
+            Err(e) => {
+                eprintln!("Failed to migrate {}: {}", path.display(), e);
+                failed += 1;
+            }
+        }
+    }
+
+    println!(
+        "Done: {} migrated, {} already current, {} failed",
+        migrated, up_to_date, failed
+    );
+    if failed > 0 {
+        std::process::exit(1);
+    }
+}
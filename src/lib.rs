@@ -53,9 +53,12 @@ pub mod verifier;
 pub mod schelling;
 pub mod tokenomics;
 pub mod mempool;
+pub mod metrics;
+pub mod snapshot;
 pub mod state;
 pub mod network;
 pub mod wallet;
+pub mod encoding;
 
 pub use types::{
     Address, JobPacket, SolutionCandidate, Block, BlockHeader,
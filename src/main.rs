@@ -6,9 +6,15 @@
 //!   hardclaw cli       - Interactive CLI
 
 mod cli;
+mod key;
 mod keygen;
 mod node;
 mod onboarding;
+mod rolling_log;
+mod rpc_client;
+mod shard;
+mod sign;
+mod verify;
 
 fn main() {
     let args: Vec<String> = std::env::args().collect();
@@ -29,6 +35,26 @@ fn main() {
             let keygen_args = args[2..].to_vec();
             keygen::run(&keygen_args);
         }
+        Some("sign") => {
+            let sign_args = args[2..].to_vec();
+            sign::run(&sign_args);
+        }
+        Some("verify") => {
+            let verify_args = args[2..].to_vec();
+            verify::run(&verify_args);
+        }
+        Some("key") => {
+            let key_args = args[2..].to_vec();
+            key::run(&key_args);
+        }
+        Some("shard") => {
+            let shard_args = args[2..].to_vec();
+            shard::run_shard(&shard_args);
+        }
+        Some("recover") => {
+            let recover_args = args[2..].to_vec();
+            shard::run_recover(&recover_args);
+        }
         Some("--help") | Some("-h") => {
             print_help();
         }
@@ -69,9 +95,30 @@ fn print_help() {
     println!("    (default)   Launch the onboarding TUI");
     println!("    node        Run a full node or verifier");
     println!("    cli         Interactive CLI for wallet & jobs");
+    println!("                  --connect <addr>  Connect to a node's RPC port (encrypted)");
     println!("    keygen      Generate a new wallet/keypair");
     println!("                  --seed       Derive from existing seed phrase");
     println!("                  --authority  Generate authority key (requires --seed)");
+    println!("    sign        Sign a message with a wallet's secret key");
+    println!("                  --wallet <path>       Wallet file (default wallet otherwise)");
+    println!("                  --message <text>      Message to sign");
+    println!("                  --file <path>         Read message from file");
+    println!("                  (reads stdin if neither --message nor --file is given)");
+    println!("    verify      Verify a signature against a public key or address");
+    println!("                  --signature <hex>     Signature to check (required)");
+    println!("                  --public-key <hex>    Signer's public key");
+    println!("                  --address <addr>      Signer's wallet address");
+    println!("                  --message/--file       Same as `sign`");
+    println!("    key         Offline key operations (no node required)");
+    println!("                  hardclaw key generate                           New mnemonic + address");
+    println!("                  hardclaw key address <phrase-or-file>           Derive public key + address");
+    println!("                  hardclaw key sign <message> [--phrase/--phrase-file/--account]");
+    println!("                  hardclaw key verify <pubkey> <message> <sig>");
+    println!("                  hardclaw key recover --address <addr> <partial phrase, '_' for unknown words>");
+    println!("    shard       Shamir-split a KEM secret key for custodial backup");
+    println!("                  hardclaw shard <threshold> <total> [--secret-key <hex>]");
+    println!("    recover     Reconstruct a KEM secret key from shard shares");
+    println!("                  hardclaw recover --share <hex> [--share <hex> ...]");
     println!();
     println!("OPTIONS:");
     println!("    -h, --help      Print help");
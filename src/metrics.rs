@@ -0,0 +1,127 @@
+//! Prometheus-style metrics registry for node observability.
+//!
+//! A single `Arc<Metrics>` is created at node startup and shared between the
+//! network event loop and the API server, so gossip-topic activity observed
+//! while handling `NetworkEvent`s is immediately visible on the `/metrics`
+//! endpoint without a channel between the two.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicI64, AtomicU64, Ordering};
+
+/// Gossip topics the node meshes on; used as the `topic` label on the
+/// per-topic gauges/counters below.
+pub const TOPICS: [&str; 3] = ["jobs", "solutions", "blocks"];
+
+/// Shared Prometheus-style metrics registry for a running node.
+pub struct Metrics {
+    topic_peers: HashMap<&'static str, AtomicI64>,
+    topic_messages: HashMap<&'static str, AtomicU64>,
+    mempool_depth: AtomicU64,
+    chain_height: AtomicU64,
+    is_verifier: AtomicBool,
+}
+
+impl Metrics {
+    /// Build an empty registry with a zeroed gauge/counter for every topic
+    /// in [`TOPICS`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            topic_peers: TOPICS.iter().map(|&t| (t, AtomicI64::new(0))).collect(),
+            topic_messages: TOPICS.iter().map(|&t| (t, AtomicU64::new(0))).collect(),
+            mempool_depth: AtomicU64::new(0),
+            chain_height: AtomicU64::new(0),
+            is_verifier: AtomicBool::new(false),
+        }
+    }
+
+    /// Record whether this node is running as a verifier (set once at startup).
+    pub fn set_is_verifier(&self, is_verifier: bool) {
+        self.is_verifier.store(is_verifier, Ordering::Relaxed);
+    }
+
+    /// Record the current mempool depth (jobs + solutions).
+    pub fn set_mempool_depth(&self, depth: u64) {
+        self.mempool_depth.store(depth, Ordering::Relaxed);
+    }
+
+    /// Record the current chain height.
+    pub fn set_chain_height(&self, height: u64) {
+        self.chain_height.store(height, Ordering::Relaxed);
+    }
+
+    /// Increment the subscribed-peer gauge for `topic`; a no-op for a name
+    /// outside [`TOPICS`].
+    pub fn inc_topic_peers(&self, topic: &str) {
+        if let Some(counter) = self.topic_peers.get(topic) {
+            counter.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Decrement the subscribed-peer gauge for `topic`; a no-op for a name
+    /// outside [`TOPICS`].
+    pub fn dec_topic_peers(&self, topic: &str) {
+        if let Some(counter) = self.topic_peers.get(topic) {
+            counter.fetch_sub(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Increment the received-message counter for `topic`; a no-op for a
+    /// name outside [`TOPICS`].
+    pub fn inc_topic_messages(&self, topic: &str) {
+        if let Some(counter) = self.topic_messages.get(topic) {
+            counter.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Render the registry in Prometheus text exposition format.
+    #[must_use]
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+
+        out.push_str("# HELP hardclaw_topic_peers Peers subscribed to a gossip topic\n");
+        out.push_str("# TYPE hardclaw_topic_peers gauge\n");
+        for &topic in &TOPICS {
+            let value = self.topic_peers[topic].load(Ordering::Relaxed);
+            out.push_str(&format!("hardclaw_topic_peers{{topic=\"{topic}\"}} {value}\n"));
+        }
+
+        out.push_str("# HELP hardclaw_topic_messages_total Messages received on a gossip topic\n");
+        out.push_str("# TYPE hardclaw_topic_messages_total counter\n");
+        for &topic in &TOPICS {
+            let value = self.topic_messages[topic].load(Ordering::Relaxed);
+            out.push_str(&format!(
+                "hardclaw_topic_messages_total{{topic=\"{topic}\"}} {value}\n"
+            ));
+        }
+
+        out.push_str("# HELP hardclaw_mempool_depth Jobs + solutions currently in the mempool\n");
+        out.push_str("# TYPE hardclaw_mempool_depth gauge\n");
+        out.push_str(&format!(
+            "hardclaw_mempool_depth {}\n",
+            self.mempool_depth.load(Ordering::Relaxed)
+        ));
+
+        out.push_str("# HELP hardclaw_chain_height Current chain height\n");
+        out.push_str("# TYPE hardclaw_chain_height gauge\n");
+        out.push_str(&format!(
+            "hardclaw_chain_height {}\n",
+            self.chain_height.load(Ordering::Relaxed)
+        ));
+
+        out.push_str("# HELP hardclaw_is_verifier Whether this node is running as a verifier\n");
+        out.push_str("# TYPE hardclaw_is_verifier gauge\n");
+        out.push_str(&format!(
+            "hardclaw_is_verifier {}\n",
+            u8::from(self.is_verifier.load(Ordering::Relaxed))
+        ));
+
+        out
+    }
+}
+
+impl Default for Metrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -2,31 +2,77 @@
 //!
 //! Run a full node that participates in the HardClaw network.
 
+use std::collections::HashMap;
 use std::fs;
 use std::io::{self, Write};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
-use tokio::sync::RwLock;
+use std::time::{Duration, Instant};
+use tokio::sync::{mpsc, Mutex, RwLock, Semaphore};
 use tracing::{info, warn};
-use tracing_subscriber::FmtSubscriber;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+
+use crate::rolling_log::RollingFileWriter;
 
 use hardclaw::{
+    consensus::{ConsensusEngine, ProofOfVerification, ValidatorEntry, ValidatorSet},
     contracts::genesis_bounty::GenesisDeploymentConfig,
-    crypto::Keypair,
+    crypto::{recover_mnemonic, Hash, Keypair},
     generate_mnemonic,
     genesis::config::GenesisConfigToml,
     genesis::DnsBreakGlassConfig,
     keypair_from_phrase,
     mempool::Mempool,
+    metrics::{Metrics, TOPICS},
     network::{NetworkConfig, NetworkEvent, NetworkNode, PeerInfo},
     state::ChainState,
     types::{
         Address, Block, GenesisAlloc, HclawAmount, JobPacket, JobType, SystemJobKind,
-        VerificationSpec,
+        VerificationSpec, VerifierAttestation,
     },
     verifier::{Verifier, VerifierConfig},
+    wallet::{Wallet, WalletError},
 };
 
+/// Name of the keystore account the node loads/creates when `--account`
+/// isn't given, keeping old single-key deployments working unchanged.
+const DEFAULT_ACCOUNT: &str = "node";
+
+/// Capacity of the bounded job/block ingestion channels. Once full, sending
+/// into them blocks the event loop, applying back-pressure to the network
+/// layer instead of buffering gossip unboundedly.
+const INGESTION_CHANNEL_CAPACITY: usize = 256;
+
+/// Default cap on concurrently in-flight job/block ingestion tasks, used
+/// when `--max-in-flight` isn't given.
+const DEFAULT_MAX_IN_FLIGHT: usize = 20;
+
+/// Default worker thread count for `--vanity` mining, used when
+/// `--vanity-workers` isn't given.
+const DEFAULT_VANITY_WORKERS: usize = 4;
+
+/// How often the `--vanity` progress line is refreshed.
+const VANITY_PROGRESS_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Rotating log file name, under `--log-dir`.
+const LOG_FILE_NAME: &str = "hardclaw-node.log";
+
+/// Nominal weight given to the local verifier in the default, single-member
+/// [`ValidatorSet`] a solo node starts with — any positive value works
+/// since a lone active member always wins [`ValidatorSet::proposer_for_height`]
+/// and always meets its own quorum.
+const DEFAULT_LOCAL_VERIFIER_WEIGHT: u128 = 1;
+
+/// Default size (MiB) at which the log file rotates, used when
+/// `--log-max-size-mb` isn't given.
+const DEFAULT_LOG_MAX_SIZE_MB: u64 = 10;
+
+/// Default number of rolled log files kept, used when `--log-max-backups`
+/// isn't given.
+const DEFAULT_LOG_MAX_BACKUPS: u32 = 5;
+
 /// Get the default data directory
 fn data_dir() -> PathBuf {
     dirs::home_dir()
@@ -39,31 +85,248 @@ fn chain_data_dir(chain_id: &str) -> PathBuf {
     data_dir().join("chains").join(chain_id)
 }
 
-/// Load or generate a persistent keypair using BIP39 mnemonic
-fn load_or_create_keypair() -> Keypair {
-    let mnemonic_path = data_dir().join("seed_phrase.txt");
-    let legacy_key_path = data_dir().join("node_key");
+/// Directory holding per-account encrypted keystore files
+/// (`<dir>/<account>.json`), one per founder/verifier/payout key an
+/// operator wants to keep separate.
+pub(crate) fn keystore_dir() -> PathBuf {
+    data_dir().join("keystore")
+}
 
-    // Try new format first (seed_phrase.txt)
-    if mnemonic_path.exists() {
-        match fs::read_to_string(&mnemonic_path) {
-            Ok(phrase) => {
-                let phrase = phrase.trim();
-                match keypair_from_phrase(phrase, "") {
-                    Ok(keypair) => {
-                        info!("Loaded wallet from seed phrase at {:?}", mnemonic_path);
-                        return keypair;
-                    }
-                    Err(e) => {
-                        warn!("Invalid seed phrase file: {}", e);
-                    }
-                }
+/// Path to `account`'s keystore file, under `keystore_path` if the
+/// operator overrode it with `--keystore-path`, or [`keystore_dir`]
+/// otherwise.
+pub(crate) fn keystore_account_path(keystore_path: Option<&Path>, account: &str) -> PathBuf {
+    let dir = keystore_path.map(Path::to_path_buf).unwrap_or_else(keystore_dir);
+    dir.join(format!("{account}.json"))
+}
+
+/// Read a single line of input, trimmed. Terminal echo isn't suppressed —
+/// this crate has no TTY dependency for it — so passphrases are only as
+/// private as the terminal itself.
+fn prompt_line(prompt: &str) -> String {
+    print!("{prompt}");
+    let _ = io::stdout().flush();
+    let mut line = String::new();
+    let _ = io::stdin().read_line(&mut line);
+    line.trim().to_string()
+}
+
+/// Prompt for the optional BIP39 passphrase (the "25th word"); an empty
+/// line derives the same wallet as omitting it entirely.
+fn prompt_bip39_passphrase() -> String {
+    prompt_line("BIP39 passphrase (25th word, optional, press Enter to skip): ")
+}
+
+/// Prompt for the optional BIP39 passphrase, confirmed twice so a typo
+/// doesn't silently derive a different (and then unrecoverable) wallet
+/// from a correctly-typed seed phrase.
+fn prompt_bip39_passphrase_confirmed() -> String {
+    loop {
+        let first = prompt_bip39_passphrase();
+        let second = prompt_line("Confirm BIP39 passphrase: ");
+        if first == second {
+            return first;
+        }
+        println!("Passphrases did not match, try again.");
+    }
+}
+
+/// Resolve the BIP39 passphrase (the "25th word") for an out-of-band node
+/// identity supplied via `--key-file`/`--mnemonic-stdin`/`HARDCLAW_KEY`:
+/// `--passphrase-prompt` prompts interactively, otherwise the
+/// `HARDCLAW_BIP39_PASSPHRASE` env var is used for headless setups. This is
+/// deliberately a different env var from [`headless_passphrase`]'s
+/// `HARDCLAW_PASSPHRASE` — one derives the key from a seed phrase, the
+/// other unlocks an already-derived key's keystore encryption.
+fn bip39_passphrase(config: &NodeConfig) -> String {
+    if config.bip39_passphrase_prompt {
+        return prompt_bip39_passphrase();
+    }
+    std::env::var("HARDCLAW_BIP39_PASSPHRASE").unwrap_or_default()
+}
+
+/// Prompt for a new keystore encryption passphrase, confirmed twice so a
+/// typo doesn't lock the operator out of a freshly created key.
+fn prompt_new_passphrase() -> String {
+    loop {
+        let first = prompt_line("New keystore passphrase: ");
+        let second = prompt_line("Confirm passphrase: ");
+        if first == second {
+            return first;
+        }
+        println!("Passphrases did not match, try again.");
+    }
+}
+
+/// Resolve a keystore passphrase for headless unlock, without prompting:
+/// `--keystore-passphrase-file` takes precedence, falling back to the
+/// `HARDCLAW_PASSPHRASE` env var. Returns `None` when neither is set, in
+/// which case the caller should fall back to an interactive prompt.
+fn headless_passphrase(passphrase_file: Option<&Path>) -> Option<String> {
+    if let Some(path) = passphrase_file {
+        return match fs::read_to_string(path) {
+            Ok(contents) => Some(contents.trim().to_string()),
+            Err(e) => {
+                warn!("Failed to read keystore passphrase file {:?}: {}", path, e);
+                std::process::exit(1);
+            }
+        };
+    }
+    std::env::var("HARDCLAW_PASSPHRASE").ok()
+}
+
+/// Unlock an existing keystore account file. If a headless passphrase is
+/// available (`passphrase_file` or `HARDCLAW_PASSPHRASE`), it's tried once
+/// and a wrong passphrase is fatal — there's no terminal to reprompt.
+/// Otherwise, prompts interactively, reprompting on a wrong passphrase.
+pub(crate) fn unlock_keystore_account(path: &Path, passphrase_file: Option<&Path>) -> Wallet {
+    if let Some(passphrase) = headless_passphrase(passphrase_file) {
+        return match Wallet::load_with_passphrase(path, &passphrase) {
+            Ok(wallet) => {
+                info!("Unlocked keystore account at {:?}", path);
+                wallet
             }
             Err(e) => {
-                warn!("Failed to read seed phrase: {}", e);
+                warn!("Failed to unlock keystore at {:?}: {}", path, e);
+                std::process::exit(1);
+            }
+        };
+    }
+
+    loop {
+        let passphrase = prompt_line("Keystore passphrase: ");
+        match Wallet::load_with_passphrase(path, &passphrase) {
+            Ok(wallet) => {
+                info!("Unlocked keystore account at {:?}", path);
+                return wallet;
+            }
+            Err(WalletError::WrongPassphrase) => {
+                println!("Wrong passphrase, try again.");
+            }
+            Err(e) => {
+                warn!("Failed to unlock keystore at {:?}: {}", path, e);
+                std::process::exit(1);
             }
         }
     }
+}
+
+/// Resolve the node's identity keypair, preferring a key supplied
+/// out-of-band over the default data-dir keystore: `--key-file` >
+/// `--mnemonic-stdin` > `HARDCLAW_KEY` env var > [`load_or_create_keypair`].
+/// A key supplied out-of-band is never persisted unless `--save-key` was
+/// also given, so multiple ephemeral/CI nodes can share a host without
+/// colliding on [`data_dir`].
+fn resolve_keypair(config: &NodeConfig) -> Keypair {
+    let out_of_band_phrase = if let Some(path) = &config.key_file {
+        Some(fs::read_to_string(path).unwrap_or_else(|e| {
+            warn!("Failed to read --key-file {:?}: {}", path, e);
+            std::process::exit(1);
+        }))
+    } else if config.mnemonic_stdin {
+        println!("Enter your 24-word seed phrase (space-separated):");
+        let mut phrase = String::new();
+        if io::stdin().read_line(&mut phrase).is_err() {
+            warn!("Failed to read mnemonic from stdin");
+            std::process::exit(1);
+        }
+        Some(phrase)
+    } else {
+        std::env::var("HARDCLAW_KEY").ok()
+    };
+
+    let Some(phrase) = out_of_band_phrase.map(|p| p.trim().to_string()) else {
+        return load_or_create_keypair(
+            config.keystore_path.as_deref(),
+            &config.account,
+            config.keystore_passphrase_file.as_deref(),
+        );
+    };
+
+    let bip39_passphrase = bip39_passphrase(config);
+    let keypair = keypair_from_phrase(&phrase, &bip39_passphrase).unwrap_or_else(|e| {
+        warn!("Invalid seed phrase supplied out-of-band: {}", e);
+        std::process::exit(1);
+    });
+
+    if !config.save_key {
+        return keypair;
+    }
+
+    let path = keystore_account_path(config.keystore_path.as_deref(), &config.account);
+    if path.exists() {
+        match Wallet::peek_public_key(&path) {
+            Ok(existing) if existing != *keypair.public_key() => {
+                warn!(
+                    "--save-key given but the key derived for account '{}' doesn't match the \
+                     existing keystore at {:?} — address mismatch, wrong BIP39 passphrase?",
+                    config.account, path
+                );
+                std::process::exit(1);
+            }
+            Ok(_) => {
+                warn!(
+                    "--save-key given but account '{}' already has a matching keystore at {:?}; \
+                     not overwriting",
+                    config.account, path
+                );
+            }
+            Err(e) => {
+                warn!(
+                    "--save-key given but account '{}' already has a keystore at {:?} that \
+                     couldn't be checked ({}); not overwriting",
+                    config.account, path, e
+                );
+            }
+        }
+        return keypair;
+    }
+
+    let mut wallet = Wallet::from_keypair_and_mnemonic(keypair, phrase);
+    wallet.name = Some(config.account.clone());
+    let passphrase = prompt_new_passphrase();
+    if let Err(e) = wallet.save_encrypted(&path, &passphrase) {
+        warn!("Failed to save keystore: {}", e);
+    }
+    wallet.into_keypair()
+}
+
+/// Load or generate `account`'s persistent keypair from the encrypted
+/// keystore, migrating a legacy plaintext `seed_phrase.txt` in place the
+/// first time the default account is loaded.
+fn load_or_create_keypair(
+    keystore_path: Option<&Path>,
+    account: &str,
+    passphrase_file: Option<&Path>,
+) -> Keypair {
+    let path = keystore_account_path(keystore_path, account);
+    let legacy_mnemonic_path = data_dir().join("seed_phrase.txt");
+    let legacy_key_path = data_dir().join("node_key");
+
+    if path.exists() {
+        return unlock_keystore_account(&path, passphrase_file).into_keypair();
+    }
+
+    // One-time migration: the default account used to live entirely as a
+    // plaintext seed_phrase.txt. Seal it into the encrypted keystore
+    // instead of leaving it on disk in the clear.
+    if account == DEFAULT_ACCOUNT && legacy_mnemonic_path.exists() {
+        if let Ok(raw) = fs::read_to_string(&legacy_mnemonic_path) {
+            let phrase = raw.trim().to_string();
+            if let Ok(keypair) = keypair_from_phrase(&phrase, "") {
+                info!("Migrating legacy plaintext seed phrase into an encrypted keystore");
+                let mut wallet = Wallet::from_keypair_and_mnemonic(keypair, phrase);
+                wallet.name = Some(account.to_string());
+                let passphrase = prompt_new_passphrase();
+                match wallet.save_encrypted(&path, &passphrase) {
+                    Ok(()) => info!("Migrated; the old seed_phrase.txt can now be deleted"),
+                    Err(e) => warn!("Failed to write migrated keystore: {}", e),
+                }
+                return wallet.into_keypair();
+            }
+            warn!("Legacy seed phrase at {:?} doesn't parse; ignoring", legacy_mnemonic_path);
+        }
+    }
 
     // Legacy Ed25519 key files (32 bytes) are incompatible with ML-DSA-65
     if legacy_key_path.exists() {
@@ -73,37 +336,36 @@ fn load_or_create_keypair() -> Keypair {
         );
     }
 
-    // Generate new mnemonic-based wallet
-    generate_and_save_wallet(&mnemonic_path)
+    // Generate a new mnemonic-based wallet for this account
+    generate_and_save_wallet(&path, account)
 }
 
-fn generate_and_save_wallet(mnemonic_path: &PathBuf) -> Keypair {
+fn generate_and_save_wallet(path: &Path, account: &str) -> Keypair {
     // Ensure directory exists
-    if let Some(parent) = mnemonic_path.parent() {
+    if let Some(parent) = path.parent() {
         let _ = fs::create_dir_all(parent);
     }
 
-    // Generate new BIP39 mnemonic
+    // Generate new BIP39 mnemonic, optionally strengthened with a
+    // passphrase (the "25th word")
+    let bip39_passphrase = prompt_bip39_passphrase();
     let mnemonic = generate_mnemonic();
     let phrase = mnemonic.to_string();
-    let keypair = keypair_from_phrase(&phrase, "").expect("generated mnemonic is valid");
-
-    // Save mnemonic to file with restrictive permissions
-    if let Err(e) = fs::write(mnemonic_path, &phrase) {
-        warn!("Failed to save seed phrase: {}", e);
-    } else {
-        // Set restrictive permissions (Unix only)
-        #[cfg(unix)]
-        {
-            use std::os::unix::fs::PermissionsExt;
-            let _ = fs::set_permissions(mnemonic_path, fs::Permissions::from_mode(0o600));
-        }
-    }
+    let keypair =
+        keypair_from_phrase(&phrase, &bip39_passphrase).expect("generated mnemonic is valid");
 
     // Display the seed phrase prominently
     display_seed_phrase(&phrase);
 
-    keypair
+    let mut wallet = Wallet::from_keypair_and_mnemonic(keypair, phrase);
+    wallet.name = Some(account.to_string());
+
+    let passphrase = prompt_new_passphrase();
+    if let Err(e) = wallet.save_encrypted(path, &passphrase) {
+        warn!("Failed to save encrypted keystore: {}", e);
+    }
+
+    wallet.into_keypair()
 }
 
 /// Display seed phrase with prominent warning
@@ -164,6 +426,45 @@ struct NodeConfig {
     genesis_config_path: Option<PathBuf>,
     /// API Port
     api_port: u16,
+    /// Override for the keystore directory (default: [`keystore_dir`])
+    keystore_path: Option<PathBuf>,
+    /// Named keystore account to load/create (default: [`DEFAULT_ACCOUNT`])
+    account: String,
+    /// Cap on concurrently in-flight job/block ingestion tasks
+    max_in_flight: usize,
+    /// File holding the keystore passphrase, for unlocking without a
+    /// terminal prompt (headless/CI deployments)
+    keystore_passphrase_file: Option<PathBuf>,
+    /// Requested address prefix for `--vanity` mining (hex, `0x` optional).
+    /// Presence of this field is what routes `parse_args` to
+    /// [`NodeCommand::Vanity`]
+    vanity_prefix: Option<String>,
+    /// Whether `--vanity`'s prefix match is case-sensitive (against the
+    /// EIP-55-style checksummed hex) rather than case-insensitive
+    vanity_case_sensitive: bool,
+    /// Number of worker threads mining for `--vanity`
+    vanity_workers: usize,
+    /// Directory for the rotating log file (default: `data_dir()/logs`)
+    log_dir: Option<PathBuf>,
+    /// Log file size in MiB that triggers rotation
+    log_max_size_mb: u64,
+    /// Number of rolled log files kept
+    log_max_backups: u32,
+    /// File containing a seed phrase to use as the node's identity instead
+    /// of the default data-dir keystore
+    key_file: Option<PathBuf>,
+    /// Read the node's seed phrase from stdin instead of the default
+    /// data-dir keystore
+    mnemonic_stdin: bool,
+    /// Persist a key supplied via `--key-file`/`--mnemonic-stdin`/
+    /// `HARDCLAW_KEY` into the keystore instead of using it for this run only
+    save_key: bool,
+    /// Prompt for (or read `HARDCLAW_BIP39_PASSPHRASE` for) the optional
+    /// BIP39 passphrase when deriving the node identity out-of-band via
+    /// `--key-file`/`--mnemonic-stdin`/`HARDCLAW_KEY`. Without this, those
+    /// paths derive with an empty BIP39 passphrase, same as before this
+    /// flag existed.
+    bip39_passphrase_prompt: bool,
 }
 
 impl Default for NodeConfig {
@@ -178,6 +479,20 @@ impl Default for NodeConfig {
             chain_id: None,
             genesis_config_path: None,
             api_port: 9001,
+            keystore_path: None,
+            account: DEFAULT_ACCOUNT.to_string(),
+            max_in_flight: DEFAULT_MAX_IN_FLIGHT,
+            keystore_passphrase_file: None,
+            vanity_prefix: None,
+            vanity_case_sensitive: false,
+            vanity_workers: DEFAULT_VANITY_WORKERS,
+            log_dir: None,
+            log_max_size_mb: DEFAULT_LOG_MAX_SIZE_MB,
+            log_max_backups: DEFAULT_LOG_MAX_BACKUPS,
+            key_file: None,
+            mnemonic_stdin: false,
+            save_key: false,
+            bip39_passphrase_prompt: false,
         }
     }
 }
@@ -196,6 +511,31 @@ struct HardClawNode {
     verifier: Option<Verifier>,
     /// Connected peers count
     peer_count: usize,
+    /// Prometheus-style metrics registry, shared with the API server's
+    /// `/metrics` endpoint
+    metrics: Arc<Metrics>,
+    /// Bounded sender for incoming gossip jobs; `send` backpressures the
+    /// event loop once [`INGESTION_CHANNEL_CAPACITY`] jobs are queued
+    job_tx: mpsc::Sender<JobPacket>,
+    /// Receiving half of `job_tx`, taken by [`Self::run`] to drive the
+    /// bounded ingestion pipeline
+    job_rx: Option<mpsc::Receiver<JobPacket>>,
+    /// Bounded sender for incoming gossip blocks; `send` backpressures the
+    /// event loop once [`INGESTION_CHANNEL_CAPACITY`] blocks are queued
+    block_tx: mpsc::Sender<Block>,
+    /// Receiving half of `block_tx`, taken by [`Self::run`] to drive the
+    /// bounded ingestion pipeline
+    block_rx: Option<mpsc::Receiver<Block>>,
+    /// The verifier set gating block proposal eligibility and attestation
+    /// quorum. Defaults to a single member (this node's verifier key) so a
+    /// solo deployment behaves exactly as before; shared with the API
+    /// server so operators can see who's currently eligible to propose.
+    verifier_set: Arc<RwLock<ValidatorSet>>,
+    /// Attestations accumulated so far per block hash, pruned once a block
+    /// crosses quorum. Tracked here rather than on the (not yet applied)
+    /// [`Block`] itself, since gossiped attestations can arrive before the
+    /// block they're for.
+    pending_attestations: HashMap<Hash, Vec<VerifierAttestation>>,
 }
 
 impl HardClawNode {
@@ -207,6 +547,25 @@ impl HardClawNode {
             None
         };
 
+        let metrics = Arc::new(Metrics::new());
+        metrics.set_is_verifier(config.is_verifier);
+
+        let (job_tx, job_rx) = mpsc::channel(INGESTION_CHANNEL_CAPACITY);
+        let (block_tx, block_rx) = mpsc::channel(INGESTION_CHANNEL_CAPACITY);
+
+        let verifier_set = ValidatorSet::new(
+            0,
+            verifier
+                .as_ref()
+                .map(|v| {
+                    vec![ValidatorEntry {
+                        public_key: v.public_key().clone(),
+                        stake: HclawAmount::from_raw(DEFAULT_LOCAL_VERIFIER_WEIGHT),
+                    }]
+                })
+                .unwrap_or_default(),
+        );
+
         Self {
             keypair,
             config,
@@ -214,6 +573,13 @@ impl HardClawNode {
             mempool: Arc::new(RwLock::new(Mempool::new())),
             verifier,
             peer_count: 0,
+            metrics,
+            job_tx,
+            job_rx: Some(job_rx),
+            block_tx,
+            block_rx: Some(block_rx),
+            verifier_set: Arc::new(RwLock::new(verifier_set)),
+            pending_attestations: HashMap::new(),
         }
     }
 
@@ -315,6 +681,7 @@ impl HardClawNode {
                 // Create GenesisDeploymentConfig — contract handles future
                 // JoinGenesis transactions, not initial allocations.
                 let genesis_config = GenesisDeploymentConfig {
+                    chain_id: toml_config.chain_id.clone(),
                     airdrop_amount: HclawAmount::from_hclaw(toml_config.airdrop_amount),
                     founder_airdrop_amount: HclawAmount::from_hclaw(
                         toml_config.founder_airdrop_amount,
@@ -335,6 +702,8 @@ impl HardClawNode {
                         authority_key: authority_key.clone(),
                     },
                     bootstrap_end: now + 30 * 24 * 3600, // 30 days
+                    transitions: Vec::new(),
+                    emission_schedule: None,
                 };
 
                 info!("Creating genesis block with config: {:?}", genesis_config);
@@ -421,6 +790,11 @@ impl HardClawNode {
                 ];
 
                 let genesis_config = GenesisDeploymentConfig {
+                    chain_id: self
+                        .config
+                        .chain_id
+                        .clone()
+                        .unwrap_or_else(|| "hardclaw-local-dev".to_string()),
                     airdrop_amount: HclawAmount::from_hclaw(
                         hardclaw::genesis::GENESIS_AIRDROP_AMOUNT,
                     ),
@@ -443,6 +817,8 @@ impl HardClawNode {
                         authority_key: authority_key.clone(),
                     },
                     bootstrap_end: now + 30 * 24 * 3600,
+                    transitions: Vec::new(),
+                    emission_schedule: None,
                 };
 
                 let init_data = bincode::serialize(&genesis_config)
@@ -505,8 +881,26 @@ impl HardClawNode {
         let api_state = self.state.clone();
         let api_mempool = self.mempool.clone();
         let api_port = self.config.api_port;
+        // No contracts are registered on the live node yet (see
+        // `contracts::verification_queue`), so this queue starts and stays
+        // empty until transaction submission is wired up.
+        let api_verification_queue = std::sync::Arc::new(
+            hardclaw::contracts::verification_queue::VerificationQueue::new(std::sync::Arc::new(
+                hardclaw::contracts::processor::TransactionProcessor::new(10_000_000),
+            )),
+        );
+        let api_metrics = self.metrics.clone();
+        let api_verifier_set = self.verifier_set.clone();
         tokio::spawn(async move {
-            hardclaw::api::start_api_server(api_state, api_mempool, api_port).await;
+            hardclaw::api::start_api_server(
+                api_state,
+                api_mempool,
+                api_verification_queue,
+                api_metrics,
+                api_verifier_set,
+                api_port,
+            )
+            .await;
         });
 
         // Configure network
@@ -545,6 +939,25 @@ impl HardClawNode {
             );
         }
 
+        // Drain the bounded job/block channels through a capped number of
+        // concurrently in-flight tasks, so a gossip burst backs up the
+        // channel (and, once full, the event loop's `send`) instead of the
+        // node accepting an unbounded amount of queued work.
+        let job_rx = self.job_rx.take().expect("run is only called once");
+        let block_rx = self.block_rx.take().expect("run is only called once");
+        tokio::spawn(ingest_jobs(
+            job_rx,
+            self.mempool.clone(),
+            self.metrics.clone(),
+            self.config.max_in_flight,
+        ));
+        tokio::spawn(ingest_blocks(
+            block_rx,
+            self.state.clone(),
+            self.metrics.clone(),
+            self.config.max_in_flight,
+        ));
+
         // Main event loop - drive the swarm and handle application events
         let is_verifier = self.verifier.is_some();
         loop {
@@ -574,6 +987,9 @@ impl HardClawNode {
         match event {
             NetworkEvent::PeerConnected(peer) => {
                 self.peer_count += 1;
+                for topic in TOPICS {
+                    self.metrics.inc_topic_peers(topic);
+                }
                 info!(
                     "Connected to {} peer{}",
                     self.peer_count,
@@ -587,6 +1003,9 @@ impl HardClawNode {
                 if self.peer_count > 0 {
                     self.peer_count -= 1;
                 }
+                for topic in TOPICS {
+                    self.metrics.dec_topic_peers(topic);
+                }
                 info!(
                     "Connected to {} peer{}",
                     self.peer_count,
@@ -603,30 +1022,34 @@ impl HardClawNode {
                 if debug {
                     info!("Received job: {}", job.id);
                 }
-                let mut mp = self.mempool.write().await;
-                if let Err(e) = mp.add_job(*job) {
-                    warn!("Failed to add job to mempool: {}", e);
+                self.metrics.inc_topic_messages("jobs");
+                // Bounded: once INGESTION_CHANNEL_CAPACITY jobs are queued,
+                // this await stalls the event loop instead of buffering an
+                // unbounded backlog in memory.
+                if self.job_tx.send(*job).await.is_err() {
+                    warn!("Job ingestion channel closed; dropping job");
                 }
             }
             NetworkEvent::SolutionReceived(solution) => {
                 if debug {
                     info!("Received solution: {}", solution.id);
                 }
+                self.metrics.inc_topic_messages("solutions");
             }
             NetworkEvent::BlockReceived(block) => {
-                info!("Received block at height {}", block.header.height);
                 if debug {
-                    info!("Block hash: {}", block.hash);
+                    info!("Received block: {}", block.hash);
                 }
-                let mut st = self.state.write().await;
-                if let Err(e) = st.apply_block(*block) {
-                    warn!("Failed to apply block: {}", e);
+                self.metrics.inc_topic_messages("blocks");
+                if self.block_tx.send(*block).await.is_err() {
+                    warn!("Block ingestion channel closed; dropping block");
                 }
             }
             NetworkEvent::AttestationReceived(attestation) => {
                 if debug {
                     info!("Received attestation for block {}", attestation.block_hash);
                 }
+                self.record_attestation(attestation).await;
             }
             NetworkEvent::PeersDiscovered(peers) => {
                 if debug {
@@ -676,6 +1099,18 @@ impl HardClawNode {
             }
         }
 
+        // Only the verifier selected as proposer for the next height tries
+        // to produce a block; everyone else in the set sits this height out.
+        let next_height = self.state.read().await.height();
+        let verifier_set = self.verifier_set.read().await;
+        let is_proposer = verifier_set.proposer_for_height(next_height)
+            == Some(verifier.public_key());
+        drop(verifier_set);
+
+        if !is_proposer {
+            return Ok(());
+        }
+
         // Try to produce a block
         let state_root = self.state.read().await.compute_state_root();
         if let Some(block) = verifier.try_produce_block(state_root)? {
@@ -689,23 +1124,211 @@ impl HardClawNode {
 
         Ok(())
     }
+
+    /// Accumulate a gossiped attestation toward its block's quorum, logging
+    /// once the active verifier set's 2/3 stake threshold is crossed.
+    /// Attestations from verifiers outside the current set, or a repeat
+    /// attestation from a verifier already counted for this block, don't
+    /// add weight.
+    async fn record_attestation(&mut self, attestation: VerifierAttestation) {
+        let verifier_set = self.verifier_set.read().await;
+        if !verifier_set.contains(&attestation.verifier) {
+            warn!(
+                "Ignoring attestation from non-member verifier for block {}",
+                attestation.block_hash
+            );
+            return;
+        }
+
+        let block_hash = attestation.block_hash;
+        let entries = self.pending_attestations.entry(block_hash).or_default();
+        if entries.iter().any(|a| a.verifier == attestation.verifier) {
+            return;
+        }
+        entries.push(attestation);
+
+        let attested: u128 = entries
+            .iter()
+            .filter_map(|a| verifier_set.stake_of(&a.verifier))
+            .map(|s| s.raw())
+            .sum();
+        let quorum = ProofOfVerification.stake_quorum(&verifier_set).raw();
+
+        if quorum > 0 && attested >= quorum {
+            info!(
+                "Block {} reached attestation quorum ({} of {} stake)",
+                block_hash,
+                attested,
+                verifier_set.total_active_stake().raw()
+            );
+            self.pending_attestations.remove(&block_hash);
+        }
+    }
+}
+
+/// Drain `rx`, adding each job to the mempool on its own task, capped at
+/// `max_in_flight` concurrently running tasks via a semaphore — a
+/// `buffer_unordered`-style pipeline without pulling in the `futures` crate
+/// for a single use.
+async fn ingest_jobs(
+    mut rx: mpsc::Receiver<JobPacket>,
+    mempool: Arc<RwLock<Mempool>>,
+    metrics: Arc<Metrics>,
+    max_in_flight: usize,
+) {
+    let semaphore = Arc::new(Semaphore::new(max_in_flight));
+
+    while let Some(job) = rx.recv().await {
+        let permit = semaphore
+            .clone()
+            .acquire_owned()
+            .await
+            .expect("semaphore is never closed");
+        let mempool = mempool.clone();
+        let metrics = metrics.clone();
+
+        tokio::spawn(async move {
+            let _permit = permit;
+            let mut mp = mempool.write().await;
+            if let Err(e) = mp.add_job(job) {
+                warn!("Failed to add job to mempool: {}", e);
+            }
+            let size = mp.size();
+            drop(mp);
+            metrics.set_mempool_depth((size.jobs + size.solutions) as u64);
+        });
+    }
+}
+
+/// Drain `rx`, applying each block on its own task (capped at
+/// `max_in_flight` concurrently, same as [`ingest_jobs`]). Blocks that
+/// arrive ahead of the chain tip are parked in an orphan pool instead of
+/// failing outright; see [`apply_block_or_park`].
+async fn ingest_blocks(
+    mut rx: mpsc::Receiver<Block>,
+    state: Arc<RwLock<ChainState>>,
+    metrics: Arc<Metrics>,
+    max_in_flight: usize,
+) {
+    let semaphore = Arc::new(Semaphore::new(max_in_flight));
+    let orphans: Arc<Mutex<HashMap<Hash, Block>>> = Arc::new(Mutex::new(HashMap::new()));
+
+    while let Some(block) = rx.recv().await {
+        let permit = semaphore
+            .clone()
+            .acquire_owned()
+            .await
+            .expect("semaphore is never closed");
+        let state = state.clone();
+        let metrics = metrics.clone();
+        let orphans = orphans.clone();
+
+        tokio::spawn(async move {
+            let _permit = permit;
+            apply_block_or_park(block, &state, &metrics, &orphans).await;
+        });
+    }
+}
+
+/// Apply `block` if its height matches the current chain tip; otherwise
+/// park it in `orphans`, keyed by the parent hash it's waiting on, instead
+/// of failing `apply_block` outright. Applying a block may fill the gap a
+/// previously parked child was waiting on, in which case that child (and
+/// transitively, anything waiting on it) is retried the same way.
+async fn apply_block_or_park(
+    block: Block,
+    state: &Arc<RwLock<ChainState>>,
+    metrics: &Arc<Metrics>,
+    orphans: &Arc<Mutex<HashMap<Hash, Block>>>,
+) {
+    let mut pending = vec![block];
+
+    while let Some(block) = pending.pop() {
+        let expected_height = state.read().await.height();
+        if block.header.height > expected_height {
+            let parent_hash = block.header.parent_hash;
+            info!(
+                "Parking block {} at height {} (expected {}); waiting on parent {}",
+                block.hash, block.header.height, expected_height, parent_hash
+            );
+            orphans.lock().await.insert(parent_hash, block);
+            continue;
+        }
+
+        let hash = block.hash;
+        let mut st = state.write().await;
+        match st.apply_block(block) {
+            Ok(()) => {
+                metrics.set_chain_height(st.height());
+                drop(st);
+                if let Some(child) = orphans.lock().await.remove(&hash) {
+                    pending.push(child);
+                }
+            }
+            Err(e) => warn!("Failed to apply block: {}", e),
+        }
+    }
 }
 
-/// Special CLI commands that exit immediately
+/// Special CLI commands that exit immediately. Each still carries the
+/// parsed [`NodeConfig`] so `--keystore-path`/`--account` apply to them too.
 enum NodeCommand {
     Run(Box<NodeConfig>),
-    ShowSeed,
-    Recover,
+    ShowSeed(Box<NodeConfig>),
+    Recover(Box<NodeConfig>),
+    Vanity(Box<NodeConfig>),
 }
 
 fn parse_args(args: Vec<String>) -> NodeCommand {
     let mut config = NodeConfig::default();
+    let mut show_seed = false;
+    let mut recover = false;
 
     let mut i = 0;
     while i < args.len() {
         match args[i].as_str() {
-            "--show-seed" => return NodeCommand::ShowSeed,
-            "--recover" => return NodeCommand::Recover,
+            "--show-seed" => show_seed = true,
+            "--recover" => recover = true,
+            "--vanity" => {
+                i += 1;
+                if i < args.len() {
+                    config.vanity_prefix = Some(args[i].clone());
+                }
+            }
+            "--vanity-case-sensitive" => config.vanity_case_sensitive = true,
+            "--vanity-workers" => {
+                i += 1;
+                if i < args.len() {
+                    config.vanity_workers = args[i].parse().unwrap_or(DEFAULT_VANITY_WORKERS);
+                }
+            }
+            "--log-dir" => {
+                i += 1;
+                if i < args.len() {
+                    config.log_dir = Some(PathBuf::from(&args[i]));
+                }
+            }
+            "--log-max-size-mb" => {
+                i += 1;
+                if i < args.len() {
+                    config.log_max_size_mb = args[i].parse().unwrap_or(DEFAULT_LOG_MAX_SIZE_MB);
+                }
+            }
+            "--log-max-backups" => {
+                i += 1;
+                if i < args.len() {
+                    config.log_max_backups = args[i].parse().unwrap_or(DEFAULT_LOG_MAX_BACKUPS);
+                }
+            }
+            "--key-file" => {
+                i += 1;
+                if i < args.len() {
+                    config.key_file = Some(PathBuf::from(&args[i]));
+                }
+            }
+            "--mnemonic-stdin" => config.mnemonic_stdin = true,
+            "--save-key" => config.save_key = true,
+            "--passphrase-prompt" => config.bip39_passphrase_prompt = true,
             "--verifier" | "-v" => config.is_verifier = true,
             "--network-debug" => config.network_debug = true,
             "--port" | "-p" => {
@@ -748,6 +1371,30 @@ fn parse_args(args: Vec<String>) -> NodeCommand {
                     config.genesis_config_path = Some(PathBuf::from(&args[i]));
                 }
             }
+            "--keystore-path" => {
+                i += 1;
+                if i < args.len() {
+                    config.keystore_path = Some(PathBuf::from(&args[i]));
+                }
+            }
+            "--account" => {
+                i += 1;
+                if i < args.len() {
+                    config.account = args[i].clone();
+                }
+            }
+            "--max-in-flight" => {
+                i += 1;
+                if i < args.len() {
+                    config.max_in_flight = args[i].parse().unwrap_or(DEFAULT_MAX_IN_FLIGHT);
+                }
+            }
+            "--keystore-passphrase-file" => {
+                i += 1;
+                if i < args.len() {
+                    config.keystore_passphrase_file = Some(PathBuf::from(&args[i]));
+                }
+            }
             "--help" | "-h" => {
                 print_help();
                 std::process::exit(0);
@@ -757,6 +1404,16 @@ fn parse_args(args: Vec<String>) -> NodeCommand {
         i += 1;
     }
 
+    if show_seed {
+        return NodeCommand::ShowSeed(Box::new(config));
+    }
+    if recover {
+        return NodeCommand::Recover(Box::new(config));
+    }
+    if config.vanity_prefix.is_some() {
+        return NodeCommand::Vanity(Box::new(config));
+    }
+
     NodeCommand::Run(Box::new(config))
 }
 
@@ -767,8 +1424,8 @@ fn print_help() {
     println!("    hardclaw node [OPTIONS]");
     println!();
     println!("WALLET COMMANDS:");
-    println!("    --show-seed                 Display your wallet seed phrase");
-    println!("    --recover                   Recover wallet from seed phrase");
+    println!("    --show-seed                 Display the keystore account's seed phrase");
+    println!("    --recover                   Recover a keystore account from a seed phrase");
     println!();
     println!("NODE OPTIONS:");
     println!("    -v, --verifier              Run as a verifier node");
@@ -780,46 +1437,66 @@ fn print_help() {
     println!("    --no-official-bootstrap     Don't use official bootstrap nodes");
     println!("    --chain-id <ID>             Chain ID for network isolation");
     println!("    --genesis <PATH>            Path to genesis config TOML file");
+    println!("    --keystore-path <DIR>       Keystore directory (default: ~/.hardclaw/keystore)");
+    println!("    --account <NAME>            Keystore account to use (default: node)");
+    println!("    --max-in-flight <N>         Concurrent job/block ingestion tasks (default: 20)");
+    println!("    --keystore-passphrase-file <PATH>  Read the keystore passphrase from a file");
+    println!("                                  (also: HARDCLAW_PASSPHRASE env var) for headless unlock");
+    println!("    --vanity <PREFIX>           Mine a mnemonic whose address starts with PREFIX");
+    println!("    --vanity-case-sensitive     Match PREFIX's case against the checksummed address");
+    println!("    --vanity-workers <N>        Worker threads for --vanity (default: 4)");
+    println!("    --log-dir <PATH>            Rotating log file directory (default: data dir/logs)");
+    println!("    --log-max-size-mb <N>       Log file size that triggers rotation (default: 10)");
+    println!("    --log-max-backups <N>       Rolled log files to keep (default: 5)");
+    println!("    --key-file <PATH>           Use a seed phrase file as node identity (not persisted");
+    println!("                                  unless --save-key is also given)");
+    println!("    --mnemonic-stdin            Read the seed phrase from stdin instead of a keystore");
+    println!("                                  (also: HARDCLAW_KEY env var; precedence: --key-file >");
+    println!("                                  --mnemonic-stdin > HARDCLAW_KEY > keystore)");
+    println!("    --save-key                  Persist a --key-file/--mnemonic-stdin/HARDCLAW_KEY key");
+    println!("    --passphrase-prompt         Prompt for the optional BIP39 passphrase (the \"25th");
+    println!("                                  word\") when deriving a --key-file/--mnemonic-stdin/");
+    println!("                                  HARDCLAW_KEY identity (also: HARDCLAW_BIP39_PASSPHRASE");
+    println!("                                  env var for headless use); omitting this derives with");
+    println!("                                  an empty BIP39 passphrase, same as before this flag");
     println!("    -h, --help                  Print help");
 }
 
-/// Show the current wallet's seed phrase
-fn show_seed() {
-    let mnemonic_path = data_dir().join("seed_phrase.txt");
+/// Show a keystore account's seed phrase
+fn show_seed(keystore_path: Option<&Path>, account: &str, passphrase_file: Option<&Path>) {
+    let path = keystore_account_path(keystore_path, account);
 
-    if !mnemonic_path.exists() {
-        println!("No wallet found. Run the node first to create a wallet.");
+    if !path.exists() {
+        println!("No wallet found for account '{account}'. Run the node first to create one.");
         std::process::exit(1);
     }
 
-    match fs::read_to_string(&mnemonic_path) {
-        Ok(phrase) => {
-            println!();
-            println!("Your wallet seed phrase (keep this secret!):");
-            println!();
-            let words: Vec<&str> = phrase.split_whitespace().collect();
-            for (i, word) in words.iter().enumerate() {
-                print!("{:2}. {:<12} ", i + 1, word);
-                if (i + 1) % 4 == 0 {
-                    println!();
-                }
-            }
+    let wallet = unlock_keystore_account(&path, passphrase_file);
+    let Some(phrase) = wallet.mnemonic.as_deref() else {
+        println!("Account '{account}' was imported from a raw key and has no seed phrase.");
+        std::process::exit(1);
+    };
+
+    println!();
+    println!("Seed phrase for account '{account}' (keep this secret!):");
+    println!();
+    let words: Vec<&str> = phrase.split_whitespace().collect();
+    for (i, word) in words.iter().enumerate() {
+        print!("{:2}. {:<12} ", i + 1, word);
+        if (i + 1) % 4 == 0 {
             println!();
         }
-        Err(e) => {
-            println!("Failed to read seed phrase: {}", e);
-            std::process::exit(1);
-        }
     }
+    println!();
 }
 
-/// Recover wallet from seed phrase
-fn recover_wallet() {
-    let mnemonic_path = data_dir().join("seed_phrase.txt");
+/// Recover a keystore account from a seed phrase
+fn recover_wallet(keystore_path: Option<&Path>, account: &str) {
+    let path = keystore_account_path(keystore_path, account);
 
-    if mnemonic_path.exists() {
-        println!("A wallet already exists at {:?}", mnemonic_path);
-        println!("To recover, first backup and delete the existing seed_phrase.txt");
+    if path.exists() {
+        println!("Account '{account}' already has a keystore at {:?}", path);
+        println!("To recover, first back up and remove the existing keystore file");
         std::process::exit(1);
     }
 
@@ -833,37 +1510,55 @@ fn recover_wallet() {
         std::process::exit(1);
     }
 
-    let phrase = phrase.trim();
+    let phrase = phrase.trim().to_string();
     let word_count = phrase.split_whitespace().count();
     if word_count != 24 {
         println!("Expected 24 words, got {}", word_count);
         std::process::exit(1);
     }
 
-    // Validate the mnemonic
-    match keypair_from_phrase(phrase, "") {
-        Ok(keypair) => {
-            // Save the mnemonic
-            if let Some(parent) = mnemonic_path.parent() {
-                let _ = fs::create_dir_all(parent);
+    let bip39_passphrase = prompt_bip39_passphrase_confirmed();
+
+    // The phrase as typed might have a single-word typo or transposition;
+    // before giving up, try the Levenshtein-based correction pass and let
+    // the operator confirm the fix rather than silently guessing.
+    let phrase = match keypair_from_phrase(&phrase, &bip39_passphrase) {
+        Ok(_) => phrase,
+        Err(e) => match recover_mnemonic(&phrase) {
+            Some((corrected, corrections)) if !corrections.is_empty() => {
+                println!("The phrase as typed is invalid ({e}); found a likely correction:");
+                for c in &corrections {
+                    println!("  word {}: '{}' -> '{}'", c.index + 1, c.original, c.corrected);
+                }
+                let answer = prompt_line("Use the corrected phrase? [y/N]: ");
+                if !answer.eq_ignore_ascii_case("y") {
+                    println!("Aborted.");
+                    std::process::exit(1);
+                }
+                corrected.to_string()
             }
-
-            if let Err(e) = fs::write(&mnemonic_path, phrase) {
-                println!("Failed to save seed phrase: {}", e);
+            _ => {
+                println!("Invalid seed phrase: {}", e);
                 std::process::exit(1);
             }
+        },
+    };
 
-            #[cfg(unix)]
-            {
-                use std::os::unix::fs::PermissionsExt;
-                let _ = fs::set_permissions(&mnemonic_path, fs::Permissions::from_mode(0o600));
+    match keypair_from_phrase(&phrase, &bip39_passphrase) {
+        Ok(keypair) => {
+            let mut wallet = Wallet::from_keypair_and_mnemonic(keypair, phrase);
+            wallet.name = Some(account.to_string());
+
+            let encryption_passphrase = prompt_new_passphrase();
+            if let Err(e) = wallet.save_encrypted(&path, &encryption_passphrase) {
+                println!("Failed to save keystore: {}", e);
+                std::process::exit(1);
             }
 
-            let address = Address::from_public_key(keypair.public_key());
             println!();
             println!("Wallet recovered successfully!");
-            println!("Address: {}", address);
-            println!("Saved to: {:?}", mnemonic_path);
+            println!("Address: {}", wallet.address());
+            println!("Saved to: {:?}", path);
         }
         Err(e) => {
             println!("Invalid seed phrase: {}", e);
@@ -872,37 +1567,149 @@ fn recover_wallet() {
     }
 }
 
+/// Mine a BIP39 mnemonic whose derived address starts with
+/// `config.vanity_prefix`, via [`Wallet::generate_with_prefix`] across
+/// `config.vanity_workers` threads, then offer to save the winning mnemonic
+/// through the same encrypted-keystore path [`recover_wallet`] uses.
+fn mine_vanity_address(config: &NodeConfig) {
+    let prefix_raw = config
+        .vanity_prefix
+        .as_deref()
+        .expect("parse_args only builds NodeCommand::Vanity when vanity_prefix is set");
+    let prefix = prefix_raw.trim_start_matches("0x");
+    if prefix.is_empty() || !prefix.chars().all(|c| c.is_ascii_hexdigit()) {
+        println!("Vanity prefix must be non-empty hex (got '{prefix_raw}')");
+        std::process::exit(1);
+    }
+
+    let case_sensitive = config.vanity_case_sensitive;
+    let workers = config.vanity_workers.max(1);
+
+    // Rough odds assuming a uniform hex digit distribution.
+    let probability = 16f64.powi(-(prefix.len() as i32));
+    println!(
+        "Mining for address prefix '{prefix}' ({workers} workers, ~1 in {:.0} odds per attempt)...",
+        1.0 / probability
+    );
+
+    let attempts = Arc::new(AtomicU64::new(0));
+    let miner = {
+        let prefix = prefix.to_string();
+        let attempts = Arc::clone(&attempts);
+        std::thread::spawn(move || {
+            Wallet::generate_with_prefix(&prefix, workers, case_sensitive, &attempts)
+        })
+    };
+
+    let start = Instant::now();
+    let mut last_report = Instant::now();
+    while !miner.is_finished() {
+        std::thread::sleep(Duration::from_millis(100));
+        if last_report.elapsed() >= VANITY_PROGRESS_INTERVAL {
+            let n = attempts.load(Ordering::Relaxed);
+            let rate = n as f64 / start.elapsed().as_secs_f64().max(0.001);
+            print!("\r{n} attempts, {rate:.0}/s...");
+            let _ = io::stdout().flush();
+            last_report = Instant::now();
+        }
+    }
+    println!();
+
+    let mut wallet = miner
+        .join()
+        .expect("vanity mining thread panicked")
+        .expect("prefix was already validated above");
+    let phrase = wallet
+        .mnemonic
+        .clone()
+        .expect("Wallet::generate_with_prefix always generates a mnemonic");
+    println!("Found after {} attempts!", attempts.load(Ordering::Relaxed));
+    println!("Address: {}", wallet.address());
+
+    let answer = prompt_line("Save this mnemonic to the encrypted keystore? [y/N]: ");
+    if !answer.eq_ignore_ascii_case("y") {
+        println!("Not saved. Seed phrase (write it down now, it will not be shown again):");
+        display_seed_phrase(&phrase);
+        return;
+    }
+
+    let mut account = prompt_line("Keystore account name: ");
+    if account.is_empty() {
+        account = DEFAULT_ACCOUNT.to_string();
+    }
+    let path = keystore_account_path(config.keystore_path.as_deref(), &account);
+    if path.exists() {
+        println!("Account '{account}' already has a keystore at {:?}; not overwriting.", path);
+        display_seed_phrase(&phrase);
+        return;
+    }
+
+    wallet.name = Some(account.clone());
+    let passphrase = prompt_new_passphrase();
+    match wallet.save_encrypted(&path, &passphrase) {
+        Ok(()) => println!("Saved to {:?}", path),
+        Err(e) => println!("Failed to save keystore: {}", e),
+    }
+}
+
 #[tokio::main]
 pub async fn run(args: Vec<String>) -> anyhow::Result<()> {
     // Parse CLI first (before logging, since some commands are interactive)
     let command = parse_args(args);
 
     // Handle wallet commands (non-node operations)
-    match &command {
-        NodeCommand::ShowSeed => {
-            show_seed();
+    let config = match command {
+        NodeCommand::ShowSeed(config) => {
+            show_seed(
+                config.keystore_path.as_deref(),
+                &config.account,
+                config.keystore_passphrase_file.as_deref(),
+            );
             return Ok(());
         }
-        NodeCommand::Recover => {
-            recover_wallet();
+        NodeCommand::Recover(config) => {
+            recover_wallet(config.keystore_path.as_deref(), &config.account);
             return Ok(());
         }
-        NodeCommand::Run(_) => {}
-    }
-
-    let config = match command {
-        NodeCommand::Run(c) => *c,
-        _ => unreachable!(),
+        NodeCommand::Vanity(config) => {
+            mine_vanity_address(&config);
+            return Ok(());
+        }
+        NodeCommand::Run(config) => *config,
     };
 
-    // Initialize logging with EnvFilter to support RUST_LOG
+    // Initialize logging with EnvFilter to support RUST_LOG. Stdout output
+    // stays exactly as before; a rotating file layer is added alongside it
+    // so long-running verifier nodes keep history past terminal scrollback.
     let env_filter = tracing_subscriber::EnvFilter::try_from_default_env()
         .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new("info"));
-
-    let subscriber = FmtSubscriber::builder()
-        .with_env_filter(env_filter)
-        .finish();
-    tracing::subscriber::set_global_default(subscriber)?;
+    let stdout_layer = tracing_subscriber::fmt::layer();
+
+    let log_dir = config.log_dir.clone().unwrap_or_else(|| data_dir().join("logs"));
+    match RollingFileWriter::open(
+        &log_dir,
+        LOG_FILE_NAME,
+        config.log_max_size_mb * 1024 * 1024,
+        config.log_max_backups,
+    ) {
+        Ok(file_writer) => {
+            let file_layer = tracing_subscriber::fmt::layer()
+                .with_ansi(false)
+                .with_writer(file_writer);
+            tracing_subscriber::registry()
+                .with(env_filter)
+                .with(stdout_layer)
+                .with(file_layer)
+                .try_init()?;
+        }
+        Err(e) => {
+            eprintln!("Failed to open log file in {:?}: {e}; logging to stdout only", log_dir);
+            tracing_subscriber::registry()
+                .with(env_filter)
+                .with(stdout_layer)
+                .try_init()?;
+        }
+    }
 
     println!();
     println!("   ██╗  ██╗ █████╗ ██████╗ ██████╗  ██████╗██╗      █████╗ ██╗    ██╗");
@@ -917,7 +1724,7 @@ pub async fn run(args: Vec<String>) -> anyhow::Result<()> {
     println!();
 
     // Load or generate persistent keypair
-    let keypair = load_or_create_keypair();
+    let keypair = resolve_keypair(&config);
     let address = Address::from_public_key(keypair.public_key());
 
     info!("Node address: {}", address);
@@ -0,0 +1,122 @@
+//! Size-triggered rotating log file writer for [`tracing_subscriber`],
+//! composed alongside the existing stdout layer so long-running verifier
+//! nodes don't lose history once terminal scrollback runs out.
+//!
+//! Rotation follows a fixed-window roller: once the active file exceeds
+//! `max_bytes`, the oldest backup is dropped, every remaining backup shifts
+//! up by one (`.1` -> `.2`, etc.), the active file becomes `.1`, and a fresh
+//! empty file takes the active name.
+
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+struct Inner {
+    dir: PathBuf,
+    file_name: String,
+    max_bytes: u64,
+    max_backups: u32,
+    file: File,
+    size: u64,
+}
+
+impl Inner {
+    fn active_path(&self) -> PathBuf {
+        self.dir.join(&self.file_name)
+    }
+
+    fn rolled_path(&self, n: u32) -> PathBuf {
+        self.dir.join(format!("{}.{n}", self.file_name))
+    }
+
+    fn rotate(&mut self) -> io::Result<()> {
+        let oldest = self.rolled_path(self.max_backups);
+        let _ = fs::remove_file(&oldest);
+        for n in (1..self.max_backups).rev() {
+            let from = self.rolled_path(n);
+            if from.exists() {
+                fs::rename(&from, self.rolled_path(n + 1))?;
+            }
+        }
+        if self.max_backups > 0 {
+            fs::rename(self.active_path(), self.rolled_path(1))?;
+        }
+
+        self.file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(self.active_path())?;
+        self.size = 0;
+        Ok(())
+    }
+}
+
+/// A cloneable handle to a size-triggered rotating log file, usable as a
+/// [`tracing_subscriber::fmt::MakeWriter`].
+#[derive(Clone)]
+pub struct RollingFileWriter {
+    inner: Arc<Mutex<Inner>>,
+}
+
+impl RollingFileWriter {
+    /// Open (or create) `dir/file_name` for appending, rotating into
+    /// `dir/file_name.1..max_backups` once it exceeds `max_bytes`.
+    ///
+    /// # Errors
+    /// Returns an error if `dir` can't be created or the active file can't
+    /// be opened for appending.
+    pub fn open(
+        dir: &Path,
+        file_name: &str,
+        max_bytes: u64,
+        max_backups: u32,
+    ) -> io::Result<Self> {
+        fs::create_dir_all(dir)?;
+        let active_path = dir.join(file_name);
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&active_path)?;
+        let size = file.metadata().map(|m| m.len()).unwrap_or(0);
+
+        Ok(Self {
+            inner: Arc::new(Mutex::new(Inner {
+                dir: dir.to_path_buf(),
+                file_name: file_name.to_string(),
+                max_bytes,
+                max_backups,
+                file,
+                size,
+            })),
+        })
+    }
+}
+
+impl Write for RollingFileWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let mut inner = self.inner.lock().expect("rolling log writer mutex poisoned");
+        if inner.size >= inner.max_bytes {
+            inner.rotate()?;
+        }
+        let written = inner.file.write(buf)?;
+        inner.size += written as u64;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner
+            .lock()
+            .expect("rolling log writer mutex poisoned")
+            .file
+            .flush()
+    }
+}
+
+impl<'a> tracing_subscriber::fmt::MakeWriter<'a> for RollingFileWriter {
+    type Writer = Self;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        self.clone()
+    }
+}
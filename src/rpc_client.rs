@@ -0,0 +1,130 @@
+//! Encrypted RPC client used by `hardclaw cli --connect <addr>`.
+//!
+//! Dials the node's RPC port and layers a post-quantum encrypted channel
+//! on top of it: the client reads the node's advertised [`KemPublicKey`]
+//! off the wire, opens a [`SealSender`] session toward it for outgoing
+//! requests, and hands the node a one-shot ephemeral [`KemKeypair`] of its
+//! own to open a [`SealReceiver`] session for incoming responses. Every
+//! request/response frame after that handshake travels only as AEAD
+//! ciphertext, so CLI-to-node traffic is confidential end to end even
+//! though it rides over a plain TCP socket.
+//!
+//! Wire format: every frame is a 4-byte big-endian length prefix followed
+//! by that many bytes. The handshake is four frames in order:
+//!   1. node -> client: the node's [`KemPublicKey`] bytes
+//!   2. client -> node: the client's ephemeral response [`KemPublicKey`] bytes
+//!   3. client -> node: the request-channel [`KemCiphertext`] bytes
+//!   4. node -> client: the response-channel [`KemCiphertext`] bytes
+//! After the handshake, every request/response is one sealed frame each.
+
+use std::io::{self, Read, Write};
+use std::net::TcpStream;
+
+use hardclaw::crypto::kem::{KemCiphertext, KemKeypair, KemPublicKey};
+use hardclaw::crypto::{CryptoError, SealReceiver, SealSender};
+use hardclaw::types::{Address, JobPacket};
+
+/// Domain separation for the client -> node request channel.
+const REQUEST_AAD: &[u8] = b"hardclaw-rpc-request-v1";
+/// Domain separation for the node -> client response channel.
+const RESPONSE_AAD: &[u8] = b"hardclaw-rpc-response-v1";
+
+/// An open, post-quantum encrypted session with a node's RPC port.
+pub struct NodeClient {
+    stream: TcpStream,
+    request_sender: SealSender,
+    response_receiver: SealReceiver,
+}
+
+impl NodeClient {
+    /// Dial `addr` (e.g. `127.0.0.1:9001`) and complete the KEM handshake.
+    ///
+    /// # Errors
+    /// Returns an I/O error if the connection drops mid-handshake, or if
+    /// the node's advertised public key or ciphertext frames don't decode.
+    pub fn connect(addr: &str) -> io::Result<Self> {
+        let mut stream = TcpStream::connect(addr)?;
+
+        let node_pk_bytes = read_frame(&mut stream)?;
+        let node_pk = KemPublicKey::from_bytes(&node_pk_bytes).map_err(crypto_io_err)?;
+
+        let (request_sender, request_kem_ct) =
+            SealSender::open_session(&node_pk, REQUEST_AAD).map_err(crypto_io_err)?;
+
+        let response_keypair = KemKeypair::generate();
+        write_frame(&mut stream, response_keypair.public_key().as_bytes())?;
+        write_frame(&mut stream, request_kem_ct.as_bytes())?;
+
+        let response_kem_ct_bytes = read_frame(&mut stream)?;
+        let response_kem_ct = KemCiphertext::from_bytes(&response_kem_ct_bytes).map_err(crypto_io_err)?;
+        let response_receiver =
+            SealReceiver::open_session(&response_keypair, &response_kem_ct, RESPONSE_AAD)
+                .map_err(crypto_io_err)?;
+
+        Ok(Self {
+            stream,
+            request_sender,
+            response_receiver,
+        })
+    }
+
+    /// Seal and send one JSON request, then wait for and open its response.
+    fn call(&mut self, request: &serde_json::Value) -> io::Result<serde_json::Value> {
+        let plaintext = serde_json::to_vec(request)?;
+        let sealed = self
+            .request_sender
+            .seal_next(REQUEST_AAD, &plaintext)
+            .map_err(crypto_io_err)?;
+        write_frame(&mut self.stream, &sealed)?;
+
+        let sealed_response = read_frame(&mut self.stream)?;
+        let response = self
+            .response_receiver
+            .open_next(RESPONSE_AAD, &sealed_response)
+            .map_err(crypto_io_err)?;
+        serde_json::from_slice(&response).map_err(io::Error::from)
+    }
+
+    /// `balance <address>` - query the account's current balance.
+    pub fn balance(&mut self, address: &Address) -> io::Result<serde_json::Value> {
+        self.call(&serde_json::json!({
+            "method": "balance",
+            "address": address.to_string(),
+        }))
+    }
+
+    /// `submit <job>` - broadcast a constructed job packet, returning its assigned ID.
+    pub fn submit_job(&mut self, job: &JobPacket) -> io::Result<serde_json::Value> {
+        self.call(&serde_json::json!({ "method": "submit_job", "job": job }))
+    }
+
+    /// `status <job_id>` - poll a previously submitted job's state.
+    pub fn status(&mut self, job_id: &str) -> io::Result<serde_json::Value> {
+        self.call(&serde_json::json!({ "method": "status", "job_id": job_id }))
+    }
+
+    /// `verify <solution_id>` - fetch a solution and check it against its `VerificationSpec`.
+    pub fn verify(&mut self, solution_id: &str) -> io::Result<serde_json::Value> {
+        self.call(&serde_json::json!({ "method": "verify", "solution_id": solution_id }))
+    }
+}
+
+fn crypto_io_err(e: CryptoError) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, e.to_string())
+}
+
+fn write_frame(stream: &mut TcpStream, payload: &[u8]) -> io::Result<()> {
+    let len = u32::try_from(payload.len())
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "frame too large"))?;
+    stream.write_all(&len.to_be_bytes())?;
+    stream.write_all(payload)
+}
+
+fn read_frame(stream: &mut TcpStream) -> io::Result<Vec<u8>> {
+    let mut len_bytes = [0u8; 4];
+    stream.read_exact(&mut len_bytes)?;
+    let len = u32::from_be_bytes(len_bytes) as usize;
+    let mut buf = vec![0u8; len];
+    stream.read_exact(&mut buf)?;
+    Ok(buf)
+}
@@ -0,0 +1,119 @@
+//! `hardclaw shard` / `hardclaw recover` — Shamir-split a KEM secret key
+//! across custodians for threshold backup and recovery.
+//!
+//! Usage:
+//!   hardclaw shard <threshold> <total> [--secret-key <hex>]
+//!       Split a KEM secret key into <total> hex shares, any <threshold>
+//!       of which reconstruct it. Reads the secret key as hex from
+//!       --secret-key, or from stdin if omitted. Prints one hex-encoded
+//!       share per line.
+//!   hardclaw recover [--share <hex>]...
+//!       Reconstruct a KEM secret key from shares, each passed as a
+//!       --share flag (or one per stdin line if none given). Prints the
+//!       recovered secret key as hex.
+
+use std::io::{self, BufRead, Read};
+
+use hardclaw::crypto::kem::KemSecretKey;
+use hardclaw::crypto::{recover, shard as shard_secret, Share};
+
+/// Run `hardclaw shard <threshold> <total>`.
+pub fn run_shard(args: &[String]) {
+    let (threshold, total) = match (args.first(), args.get(1)) {
+        (Some(t), Some(n)) => (parse_count(t, "threshold"), parse_count(n, "total")),
+        _ => {
+            eprintln!("Usage: hardclaw shard <threshold> <total> [--secret-key <hex>]");
+            std::process::exit(1);
+        }
+    };
+
+    let secret_hex = args
+        .iter()
+        .position(|a| a == "--secret-key")
+        .and_then(|i| args.get(i + 1))
+        .cloned()
+        .unwrap_or_else(read_stdin_line);
+
+    let secret_bytes = hex::decode(secret_hex.trim()).unwrap_or_else(|e| {
+        eprintln!("Invalid secret key hex: {e}");
+        std::process::exit(1);
+    });
+    let secret = KemSecretKey::from_bytes(&secret_bytes).unwrap_or_else(|e| {
+        eprintln!("Invalid KEM secret key: {e}");
+        std::process::exit(1);
+    });
+
+    let shares = shard_secret(&secret, threshold, total).unwrap_or_else(|e| {
+        eprintln!("Failed to shard secret key: {e}");
+        std::process::exit(1);
+    });
+
+    println!("Split into {total} shares, threshold {threshold}:");
+    for share in &shares {
+        println!("{}", share.to_hex());
+    }
+}
+
+/// Run `hardclaw recover`.
+pub fn run_recover(args: &[String]) {
+    let share_hexes: Vec<String> = args
+        .iter()
+        .enumerate()
+        .filter(|(_, a)| *a == "--share")
+        .filter_map(|(i, _)| args.get(i + 1).cloned())
+        .collect();
+
+    let share_hexes = if share_hexes.is_empty() {
+        read_stdin_lines()
+    } else {
+        share_hexes
+    };
+
+    if share_hexes.is_empty() {
+        eprintln!("Usage: hardclaw recover --share <hex> [--share <hex> ...]");
+        std::process::exit(1);
+    }
+
+    let shares: Vec<Share> = share_hexes
+        .iter()
+        .map(|s| {
+            Share::from_hex(s).unwrap_or_else(|e| {
+                eprintln!("Invalid share: {e}");
+                std::process::exit(1);
+            })
+        })
+        .collect();
+
+    let secret = recover(&shares).unwrap_or_else(|e| {
+        eprintln!("Failed to recover secret key: {e}");
+        std::process::exit(1);
+    });
+
+    println!("Recovered KEM secret key (hex):");
+    println!("{}", hex::encode(secret.to_bytes()));
+}
+
+fn parse_count(s: &str, name: &str) -> u8 {
+    s.parse().unwrap_or_else(|_| {
+        eprintln!("Error: {name} must be a number from 1-255, got '{s}'");
+        std::process::exit(1);
+    })
+}
+
+fn read_stdin_line() -> String {
+    let mut line = String::new();
+    io::stdin()
+        .lock()
+        .read_line(&mut line)
+        .expect("failed to read stdin");
+    line
+}
+
+fn read_stdin_lines() -> Vec<String> {
+    let mut buf = String::new();
+    io::stdin()
+        .lock()
+        .read_to_string(&mut buf)
+        .expect("failed to read stdin");
+    buf.lines().map(str::to_string).filter(|l| !l.is_empty()).collect()
+}
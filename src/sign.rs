@@ -0,0 +1,71 @@
+//! `hardclaw sign` — sign an arbitrary message with a wallet's secret key.
+//!
+//! Usage:
+//!   hardclaw sign [--wallet <path>] [--message <text> | --file <path>]
+//!
+//! The message comes from `--message`, `--file`, or stdin (read to EOF) if
+//! neither flag is given. The wallet is loaded like any other `keygen`
+//! subcommand, prompting for its passphrase if it's encrypted.
+
+use std::io::Read;
+
+use hardclaw::wallet::{Wallet, WalletError};
+
+use crate::keygen::{prompt_passphrase, wallet_path_arg};
+
+pub fn run(args: &[String]) {
+    let path = wallet_path_arg(args);
+    let message = read_message(args);
+
+    let wallet = match Wallet::load(&path) {
+        Ok(wallet) => wallet,
+        Err(WalletError::PassphraseRequired) => {
+            let passphrase = prompt_passphrase("Enter passphrase: ");
+            match Wallet::load_with_passphrase(&path, &passphrase) {
+                Ok(wallet) => wallet,
+                Err(e) => {
+                    eprintln!("Failed to unlock wallet at {}: {}", path.display(), e);
+                    std::process::exit(1);
+                }
+            }
+        }
+        Err(e) => {
+            eprintln!("Failed to load wallet at {}: {}", path.display(), e);
+            std::process::exit(1);
+        }
+    };
+
+    let signature = wallet.sign(&message);
+
+    println!("Signature (Hex):");
+    println!("{}", signature.to_hex());
+}
+
+/// Read the message to sign from `--message <TEXT>`, `--file <PATH>`, or
+/// stdin (read to EOF) if neither flag is given.
+fn read_message(args: &[String]) -> Vec<u8> {
+    if let Some(text) = args
+        .iter()
+        .position(|a| a == "--message")
+        .and_then(|i| args.get(i + 1))
+    {
+        return text.clone().into_bytes();
+    }
+
+    if let Some(path) = args
+        .iter()
+        .position(|a| a == "--file")
+        .and_then(|i| args.get(i + 1))
+    {
+        return std::fs::read(path).unwrap_or_else(|e| {
+            eprintln!("Failed to read {}: {}", path, e);
+            std::process::exit(1);
+        });
+    }
+
+    let mut buf = Vec::new();
+    std::io::stdin()
+        .read_to_end(&mut buf)
+        .expect("failed to read stdin");
+    buf
+}
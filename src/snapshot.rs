@@ -0,0 +1,369 @@
+//! Snapshot / warp-sync: restore a verifier's state from a committed
+//! manifest instead of replaying every [`crate::types::block::Block`]
+//! from genesis.
+//!
+//! Modeled on OpenEthereum's warp-sync snapshots: [`SnapshotManifest`]
+//! commits the chunking of serialized state into fixed-size chunks, each
+//! addressed by `hash_data(chunk_bytes)`. A restoring node fetches the
+//! manifest, then feeds chunks into a [`Restore`], which verifies each one
+//! against `chunk_hashes` before accepting it and only yields the
+//! reconstructed state once every chunk is in and the whole thing hashes
+//! to `state_root`. [`SnapshotSync`] tracks manifests that failed to fully
+//! restore so the caller retries a different peer's manifest instead of
+//! looping on the same poisoned one.
+//!
+//! This module doesn't depend on a concrete chain-state type: `state.rs`
+//! is declared in `lib.rs` but unimplemented in this tree, so
+//! [`Snapshot::create`] takes already-serialized state bytes rather than
+//! `&State` — whatever state container eventually lands there can
+//! serialize itself and hand the bytes here.
+
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+
+use crate::crypto::{hash_data, Hash};
+
+/// Chunk size snapshot state is split into (4 MiB)
+pub const SNAPSHOT_CHUNK_SIZE: usize = 4 * 1024 * 1024;
+
+/// Commits a point-in-time snapshot of chain state: the block it was
+/// taken at, the resulting state root, and the hash of every chunk the
+/// serialized state was split into.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SnapshotManifest {
+    /// State root the reconstructed state must hash to
+    pub state_root: Hash,
+    /// Height of the block this snapshot was taken at
+    pub block_height: u64,
+    /// Hash of the block this snapshot was taken at — restoring nodes
+    /// must confirm this is on the verified chain before trusting the
+    /// snapshot at all
+    pub block_hash: Hash,
+    /// Hash of each fixed-size chunk, in order
+    pub chunk_hashes: Vec<Hash>,
+}
+
+impl SnapshotManifest {
+    /// Deterministic hash identifying this exact manifest, used as the
+    /// [`SnapshotSync`] blacklist key.
+    #[must_use]
+    pub fn manifest_hash(&self) -> Hash {
+        let mut data = Vec::new();
+        data.extend_from_slice(self.state_root.as_bytes());
+        data.extend_from_slice(&self.block_height.to_le_bytes());
+        data.extend_from_slice(self.block_hash.as_bytes());
+        for chunk_hash in &self.chunk_hashes {
+            data.extend_from_slice(chunk_hash.as_bytes());
+        }
+        hash_data(&data)
+    }
+}
+
+/// Errors raised while creating or restoring a snapshot.
+#[derive(Debug, thiserror::Error)]
+pub enum SnapshotError {
+    /// A fed chunk's hash didn't match the manifest's entry at that index
+    #[error("chunk {index} does not match its manifest hash")]
+    ChunkHashMismatch {
+        /// The chunk index that failed to verify
+        index: usize,
+    },
+    /// `index` is outside `0..chunk_hashes.len()`
+    #[error("chunk index {index} out of range for {total} chunks")]
+    ChunkIndexOutOfRange {
+        /// The out-of-range index that was fed
+        index: usize,
+        /// Total number of chunks the manifest declares
+        total: usize,
+    },
+    /// `finalize` was called before every chunk had been fed
+    #[error("restore incomplete: {missing} of {total} chunks still missing")]
+    Incomplete {
+        /// How many chunks are still missing
+        missing: usize,
+        /// Total number of chunks the manifest declares
+        total: usize,
+    },
+    /// Every chunk verified individually, but the reconstructed state
+    /// doesn't hash to the manifest's `state_root`
+    #[error("reconstructed state root does not match the manifest")]
+    StateRootMismatch,
+    /// The manifest has already failed to restore once and is
+    /// blacklisted; try a different peer's manifest instead
+    #[error("manifest is blacklisted after a prior failed restore")]
+    ManifestBlacklisted,
+}
+
+/// Creates snapshot manifests from already-serialized state.
+pub struct Snapshot;
+
+impl Snapshot {
+    /// Split `state_bytes` into [`SNAPSHOT_CHUNK_SIZE`]-sized chunks and
+    /// commit their hashes into a [`SnapshotManifest`] for the state at
+    /// `block_height`/`block_hash`. Returns the manifest alongside the
+    /// chunks themselves, ready to be served to restoring peers.
+    #[must_use]
+    pub fn create(
+        state_bytes: &[u8],
+        state_root: Hash,
+        block_height: u64,
+        block_hash: Hash,
+    ) -> (SnapshotManifest, Vec<Vec<u8>>) {
+        let chunks: Vec<Vec<u8>> = state_bytes
+            .chunks(SNAPSHOT_CHUNK_SIZE)
+            .map(<[u8]>::to_vec)
+            .collect();
+        let chunk_hashes = chunks.iter().map(|chunk| hash_data(chunk)).collect();
+
+        (
+            SnapshotManifest {
+                state_root,
+                block_height,
+                block_hash,
+                chunk_hashes,
+            },
+            chunks,
+        )
+    }
+}
+
+/// Restores state from a [`SnapshotManifest`] by feeding it chunks in any
+/// order, verifying each against `manifest.chunk_hashes` before accepting
+/// it.
+pub struct Restore {
+    manifest: SnapshotManifest,
+    chunks: Vec<Option<Vec<u8>>>,
+}
+
+impl Restore {
+    /// Begin a restore against `manifest`
+    #[must_use]
+    pub fn new(manifest: SnapshotManifest) -> Self {
+        let chunk_count = manifest.chunk_hashes.len();
+        Self {
+            manifest,
+            chunks: vec![None; chunk_count],
+        }
+    }
+
+    /// Feed one chunk at `index`, verifying it against
+    /// `manifest.chunk_hashes[index]` before accepting it.
+    ///
+    /// # Errors
+    /// Returns [`SnapshotError::ChunkIndexOutOfRange`] if `index` is out
+    /// of range, or [`SnapshotError::ChunkHashMismatch`] if the chunk
+    /// doesn't hash to the manifest's entry for `index`.
+    pub fn feed_chunk(&mut self, index: usize, chunk: Vec<u8>) -> Result<(), SnapshotError> {
+        let expected = self.manifest.chunk_hashes.get(index).cloned().ok_or(
+            SnapshotError::ChunkIndexOutOfRange {
+                index,
+                total: self.manifest.chunk_hashes.len(),
+            },
+        )?;
+
+        if hash_data(&chunk) != expected {
+            return Err(SnapshotError::ChunkHashMismatch { index });
+        }
+
+        self.chunks[index] = Some(chunk);
+        Ok(())
+    }
+
+    /// How many chunks are still missing
+    #[must_use]
+    pub fn missing_count(&self) -> usize {
+        self.chunks.iter().filter(|c| c.is_none()).count()
+    }
+
+    /// Finalize the restore: every chunk must be present, and the
+    /// reconstructed bytes must hash to `manifest.state_root`.
+    ///
+    /// # Errors
+    /// Returns [`SnapshotError::Incomplete`] if chunks are still missing,
+    /// or [`SnapshotError::StateRootMismatch`] if the reconstructed state
+    /// doesn't match `manifest.state_root`.
+    pub fn finalize(self) -> Result<Vec<u8>, SnapshotError> {
+        let total = self.chunks.len();
+        let missing = self.missing_count();
+        if missing > 0 {
+            return Err(SnapshotError::Incomplete { missing, total });
+        }
+
+        let mut state_bytes = Vec::new();
+        for chunk in self.chunks {
+            state_bytes.extend(chunk.unwrap_or_default());
+        }
+
+        if hash_data(&state_bytes) != self.manifest.state_root {
+            return Err(SnapshotError::StateRootMismatch);
+        }
+
+        Ok(state_bytes)
+    }
+}
+
+/// Coordinates restoring from a sequence of candidate manifests (e.g. one
+/// offered by each peer advertising a snapshot). Blacklists any manifest
+/// whose restore doesn't fully complete, so a caller retrying sync tries
+/// a different peer's manifest instead of looping on the same poisoned
+/// one.
+#[derive(Debug, Default)]
+pub struct SnapshotSync {
+    blacklist: HashSet<Hash>,
+}
+
+impl SnapshotSync {
+    /// Create a fresh coordinator with an empty blacklist
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether `manifest` has previously failed to restore
+    #[must_use]
+    pub fn is_blacklisted(&self, manifest: &SnapshotManifest) -> bool {
+        self.blacklist.contains(&manifest.manifest_hash())
+    }
+
+    /// Begin a restore against `manifest`, refusing one that's already
+    /// blacklisted from a prior failed attempt.
+    ///
+    /// # Errors
+    /// Returns [`SnapshotError::ManifestBlacklisted`] if `manifest` was
+    /// previously blacklisted via [`Self::blacklist_manifest`].
+    pub fn begin_restore(&self, manifest: SnapshotManifest) -> Result<Restore, SnapshotError> {
+        if self.is_blacklisted(&manifest) {
+            return Err(SnapshotError::ManifestBlacklisted);
+        }
+        Ok(Restore::new(manifest))
+    }
+
+    /// Record that `manifest` failed to restore, so future
+    /// [`Self::begin_restore`] calls against it are rejected.
+    pub fn blacklist_manifest(&mut self, manifest: &SnapshotManifest) {
+        self.blacklist.insert(manifest.manifest_hash());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_hash(seed: u8) -> Hash {
+        hash_data(&[seed; 32])
+    }
+
+    #[test]
+    fn test_create_and_restore_round_trip() {
+        let state_bytes = vec![7u8; SNAPSHOT_CHUNK_SIZE + 100];
+        let state_root = hash_data(&state_bytes);
+        let block_hash = test_hash(1);
+        let (manifest, chunks) = Snapshot::create(&state_bytes, state_root, 42, block_hash);
+
+        assert_eq!(manifest.chunk_hashes.len(), 2);
+        assert_eq!(chunks.len(), 2);
+
+        let mut restore = Restore::new(manifest);
+        for (i, chunk) in chunks.into_iter().enumerate() {
+            restore.feed_chunk(i, chunk).unwrap();
+        }
+
+        let restored = restore.finalize().unwrap();
+        assert_eq!(restored, state_bytes);
+    }
+
+    #[test]
+    fn test_restore_accepts_chunks_out_of_order() {
+        let state_bytes = vec![9u8; SNAPSHOT_CHUNK_SIZE + 1];
+        let state_root = hash_data(&state_bytes);
+        let (manifest, chunks) = Snapshot::create(&state_bytes, state_root, 1, test_hash(1));
+        let mut restore = Restore::new(manifest);
+
+        restore.feed_chunk(1, chunks[1].clone()).unwrap();
+        assert_eq!(restore.missing_count(), 1);
+        restore.feed_chunk(0, chunks[0].clone()).unwrap();
+        assert_eq!(restore.missing_count(), 0);
+
+        assert_eq!(restore.finalize().unwrap(), state_bytes);
+    }
+
+    #[test]
+    fn test_feed_chunk_rejects_hash_mismatch() {
+        let state_bytes = vec![1u8; 10];
+        let state_root = hash_data(&state_bytes);
+        let (manifest, _chunks) = Snapshot::create(&state_bytes, state_root, 1, test_hash(1));
+        let mut restore = Restore::new(manifest);
+
+        let result = restore.feed_chunk(0, vec![2u8; 10]);
+        assert!(matches!(
+            result,
+            Err(SnapshotError::ChunkHashMismatch { index: 0 })
+        ));
+    }
+
+    #[test]
+    fn test_feed_chunk_rejects_out_of_range_index() {
+        let state_bytes = vec![1u8; 10];
+        let state_root = hash_data(&state_bytes);
+        let (manifest, _chunks) = Snapshot::create(&state_bytes, state_root, 1, test_hash(1));
+        let mut restore = Restore::new(manifest);
+
+        let result = restore.feed_chunk(5, vec![1u8; 10]);
+        assert!(matches!(
+            result,
+            Err(SnapshotError::ChunkIndexOutOfRange { index: 5, total: 1 })
+        ));
+    }
+
+    #[test]
+    fn test_finalize_rejects_incomplete_restore() {
+        let state_bytes = vec![1u8; SNAPSHOT_CHUNK_SIZE + 1];
+        let state_root = hash_data(&state_bytes);
+        let (manifest, chunks) = Snapshot::create(&state_bytes, state_root, 1, test_hash(1));
+        let mut restore = Restore::new(manifest);
+        restore.feed_chunk(0, chunks[0].clone()).unwrap();
+
+        assert!(matches!(
+            restore.finalize(),
+            Err(SnapshotError::Incomplete {
+                missing: 1,
+                total: 2
+            })
+        ));
+    }
+
+    #[test]
+    fn test_finalize_rejects_wrong_state_root() {
+        let state_bytes = vec![1u8; 10];
+        // Deliberately commit the wrong state root.
+        let (manifest, chunks) = Snapshot::create(&state_bytes, test_hash(99), 1, test_hash(1));
+        let mut restore = Restore::new(manifest);
+        restore.feed_chunk(0, chunks[0].clone()).unwrap();
+
+        assert!(matches!(
+            restore.finalize(),
+            Err(SnapshotError::StateRootMismatch)
+        ));
+    }
+
+    #[test]
+    fn test_snapshot_sync_blacklists_failed_manifest_and_rejects_retry() {
+        let state_bytes = vec![1u8; 10];
+        let (manifest, _chunks) = Snapshot::create(&state_bytes, test_hash(99), 1, test_hash(1));
+        let mut sync = SnapshotSync::new();
+
+        assert!(!sync.is_blacklisted(&manifest));
+        let restore = sync.begin_restore(manifest.clone()).unwrap();
+        // The chunk never verifies against the wrong state_root, so the
+        // caller gives up on this manifest and blacklists it.
+        drop(restore);
+        sync.blacklist_manifest(&manifest);
+
+        assert!(sync.is_blacklisted(&manifest));
+        assert!(matches!(
+            sync.begin_restore(manifest),
+            Err(SnapshotError::ManifestBlacklisted)
+        ));
+    }
+}
@@ -1,10 +1,13 @@
 //! Fee distribution between participants.
 
-use crate::types::{Address, HclawAmount};
+use crate::crypto::{verify, CryptoResult, Keypair, PublicKey, Signature};
+use crate::types::{Address, HclawAmount, Id};
 
 /// Result of fee distribution
 #[derive(Clone, Debug)]
 pub struct FeeDistribution {
+    /// Bounty this distribution was paid out from
+    pub bounty_id: Id,
     /// Amount to solver
     pub solver_amount: HclawAmount,
     /// Solver's address
@@ -25,6 +28,66 @@ impl FeeDistribution {
             .saturating_add(self.verifier_amount)
             .saturating_add(self.burn_amount)
     }
+
+    /// Canonical bytes covering every field of this distribution, in the
+    /// order a tamper would have to reproduce exactly. Used both to sign
+    /// (see [`FeeDistribution::sign`]) and to re-verify
+    /// ([`SignedFeeDistribution::verify`]) — any change to amounts,
+    /// addresses, or the bounty id changes these bytes and invalidates the
+    /// signature.
+    #[must_use]
+    pub fn signing_bytes(&self) -> Vec<u8> {
+        let mut data = Vec::new();
+        data.extend_from_slice(self.bounty_id.as_bytes());
+        data.extend_from_slice(&self.solver_amount.raw().to_be_bytes());
+        data.extend_from_slice(self.solver.as_bytes());
+        data.extend_from_slice(&self.verifier_amount.raw().to_be_bytes());
+        data.extend_from_slice(self.verifier.as_bytes());
+        data.extend_from_slice(&self.burn_amount.raw().to_be_bytes());
+        data
+    }
+
+    /// Sign this distribution with `keypair`, producing a receipt any node
+    /// can independently verify as authorized without trusting whoever
+    /// relays it.
+    #[must_use]
+    pub fn sign(self, keypair: &Keypair) -> SignedFeeDistribution {
+        let signature = keypair.sign(&self.signing_bytes());
+        SignedFeeDistribution {
+            distribution: self,
+            signer: keypair.public_key().clone(),
+            signature,
+        }
+    }
+}
+
+/// A [`FeeDistribution`] bundled with a signature over its canonical bytes,
+/// letting any node audit that a bounty was split by an authorized
+/// distributor rather than forged or tampered with in transit.
+#[derive(Clone, Debug)]
+pub struct SignedFeeDistribution {
+    /// The distribution being attested to
+    pub distribution: FeeDistribution,
+    /// Public key of the distributor that signed this receipt
+    pub signer: PublicKey,
+    /// Signature over `distribution.signing_bytes()`
+    pub signature: Signature,
+}
+
+impl SignedFeeDistribution {
+    /// Recompute the distribution's canonical bytes and check `signature`
+    /// against `signer`. A tampered amount, address, or bounty id changes
+    /// the canonical bytes and fails verification.
+    ///
+    /// # Errors
+    /// Returns error if the signature doesn't verify.
+    pub fn verify(&self) -> CryptoResult<()> {
+        verify(
+            &self.signer,
+            &self.distribution.signing_bytes(),
+            &self.signature,
+        )
+    }
 }
 
 /// Distributes fees according to protocol rules
@@ -67,6 +130,7 @@ impl FeeDistributor {
     #[must_use]
     pub fn distribute(
         &self,
+        bounty_id: Id,
         bounty: HclawAmount,
         solver: Address,
         verifier: Address,
@@ -80,6 +144,7 @@ impl FeeDistributor {
             .saturating_sub(verifier_amount);
 
         FeeDistribution {
+            bounty_id,
             solver_amount,
             solver,
             verifier_amount,
@@ -110,7 +175,7 @@ mod tests {
         let distributor = FeeDistributor::default_shares();
         let bounty = HclawAmount::from_hclaw(100);
 
-        let dist = distributor.distribute(bounty, test_address(), test_address());
+        let dist = distributor.distribute(Id::ZERO, bounty, test_address(), test_address());
 
         assert_eq!(dist.solver_amount.whole_hclaw(), 95);
         assert_eq!(dist.verifier_amount.whole_hclaw(), 4);
@@ -122,7 +187,7 @@ mod tests {
         let distributor = FeeDistributor::new(50, 30, 20);
         let bounty = HclawAmount::from_hclaw(1000);
 
-        let dist = distributor.distribute(bounty, test_address(), test_address());
+        let dist = distributor.distribute(Id::ZERO, bounty, test_address(), test_address());
 
         // Total should equal original (minus any rounding dust)
         let total = dist.total();
@@ -135,4 +200,42 @@ mod tests {
     fn test_invalid_shares() {
         let _ = FeeDistributor::new(50, 50, 50); // Sums to 150, not 100
     }
+
+    #[test]
+    fn test_signed_distribution_round_trips() {
+        let distributor = FeeDistributor::default_shares();
+        let bounty = HclawAmount::from_hclaw(100);
+        let dist = distributor.distribute(Id::ZERO, bounty, test_address(), test_address());
+
+        let kp = Keypair::generate();
+        let signed = dist.sign(&kp);
+
+        assert!(signed.verify().is_ok());
+    }
+
+    #[test]
+    fn test_signed_distribution_rejects_tampered_amount() {
+        let distributor = FeeDistributor::default_shares();
+        let bounty = HclawAmount::from_hclaw(100);
+        let dist = distributor.distribute(Id::ZERO, bounty, test_address(), test_address());
+
+        let kp = Keypair::generate();
+        let mut signed = dist.sign(&kp);
+        signed.distribution.solver_amount = HclawAmount::from_hclaw(1);
+
+        assert!(signed.verify().is_err());
+    }
+
+    #[test]
+    fn test_signed_distribution_rejects_wrong_signer() {
+        let distributor = FeeDistributor::default_shares();
+        let bounty = HclawAmount::from_hclaw(100);
+        let dist = distributor.distribute(Id::ZERO, bounty, test_address(), test_address());
+
+        let signer = Keypair::generate();
+        let mut signed = dist.sign(&signer);
+        signed.signer = Keypair::generate().public_key().clone();
+
+        assert!(signed.verify().is_err());
+    }
 }
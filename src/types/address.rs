@@ -8,7 +8,7 @@ use crate::crypto::{hash_data, PublicKey};
 /// A network address derived from a public key.
 ///
 /// Address = BLAKE3(PublicKey)[0..20] (20 bytes, similar to Ethereum)
-#[derive(Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct Address([u8; 20]);
 
 impl Address {
@@ -30,6 +30,26 @@ impl Address {
         Self(addr)
     }
 
+    /// Derive the address of a k-of-n multisig group from its key set and
+    /// threshold. The keys are sorted first, so the address doesn't depend
+    /// on the order callers happened to list them in.
+    #[must_use]
+    pub fn from_multisig(public_keys: &[PublicKey], threshold: u8) -> Self {
+        let mut sorted_keys: Vec<&PublicKey> = public_keys.iter().collect();
+        sorted_keys.sort_by(|a, b| a.as_bytes().cmp(b.as_bytes()));
+
+        let mut data = Vec::new();
+        for key in sorted_keys {
+            data.extend_from_slice(key.as_bytes());
+        }
+        data.push(threshold);
+
+        let hash = hash_data(&data);
+        let mut addr = [0u8; 20];
+        addr.copy_from_slice(&hash.as_bytes()[..20]);
+        Self(addr)
+    }
+
     /// Get the underlying bytes
     #[must_use]
     pub const fn as_bytes(&self) -> &[u8; 20] {
@@ -42,12 +62,54 @@ impl Address {
         format!("0x{}", hex::encode(self.0))
     }
 
-    /// Parse from hex string (with or without 0x prefix)
+    /// Convert to an EIP-55-style mixed-case checksummed hex string.
+    ///
+    /// The 40 lowercase hex characters are hashed with `hash_data` (BLAKE3),
+    /// and each hex character is uppercased when the corresponding nibble of
+    /// the hash is `>= 8`. Digits (`0`-`9`) are left unchanged.
+    #[must_use]
+    pub fn to_checksummed_hex(&self) -> String {
+        let lower = hex::encode(self.0);
+        let digest = hash_data(lower.as_bytes());
+        let digest_hex = hex::encode(digest.as_bytes());
+
+        let checksummed: String = lower
+            .chars()
+            .zip(digest_hex.chars())
+            .map(|(c, nibble)| {
+                if c.is_ascii_digit() {
+                    c
+                } else {
+                    let nibble_value = nibble.to_digit(16).unwrap_or(0);
+                    if nibble_value >= 8 {
+                        c.to_ascii_uppercase()
+                    } else {
+                        c.to_ascii_lowercase()
+                    }
+                }
+            })
+            .collect();
+
+        format!("0x{checksummed}")
+    }
+
+    /// Parse from hex string (with or without 0x prefix).
+    ///
+    /// If the string mixes upper and lower case, its checksum is verified
+    /// against the EIP-55-style scheme used by [`Self::to_checksummed_hex`].
+    /// All-lowercase or all-uppercase strings are accepted unchecked, for
+    /// backward compatibility with plain hex encodings.
     ///
     /// # Errors
-    /// Returns error if hex is invalid or wrong length
+    /// Returns error if hex is invalid, wrong length, or fails checksum
+    /// verification.
     pub fn from_hex(s: &str) -> Result<Self, AddressError> {
         let s = s.strip_prefix("0x").unwrap_or(s);
+
+        let has_upper = s.chars().any(|c| c.is_ascii_uppercase());
+        let has_lower = s.chars().any(|c| c.is_ascii_lowercase());
+        let is_mixed_case = has_upper && has_lower;
+
         let bytes = hex::decode(s).map_err(|_| AddressError::InvalidHex)?;
 
         if bytes.len() != 20 {
@@ -56,7 +118,13 @@ impl Address {
 
         let mut arr = [0u8; 20];
         arr.copy_from_slice(&bytes);
-        Ok(Self(arr))
+        let addr = Self(arr);
+
+        if is_mixed_case && addr.to_checksummed_hex() != format!("0x{s}") {
+            return Err(AddressError::InvalidChecksum);
+        }
+
+        Ok(addr)
     }
 
     /// Check if this is the zero/burn address
@@ -87,6 +155,9 @@ pub enum AddressError {
     /// Invalid address length
     #[error("invalid address length: expected 20 bytes, got {0}")]
     InvalidLength(usize),
+    /// Mixed-case address failed EIP-55-style checksum verification
+    #[error("invalid address checksum")]
+    InvalidChecksum,
 }
 
 #[cfg(test)]
@@ -122,4 +193,61 @@ mod tests {
         let addr = Address::from_public_key(kp.public_key());
         assert!(!addr.is_zero());
     }
+
+    #[test]
+    fn test_checksummed_hex_roundtrip() {
+        let kp = Keypair::generate();
+        let addr = Address::from_public_key(kp.public_key());
+
+        let checksummed = addr.to_checksummed_hex();
+        let parsed = Address::from_hex(&checksummed).unwrap();
+        assert_eq!(addr, parsed);
+    }
+
+    #[test]
+    fn test_checksummed_hex_is_mixed_case() {
+        // Run over several random addresses; at least one should produce a
+        // mixed-case checksum (all-same-case would only happen by chance).
+        let mixed = (0..8).any(|_| {
+            let kp = Keypair::generate();
+            let addr = Address::from_public_key(kp.public_key());
+            let hex = addr.to_checksummed_hex();
+            let has_upper = hex.chars().any(|c| c.is_ascii_uppercase());
+            let has_lower = hex.chars().any(|c| c.is_ascii_lowercase());
+            has_upper && has_lower
+        });
+        assert!(mixed);
+    }
+
+    #[test]
+    fn test_lowercase_hex_accepted_unchecked() {
+        let kp = Keypair::generate();
+        let addr = Address::from_public_key(kp.public_key());
+        let lower = addr.to_hex();
+        assert_eq!(Address::from_hex(&lower).unwrap(), addr);
+    }
+
+    #[test]
+    fn test_bad_checksum_rejected() {
+        let kp = Keypair::generate();
+        let addr = Address::from_public_key(kp.public_key());
+        let checksummed = addr.to_checksummed_hex();
+        let stripped = checksummed.strip_prefix("0x").unwrap();
+
+        // Flip the case of the first alphabetic character to corrupt the checksum.
+        let mut chars: Vec<char> = stripped.chars().collect();
+        let idx = chars.iter().position(|c| c.is_ascii_alphabetic());
+        if let Some(idx) = idx {
+            chars[idx] = if chars[idx].is_ascii_uppercase() {
+                chars[idx].to_ascii_lowercase()
+            } else {
+                chars[idx].to_ascii_uppercase()
+            };
+            let corrupted = format!("0x{}", chars.into_iter().collect::<String>());
+            assert!(matches!(
+                Address::from_hex(&corrupted),
+                Err(AddressError::InvalidChecksum)
+            ));
+        }
+    }
 }
@@ -1,11 +1,21 @@
 //! Blocks in the `HardClaw` blockchain.
 //!
-//! A block contains verified solutions and state transitions.
-//! Blocks are valid only with 66% consensus from verifiers.
+//! A block contains verified solutions and state transitions. Validity
+//! and quorum rules are pluggable: callers pass in a
+//! [`ConsensusEngine`](crate::consensus::ConsensusEngine) (proof-of-
+//! verification's 66% attestation rule by default) rather than `Block`
+//! hardcoding one itself. Quorum is checked against a
+//! [`ValidatorSet`](crate::consensus::ValidatorSet) rather than a bare
+//! attestation headcount — `header.epoch` names which epoch's set that
+//! is, so a block's attestations are always checked against the set that
+//! was active when it was produced.
+
+use std::collections::HashSet;
 
 use serde::{Deserialize, Serialize};
 
 use super::{now_millis, Address, HclawAmount, Id, Timestamp, VerificationResult};
+use crate::consensus::ValidatorSet;
 use crate::crypto::{hash_data, merkle_root, Hash, PublicKey, Signature};
 use crate::types::job::JobPacket;
 
@@ -14,6 +24,10 @@ use crate::types::job::JobPacket;
 pub struct BlockHeader {
     /// Block number (height)
     pub height: u64,
+    /// Epoch this block belongs to. Selects which
+    /// [`ValidatorSet`](crate::consensus::ValidatorSet) snapshot its
+    /// attestations are checked against.
+    pub epoch: u64,
     /// Hash of the previous block
     pub parent_hash: Hash,
     /// Merkle root of verified solutions in this block
@@ -36,6 +50,7 @@ impl BlockHeader {
     pub fn compute_hash(&self) -> Hash {
         let mut data = Vec::new();
         data.extend_from_slice(&self.height.to_le_bytes());
+        data.extend_from_slice(&self.epoch.to_le_bytes());
         data.extend_from_slice(self.parent_hash.as_bytes());
         data.extend_from_slice(self.solutions_root.as_bytes());
         data.extend_from_slice(self.state_root.as_bytes());
@@ -135,6 +150,7 @@ impl Block {
     #[must_use]
     pub fn new(
         height: u64,
+        epoch: u64,
         parent_hash: Hash,
         proposer: PublicKey,
         verifications: Vec<VerificationResult>,
@@ -145,6 +161,7 @@ impl Block {
 
         let header = BlockHeader {
             height,
+            epoch,
             parent_hash,
             solutions_root,
             state_root,
@@ -170,7 +187,7 @@ impl Block {
     /// Create the genesis block
     #[must_use]
     pub fn genesis(proposer: PublicKey) -> Self {
-        Self::new(0, Hash::ZERO, proposer, Vec::new(), Hash::ZERO)
+        Self::new(0, 0, Hash::ZERO, proposer, Vec::new(), Hash::ZERO)
     }
 
     /// Create the genesis block with the Genesis Job and initial allocations.
@@ -180,7 +197,7 @@ impl Block {
     /// to Ethereum's genesis alloc.
     #[must_use]
     pub fn genesis_with_job(proposer: PublicKey, job: JobPacket, alloc: Vec<GenesisAlloc>) -> Self {
-        let mut block = Self::new(0, Hash::ZERO, proposer, Vec::new(), Hash::ZERO);
+        let mut block = Self::new(0, 0, Hash::ZERO, proposer, Vec::new(), Hash::ZERO);
         block.genesis_job = Some(job);
         block.genesis_alloc = alloc;
         // Recompute hash to include genesis job + alloc commitment
@@ -206,35 +223,59 @@ impl Block {
         self.attestations.push(attestation);
     }
 
-    /// Check if the block has reached consensus (66%+ attestations)
-    ///
-    /// # Arguments
-    /// * `total_verifiers` - Total number of active verifiers in the network
+    /// Check if the block has reached consensus under `engine`, given the
+    /// `validator_set` active during its epoch.
     #[must_use]
-    pub fn has_consensus(&self, total_verifiers: usize) -> bool {
-        if total_verifiers == 0 {
+    pub fn has_consensus(
+        &self,
+        engine: &impl crate::consensus::ConsensusEngine,
+        validator_set: &ValidatorSet,
+    ) -> bool {
+        if validator_set.is_empty() {
             return false;
         }
 
-        let threshold = (total_verifiers as f64 * crate::CONSENSUS_THRESHOLD).ceil() as usize;
-        self.attestations.len() >= threshold
+        self.attesting_stake(validator_set).raw() >= engine.stake_quorum(validator_set).raw()
     }
 
-    /// Get consensus percentage
+    /// Get the fraction of `validator_set`'s total stake that has
+    /// attested to this block.
     #[must_use]
-    pub fn consensus_percentage(&self, total_verifiers: usize) -> f64 {
-        if total_verifiers == 0 {
+    pub fn consensus_percentage(&self, validator_set: &ValidatorSet) -> f64 {
+        let total = validator_set.total_stake().raw();
+        if total == 0 {
             return 0.0;
         }
 
-        self.attestations.len() as f64 / total_verifiers as f64
+        self.attesting_stake(validator_set).raw() as f64 / total as f64
+    }
+
+    /// Sum the stake of every distinct validator-set member who has
+    /// attested to this block. Attestations from addresses outside
+    /// `validator_set` (and duplicate attestations from the same
+    /// validator) don't add weight.
+    fn attesting_stake(&self, validator_set: &ValidatorSet) -> HclawAmount {
+        let mut counted = HashSet::new();
+        let mut total: u128 = 0;
+
+        for attestation in &self.attestations {
+            if !counted.insert(&attestation.verifier) {
+                continue;
+            }
+            if let Some(stake) = validator_set.stake_of(&attestation.verifier) {
+                total += stake.raw();
+            }
+        }
+
+        HclawAmount::from_raw(total)
     }
 
-    /// Verify block integrity
+    /// Verify block integrity against the `validator_set` active during
+    /// its epoch.
     ///
     /// # Errors
     /// Returns error if block is invalid
-    pub fn verify_integrity(&self) -> Result<(), BlockError> {
+    pub fn verify_integrity(&self, validator_set: &ValidatorSet) -> Result<(), BlockError> {
         // Check hash matches header
         let computed_hash = self.header.compute_hash();
         if computed_hash != self.hash {
@@ -247,11 +288,14 @@ impl Block {
             return Err(BlockError::SolutionsRootMismatch);
         }
 
-        // Verify attestation signatures
+        // Verify attestation signatures and validator-set membership
         for attestation in &self.attestations {
             attestation
                 .verify_signature()
                 .map_err(|_| BlockError::InvalidAttestation)?;
+            if !validator_set.contains(&attestation.verifier) {
+                return Err(BlockError::UnauthorizedAttester);
+            }
         }
 
         Ok(())
@@ -287,11 +331,14 @@ pub enum BlockError {
     #[error("invalid block height: expected {expected}, got {got}")]
     InvalidHeight { expected: u64, got: u64 },
     /// Insufficient consensus
-    #[error("insufficient consensus: {percentage}% < 66%")]
+    #[error("insufficient consensus: {percentage}% of validator set attested")]
     InsufficientConsensus { percentage: f64 },
     /// Invalid attestation signature
     #[error("invalid attestation signature")]
     InvalidAttestation,
+    /// Attestation came from an address outside the epoch's validator set
+    #[error("attestation from address outside the epoch validator set")]
+    UnauthorizedAttester,
     /// Block timestamp too far in future
     #[error("block timestamp in future")]
     FutureTimestamp,
@@ -300,6 +347,7 @@ pub enum BlockError {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::consensus::ValidatorEntry;
     use crate::crypto::Keypair;
 
     #[test]
@@ -316,6 +364,7 @@ mod tests {
         let kp = Keypair::generate();
         let block = Block::new(
             1,
+            0,
             Hash::ZERO,
             kp.public_key().clone(),
             Vec::new(),
@@ -331,25 +380,74 @@ mod tests {
         let kp = Keypair::generate();
         let mut block = Block::new(
             1,
+            0,
             Hash::ZERO,
             kp.public_key().clone(),
             Vec::new(),
             Hash::ZERO,
         );
+        let engine = crate::consensus::ProofOfVerification;
+        let validator_kps: Vec<Keypair> = (0..10).map(|_| Keypair::generate()).collect();
+        let validator_set = ValidatorSet::new(
+            0,
+            validator_kps
+                .iter()
+                .map(|kp| ValidatorEntry {
+                    public_key: kp.public_key().clone(),
+                    stake: HclawAmount::from_raw(100),
+                })
+                .collect(),
+        );
 
-        // With 10 verifiers, need 7 (66% rounded up)
-        assert!(!block.has_consensus(10));
+        // With 10 equally-staked validators, need 700/1000 (66% rounded up)
+        assert!(!block.has_consensus(&engine, &validator_set));
 
-        // Add 7 attestations
-        for _ in 0..7 {
-            let verifier_kp = Keypair::generate();
+        // Attest with 7 of the 10 validators in the set
+        for verifier_kp in validator_kps.iter().take(7) {
             let mut attestation =
                 VerifierAttestation::new(verifier_kp.public_key().clone(), block.hash, Vec::new());
             attestation.signature = verifier_kp.sign(&attestation.signing_bytes());
             block.add_attestation(attestation);
         }
 
-        assert!(block.has_consensus(10));
+        assert!(block.has_consensus(&engine, &validator_set));
+    }
+
+    #[test]
+    fn test_consensus_ignores_attestations_outside_validator_set() {
+        let kp = Keypair::generate();
+        let mut block = Block::new(
+            1,
+            0,
+            Hash::ZERO,
+            kp.public_key().clone(),
+            Vec::new(),
+            Hash::ZERO,
+        );
+        let engine = crate::consensus::ProofOfVerification;
+        let validator_kps: Vec<Keypair> = (0..10).map(|_| Keypair::generate()).collect();
+        let validator_set = ValidatorSet::new(
+            0,
+            validator_kps
+                .iter()
+                .map(|kp| ValidatorEntry {
+                    public_key: kp.public_key().clone(),
+                    stake: HclawAmount::from_raw(100),
+                })
+                .collect(),
+        );
+
+        // 7 attestations from outsiders carry no weight in this set.
+        for _ in 0..7 {
+            let outsider_kp = Keypair::generate();
+            let mut attestation =
+                VerifierAttestation::new(outsider_kp.public_key().clone(), block.hash, Vec::new());
+            attestation.signature = outsider_kp.sign(&attestation.signing_bytes());
+            block.add_attestation(attestation);
+        }
+
+        assert!(!block.has_consensus(&engine, &validator_set));
+        assert_eq!(block.consensus_percentage(&validator_set), 0.0);
     }
 
     #[test]
@@ -357,12 +455,36 @@ mod tests {
         let kp = Keypair::generate();
         let block = Block::new(
             1,
+            0,
             Hash::ZERO,
             kp.public_key().clone(),
             Vec::new(),
             Hash::ZERO,
         );
 
-        assert!(block.verify_integrity().is_ok());
+        assert!(block.verify_integrity(&ValidatorSet::default()).is_ok());
+    }
+
+    #[test]
+    fn test_verify_integrity_rejects_attestation_outside_validator_set() {
+        let kp = Keypair::generate();
+        let mut block = Block::new(
+            1,
+            0,
+            Hash::ZERO,
+            kp.public_key().clone(),
+            Vec::new(),
+            Hash::ZERO,
+        );
+        let outsider_kp = Keypair::generate();
+        let mut attestation =
+            VerifierAttestation::new(outsider_kp.public_key().clone(), block.hash, Vec::new());
+        attestation.signature = outsider_kp.sign(&attestation.signing_bytes());
+        block.add_attestation(attestation);
+
+        assert!(matches!(
+            block.verify_integrity(&ValidatorSet::default()),
+            Err(BlockError::UnauthorizedAttester)
+        ));
     }
 }
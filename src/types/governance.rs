@@ -45,4 +45,82 @@ pub enum GovernanceAction {
         /// Contract ID to resume
         contract_id: Id,
     },
+    /// Register a standing payment stream from the treasury, disbursed a
+    /// fixed amount per epoch over `[start, end]` rather than transferring
+    /// once
+    ContinuousFunding {
+        /// Recipient of each epoch's disbursement
+        recipient: Address,
+        /// Amount paid out per epoch the stream is active
+        amount_per_epoch: HclawAmount,
+        /// First epoch (inclusive) the stream pays out for
+        start: u64,
+        /// Last epoch (inclusive) the stream pays out for
+        end: u64,
+    },
+    /// Cancel an active continuous funding stream early
+    StopFunding {
+        /// ID of the stream to cancel
+        stream_id: Hash,
+    },
+    /// Update the governance contract's voting configuration (quorum,
+    /// approval threshold, minimum voting period, execution delay). Only
+    /// takes effect for proposals created after this action runs — an
+    /// already-created proposal keeps the rules it was created under.
+    ConfigUpdate {
+        /// New minimum voting period a proposal must allow (milliseconds)
+        min_voting_period: i64,
+        /// New quorum requirement (percent of total voting power)
+        quorum_percent: u8,
+        /// New approval threshold (percent of directional votes cast)
+        approval_threshold: u8,
+        /// New delay between a proposal passing and becoming eligible to
+        /// execute (milliseconds)
+        execution_delay: i64,
+    },
+    /// Escrow a treasury spend that only releases to `recipient` once every
+    /// condition in `conditions` is witnessed satisfied, instead of
+    /// transferring immediately like `TreasurySpend`
+    ConditionalSpend {
+        /// Recipient once all conditions are satisfied
+        recipient: Address,
+        /// Amount to move from the treasury into escrow
+        amount: HclawAmount,
+        /// Conditions gating release, all of which must be satisfied
+        conditions: Vec<SpendCondition>,
+        /// If the conditions aren't all satisfied by this time, the escrow
+        /// can be refunded back to the treasury instead of released
+        expires_at: Option<i64>,
+    },
+    /// Move funds from the treasury into the governance contract's own
+    /// custody as a collateral backstop, without assigning them to any
+    /// recipient the way `TreasurySpend`/`ConditionalSpend` do
+    AddCollateral {
+        /// Amount to move from the treasury into collateral
+        amount: HclawAmount,
+    },
+    /// Deregister `account` from the chairperson-curated registered-voter
+    /// set via a passed proposal, rather than requiring the chairperson to
+    /// call `RevokeVoter` directly
+    RemoveVoter {
+        /// Account to deregister
+        account: Address,
+    },
+}
+
+/// A condition gating a `ConditionalSpend` escrow's release. Conditions form
+/// a small boolean expression tree (via `Or`/`And`) over timestamp and
+/// signer-based leaves, modeled on the Solana budget program's
+/// `PaymentPlan`/`Witness` design.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SpendCondition {
+    /// Satisfied once the current time is at or past this timestamp (ms)
+    AfterTimestamp(i64),
+    /// Satisfied once a witnessing transaction has been submitted on behalf
+    /// of this address (the named oracle/committee member)
+    Signature(Address),
+    /// Satisfied if either inner condition is satisfied
+    Or(Box<SpendCondition>, Box<SpendCondition>),
+    /// Satisfied only once both inner conditions are satisfied
+    And(Box<SpendCondition>, Box<SpendCondition>),
 }
@@ -4,7 +4,7 @@
 //! rolling window approach. This protects honest contrarians while
 //! still catching lazy or malicious verifiers through pattern detection.
 
-use std::collections::{HashMap, VecDeque};
+use std::collections::{HashMap, HashSet, VecDeque};
 
 use serde::{Deserialize, Serialize};
 
@@ -28,6 +28,26 @@ pub struct AccuracyConfig {
     pub critical_slash_percent: u8,
     /// Minimum verifications before accuracy is evaluated
     pub min_verifications: usize,
+    /// Slash-and-deactivate percentage for a proven equivocation (two
+    /// contradictory outcomes recorded for the same `solution_id`) — kept
+    /// separate from, and typically far higher than, `critical_slash_percent`
+    /// since a double vote is proven fraud rather than a rolling-window
+    /// accuracy trend.
+    pub equivocation_slash_percent: u8,
+    /// Number of most-recent solutions considered when building the
+    /// pairwise agreement matrix for collusion detection
+    pub collusion_window: usize,
+    /// Minimum number of solutions two verifiers must have both voted on
+    /// before their pairwise agreement rate is considered statistically
+    /// meaningful
+    pub min_pair_observations: usize,
+    /// How many standard deviations above the network-wide baseline
+    /// pairwise agreement rate a pair must clear to be flagged as
+    /// suspicious
+    pub collusion_stddev_threshold: f64,
+    /// Slash percentage applied to every member of a detected collusion
+    /// cluster
+    pub collusion_slash_percent: u8,
 }
 
 impl Default for AccuracyConfig {
@@ -40,12 +60,17 @@ impl Default for AccuracyConfig {
             slash_percent: 2,
             critical_slash_percent: 10,
             min_verifications: 20,
+            equivocation_slash_percent: 50,
+            collusion_window: 500,
+            min_pair_observations: 20,
+            collusion_stddev_threshold: 3.0,
+            collusion_slash_percent: 15,
         }
     }
 }
 
 /// Result of a single verification from this verifier's perspective
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct VerificationOutcome {
     /// Did this verifier agree with consensus?
     pub agreed_with_consensus: bool,
@@ -85,6 +110,12 @@ pub struct VerifierAccuracy {
     pub total_verifications: u64,
     /// Lifetime agreements
     pub total_agreements: u64,
+    /// First recorded outcome per `solution_id`, kept for the verifier's
+    /// whole lifetime rather than trimmed with the rolling window — a
+    /// contradictory second vote on the same solution is provable
+    /// equivocation even after the original vote has aged out of
+    /// `outcomes`.
+    solution_votes: HashMap<Hash, VerificationOutcome>,
 }
 
 impl VerifierAccuracy {
@@ -98,11 +129,29 @@ impl VerifierAccuracy {
             status: AccuracyStatus::Probationary,
             total_verifications: 0,
             total_agreements: 0,
+            solution_votes: HashMap::new(),
         }
     }
 
-    /// Record a new verification outcome
-    pub fn record(&mut self, outcome: VerificationOutcome, config: &AccuracyConfig) {
+    /// Record a new verification outcome. Returns the conflicting pair of
+    /// outcomes if this contradicts an earlier vote recorded for the same
+    /// `solution_id` — a proven equivocation, distinct from the rolling
+    /// accuracy trend tracked below.
+    pub fn record(
+        &mut self,
+        outcome: VerificationOutcome,
+        config: &AccuracyConfig,
+    ) -> Option<(VerificationOutcome, VerificationOutcome)> {
+        let equivocation = match self.solution_votes.get(&outcome.solution_id) {
+            Some(first) if first.agreed_with_consensus != outcome.agreed_with_consensus => {
+                Some((first.clone(), outcome.clone()))
+            }
+            _ => None,
+        };
+        self.solution_votes
+            .entry(outcome.solution_id)
+            .or_insert_with(|| outcome.clone());
+
         self.total_verifications += 1;
         if outcome.agreed_with_consensus {
             self.agreement_count += 1;
@@ -120,6 +169,8 @@ impl VerifierAccuracy {
         }
 
         self.update_status(config);
+
+        equivocation
     }
 
     fn update_status(&mut self, config: &AccuracyConfig) {
@@ -163,6 +214,22 @@ pub enum SlashAction {
         /// Percentage to slash
         percent: u8,
     },
+    /// Graduated slash applied to every member of a detected collusion
+    /// cluster (see [`AccuracyTracker::detect_collusion_clusters`])
+    Collusion {
+        /// Percentage to slash each cluster member
+        percent: u8,
+    },
+    /// Immediate slash-and-deactivate for a proven equivocation — bypasses
+    /// the rolling accuracy status entirely, since a double vote on the
+    /// same solution is fraud rather than a trend
+    Equivocation {
+        /// Percentage to slash
+        percent: u8,
+        /// The two contradictory outcomes proving the double vote, kept so
+        /// the node can persist them as a fraud proof
+        evidence: (VerificationOutcome, VerificationOutcome),
+    },
 }
 
 /// Manages accuracy tracking for all verifiers
@@ -172,6 +239,14 @@ pub struct AccuracyTracker {
     config: AccuracyConfig,
     /// Accuracy records per verifier
     verifiers: HashMap<Address, VerifierAccuracy>,
+    /// `solution_id`s with at least one recorded vote, oldest first, in the
+    /// collusion-detection window — paired with `solution_votes` so the
+    /// oldest solution's votes can be evicted once the window overflows.
+    solution_order: VecDeque<Hash>,
+    /// Every verifier's vote on each solution still in the collusion
+    /// window, used to build the pairwise agreement matrix in
+    /// [`Self::detect_collusion_clusters`].
+    solution_votes: HashMap<Hash, Vec<(Address, bool)>>,
 }
 
 impl AccuracyTracker {
@@ -181,13 +256,121 @@ impl AccuracyTracker {
         Self {
             config,
             verifiers: HashMap::new(),
+            solution_order: VecDeque::new(),
+            solution_votes: HashMap::new(),
         }
     }
 
-    /// Record a verification outcome for a verifier
-    pub fn record_outcome(&mut self, verifier: &Address, outcome: VerificationOutcome) {
+    /// Record a verification outcome for a verifier. Returns the slash
+    /// action to apply immediately: a proven equivocation always wins over
+    /// the rolling accuracy status, since it's fraud rather than a trend.
+    #[must_use]
+    pub fn record_outcome(&mut self, verifier: &Address, outcome: VerificationOutcome) -> SlashAction {
+        self.record_for_collusion(*verifier, &outcome);
+
         let accuracy = self.verifiers.entry(*verifier).or_default();
-        accuracy.record(outcome, &self.config);
+        if let Some((first, second)) = accuracy.record(outcome, &self.config) {
+            return SlashAction::Equivocation {
+                percent: self.config.equivocation_slash_percent,
+                evidence: (first, second),
+            };
+        }
+
+        self.get_slash_action(verifier)
+    }
+
+    /// Fold a single verifier's vote into the collusion-detection window,
+    /// evicting the oldest solution's votes once more than `collusion_window`
+    /// solutions are being tracked.
+    fn record_for_collusion(&mut self, verifier: Address, outcome: &VerificationOutcome) {
+        if !self.solution_votes.contains_key(&outcome.solution_id) {
+            self.solution_order.push_back(outcome.solution_id);
+        }
+        self.solution_votes
+            .entry(outcome.solution_id)
+            .or_default()
+            .push((verifier, outcome.agreed_with_consensus));
+
+        while self.solution_order.len() > self.config.collusion_window {
+            if let Some(oldest) = self.solution_order.pop_front() {
+                self.solution_votes.remove(&oldest);
+            }
+        }
+    }
+
+    /// Find clusters of verifiers who vote identically with each other far
+    /// more often than the network baseline — a coordinated bloc that can
+    /// dominate consensus without ever looking inaccurate, since it defines
+    /// the consensus it would otherwise be measured against.
+    ///
+    /// Builds a per-pair agreement rate from every solution in the
+    /// collusion window, flags pairs whose rate clears
+    /// `baseline + collusion_stddev_threshold * stddev` (and that have at
+    /// least `min_pair_observations` shared solutions to be statistically
+    /// meaningful), then returns the connected components of the graph
+    /// formed by those flagged pairs. An honest contrarian — someone with a
+    /// low agreement rate with every specific peer, even if their
+    /// network-wide accuracy is also low — never shares a flagged edge with
+    /// anyone, so never appears in a returned cluster.
+    #[must_use]
+    pub fn detect_collusion_clusters(&self) -> Vec<HashSet<Address>> {
+        let mut co_occurrences: HashMap<(Address, Address), usize> = HashMap::new();
+        let mut agreements: HashMap<(Address, Address), usize> = HashMap::new();
+
+        for votes in self.solution_votes.values() {
+            for i in 0..votes.len() {
+                for j in (i + 1)..votes.len() {
+                    let (a, vote_a) = votes[i];
+                    let (b, vote_b) = votes[j];
+                    let pair = if a < b { (a, b) } else { (b, a) };
+                    *co_occurrences.entry(pair).or_insert(0) += 1;
+                    if vote_a == vote_b {
+                        *agreements.entry(pair).or_insert(0) += 1;
+                    }
+                }
+            }
+        }
+
+        let rates: Vec<((Address, Address), f64)> = co_occurrences
+            .iter()
+            .filter(|(_, &count)| count >= self.config.min_pair_observations)
+            .map(|(&pair, &count)| {
+                let agreed = agreements.get(&pair).copied().unwrap_or(0);
+                (pair, agreed as f64 / count as f64)
+            })
+            .collect();
+
+        if rates.is_empty() {
+            return Vec::new();
+        }
+
+        let baseline = rates.iter().map(|(_, rate)| rate).sum::<f64>() / rates.len() as f64;
+        let variance = rates
+            .iter()
+            .map(|(_, rate)| (rate - baseline).powi(2))
+            .sum::<f64>()
+            / rates.len() as f64;
+        let stddev = variance.sqrt();
+        let threshold = baseline + self.config.collusion_stddev_threshold * stddev;
+
+        let suspicious_edges: Vec<(Address, Address)> = rates
+            .into_iter()
+            .filter(|(_, rate)| *rate > threshold)
+            .map(|(pair, _)| pair)
+            .collect();
+
+        group_into_clusters(&suspicious_edges)
+    }
+
+    /// The graduated slash to apply to each member of a detected collusion
+    /// cluster. Separate from [`Self::get_slash_action`] since it applies
+    /// uniformly to every member of a cluster returned by
+    /// [`Self::detect_collusion_clusters`] rather than to a single verifier.
+    #[must_use]
+    pub fn collusion_slash_action(&self) -> SlashAction {
+        SlashAction::Collusion {
+            percent: self.config.collusion_slash_percent,
+        }
     }
 
     /// Get the current slash action for a verifier
@@ -228,6 +411,46 @@ impl Default for AccuracyTracker {
     }
 }
 
+/// Collapse a list of flagged pairs into connected components — everyone
+/// reachable from everyone else through a chain of flagged edges ends up in
+/// the same cluster, even if not every pair within it was individually
+/// flagged.
+fn group_into_clusters(edges: &[(Address, Address)]) -> Vec<HashSet<Address>> {
+    let mut clusters: Vec<HashSet<Address>> = Vec::new();
+
+    for &(a, b) in edges {
+        let mut matches: Vec<usize> = clusters
+            .iter()
+            .enumerate()
+            .filter(|(_, c)| c.contains(&a) || c.contains(&b))
+            .map(|(i, _)| i)
+            .collect();
+
+        match matches.len() {
+            0 => clusters.push(HashSet::from([a, b])),
+            1 => {
+                let cluster = &mut clusters[matches[0]];
+                cluster.insert(a);
+                cluster.insert(b);
+            }
+            _ => {
+                // This edge bridges two or more existing clusters — merge
+                // them all into the first and drop the rest.
+                matches.sort_unstable();
+                let primary = matches[0];
+                for &idx in matches[1..].iter().rev() {
+                    let merged = clusters.remove(idx);
+                    clusters[primary].extend(merged);
+                }
+                clusters[primary].insert(a);
+                clusters[primary].insert(b);
+            }
+        }
+    }
+
+    clusters
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -252,7 +475,7 @@ mod tests {
 
         // Less than 20 verifications — should be probationary
         for _ in 0..19 {
-            tracker.record_outcome(&addr, make_outcome(false));
+            let _ = tracker.record_outcome(&addr, make_outcome(false));
         }
 
         assert_eq!(tracker.get_status(&addr), AccuracyStatus::Probationary);
@@ -266,7 +489,7 @@ mod tests {
 
         // 80% agreement — above 70% warning threshold
         for i in 0..100 {
-            tracker.record_outcome(&addr, make_outcome(i % 5 != 0));
+            let _ = tracker.record_outcome(&addr, make_outcome(i % 5 != 0));
         }
 
         assert_eq!(tracker.get_status(&addr), AccuracyStatus::Good);
@@ -279,7 +502,7 @@ mod tests {
 
         // 65% agreement — between 60% slash and 70% warning
         for i in 0..100 {
-            tracker.record_outcome(&addr, make_outcome(i < 65));
+            let _ = tracker.record_outcome(&addr, make_outcome(i < 65));
         }
 
         assert_eq!(tracker.get_status(&addr), AccuracyStatus::Warning);
@@ -293,7 +516,7 @@ mod tests {
 
         // 50% agreement — between 40% critical and 60% slash
         for i in 0..100 {
-            tracker.record_outcome(&addr, make_outcome(i < 50));
+            let _ = tracker.record_outcome(&addr, make_outcome(i < 50));
         }
 
         assert_eq!(tracker.get_status(&addr), AccuracyStatus::Slashing);
@@ -310,7 +533,7 @@ mod tests {
 
         // 30% agreement — below 40% critical
         for i in 0..100 {
-            tracker.record_outcome(&addr, make_outcome(i < 30));
+            let _ = tracker.record_outcome(&addr, make_outcome(i < 30));
         }
 
         assert_eq!(tracker.get_status(&addr), AccuracyStatus::Critical);
@@ -327,13 +550,13 @@ mod tests {
 
         // Start with bad accuracy (50 agreements out of 100)
         for i in 0..100 {
-            tracker.record_outcome(&addr, make_outcome(i < 50));
+            let _ = tracker.record_outcome(&addr, make_outcome(i < 50));
         }
         assert_eq!(tracker.get_status(&addr), AccuracyStatus::Slashing);
 
         // Now 100 more all agreeing — pushes out the old bad ones
         for _ in 0..100 {
-            tracker.record_outcome(&addr, make_outcome(true));
+            let _ = tracker.record_outcome(&addr, make_outcome(true));
         }
         assert_eq!(tracker.get_status(&addr), AccuracyStatus::Good);
     }
@@ -345,10 +568,125 @@ mod tests {
 
         // 99 agreements, 1 disagreement
         for i in 0..100 {
-            tracker.record_outcome(&addr, make_outcome(i != 50));
+            let _ = tracker.record_outcome(&addr, make_outcome(i != 50));
         }
 
         assert_eq!(tracker.get_status(&addr), AccuracyStatus::Good);
         assert_eq!(tracker.get_slash_action(&addr), SlashAction::None);
     }
+
+    fn make_outcome_for(agreed: bool, solution_id: Hash) -> VerificationOutcome {
+        VerificationOutcome {
+            agreed_with_consensus: agreed,
+            solution_id,
+            timestamp: 0,
+        }
+    }
+
+    #[test]
+    fn test_collusion_cluster_detected_for_always_aligned_pair() {
+        // A lower stddev multiplier than the default, since this test's
+        // tiny three-pair network doesn't give the baseline/stddev enough
+        // samples to be as conservative as production config would want.
+        let config = AccuracyConfig {
+            collusion_stddev_threshold: 1.0,
+            ..AccuracyConfig::default()
+        };
+        let mut tracker = AccuracyTracker::new(config);
+        let colluder_a = test_addr();
+        let colluder_b = test_addr();
+        let honest = test_addr();
+
+        // The colluding pair always vote identically; the honest verifier
+        // disagrees with them roughly half the time, like an unrelated
+        // third party.
+        for i in 0..60 {
+            let solution = crate::crypto::hash_data(format!("solution-{i}").as_bytes());
+            let bloc_vote = i % 2 == 0;
+            let _ = tracker.record_outcome(&colluder_a, make_outcome_for(bloc_vote, solution));
+            let _ = tracker.record_outcome(&colluder_b, make_outcome_for(bloc_vote, solution));
+            let _ = tracker.record_outcome(&honest, make_outcome_for(i % 3 == 0, solution));
+        }
+
+        let clusters = tracker.detect_collusion_clusters();
+        assert!(
+            clusters
+                .iter()
+                .any(|c| c.contains(&colluder_a) && c.contains(&colluder_b)),
+            "expected the always-aligned pair to form a cluster: {clusters:?}"
+        );
+        assert!(
+            clusters.iter().all(|c| !c.contains(&honest)),
+            "honest contrarian must never be flagged: {clusters:?}"
+        );
+    }
+
+    #[test]
+    fn test_no_collusion_cluster_when_votes_uncorrelated() {
+        let mut tracker = AccuracyTracker::default();
+        let a = test_addr();
+        let b = test_addr();
+
+        for i in 0..60 {
+            let solution = crate::crypto::hash_data(format!("solution-{i}").as_bytes());
+            let _ = tracker.record_outcome(&a, make_outcome_for(i % 2 == 0, solution));
+            let _ = tracker.record_outcome(&b, make_outcome_for(i % 3 == 0, solution));
+        }
+
+        assert!(tracker.detect_collusion_clusters().is_empty());
+    }
+
+    #[test]
+    fn test_collusion_slash_action_uses_configured_percent() {
+        let config = AccuracyConfig {
+            collusion_slash_percent: 42,
+            ..AccuracyConfig::default()
+        };
+        let tracker = AccuracyTracker::new(config);
+        assert_eq!(
+            tracker.collusion_slash_action(),
+            SlashAction::Collusion { percent: 42 }
+        );
+    }
+
+    #[test]
+    fn test_equivocation_detected_on_conflicting_vote() {
+        let mut tracker = AccuracyTracker::default();
+        let addr = test_addr();
+        let solution = crate::crypto::hash_data(b"solution-1");
+
+        assert_eq!(
+            tracker.record_outcome(&addr, make_outcome_for(true, solution)),
+            SlashAction::None
+        );
+
+        // Same solution, opposite vote — a proven double vote.
+        let action = tracker.record_outcome(&addr, make_outcome_for(false, solution));
+        match action {
+            SlashAction::Equivocation { percent, evidence } => {
+                assert_eq!(percent, AccuracyConfig::default().equivocation_slash_percent);
+                assert!(evidence.0.agreed_with_consensus);
+                assert!(!evidence.1.agreed_with_consensus);
+            }
+            other => panic!("expected equivocation, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_no_equivocation_on_repeated_identical_vote() {
+        let mut tracker = AccuracyTracker::default();
+        let addr = test_addr();
+        let solution = crate::crypto::hash_data(b"solution-2");
+
+        assert_eq!(
+            tracker.record_outcome(&addr, make_outcome_for(true, solution)),
+            SlashAction::None
+        );
+        // Casting the same vote twice for the same solution is not a
+        // contradiction.
+        assert_eq!(
+            tracker.record_outcome(&addr, make_outcome_for(true, solution)),
+            SlashAction::None
+        );
+    }
 }
@@ -4,7 +4,7 @@
 //! environment setup validation.
 
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::process::Command;
 
 /// Languages supported by the verification system
@@ -46,6 +46,493 @@ impl LanguageSupport {
             Self::Wasm => "WebAssembly",
         }
     }
+
+    /// Minimum runtime version recommended for this language, checked
+    /// against the parsed [`Version`] in [`EnvironmentCheck::check_python`]
+    /// and [`EnvironmentCheck::check_nodejs`]. `None` for languages with no
+    /// externally-versioned runtime to check (WASM is embedded).
+    #[must_use]
+    pub fn version_requirement(&self) -> Option<VersionReq> {
+        match self {
+            Self::Python => VersionReq::parse(">=3.8").ok(),
+            Self::JavaScript | Self::TypeScript => VersionReq::parse(">=18").ok(),
+            Self::Wasm => None,
+        }
+    }
+}
+
+/// A parsed `major.minor.patch` runtime version, as reported by a language's
+/// `--version` banner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct Version {
+    /// Major component
+    pub major: u64,
+    /// Minor component
+    pub minor: u64,
+    /// Patch component
+    pub patch: u64,
+}
+
+impl Version {
+    /// Extract the first `X`, `X.Y`, or `X.Y.Z` run of digits from `s`,
+    /// tolerating banners like `Python 3.11.4` or `v20.1.0` that wrap the
+    /// version in other text. Missing minor/patch components default to 0.
+    #[must_use]
+    pub fn parse(s: &str) -> Option<Self> {
+        let bytes = s.as_bytes();
+        let mut i = 0;
+        while i < bytes.len() {
+            if bytes[i].is_ascii_digit() {
+                break;
+            }
+            i += 1;
+        }
+        if i >= bytes.len() {
+            return None;
+        }
+
+        let digits_and_dots: String = s[i..]
+            .chars()
+            .take_while(|c| c.is_ascii_digit() || *c == '.')
+            .collect();
+        // A run can't start or end with a dot once trimmed of trailing
+        // punctuation (e.g. a banner ending in "3.11.4."), and numeric
+        // components must be non-empty.
+        let trimmed = digits_and_dots.trim_end_matches('.');
+
+        let mut parts = trimmed.splitn(3, '.');
+        let major = parts.next()?.parse::<u64>().ok()?;
+        let minor = parts.next().and_then(|p| p.parse::<u64>().ok()).unwrap_or(0);
+        let patch = parts.next().and_then(|p| p.parse::<u64>().ok()).unwrap_or(0);
+
+        Some(Self {
+            major,
+            minor,
+            patch,
+        })
+    }
+}
+
+impl std::fmt::Display for Version {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// Comparison operator for a single [`Predicate`] within a [`VersionReq`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+enum Op {
+    /// `=`, or a bare version with no operator (wildcard components allowed)
+    Eq,
+    /// `>`
+    Gt,
+    /// `>=`
+    Ge,
+    /// `<`
+    Lt,
+    /// `<=`
+    Le,
+    /// `~1.2` — allow patch bumps only, i.e. `>=1.2.0, <1.3.0`. A bare
+    /// major with no minor written (`~1`) allows minor bumps too, i.e.
+    /// `>=1.0.0, <2.0.0`.
+    Tilde,
+    /// `^1.2` — allow minor/patch bumps but not a major bump, i.e.
+    /// `>=1.2.0, <2.0.0`
+    Caret,
+    /// `!=`
+    Ne,
+    /// PEP 440 "compatible release" `~=`. `~=3.10` means `>=3.10,<4`
+    /// (bump the last-but-one segment); `~=3.10.2` means `>=3.10.2,<3.11`
+    /// (bump the last segment) — unlike [`Self::Tilde`], the bump point
+    /// depends on how many components the requirement actually wrote.
+    Compatible,
+}
+
+/// A single comma-separated clause of a [`VersionReq`], e.g. `>=3.8` or
+/// `1.2.*`. Components omitted from the requirement string (a trailing
+/// `*`/`x`, or simply not written) are `None`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct Predicate {
+    op: Op,
+    major: Option<u64>,
+    minor: Option<u64>,
+    patch: Option<u64>,
+}
+
+impl Predicate {
+    /// Fill any omitted component with 0, for operators that need a single
+    /// concrete version to compare against (everything but `Eq`'s wildcard
+    /// matching).
+    fn floor(&self) -> Version {
+        Version {
+            major: self.major.unwrap_or(0),
+            minor: self.minor.unwrap_or(0),
+            patch: self.patch.unwrap_or(0),
+        }
+    }
+
+    fn matches(&self, v: &Version) -> bool {
+        match self.op {
+            Op::Eq => {
+                !self.major.is_some_and(|m| m != v.major)
+                    && !self.minor.is_some_and(|m| m != v.minor)
+                    && !self.patch.is_some_and(|p| p != v.patch)
+            }
+            Op::Gt => *v > self.floor(),
+            Op::Ge => *v >= self.floor(),
+            Op::Lt => *v < self.floor(),
+            Op::Le => *v <= self.floor(),
+            Op::Tilde => {
+                let lower = self.floor();
+                let upper = if self.minor.is_some() {
+                    Version {
+                        major: lower.major,
+                        minor: lower.minor + 1,
+                        patch: 0,
+                    }
+                } else {
+                    Version {
+                        major: lower.major + 1,
+                        minor: 0,
+                        patch: 0,
+                    }
+                };
+                *v >= lower && *v < upper
+            }
+            Op::Caret => {
+                let lower = self.floor();
+                let upper = Version {
+                    major: lower.major + 1,
+                    minor: 0,
+                    patch: 0,
+                };
+                *v >= lower && *v < upper
+            }
+            Op::Ne => {
+                !(!self.major.is_some_and(|m| m != v.major)
+                    && !self.minor.is_some_and(|m| m != v.minor)
+                    && !self.patch.is_some_and(|p| p != v.patch))
+            }
+            Op::Compatible => {
+                let lower = self.floor();
+                let upper = if self.patch.is_some() {
+                    Version {
+                        major: lower.major,
+                        minor: lower.minor + 1,
+                        patch: 0,
+                    }
+                } else {
+                    Version {
+                        major: lower.major + 1,
+                        minor: 0,
+                        patch: 0,
+                    }
+                };
+                *v >= lower && *v < upper
+            }
+        }
+    }
+}
+
+fn parse_version_component(part: Option<&str>) -> Result<Option<u64>, String> {
+    match part {
+        None => Ok(None),
+        Some("*" | "x" | "X") => Ok(None),
+        Some(p) => p
+            .parse::<u64>()
+            .map(Some)
+            .map_err(|_| format!("invalid version component: '{p}'")),
+    }
+}
+
+fn parse_predicate(raw: &str) -> Result<Predicate, String> {
+    let s = raw.trim();
+    if s == "*" {
+        return Ok(Predicate {
+            op: Op::Eq,
+            major: None,
+            minor: None,
+            patch: None,
+        });
+    }
+
+    let (op, rest) = if let Some(r) = s.strip_prefix(">=") {
+        (Op::Ge, r)
+    } else if let Some(r) = s.strip_prefix("<=") {
+        (Op::Le, r)
+    } else if let Some(r) = s.strip_prefix("==") {
+        (Op::Eq, r)
+    } else if let Some(r) = s.strip_prefix("!=") {
+        (Op::Ne, r)
+    } else if let Some(r) = s.strip_prefix("~=") {
+        (Op::Compatible, r)
+    } else if let Some(r) = s.strip_prefix('>') {
+        (Op::Gt, r)
+    } else if let Some(r) = s.strip_prefix('<') {
+        (Op::Lt, r)
+    } else if let Some(r) = s.strip_prefix('~') {
+        (Op::Tilde, r)
+    } else if let Some(r) = s.strip_prefix('^') {
+        (Op::Caret, r)
+    } else if let Some(r) = s.strip_prefix('=') {
+        (Op::Eq, r)
+    } else {
+        (Op::Eq, s)
+    };
+
+    let rest = rest.trim();
+    let mut parts = rest.split('.');
+    let major = parse_version_component(parts.next())?;
+    let minor = parse_version_component(parts.next())?;
+    let patch = parse_version_component(parts.next())?;
+
+    if major.is_none() {
+        return Err(format!("version requirement has no major component: '{raw}'"));
+    }
+
+    Ok(Predicate {
+        op,
+        major,
+        minor,
+        patch,
+    })
+}
+
+/// A semver-style version requirement, e.g. `>=3.8`, `^1.2`, or a
+/// comma-joined set of clauses that must all hold (`>=3.8,<4`).
+///
+/// Modeled after the classic `semver` crate's `VersionReq`: comma-separated
+/// predicates, each with an operator (`=`, `>`, `>=`, `<`, `<=`, `~`, `^`,
+/// or a bare `*` wildcard), ANDed together.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VersionReq {
+    /// Original requirement string, kept around for display rather than
+    /// trying to losslessly reconstruct it from the parsed predicates.
+    raw: String,
+    predicates: Vec<Predicate>,
+}
+
+impl VersionReq {
+    /// Parse a comma-separated version requirement string.
+    ///
+    /// # Errors
+    /// Returns an error describing the first clause that failed to parse.
+    pub fn parse(s: &str) -> Result<Self, String> {
+        let predicates = s
+            .split(',')
+            .map(parse_predicate)
+            .collect::<Result<Vec<_>, _>>()?;
+        if predicates.is_empty() {
+            return Err("empty version requirement".to_string());
+        }
+        Ok(Self {
+            raw: s.to_string(),
+            predicates,
+        })
+    }
+
+    /// Whether `version` satisfies every predicate in this requirement
+    #[must_use]
+    pub fn matches(&self, version: &Version) -> bool {
+        self.predicates.iter().all(|p| p.matches(version))
+    }
+}
+
+impl std::fmt::Display for VersionReq {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.raw)
+    }
+}
+
+/// A validator or job's platform identity, borrowed from the Python wheel
+/// tag model: operating system, CPU architecture, and (for CPython
+/// extension modules) the ABI tag such as `cp311` or the stable `abi3`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct PlatformTag {
+    /// Operating system, e.g. `linux`, `macos`, `windows`
+    pub os: String,
+    /// CPU architecture, e.g. `x86_64`, `arm64`
+    pub arch: String,
+    /// CPython ABI tag, e.g. `cp311`; `abi3` is treated as forward/backward
+    /// compatible with any ABI tag a job requests for the same os/arch
+    pub abi: Option<String>,
+}
+
+impl PlatformTag {
+    /// Create a tag with no ABI constraint
+    pub fn new(os: impl Into<String>, arch: impl Into<String>) -> Self {
+        Self {
+            os: os.into(),
+            arch: arch.into(),
+            abi: None,
+        }
+    }
+
+    /// Attach a CPython ABI tag
+    #[must_use]
+    pub fn with_abi(mut self, abi: impl Into<String>) -> Self {
+        self.abi = Some(abi.into());
+        self
+    }
+
+    /// Whether a validator advertising this tag can run a job that requests
+    /// `job_tag`
+    fn satisfies(&self, job_tag: &Self) -> bool {
+        if self.os != job_tag.os || self.arch != job_tag.arch {
+            return false;
+        }
+        match (&self.abi, &job_tag.abi) {
+            (_, None) => true,
+            (Some(validator_abi), Some(job_abi)) => validator_abi == job_abi || validator_abi == "abi3",
+            (None, Some(_)) => false,
+        }
+    }
+}
+
+/// Result of matching a job's acceptable platform tags against a
+/// validator's advertised tags
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TagCompatibility {
+    /// No job tag is satisfied by any validator tag
+    Incompatible(String),
+    /// Compatible via the job tag at this index; lower is more preferred
+    Compatible(usize),
+}
+
+/// Match a job's ordered, acceptable platform tags against a validator's
+/// advertised tag set. A job with no tag requirements is always compatible
+/// at top priority. Otherwise the job tags are tried in order and the index
+/// of the first one the validator satisfies is returned as its priority.
+#[must_use]
+pub fn compatibility(job_tags: &[PlatformTag], validator_tags: &HashSet<PlatformTag>) -> TagCompatibility {
+    if job_tags.is_empty() {
+        return TagCompatibility::Compatible(0);
+    }
+
+    for (priority, job_tag) in job_tags.iter().enumerate() {
+        if validator_tags.iter().any(|vt| vt.satisfies(job_tag)) {
+            return TagCompatibility::Compatible(priority);
+        }
+    }
+
+    TagCompatibility::Incompatible(format!(
+        "validator advertises none of the {} acceptable platform tag(s)",
+        job_tags.len()
+    ))
+}
+
+/// A job's language requirement: the language it needs, and optionally a
+/// version specifier (PEP 440 style: comma-joined `>=`, `>`, `<`, `<=`,
+/// `==`, `!=`, `~=` clauses) the validator's detected runtime must satisfy.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LanguageRequirement {
+    /// Language the job needs
+    pub language: LanguageSupport,
+    /// Version specifier the validator's detected runtime must satisfy;
+    /// `None` means any detected (or undetected) version is acceptable
+    pub specifier: Option<VersionReq>,
+}
+
+impl LanguageRequirement {
+    /// A requirement with no version constraint
+    pub fn any(language: LanguageSupport) -> Self {
+        Self {
+            language,
+            specifier: None,
+        }
+    }
+
+    /// A requirement with a PEP 440–style version specifier
+    ///
+    /// # Errors
+    /// Returns an error if `specifier` fails to parse.
+    pub fn with_specifier(language: LanguageSupport, specifier: &str) -> Result<Self, String> {
+        Ok(Self {
+            language,
+            specifier: Some(VersionReq::parse(specifier)?),
+        })
+    }
+}
+
+/// WASI preview level a WASM runtime implements
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum WasiPreview {
+    /// WASI 0.1 ("preview1"), the socket/module-based ABI
+    Preview1,
+    /// WASI 0.2 ("preview2"), the component-model ABI
+    Preview2,
+}
+
+/// What the embedded WASM runtime actually exposes: which WASI preview(s)
+/// it implements, and which import namespaces / WIT interface names it
+/// provides (e.g. `wasi:filesystem`, `wasi:http`), mirroring how a
+/// WIT-based toolchain validates a module's imports before instantiation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WasmCapabilities {
+    /// WASI previews this runtime implements
+    pub wasi_previews: HashSet<WasiPreview>,
+    /// Import namespaces / WIT interface names this runtime provides
+    pub import_namespaces: HashSet<String>,
+}
+
+impl WasmCapabilities {
+    /// The WASI previews and import namespaces the embedded wasmer runtime
+    /// provides. This is a fixed set (previews 1 and 2; `wasi:cli`,
+    /// `wasi:io`, `wasi:filesystem`, `wasi:clocks`, `wasi:random`); the
+    /// linked build is not queried at runtime.
+    #[must_use]
+    pub fn probe() -> Self {
+        let mut wasi_previews = HashSet::new();
+        wasi_previews.insert(WasiPreview::Preview1);
+        wasi_previews.insert(WasiPreview::Preview2);
+
+        let mut import_namespaces = HashSet::new();
+        for ns in ["wasi:cli", "wasi:io", "wasi:filesystem", "wasi:clocks", "wasi:random"] {
+            import_namespaces.insert(ns.to_string());
+        }
+
+        Self {
+            wasi_previews,
+            import_namespaces,
+        }
+    }
+
+    /// Whether this runtime implements `preview`
+    #[must_use]
+    pub fn supports_preview(&self, preview: WasiPreview) -> bool {
+        self.wasi_previews.contains(&preview)
+    }
+
+    /// Whether this runtime provides the given import namespace
+    #[must_use]
+    pub fn provides_namespace(&self, namespace: &str) -> bool {
+        self.import_namespaces.contains(namespace)
+    }
+}
+
+/// A WASM job's required WASI preview and import namespaces, checked
+/// against a validator's [`WasmCapabilities`] before dispatch
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WasmRequirement {
+    /// WASI preview the job's module targets; `None` if it doesn't use WASI
+    pub required_preview: Option<WasiPreview>,
+    /// Import namespaces / WIT interfaces the job's module imports
+    pub required_imports: HashSet<String>,
+}
+
+impl WasmRequirement {
+    /// Whether `caps` can satisfy this requirement
+    #[must_use]
+    pub fn satisfied_by(&self, caps: &WasmCapabilities) -> bool {
+        let preview_ok = !self
+            .required_preview
+            .is_some_and(|preview| !caps.supports_preview(preview));
+        let imports_ok = self
+            .required_imports
+            .iter()
+            .all(|ns| caps.provides_namespace(ns));
+        preview_ok && imports_ok
+    }
 }
 
 /// Environment check results
@@ -61,6 +548,9 @@ pub struct EnvironmentCheck {
     pub warnings: Vec<String>,
     /// Setup instructions if not available
     pub setup_instructions: Option<String>,
+    /// WASI preview / import-namespace support, populated only for
+    /// [`LanguageSupport::Wasm`] by [`Self::check_wasm`]
+    pub wasm_capabilities: Option<WasmCapabilities>,
 }
 
 impl EnvironmentCheck {
@@ -77,14 +567,17 @@ impl EnvironmentCheck {
             Ok(output) if output.status.success() => {
                 let version_str = String::from_utf8_lossy(&output.stdout);
                 let version = version_str.trim().to_string();
-                
-                // Check if version is >= 3.8
-                if !version.contains("3.8") && !version.contains("3.9") 
-                    && !version.contains("3.10") && !version.contains("3.11")
-                    && !version.contains("3.12") && !version.contains("3.13") {
-                    warnings.push("Python 3.8 or higher recommended".to_string());
+
+                match (Version::parse(&version), LanguageSupport::Python.version_requirement()) {
+                    (Some(parsed), Some(req)) if !req.matches(&parsed) => {
+                        warnings.push(format!("Python {req} required, found {parsed}"));
+                    }
+                    (None, _) => {
+                        warnings.push(format!("could not parse Python version from '{version}'"));
+                    }
+                    _ => {}
                 }
-                
+
                 (true, Some(version))
             }
             _ => (false, None),
@@ -107,6 +600,7 @@ impl EnvironmentCheck {
             version,
             warnings,
             setup_instructions,
+            wasm_capabilities: None,
         }
     }
 
@@ -123,18 +617,17 @@ impl EnvironmentCheck {
             Ok(output) if output.status.success() => {
                 let version_str = String::from_utf8_lossy(&output.stdout);
                 let version = version_str.trim().to_string();
-                
-                // Check if version is >= 18
-                if let Some(v) = version.strip_prefix('v') {
-                    if let Some(major) = v.split('.').next() {
-                        if let Ok(major_num) = major.parse::<u32>() {
-                            if major_num < 18 {
-                                warnings.push("Node.js 18 or higher recommended".to_string());
-                            }
-                        }
+
+                match (Version::parse(&version), LanguageSupport::JavaScript.version_requirement()) {
+                    (Some(parsed), Some(req)) if !req.matches(&parsed) => {
+                        warnings.push(format!("Node.js {req} required, found {parsed}"));
                     }
+                    (None, _) => {
+                        warnings.push(format!("could not parse Node.js version from '{version}'"));
+                    }
+                    _ => {}
                 }
-                
+
                 (true, Some(version))
             }
             _ => {
@@ -149,6 +642,7 @@ impl EnvironmentCheck {
             version,
             warnings,
             setup_instructions: None, // Deno is embedded
+            wasm_capabilities: None,
         }
     }
 
@@ -169,6 +663,7 @@ impl EnvironmentCheck {
             version: Some("embedded (wasmer)".to_string()),
             warnings: Vec::new(),
             setup_instructions: None,
+            wasm_capabilities: Some(WasmCapabilities::probe()),
         }
     }
 
@@ -183,6 +678,170 @@ impl EnvironmentCheck {
     }
 }
 
+/// A detected package-manager or toolchain binary relevant to one of the
+/// supported languages (e.g. `pip`/`uv` for Python, `npm`/`pnpm`/`yarn` for
+/// JavaScript, `wasmer` for WASM).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ToolInfo {
+    /// Binary name, e.g. `"pip"`
+    pub name: String,
+    /// Language this tool is relevant to
+    pub language: LanguageSupport,
+    /// Whether the tool was found on PATH
+    pub available: bool,
+    /// Reported version, if available
+    pub version: Option<String>,
+}
+
+impl ToolInfo {
+    fn probe(name: &str, language: LanguageSupport, version_args: &[&str]) -> Self {
+        let output = Command::new(name).args(version_args).output();
+        let (available, version) = match output {
+            Ok(output) if output.status.success() => {
+                let version = String::from_utf8_lossy(&output.stdout).trim().to_string();
+                (true, Some(version))
+            }
+            _ => (false, None),
+        };
+
+        Self {
+            name: name.to_string(),
+            language,
+            available,
+            version,
+        }
+    }
+}
+
+/// Host operating system and CPU architecture, as reported by `rustc`'s
+/// target triple at compile time
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HostInfo {
+    /// e.g. `"linux"`, `"macos"`, `"windows"`
+    pub os: String,
+    /// e.g. `"x86_64"`, `"aarch64"`
+    pub arch: String,
+}
+
+impl HostInfo {
+    fn detect() -> Self {
+        Self {
+            os: std::env::consts::OS.to_string(),
+            arch: std::env::consts::ARCH.to_string(),
+        }
+    }
+}
+
+/// All directories on `PATH` that contain an executable named `name`, in
+/// lookup order. More than one hit means a later installation is shadowing
+/// an earlier one.
+fn find_on_path(name: &str) -> Vec<String> {
+    let Some(path_var) = std::env::var_os("PATH") else {
+        return Vec::new();
+    };
+
+    std::env::split_paths(&path_var)
+        .map(|dir| dir.join(name))
+        .filter(|candidate| candidate.is_file())
+        .map(|candidate| candidate.display().to_string())
+        .collect()
+}
+
+/// A comprehensive environment diagnostic report, similar to a CLI `doctor`
+/// or `info` command: language runtime checks, relevant package-manager /
+/// toolchain versions, and host OS/arch — serializable so operators can
+/// attach it to bug reports.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnvironmentReport {
+    /// Per-language runtime checks
+    pub checks: Vec<EnvironmentCheck>,
+    /// Detected package-manager / toolchain binaries
+    pub managers: Vec<ToolInfo>,
+    /// Host OS and architecture
+    pub host: HostInfo,
+}
+
+impl EnvironmentReport {
+    /// Probe the current machine and build a full report
+    #[must_use]
+    pub fn generate() -> Self {
+        let mut checks = EnvironmentCheck::check_all();
+        for check in &mut checks {
+            let binary = match check.language {
+                LanguageSupport::Python => Some("python3"),
+                LanguageSupport::JavaScript | LanguageSupport::TypeScript => Some("node"),
+                LanguageSupport::Wasm => None,
+            };
+            let Some(binary) = binary else { continue };
+
+            let hits = find_on_path(binary);
+            if hits.len() > 1 {
+                check.warnings.push(format!(
+                    "multiple '{binary}' binaries found on PATH, the first one wins: {}",
+                    hits.join(", ")
+                ));
+            }
+        }
+
+        let managers = vec![
+            ToolInfo::probe("pip", LanguageSupport::Python, &["--version"]),
+            ToolInfo::probe("uv", LanguageSupport::Python, &["--version"]),
+            ToolInfo::probe("npm", LanguageSupport::JavaScript, &["--version"]),
+            ToolInfo::probe("pnpm", LanguageSupport::JavaScript, &["--version"]),
+            ToolInfo::probe("yarn", LanguageSupport::JavaScript, &["--version"]),
+            ToolInfo::probe("deno", LanguageSupport::JavaScript, &["--version"]),
+            ToolInfo::probe("wasmer", LanguageSupport::Wasm, &["--version"]),
+        ];
+
+        Self {
+            checks,
+            managers,
+            host: HostInfo::detect(),
+        }
+    }
+
+    /// Render this report as a colorized, human-readable table for terminal
+    /// output (e.g. a `doctor`/`info` CLI command)
+    #[must_use]
+    pub fn render_human(&self) -> String {
+        const BOLD: &str = "\x1b[1m";
+        const GREEN: &str = "\x1b[32m";
+        const RED: &str = "\x1b[31m";
+        const YELLOW: &str = "\x1b[33m";
+        const RESET: &str = "\x1b[0m";
+
+        let mut out = String::new();
+        out.push_str(&format!("{BOLD}Host{RESET}: {} / {}\n\n", self.host.os, self.host.arch));
+
+        out.push_str(&format!("{BOLD}Languages{RESET}\n"));
+        for check in &self.checks {
+            let status = if check.available {
+                format!("{GREEN}available{RESET}")
+            } else {
+                format!("{RED}missing{RESET}")
+            };
+            let version = check.version.as_deref().unwrap_or("-");
+            out.push_str(&format!("  {:<14} {status} {version}\n", check.language.display_name()));
+            for warning in &check.warnings {
+                out.push_str(&format!("    {YELLOW}warning{RESET}: {warning}\n"));
+            }
+        }
+
+        out.push_str(&format!("\n{BOLD}Package managers / toolchains{RESET}\n"));
+        for tool in &self.managers {
+            let status = if tool.available {
+                format!("{GREEN}available{RESET}")
+            } else {
+                format!("{RED}missing{RESET}")
+            };
+            let version = tool.version.as_deref().unwrap_or("-");
+            out.push_str(&format!("  {:<14} {status} {version}\n", tool.name));
+        }
+
+        out
+    }
+}
+
 /// Validator's language capabilities
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ValidatorCapabilities {
@@ -191,6 +850,15 @@ pub struct ValidatorCapabilities {
     /// Preference weights (0.0 to 1.0) for each language
     /// Higher weight = more capacity/preference for this language
     pub weights: HashMap<LanguageSupport, f64>,
+    /// Platform tags (OS/arch/ABI) this validator advertises, used to gate
+    /// and prioritize jobs with native-extension runtime requirements
+    pub platform_tags: HashSet<PlatformTag>,
+    /// Detected runtime version per supported language, used to evaluate a
+    /// job's [`LanguageRequirement::specifier`]
+    pub detected_versions: HashMap<LanguageSupport, Version>,
+    /// WASI preview / import-namespace support, if this validator supports
+    /// [`LanguageSupport::Wasm`]
+    pub wasm_capabilities: Option<WasmCapabilities>,
 }
 
 impl ValidatorCapabilities {
@@ -199,18 +867,29 @@ impl ValidatorCapabilities {
         let checks = EnvironmentCheck::check_all();
         let mut supported = Vec::new();
         let mut weights = HashMap::new();
+        let mut detected_versions = HashMap::new();
+        let mut wasm_capabilities = None;
 
         for check in checks {
             if check.available {
                 supported.push(check.language);
                 // Default weight of 1.0 for available languages
                 weights.insert(check.language, 1.0);
+                if let Some(version) = check.version.as_deref().and_then(Version::parse) {
+                    detected_versions.insert(check.language, version);
+                }
+                if let Some(caps) = check.wasm_capabilities {
+                    wasm_capabilities = Some(caps);
+                }
             }
         }
 
         Self {
             supported_languages: supported,
             weights,
+            platform_tags: HashSet::new(),
+            detected_versions,
+            wasm_capabilities,
         }
     }
 
@@ -224,9 +903,32 @@ impl ValidatorCapabilities {
         Self {
             supported_languages: languages,
             weights,
+            platform_tags: HashSet::new(),
+            detected_versions: HashMap::new(),
+            wasm_capabilities: None,
         }
     }
 
+    /// Advertise a platform tag this validator satisfies
+    pub fn add_platform_tag(&mut self, tag: PlatformTag) {
+        self.platform_tags.insert(tag);
+    }
+
+    /// Record the detected runtime version for a language
+    pub fn set_detected_version(&mut self, language: LanguageSupport, version: Version) {
+        self.detected_versions.insert(language, version);
+    }
+
+    /// Get the detected runtime version for a language, if known
+    pub fn detected_version(&self, language: &LanguageSupport) -> Option<Version> {
+        self.detected_versions.get(language).copied()
+    }
+
+    /// Set the WASM host-interface capabilities this validator provides
+    pub fn set_wasm_capabilities(&mut self, caps: WasmCapabilities) {
+        self.wasm_capabilities = Some(caps);
+    }
+
     /// Set preference weight for a language (0.0 to 1.0)
     pub fn set_weight(&mut self, language: LanguageSupport, weight: f64) {
         if self.supported_languages.contains(&language) {
@@ -264,8 +966,16 @@ impl JobDistribution {
     /// This implements a supply/demand weighting system:
     /// - If few validators support a language, weight increases (scarcity premium)
     /// - If many validators support a language, weight decreases (abundance discount)
+    ///
+    /// Validators that don't advertise any of `job_tags` are excluded
+    /// entirely; validators matching a more-preferred (lower-index) tag get
+    /// a priority bonus on top of the scarcity weight, so better-matched
+    /// platforms win ties. A validator that can't satisfy `wasm_requirement`
+    /// (missing WASI preview or import namespace) is excluded entirely.
     pub fn calculate_weights(
         language: LanguageSupport,
+        job_tags: &[PlatformTag],
+        wasm_requirement: Option<&WasmRequirement>,
         all_validators: &[ValidatorCapabilities],
     ) -> Vec<(usize, f64)> {
         let total_validators = all_validators.len() as f64;
@@ -291,24 +1001,57 @@ impl JobDistribution {
             .iter()
             .enumerate()
             .filter_map(|(idx, validator)| {
-                if validator.supports(&language) {
-                    let base_weight = validator.get_weight(&language);
-                    let final_weight = base_weight * scarcity;
-                    Some((idx, final_weight))
-                } else {
-                    None
+                if !validator.supports(&language) {
+                    return None;
                 }
+                let priority = match compatibility(job_tags, &validator.platform_tags) {
+                    TagCompatibility::Incompatible(_) => return None,
+                    TagCompatibility::Compatible(priority) => priority,
+                };
+                if let Some(requirement) = wasm_requirement {
+                    let satisfied = validator
+                        .wasm_capabilities
+                        .as_ref()
+                        .is_some_and(|caps| requirement.satisfied_by(caps));
+                    if !satisfied {
+                        return None;
+                    }
+                }
+
+                let base_weight = validator.get_weight(&language);
+                let priority_bonus = 1.0 / (priority as f64 + 1.0);
+                let final_weight = base_weight * scarcity * priority_bonus;
+                Some((idx, final_weight))
             })
             .collect()
     }
 
     /// Select a validator for a job based on weighted random selection
+    ///
+    /// Validators whose detected version for `requirement.language` fails
+    /// `requirement.specifier` are excluded before weighting; a validator
+    /// with no detected version is excluded whenever a specifier is given.
     pub fn select_validator(
-        language: LanguageSupport,
+        requirement: &LanguageRequirement,
+        job_tags: &[PlatformTag],
+        wasm_requirement: Option<&WasmRequirement>,
         all_validators: &[ValidatorCapabilities],
         random_value: f64, // 0.0 to 1.0
     ) -> Option<usize> {
-        let weights = Self::calculate_weights(language, all_validators);
+        let weights: Vec<(usize, f64)> = Self::calculate_weights(
+            requirement.language,
+            job_tags,
+            wasm_requirement,
+            all_validators,
+        )
+        .into_iter()
+        .filter(|(idx, _)| match &requirement.specifier {
+            None => true,
+            Some(spec) => all_validators[*idx]
+                .detected_version(&requirement.language)
+                .is_some_and(|v| spec.matches(&v)),
+        })
+        .collect();
         if weights.is_empty() {
             return None;
         }
@@ -360,11 +1103,11 @@ mod tests {
         ];
 
         // Python is supported by 2/3 validators
-        let weights = JobDistribution::calculate_weights(LanguageSupport::Python, &validators);
+        let weights = JobDistribution::calculate_weights(LanguageSupport::Python, &[], None, &validators);
         assert_eq!(weights.len(), 2);
 
         // JavaScript is supported by 2/3 validators
-        let weights = JobDistribution::calculate_weights(LanguageSupport::JavaScript, &validators);
+        let weights = JobDistribution::calculate_weights(LanguageSupport::JavaScript, &[], None, &validators);
         assert_eq!(weights.len(), 2);
     }
 
@@ -377,10 +1120,304 @@ mod tests {
             ValidatorCapabilities::new(vec![LanguageSupport::JavaScript]), // Rare
         ];
 
-        let python_weights = JobDistribution::calculate_weights(LanguageSupport::Python, &validators);
-        let js_weights = JobDistribution::calculate_weights(LanguageSupport::JavaScript, &validators);
+        let python_weights = JobDistribution::calculate_weights(LanguageSupport::Python, &[], None, &validators);
+        let js_weights = JobDistribution::calculate_weights(LanguageSupport::JavaScript, &[], None, &validators);
 
         // JavaScript should have higher weight due to scarcity (4/1 = 4x multiplier)
         assert!(js_weights[0].1 > python_weights[0].1);
     }
+
+    #[test]
+    fn test_version_parse_from_banners() {
+        assert_eq!(
+            Version::parse("Python 3.11.4"),
+            Some(Version { major: 3, minor: 11, patch: 4 })
+        );
+        assert_eq!(
+            Version::parse("v20.1.0"),
+            Some(Version { major: 20, minor: 1, patch: 0 })
+        );
+        assert_eq!(
+            Version::parse("18"),
+            Some(Version { major: 18, minor: 0, patch: 0 })
+        );
+        assert_eq!(Version::parse("not a version"), None);
+    }
+
+    #[test]
+    fn test_version_req_ge_and_lt() {
+        let req = VersionReq::parse(">=3.8").unwrap();
+        assert!(req.matches(&Version { major: 3, minor: 8, patch: 0 }));
+        assert!(req.matches(&Version { major: 3, minor: 100, patch: 0 }));
+        assert!(req.matches(&Version { major: 4, minor: 0, patch: 0 }));
+        assert!(!req.matches(&Version { major: 3, minor: 7, patch: 9 }));
+
+        let req = VersionReq::parse("<4").unwrap();
+        assert!(req.matches(&Version { major: 3, minor: 9, patch: 9 }));
+        assert!(!req.matches(&Version { major: 4, minor: 0, patch: 0 }));
+    }
+
+    #[test]
+    fn test_version_req_caret_and_tilde() {
+        let caret = VersionReq::parse("^1.2").unwrap();
+        assert!(caret.matches(&Version { major: 1, minor: 2, patch: 0 }));
+        assert!(caret.matches(&Version { major: 1, minor: 9, patch: 0 }));
+        assert!(!caret.matches(&Version { major: 2, minor: 0, patch: 0 }));
+
+        let tilde = VersionReq::parse("~1.2").unwrap();
+        assert!(tilde.matches(&Version { major: 1, minor: 2, patch: 5 }));
+        assert!(!tilde.matches(&Version { major: 1, minor: 3, patch: 0 }));
+    }
+
+    #[test]
+    fn test_version_req_tilde_and_caret_bare_major() {
+        let tilde = VersionReq::parse("~1").unwrap();
+        assert!(tilde.matches(&Version { major: 1, minor: 9, patch: 9 }));
+        assert!(!tilde.matches(&Version { major: 2, minor: 0, patch: 0 }));
+
+        let caret = VersionReq::parse("^1").unwrap();
+        assert!(caret.matches(&Version { major: 1, minor: 9, patch: 9 }));
+        assert!(!caret.matches(&Version { major: 2, minor: 0, patch: 0 }));
+    }
+
+    #[test]
+    fn test_version_req_wildcard_and_compound() {
+        let wildcard = VersionReq::parse("1.2.*").unwrap();
+        assert!(wildcard.matches(&Version { major: 1, minor: 2, patch: 0 }));
+        assert!(wildcard.matches(&Version { major: 1, minor: 2, patch: 99 }));
+        assert!(!wildcard.matches(&Version { major: 1, minor: 3, patch: 0 }));
+
+        let any = VersionReq::parse("*").unwrap();
+        assert!(any.matches(&Version { major: 0, minor: 0, patch: 0 }));
+
+        let compound = VersionReq::parse(">=3.8,<4").unwrap();
+        assert!(compound.matches(&Version { major: 3, minor: 11, patch: 4 }));
+        assert!(!compound.matches(&Version { major: 4, minor: 0, patch: 0 }));
+    }
+
+    #[test]
+    fn test_python_version_requirement_boundary() {
+        // The requirement logic itself should never warn for a version that
+        // satisfies ">=3.8" regardless of which python3 happens to be on
+        // this machine; exercise the parser/requirement path directly
+        // instead of depending on the test runner's installed Python.
+        let req = LanguageSupport::Python.version_requirement().unwrap();
+        assert!(req.matches(&Version { major: 3, minor: 8, patch: 0 }));
+        assert!(!req.matches(&Version { major: 3, minor: 7, patch: 0 }));
+    }
+
+    #[test]
+    fn test_tag_compatibility_no_requirement_is_always_compatible() {
+        let validator_tags = HashSet::new();
+        assert_eq!(compatibility(&[], &validator_tags), TagCompatibility::Compatible(0));
+    }
+
+    #[test]
+    fn test_tag_compatibility_exact_and_abi3_match() {
+        let mut validator_tags = HashSet::new();
+        validator_tags.insert(PlatformTag::new("linux", "x86_64").with_abi("cp311"));
+
+        let job_tags = vec![PlatformTag::new("linux", "x86_64").with_abi("cp311")];
+        assert_eq!(compatibility(&job_tags, &validator_tags), TagCompatibility::Compatible(0));
+
+        // abi3 wheels are stable-ABI and satisfy a job that asked for a
+        // specific (different) CPython minor version's ABI tag.
+        let mut abi3_validator = HashSet::new();
+        abi3_validator.insert(PlatformTag::new("linux", "x86_64").with_abi("abi3"));
+        let job_tags = vec![PlatformTag::new("linux", "x86_64").with_abi("cp312")];
+        assert_eq!(compatibility(&job_tags, &abi3_validator), TagCompatibility::Compatible(0));
+    }
+
+    #[test]
+    fn test_tag_compatibility_prefers_earlier_job_tag() {
+        let mut validator_tags = HashSet::new();
+        validator_tags.insert(PlatformTag::new("macos", "arm64"));
+
+        let job_tags = vec![
+            PlatformTag::new("linux", "x86_64"),
+            PlatformTag::new("macos", "arm64"),
+        ];
+        assert_eq!(compatibility(&job_tags, &validator_tags), TagCompatibility::Compatible(1));
+    }
+
+    #[test]
+    fn test_tag_compatibility_rejects_mismatched_platform() {
+        let mut validator_tags = HashSet::new();
+        validator_tags.insert(PlatformTag::new("windows", "x86_64"));
+
+        let job_tags = vec![PlatformTag::new("linux", "x86_64")];
+        assert!(matches!(
+            compatibility(&job_tags, &validator_tags),
+            TagCompatibility::Incompatible(_)
+        ));
+    }
+
+    #[test]
+    fn test_calculate_weights_excludes_tag_incompatible_validators() {
+        let mut linux_validator = ValidatorCapabilities::new(vec![LanguageSupport::Python]);
+        linux_validator.add_platform_tag(PlatformTag::new("linux", "x86_64"));
+        let mut macos_validator = ValidatorCapabilities::new(vec![LanguageSupport::Python]);
+        macos_validator.add_platform_tag(PlatformTag::new("macos", "arm64"));
+
+        let job_tags = vec![PlatformTag::new("linux", "x86_64")];
+        let weights = JobDistribution::calculate_weights(
+            LanguageSupport::Python,
+            &job_tags,
+            None,
+            &[linux_validator, macos_validator],
+        );
+
+        assert_eq!(weights.len(), 1);
+        assert_eq!(weights[0].0, 0);
+    }
+
+    #[test]
+    fn test_calculate_weights_priority_bonus_breaks_ties() {
+        let mut preferred = ValidatorCapabilities::new(vec![LanguageSupport::Python]);
+        preferred.add_platform_tag(PlatformTag::new("linux", "x86_64"));
+        let mut fallback = ValidatorCapabilities::new(vec![LanguageSupport::Python]);
+        fallback.add_platform_tag(PlatformTag::new("macos", "arm64"));
+
+        let job_tags = vec![
+            PlatformTag::new("linux", "x86_64"),
+            PlatformTag::new("macos", "arm64"),
+        ];
+        let weights = JobDistribution::calculate_weights(
+            LanguageSupport::Python,
+            &job_tags,
+            None,
+            &[preferred, fallback],
+        );
+
+        let preferred_weight = weights.iter().find(|(idx, _)| *idx == 0).unwrap().1;
+        let fallback_weight = weights.iter().find(|(idx, _)| *idx == 1).unwrap().1;
+        assert!(preferred_weight > fallback_weight);
+    }
+
+    #[test]
+    fn test_version_req_ne_and_eq() {
+        let ne = VersionReq::parse("!=3.9").unwrap();
+        assert!(ne.matches(&Version { major: 3, minor: 10, patch: 0 }));
+        assert!(!ne.matches(&Version { major: 3, minor: 9, patch: 0 }));
+
+        let eq = VersionReq::parse("==3.11.4").unwrap();
+        assert!(eq.matches(&Version { major: 3, minor: 11, patch: 4 }));
+        assert!(!eq.matches(&Version { major: 3, minor: 11, patch: 5 }));
+    }
+
+    #[test]
+    fn test_version_req_compatible_release() {
+        // ~=3.10 bumps the major: >=3.10,<4
+        let two_segment = VersionReq::parse("~=3.10").unwrap();
+        assert!(two_segment.matches(&Version { major: 3, minor: 10, patch: 0 }));
+        assert!(two_segment.matches(&Version { major: 3, minor: 99, patch: 0 }));
+        assert!(!two_segment.matches(&Version { major: 4, minor: 0, patch: 0 }));
+        assert!(!two_segment.matches(&Version { major: 3, minor: 9, patch: 9 }));
+
+        // ~=3.10.2 bumps the minor: >=3.10.2,<3.11
+        let three_segment = VersionReq::parse("~=3.10.2").unwrap();
+        assert!(three_segment.matches(&Version { major: 3, minor: 10, patch: 9 }));
+        assert!(!three_segment.matches(&Version { major: 3, minor: 11, patch: 0 }));
+        assert!(!three_segment.matches(&Version { major: 3, minor: 10, patch: 1 }));
+    }
+
+    #[test]
+    fn test_select_validator_filters_by_language_requirement() {
+        let mut old_python = ValidatorCapabilities::new(vec![LanguageSupport::Python]);
+        old_python.set_detected_version(LanguageSupport::Python, Version { major: 3, minor: 7, patch: 0 });
+        let mut new_python = ValidatorCapabilities::new(vec![LanguageSupport::Python]);
+        new_python.set_detected_version(LanguageSupport::Python, Version { major: 3, minor: 11, patch: 0 });
+
+        let requirement = LanguageRequirement::with_specifier(LanguageSupport::Python, ">=3.10").unwrap();
+        let validators = [old_python, new_python];
+        let selected = JobDistribution::select_validator(&requirement, &[], None, &validators, 0.5);
+        assert_eq!(selected, Some(1));
+    }
+
+    #[test]
+    fn test_select_validator_excludes_undetected_version_when_specifier_given() {
+        let undetected = ValidatorCapabilities::new(vec![LanguageSupport::Python]);
+        let requirement = LanguageRequirement::with_specifier(LanguageSupport::Python, ">=3.10").unwrap();
+        let selected = JobDistribution::select_validator(&requirement, &[], None, &[undetected], 0.5);
+        assert_eq!(selected, None);
+    }
+
+    #[test]
+    fn test_check_wasm_reports_probed_capabilities() {
+        let check = EnvironmentCheck::check_wasm();
+        let caps = check.wasm_capabilities.expect("wasm check should probe capabilities");
+        assert!(caps.supports_preview(WasiPreview::Preview1));
+        assert!(caps.supports_preview(WasiPreview::Preview2));
+        assert!(caps.provides_namespace("wasi:filesystem"));
+    }
+
+    #[test]
+    fn test_wasm_requirement_satisfied_by_matching_capabilities() {
+        let caps = WasmCapabilities::probe();
+        let requirement = WasmRequirement {
+            required_preview: Some(WasiPreview::Preview2),
+            required_imports: ["wasi:filesystem".to_string()].into_iter().collect(),
+        };
+        assert!(requirement.satisfied_by(&caps));
+    }
+
+    #[test]
+    fn test_wasm_requirement_rejects_missing_import() {
+        let caps = WasmCapabilities::probe();
+        let requirement = WasmRequirement {
+            required_preview: None,
+            required_imports: ["wasi:http".to_string()].into_iter().collect(),
+        };
+        assert!(!requirement.satisfied_by(&caps));
+    }
+
+    #[test]
+    fn test_calculate_weights_excludes_validators_missing_wasm_capabilities() {
+        let capable = {
+            let mut v = ValidatorCapabilities::new(vec![LanguageSupport::Wasm]);
+            v.set_wasm_capabilities(WasmCapabilities::probe());
+            v
+        };
+        let incapable = ValidatorCapabilities::new(vec![LanguageSupport::Wasm]);
+
+        let requirement = WasmRequirement {
+            required_preview: Some(WasiPreview::Preview2),
+            required_imports: HashSet::new(),
+        };
+        let weights = JobDistribution::calculate_weights(
+            LanguageSupport::Wasm,
+            &[],
+            Some(&requirement),
+            &[capable, incapable],
+        );
+
+        assert_eq!(weights.len(), 1);
+        assert_eq!(weights[0].0, 0);
+    }
+
+    #[test]
+    fn test_environment_report_generate_covers_all_languages() {
+        let report = EnvironmentReport::generate();
+        assert_eq!(report.checks.len(), LanguageSupport::all().len());
+        assert!(!report.host.os.is_empty());
+        assert!(!report.host.arch.is_empty());
+        // Every manager probed should be tied to one of the known languages.
+        for tool in &report.managers {
+            assert!(LanguageSupport::all().contains(&tool.language));
+        }
+    }
+
+    #[test]
+    fn test_environment_report_render_human_contains_sections() {
+        let report = EnvironmentReport::generate();
+        let rendered = report.render_human();
+        assert!(rendered.contains("Host"));
+        assert!(rendered.contains("Languages"));
+        assert!(rendered.contains("Package managers"));
+    }
+
+    #[test]
+    fn test_find_on_path_returns_empty_for_nonexistent_binary() {
+        assert!(find_on_path("definitely-not-a-real-binary-xyz").is_empty());
+    }
 }
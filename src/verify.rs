@@ -0,0 +1,110 @@
+//! `hardclaw verify` — check an ML-DSA-65 signature against a public key or
+//! wallet address.
+//!
+//! Usage:
+//!   hardclaw verify --signature <hex> (--public-key <hex> | --address <addr>)
+//!                    [--message <text> | --file <path>]
+//!
+//! With `--address`, the public key is read straight off that wallet's file
+//! on disk instead of from the command line — it stays in cleartext even
+//! when the wallet is passphrase-encrypted (see [`hardclaw::wallet`]), so no
+//! passphrase prompt is needed just to check a signature.
+
+use std::io::Read;
+
+use hardclaw::wallet::Wallet;
+use hardclaw::{Address, PublicKey, Signature};
+
+pub fn run(args: &[String]) {
+    let Some(signature_hex) = arg_value(args, "--signature") else {
+        eprintln!("Error: --signature <hex> is required");
+        std::process::exit(1);
+    };
+    let signature = match Signature::from_hex(signature_hex) {
+        Ok(signature) => signature,
+        Err(e) => {
+            eprintln!("Invalid signature: {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    let given_address = arg_value(args, "--address").map(|s| match Address::from_hex(s) {
+        Ok(address) => address,
+        Err(e) => {
+            eprintln!("Invalid address: {}", e);
+            std::process::exit(1);
+        }
+    });
+
+    let public_key = match arg_value(args, "--public-key") {
+        Some(hex) => match PublicKey::from_hex(hex) {
+            Ok(public_key) => public_key,
+            Err(e) => {
+                eprintln!("Invalid public key: {}", e);
+                std::process::exit(1);
+            }
+        },
+        None => {
+            let Some(address) = given_address else {
+                eprintln!("Error: --public-key <hex> or --address <addr> is required");
+                std::process::exit(1);
+            };
+            let path = Wallet::default_dir().join(format!("{}.json", address));
+            match Wallet::peek_public_key(&path) {
+                Ok(public_key) => public_key,
+                Err(e) => {
+                    eprintln!("Failed to read public key for {}: {}", address, e);
+                    std::process::exit(1);
+                }
+            }
+        }
+    };
+
+    let message = read_message(args);
+    let valid = hardclaw::crypto::verify(&public_key, &message, &signature).is_ok();
+    let derived_address = Address::from_public_key(&public_key);
+
+    println!("Signature valid:");
+    println!("{}", valid);
+    println!();
+    println!("Public Key (Hex):");
+    println!("{}", public_key.to_hex());
+    println!();
+    println!("Address:");
+    println!("{}", derived_address);
+
+    if let Some(address) = given_address {
+        println!();
+        println!("Matches address {}:", address);
+        println!("{}", valid && derived_address == address);
+    }
+
+    if !valid {
+        std::process::exit(1);
+    }
+}
+
+fn arg_value<'a>(args: &'a [String], flag: &str) -> Option<&'a String> {
+    args.iter().position(|a| a == flag).and_then(|i| args.get(i + 1))
+}
+
+/// Read the message to verify from `--message <TEXT>`, `--file <PATH>`, or
+/// stdin (read to EOF) if neither flag is given.
+fn read_message(args: &[String]) -> Vec<u8> {
+    if let Some(text) = arg_value(args, "--message") {
+        return text.clone().into_bytes();
+    }
+
+    if let Some(path) = arg_value(args, "--file") {
+        return std::fs::read(path).unwrap_or_else(|e| {
+            eprintln!("Failed to read {}: {}", path, e);
+            std::process::exit(1);
+        });
+    }
+
+    let mut buf = Vec::new();
+    std::io::stdin()
+        .read_to_end(&mut buf)
+        .expect("failed to read stdin");
+    buf
+}
@@ -0,0 +1,77 @@
+//! Wallet file schema migrations.
+//!
+//! Wallet JSON carries a `version` field; [`Wallet::load`](super::Wallet::load)
+//! walks an outdated file forward to [`super::WALLET_VERSION`] one step at a
+//! time via [`migrate`], so a future field change only needs a new entry in
+//! [`MIGRATIONS`] rather than breaking every wallet saved under the old shape.
+
+use serde_json::Value;
+
+use super::WalletError;
+
+/// A single migration step: rewrites a wallet JSON object from the version
+/// immediately preceding it to the next version up.
+pub type MigrationStep = fn(Value) -> Result<Value, WalletError>;
+
+/// Migration steps, keyed by the version they upgrade *from*. To introduce a
+/// new wallet file version, append a `(N, step)` entry here and bump
+/// `WALLET_VERSION` in `super`.
+const MIGRATIONS: &[(u8, MigrationStep)] = &[(1, migrate_v1_to_v2), (2, migrate_v2_to_v3)];
+
+/// Walk a wallet JSON `value` forward from `from_version` to
+/// `super::WALLET_VERSION`, applying each step in turn.
+///
+/// # Errors
+/// Returns [`WalletError::UnsupportedVersion`] if the chain has a gap (no
+/// step is registered for an intermediate version), or propagates whatever
+/// error an individual step returns.
+pub fn migrate(mut value: Value, from_version: u8) -> Result<Value, WalletError> {
+    let mut current = from_version;
+
+    while current < super::WALLET_VERSION {
+        let step = MIGRATIONS
+            .iter()
+            .find(|(source, _)| *source == current)
+            .map(|(_, step)| *step)
+            .ok_or(WalletError::UnsupportedVersion(current))?;
+
+        value = step(value)?;
+        current += 1;
+    }
+
+    Ok(value)
+}
+
+/// v1 (no explicit algorithm tag, no encryption envelope) -> v2: tag the
+/// algorithm explicitly and default the encryption envelope fields that
+/// were later added for passphrase-sealed wallets.
+fn migrate_v1_to_v2(mut value: Value) -> Result<Value, WalletError> {
+    let obj = value.as_object_mut().ok_or_else(|| {
+        WalletError::SerializationError("wallet file is not a JSON object".to_string())
+    })?;
+
+    obj.entry("algorithm")
+        .or_insert_with(|| Value::String("ml-dsa-65".to_string()));
+    obj.entry("encrypted").or_insert(Value::Bool(false));
+    obj.entry("mnemonic").or_insert(Value::Null);
+    obj.entry("kdf").or_insert(Value::Null);
+    obj.insert("version".to_string(), Value::from(2u8));
+
+    Ok(value)
+}
+
+/// v2 -> v3: no shape change. v3 only changes which AEAD cipher newly-sealed
+/// wallets use (see `super::KdfParams::cipher`); an encrypted v2 file's
+/// existing sealed blob still unseals under the legacy cipher its `kdf`
+/// object defaults to (missing the `cipher` field entirely), and re-seals
+/// under the new one the next time it's saved. Unencrypted wallets are
+/// unaffected either way. This step only needs to bump the version tag.
+fn migrate_v2_to_v3(mut value: Value) -> Result<Value, WalletError> {
+    let obj = value.as_object_mut().ok_or_else(|| {
+        WalletError::SerializationError("wallet file is not a JSON object".to_string())
+    })?;
+
+    obj.insert("version".to_string(), Value::from(3u8));
+
+    Ok(value)
+}
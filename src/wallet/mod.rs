@@ -2,56 +2,185 @@
 //!
 //! Handles key generation, storage, and loading.
 //! Version 2 format stores ML-DSA-65 keys (4032-byte secret key, 1952-byte public key).
-
+//!
+//! Wallet files may additionally be sealed at rest with a passphrase: an
+//! Argon2id-derived key encrypts the secret key and mnemonic, leaving the
+//! public key and address in cleartext for identification. Version 3 seals
+//! with XChaCha20-Poly1305 (a 24-byte random nonce, removing any risk of
+//! nonce reuse across saves); version 2 files sealed with plain
+//! ChaCha20-Poly1305 (12-byte nonce) remain loadable — see [`unseal`] — so an
+//! existing encrypted wallet keeps working until it's next saved, which
+//! re-seals it under the newer cipher. See [`Wallet::save_encrypted`] /
+//! [`Wallet::load_with_passphrase`].
+
+mod migration;
+
+use std::ffi::OsStr;
 use std::fs::{self, File};
 use std::io::{Read, Write};
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
 
+use chacha20poly1305::aead::Aead;
+use chacha20poly1305::{ChaCha20Poly1305, KeyInit, Nonce, XChaCha20Poly1305, XNonce};
+use rand::RngCore;
 use serde::{Deserialize, Serialize};
+use zeroize::Zeroize;
 
 use crate::crypto::{Keypair, PublicKey, SecretKey, Signature, SECRET_KEY_SIZE};
 use crate::types::Address;
 
 /// Current wallet file format version
-const WALLET_VERSION: u8 = 2;
+const WALLET_VERSION: u8 = 3;
+
+/// AEAD tag [`KdfParams::cipher`] carries for wallets sealed by the current
+/// version; legacy sealed wallets are tagged [`LEGACY_CIPHER`] instead (see
+/// [`default_cipher`]).
+const CURRENT_CIPHER: &str = "xchacha20poly1305";
+/// AEAD tag for wallets sealed before `cipher` existed in [`KdfParams`] —
+/// plain ChaCha20-Poly1305 with a 12-byte nonce.
+const LEGACY_CIPHER: &str = "chacha20poly1305";
+
+/// Default for [`KdfParams::cipher`] on records predating the field, so a
+/// v2 wallet's existing sealed blob keeps unsealing under the cipher it was
+/// actually sealed with.
+fn default_cipher() -> String {
+    LEGACY_CIPHER.to_string()
+}
 
-/// Wallet file format (v2: ML-DSA-65)
+/// Argon2id memory cost in KiB (~19 MiB, OWASP's current minimum recommendation)
+const ARGON2_MEM_COST_KIB: u32 = 19_456;
+/// Argon2id iteration count
+const ARGON2_TIME_COST: u32 = 2;
+/// Argon2id parallelism (lanes)
+const ARGON2_PARALLELISM: u32 = 1;
+/// Derived key length for the AEAD seal (XChaCha20-Poly1305, or the legacy
+/// ChaCha20-Poly1305; both take a 32-byte key)
+const ARGON2_OUTPUT_LEN: usize = 32;
+/// Argon2id salt length
+const SALT_LEN: usize = 16;
+
+/// Longest prefix [`Wallet::generate_with_prefix`] will search for. Beyond
+/// this, the expected attempt count (`16^N`) makes the search effectively
+/// unbounded.
+const MAX_VANITY_PREFIX_NIBBLES: usize = 8;
+
+/// Wallet file format (v2/v3: ML-DSA-65; v3 only changes the AEAD cipher
+/// used when `encrypted` is set, see [`KdfParams::cipher`])
 #[derive(Serialize, Deserialize)]
 struct WalletFile {
-    /// Version for compatibility (2 = ML-DSA-65)
+    /// Version for compatibility (2 or 3 = ML-DSA-65)
     version: u8,
     /// Algorithm identifier
     algorithm: String,
     /// Public key (hex)
     public_key: String,
-    /// Secret key (hex) - in production, this would be encrypted
+    /// Whether `secret_key` below is an AEAD-sealed blob rather than plaintext hex
+    #[serde(default)]
+    encrypted: bool,
+    /// Secret key (hex), or the sealed blob (hex) when `encrypted` is set
     secret_key: String,
+    /// Mnemonic phrase in the clear; `None` when `encrypted` (it travels
+    /// inside the sealed blob instead) or when the wallet has none
+    #[serde(default)]
+    mnemonic: Option<String>,
+    /// KDF + AEAD parameters used to produce the sealed blob; only present
+    /// when `encrypted` is set
+    #[serde(default)]
+    kdf: Option<KdfParams>,
     /// Optional wallet name/label
     name: Option<String>,
     /// Creation timestamp
     created_at: i64,
+    /// HD account index this wallet was derived at via
+    /// [`Wallet::derive_account`], if any. `None` for wallets generated or
+    /// restored without an explicit index. Such a wallet's key is derived
+    /// with no index folded in, so it differs from the key at index 0;
+    /// `list_wallets` only labels wallets that actually asked for HD
+    /// derivation.
+    #[serde(default)]
+    account_index: Option<u32>,
+}
+
+/// Argon2id + AEAD parameters needed to unseal an encrypted wallet file.
+/// The salt and nonce are single-use and regenerated on every
+/// [`Wallet::save_encrypted`] call.
+#[derive(Clone, Serialize, Deserialize)]
+struct KdfParams {
+    /// Argon2id salt (hex)
+    salt: String,
+    /// AEAD nonce (hex) — 24 bytes for [`CURRENT_CIPHER`], 12 bytes for
+    /// [`LEGACY_CIPHER`]
+    nonce: String,
+    /// Argon2id memory cost, in KiB
+    mem_cost_kib: u32,
+    /// Argon2id iteration count
+    time_cost: u32,
+    /// Argon2id parallelism (lanes)
+    parallelism: u32,
+    /// Which AEAD cipher sealed `secret_key`: [`CURRENT_CIPHER`] for wallets
+    /// saved under this version, [`LEGACY_CIPHER`] for ones saved under v2.
+    /// Defaults to [`LEGACY_CIPHER`] via [`default_cipher`] for wallet files
+    /// from before this field existed.
+    #[serde(default = "default_cipher")]
+    cipher: String,
+}
+
+/// The plaintext sealed inside an encrypted wallet's AEAD blob.
+#[derive(Serialize, Deserialize)]
+struct SealedSecret {
+    /// Secret key (hex)
+    secret_key: String,
+    /// Mnemonic phrase, if any
+    mnemonic: Option<String>,
 }
 
 /// A `HardClaw` wallet
 pub struct Wallet {
     /// The underlying keypair
     keypair: Keypair,
+    /// Mnemonic phrase this wallet was generated or restored from, if any
+    /// (e.g. wallets loaded from a raw keypair have none)
+    pub mnemonic: Option<String>,
     /// Wallet name/label
     pub name: Option<String>,
     /// Path to wallet file (if loaded from disk)
     pub path: Option<PathBuf>,
+    /// HD account index this wallet was derived at via
+    /// [`Wallet::derive_account`], if any
+    pub account_index: Option<u32>,
+}
+
+impl crate::crypto::Signer for Wallet {
+    fn public_key(&self) -> &PublicKey {
+        self.keypair.public_key()
+    }
+
+    fn sign(&self, signing_bytes: &[u8]) -> Result<Signature, crate::crypto::SignerError> {
+        Ok(self.keypair.sign(signing_bytes))
+    }
+}
+
+impl Drop for Wallet {
+    fn drop(&mut self) {
+        // SecretKey already zeroizes itself on drop; the mnemonic is a
+        // plain String and needs the same treatment so an `unlock`ed wallet
+        // actually clears decrypted key material once it goes out of scope.
+        if let Some(phrase) = self.mnemonic.as_mut() {
+            phrase.zeroize();
+        }
+    }
 }
 
 impl Wallet {
-    /// Generate a new wallet
+    /// Generate a new wallet from a freshly generated 24-word mnemonic
     #[must_use]
     pub fn generate() -> Self {
-        let keypair = Keypair::generate();
-        Self {
-            keypair,
-            name: None,
-            path: None,
-        }
+        let mnemonic = crate::crypto::generate_mnemonic();
+        let keypair = crate::crypto::keypair_from_mnemonic(&mnemonic, "");
+        Self::from_keypair_and_mnemonic(keypair, mnemonic.to_string())
     }
 
     /// Generate with a name
@@ -62,14 +191,142 @@ impl Wallet {
         wallet
     }
 
-    /// Create from an existing keypair
+    /// Create from an existing keypair, with no mnemonic on record
     #[must_use]
     pub fn from_keypair(keypair: Keypair) -> Self {
         Self {
             keypair,
+            mnemonic: None,
             name: None,
             path: None,
+            account_index: None,
+        }
+    }
+
+    /// Create from a keypair restored from (or derived alongside) a mnemonic
+    #[must_use]
+    pub fn from_keypair_and_mnemonic(keypair: Keypair, mnemonic: String) -> Self {
+        Self {
+            keypair,
+            mnemonic: Some(mnemonic),
+            name: None,
+            path: None,
+            account_index: None,
+        }
+    }
+
+    /// Derive account `index` from `mnemonic`, the same phrase backing
+    /// account 0 (or any other index) — one seed phrase can therefore back
+    /// many independent accounts instead of needing one phrase each.
+    ///
+    /// Builds on [`crate::crypto::keypair_from_mnemonic_at_index`], which
+    /// folds the index into the BIP39 seed via a BLAKE3 KDF before deriving
+    /// the ML-DSA-65 keypair. The index is recorded on the returned wallet
+    /// (and persisted through [`Wallet::save`]/[`Wallet::save_encrypted`])
+    /// so [`Wallet::list_wallets`] can display which account a saved wallet
+    /// is.
+    ///
+    /// ML-DSA has no public-key-only child derivation the way elliptic-curve
+    /// schemes do (there is no way to derive account N's public key from
+    /// account 0's public key alone) — deriving *any* account still requires
+    /// the mnemonic and passphrase, not just a previously derived keypair.
+    #[must_use]
+    pub fn derive_account(mnemonic: &bip39::Mnemonic, passphrase: &str, index: u32) -> Self {
+        let keypair = crate::crypto::keypair_from_mnemonic_at_index(mnemonic, passphrase, index);
+        Self {
+            keypair,
+            mnemonic: Some(mnemonic.to_string()),
+            name: None,
+            path: None,
+            account_index: Some(index),
+        }
+    }
+
+    /// Repeatedly generate fresh wallets across `threads` worker threads
+    /// until one's [`Address`] hex starts with `prefix`, then return it.
+    ///
+    /// `threads` is clamped to at least 1. Workers share an atomic "found"
+    /// flag so the whole pool stops as soon as any one of them matches,
+    /// rather than running to completion independently. Every attempt
+    /// (matching or not) increments `attempts`, shared with the caller so
+    /// it can poll the count for progress reporting while this blocks.
+    ///
+    /// Matching is case-insensitive against [`Address::to_hex`] unless
+    /// `case_sensitive` is set, in which case it's checked against
+    /// [`Address::to_checksummed_hex`] instead.
+    ///
+    /// # Errors
+    /// Returns [`WalletError::InvalidVanityPrefix`] if `prefix` contains a
+    /// non-hex character, or is longer than [`MAX_VANITY_PREFIX_NIBBLES`]
+    /// nibbles (beyond that the expected `16^N` attempts make the search
+    /// effectively unbounded, so this is rejected up front instead of
+    /// hanging the caller indefinitely).
+    pub fn generate_with_prefix(
+        prefix: &str,
+        threads: usize,
+        case_sensitive: bool,
+        attempts: &Arc<AtomicU64>,
+    ) -> Result<Self, WalletError> {
+        if !prefix.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(WalletError::InvalidVanityPrefix(format!(
+                "'{}' contains a non-hex character",
+                prefix
+            )));
+        }
+        if prefix.len() > MAX_VANITY_PREFIX_NIBBLES {
+            return Err(WalletError::InvalidVanityPrefix(format!(
+                "prefix of {} hex characters would take too long to search for (max {})",
+                prefix.len(),
+                MAX_VANITY_PREFIX_NIBBLES
+            )));
+        }
+
+        let prefix = if case_sensitive {
+            prefix.to_string()
+        } else {
+            prefix.to_ascii_lowercase()
+        };
+        let num_workers = threads.max(1);
+        let found = Arc::new(AtomicBool::new(false));
+        let winner: Arc<Mutex<Option<Self>>> = Arc::new(Mutex::new(None));
+
+        let workers: Vec<_> = (0..num_workers)
+            .map(|_| {
+                let found = Arc::clone(&found);
+                let attempts = Arc::clone(attempts);
+                let winner = Arc::clone(&winner);
+                let prefix = prefix.clone();
+
+                thread::spawn(move || {
+                    while !found.load(Ordering::Relaxed) {
+                        let wallet = Self::generate();
+                        attempts.fetch_add(1, Ordering::Relaxed);
+
+                        let rendered = if case_sensitive {
+                            wallet.address().to_checksummed_hex()
+                        } else {
+                            wallet.address().to_hex().to_ascii_lowercase()
+                        };
+                        let matches = rendered.trim_start_matches("0x").starts_with(&prefix);
+
+                        if matches && !found.swap(true, Ordering::SeqCst) {
+                            *winner.lock().expect("vanity result mutex poisoned") = Some(wallet);
+                            return;
+                        }
+                    }
+                })
+            })
+            .collect();
+
+        for worker in workers {
+            let _ = worker.join();
         }
+
+        Ok(winner
+            .lock()
+            .expect("vanity result mutex poisoned")
+            .take()
+            .expect("a worker recorded the matching wallet before setting found"))
     }
 
     /// Get the public key
@@ -90,34 +347,82 @@ impl Wallet {
         &self.keypair
     }
 
+    /// Consume the wallet, returning its underlying keypair
+    #[must_use]
+    pub fn into_keypair(self) -> Keypair {
+        self.keypair
+    }
+
     /// Sign a message
     #[must_use]
     pub fn sign(&self, message: &[u8]) -> Signature {
         self.keypair.sign(message)
     }
 
-    /// Save wallet to a file
+    /// Save wallet to a file in cleartext
     ///
     /// # Errors
     /// Returns error if file cannot be written
     pub fn save<P: AsRef<Path>>(&mut self, path: P) -> Result<(), WalletError> {
-        let path = path.as_ref();
+        let wallet_file = WalletFile {
+            version: WALLET_VERSION,
+            algorithm: "ml-dsa-65".to_string(),
+            public_key: self.keypair.public_key().to_hex(),
+            encrypted: false,
+            secret_key: hex::encode(self.keypair.secret_key().to_bytes()),
+            mnemonic: self.mnemonic.clone(),
+            kdf: None,
+            name: self.name.clone(),
+            created_at: crate::types::now_millis(),
+            account_index: self.account_index,
+        };
 
-        // Ensure parent directory exists
-        if let Some(parent) = path.parent() {
-            fs::create_dir_all(parent).map_err(|e| WalletError::IoError(e.to_string()))?;
-        }
+        self.write_wallet_file(path, &wallet_file)
+    }
+
+    /// Save wallet to a file, sealing the secret key and mnemonic behind an
+    /// Argon2id-derived passphrase. The public key and address remain in
+    /// cleartext so the wallet can still be identified without unlocking it.
+    ///
+    /// # Errors
+    /// Returns error if the file cannot be written or encryption fails
+    pub fn save_encrypted<P: AsRef<Path>>(
+        &mut self,
+        path: P,
+        passphrase: &str,
+    ) -> Result<(), WalletError> {
+        let secret_hex = hex::encode(self.keypair.secret_key().to_bytes());
+        let (sealed_hex, kdf) = seal(passphrase, &secret_hex, self.mnemonic.as_deref())?;
 
         let wallet_file = WalletFile {
             version: WALLET_VERSION,
             algorithm: "ml-dsa-65".to_string(),
             public_key: self.keypair.public_key().to_hex(),
-            secret_key: hex::encode(self.keypair.secret_key().to_bytes()),
+            encrypted: true,
+            secret_key: sealed_hex,
+            mnemonic: None,
+            kdf: Some(kdf),
             name: self.name.clone(),
             created_at: crate::types::now_millis(),
+            account_index: self.account_index,
         };
 
-        let json = serde_json::to_string_pretty(&wallet_file)
+        self.write_wallet_file(path, &wallet_file)
+    }
+
+    fn write_wallet_file<P: AsRef<Path>>(
+        &mut self,
+        path: P,
+        wallet_file: &WalletFile,
+    ) -> Result<(), WalletError> {
+        let path = path.as_ref();
+
+        // Ensure parent directory exists
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|e| WalletError::IoError(e.to_string()))?;
+        }
+
+        let json = serde_json::to_string_pretty(wallet_file)
             .map_err(|e| WalletError::SerializationError(e.to_string()))?;
 
         let mut file = File::create(path).map_err(|e| WalletError::IoError(e.to_string()))?;
@@ -131,11 +436,40 @@ impl Wallet {
 
     /// Load wallet from a file
     ///
+    /// Returns [`WalletError::PassphraseRequired`] if the file is encrypted;
+    /// use [`Wallet::load_with_passphrase`] instead in that case.
+    ///
     /// # Errors
     /// Returns error if file cannot be read or is invalid
     pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, WalletError> {
+        Self::load_inner(path, None)
+    }
+
+    /// Load a passphrase-encrypted wallet, unsealing the secret key and
+    /// mnemonic with the given passphrase.
+    ///
+    /// # Errors
+    /// Returns error if the file cannot be read, isn't encrypted, or the
+    /// passphrase is wrong
+    pub fn load_with_passphrase<P: AsRef<Path>>(
+        path: P,
+        passphrase: &str,
+    ) -> Result<Self, WalletError> {
+        Self::load_inner(path, Some(passphrase))
+    }
+
+    fn load_inner<P: AsRef<Path>>(path: P, passphrase: Option<&str>) -> Result<Self, WalletError> {
         let path = path.as_ref();
 
+        if let Some((from, to)) = Self::migrate_file(path)? {
+            eprintln!(
+                "Migrated wallet {} from schema v{} to v{}",
+                path.display(),
+                from,
+                to
+            );
+        }
+
         let mut file = File::open(path).map_err(|e| WalletError::IoError(e.to_string()))?;
 
         let mut contents = String::new();
@@ -145,12 +479,20 @@ impl Wallet {
         let wallet_file: WalletFile = serde_json::from_str(&contents)
             .map_err(|e| WalletError::SerializationError(e.to_string()))?;
 
-        if wallet_file.version != WALLET_VERSION {
-            return Err(WalletError::UnsupportedVersion(wallet_file.version));
-        }
+        let (secret_hex, mnemonic) = if wallet_file.encrypted {
+            let passphrase = passphrase.ok_or(WalletError::PassphraseRequired)?;
+            let kdf = wallet_file
+                .kdf
+                .as_ref()
+                .ok_or_else(|| WalletError::InvalidKey("missing kdf parameters".to_string()))?;
+            let sealed = unseal(passphrase, &wallet_file.secret_key, kdf)?;
+            (sealed.secret_key, sealed.mnemonic)
+        } else {
+            (wallet_file.secret_key.clone(), wallet_file.mnemonic.clone())
+        };
 
-        let secret_bytes = hex::decode(&wallet_file.secret_key)
-            .map_err(|e| WalletError::InvalidKey(e.to_string()))?;
+        let secret_bytes =
+            hex::decode(&secret_hex).map_err(|e| WalletError::InvalidKey(e.to_string()))?;
 
         if secret_bytes.len() != SECRET_KEY_SIZE {
             return Err(WalletError::InvalidKey(format!(
@@ -170,8 +512,10 @@ impl Wallet {
 
         Ok(Self {
             keypair,
+            mnemonic,
             name: wallet_file.name,
             path: Some(path.to_path_buf()),
+            account_index: wallet_file.account_index,
         })
     }
 
@@ -240,6 +584,7 @@ impl Wallet {
                         address: wallet.address(),
                         public_key: wallet.public_key().to_hex(),
                         path,
+                        account_index: wallet.account_index,
                     });
                 }
             }
@@ -247,6 +592,86 @@ impl Wallet {
 
         Ok(wallets)
     }
+
+    /// Read just the public key out of a wallet file, without unsealing it.
+    ///
+    /// The public key is always stored in cleartext (even in an encrypted
+    /// wallet, see the module docs), so this needs no passphrase.
+    ///
+    /// # Errors
+    /// Returns error if the file cannot be read/parsed or its public key is
+    /// malformed
+    pub fn peek_public_key<P: AsRef<Path>>(path: P) -> Result<PublicKey, WalletError> {
+        let contents =
+            fs::read_to_string(path).map_err(|e| WalletError::IoError(e.to_string()))?;
+        let wallet_file: WalletFile = serde_json::from_str(&contents)
+            .map_err(|e| WalletError::SerializationError(e.to_string()))?;
+        PublicKey::from_hex(&wallet_file.public_key)
+            .map_err(|e| WalletError::InvalidKey(e.to_string()))
+    }
+
+    /// Migrate a wallet file on disk forward to [`WALLET_VERSION`] in place,
+    /// if it isn't already current. The pre-migration file is preserved
+    /// alongside it as `<path>.bak`.
+    ///
+    /// Returns `Some((from_version, to_version))` if a migration was
+    /// applied, or `None` if the file was already current. Called
+    /// automatically by [`Wallet::load`]/[`Wallet::load_with_passphrase`],
+    /// so callers generally only need this directly for a bulk upgrade
+    /// pass (see `hardclaw keygen migrate`).
+    ///
+    /// # Errors
+    /// Returns error if the file cannot be read/parsed, its `version` is
+    /// newer than this build supports, or a migration step fails
+    pub fn migrate_file<P: AsRef<Path>>(path: P) -> Result<Option<(u8, u8)>, WalletError> {
+        let path = path.as_ref();
+        if !path.exists() {
+            return Err(WalletError::NotFound);
+        }
+
+        let contents = fs::read_to_string(path).map_err(|e| WalletError::IoError(e.to_string()))?;
+        let raw: serde_json::Value = serde_json::from_str(&contents)
+            .map_err(|e| WalletError::SerializationError(e.to_string()))?;
+
+        let file_version = wallet_file_version(&raw)?;
+        if file_version == WALLET_VERSION {
+            return Ok(None);
+        }
+        if file_version > WALLET_VERSION {
+            return Err(WalletError::UnsupportedVersion(file_version));
+        }
+
+        fs::write(backup_path_for(path), &contents)
+            .map_err(|e| WalletError::IoError(e.to_string()))?;
+
+        let migrated = migration::migrate(raw, file_version)?;
+        let rewritten = serde_json::to_string_pretty(&migrated)
+            .map_err(|e| WalletError::SerializationError(e.to_string()))?;
+        fs::write(path, rewritten).map_err(|e| WalletError::IoError(e.to_string()))?;
+
+        Ok(Some((file_version, WALLET_VERSION)))
+    }
+}
+
+/// Read the `version` field out of a raw wallet JSON value.
+fn wallet_file_version(raw: &serde_json::Value) -> Result<u8, WalletError> {
+    let version = raw
+        .get("version")
+        .and_then(serde_json::Value::as_u64)
+        .ok_or_else(|| WalletError::SerializationError("missing version field".to_string()))?;
+    u8::try_from(version)
+        .map_err(|_| WalletError::SerializationError("version field out of range".to_string()))
+}
+
+/// `<path>` with `.bak` appended to the file name, e.g. `wallet.json` ->
+/// `wallet.json.bak`.
+fn backup_path_for(path: &Path) -> PathBuf {
+    let mut name = path
+        .file_name()
+        .map(OsStr::to_os_string)
+        .unwrap_or_default();
+    name.push(".bak");
+    path.with_file_name(name)
 }
 
 /// Information about a wallet (without sensitive data)
@@ -260,6 +685,9 @@ pub struct WalletInfo {
     pub public_key: String,
     /// Path to wallet file
     pub path: PathBuf,
+    /// HD account index this wallet was derived at via
+    /// [`Wallet::derive_account`], if any
+    pub account_index: Option<u32>,
 }
 
 /// Wallet errors
@@ -280,6 +708,127 @@ pub enum WalletError {
     /// Wallet not found
     #[error("wallet not found")]
     NotFound,
+    /// Wallet is encrypted but no passphrase was supplied
+    #[error("wallet is encrypted; a passphrase is required")]
+    PassphraseRequired,
+    /// Passphrase did not unseal the wallet (wrong passphrase or corrupt file)
+    #[error("wrong passphrase")]
+    WrongPassphrase,
+    /// Encryption or decryption failed
+    #[error("encryption error: {0}")]
+    EncryptionFailed(String),
+    /// [`Wallet::generate_with_prefix`] was given an unusable search prefix
+    #[error("invalid vanity prefix: {0}")]
+    InvalidVanityPrefix(String),
+}
+
+/// Derive a 32-byte key from `passphrase` via Argon2id.
+fn derive_key(
+    passphrase: &str,
+    salt: &[u8],
+    mem_cost_kib: u32,
+    time_cost: u32,
+    parallelism: u32,
+) -> Result<[u8; ARGON2_OUTPUT_LEN], WalletError> {
+    let params = argon2::Params::new(
+        mem_cost_kib,
+        time_cost,
+        parallelism,
+        Some(ARGON2_OUTPUT_LEN),
+    )
+    .map_err(|e| WalletError::EncryptionFailed(e.to_string()))?;
+    let argon2 = argon2::Argon2::new(argon2::Algorithm::Argon2id, argon2::Version::V0x13, params);
+
+    let mut key = [0u8; ARGON2_OUTPUT_LEN];
+    argon2
+        .hash_password_into(passphrase.as_bytes(), salt, &mut key)
+        .map_err(|e| WalletError::EncryptionFailed(e.to_string()))?;
+    Ok(key)
+}
+
+/// Seal a secret key (and optional mnemonic) under `passphrase`, returning
+/// the hex-encoded ciphertext and the KDF parameters needed to reverse it.
+fn seal(
+    passphrase: &str,
+    secret_key_hex: &str,
+    mnemonic: Option<&str>,
+) -> Result<(String, KdfParams), WalletError> {
+    let mut salt = [0u8; SALT_LEN];
+    rand::thread_rng().fill_bytes(&mut salt);
+    let mut nonce_bytes = [0u8; 24];
+    rand::thread_rng().fill_bytes(&mut nonce_bytes);
+
+    let key = derive_key(
+        passphrase,
+        &salt,
+        ARGON2_MEM_COST_KIB,
+        ARGON2_TIME_COST,
+        ARGON2_PARALLELISM,
+    )?;
+
+    let payload = SealedSecret {
+        secret_key: secret_key_hex.to_string(),
+        mnemonic: mnemonic.map(ToString::to_string),
+    };
+    let plaintext =
+        serde_json::to_vec(&payload).map_err(|e| WalletError::SerializationError(e.to_string()))?;
+
+    let cipher = XChaCha20Poly1305::new(key.as_slice().into());
+    let nonce = XNonce::from_slice(&nonce_bytes);
+    let ciphertext = cipher
+        .encrypt(nonce, plaintext.as_slice())
+        .map_err(|e| WalletError::EncryptionFailed(e.to_string()))?;
+
+    Ok((
+        hex::encode(ciphertext),
+        KdfParams {
+            salt: hex::encode(salt),
+            nonce: hex::encode(nonce_bytes),
+            mem_cost_kib: ARGON2_MEM_COST_KIB,
+            time_cost: ARGON2_TIME_COST,
+            parallelism: ARGON2_PARALLELISM,
+            cipher: CURRENT_CIPHER.to_string(),
+        },
+    ))
+}
+
+/// Reverse [`seal`]: derive the same key from `passphrase` and `kdf`, then
+/// decrypt and deserialize the sealed payload. Dispatches on `kdf.cipher` so
+/// a wallet sealed under v2's plain ChaCha20-Poly1305 (12-byte nonce) still
+/// unseals correctly alongside v3's XChaCha20-Poly1305 (24-byte nonce).
+fn unseal(
+    passphrase: &str,
+    sealed_hex: &str,
+    kdf: &KdfParams,
+) -> Result<SealedSecret, WalletError> {
+    let salt = hex::decode(&kdf.salt).map_err(|e| WalletError::InvalidKey(e.to_string()))?;
+    let nonce_bytes =
+        hex::decode(&kdf.nonce).map_err(|e| WalletError::InvalidKey(e.to_string()))?;
+    let ciphertext = hex::decode(sealed_hex).map_err(|e| WalletError::InvalidKey(e.to_string()))?;
+
+    let key = derive_key(
+        passphrase,
+        &salt,
+        kdf.mem_cost_kib,
+        kdf.time_cost,
+        kdf.parallelism,
+    )?;
+
+    let plaintext = if kdf.cipher == LEGACY_CIPHER {
+        let cipher = ChaCha20Poly1305::new(key.as_slice().into());
+        let nonce = Nonce::from_slice(&nonce_bytes);
+        cipher
+            .decrypt(nonce, ciphertext.as_slice())
+            .map_err(|_| WalletError::WrongPassphrase)?
+    } else {
+        let cipher = XChaCha20Poly1305::new(key.as_slice().into());
+        let nonce = XNonce::from_slice(&nonce_bytes);
+        cipher
+            .decrypt(nonce, ciphertext.as_slice())
+            .map_err(|_| WalletError::WrongPassphrase)?
+    };
+
+    serde_json::from_slice(&plaintext).map_err(|e| WalletError::SerializationError(e.to_string()))
 }
 
 #[cfg(test)]
@@ -317,4 +866,136 @@ mod tests {
 
         assert!(crate::crypto::verify(wallet.public_key(), message, &signature).is_ok());
     }
+
+    #[test]
+    fn test_wallet_save_load_encrypted() {
+        let mut wallet = Wallet::generate_with_name("encrypted".to_string());
+        let original_pubkey = wallet.public_key().to_hex();
+
+        let path = temp_dir().join("hardclaw_test_wallet_v3_encrypted.json");
+        wallet
+            .save_encrypted(&path, "correct horse battery staple")
+            .unwrap();
+
+        // Loading without a passphrase is rejected outright.
+        assert!(matches!(
+            Wallet::load(&path),
+            Err(WalletError::PassphraseRequired)
+        ));
+
+        let loaded = Wallet::load_with_passphrase(&path, "correct horse battery staple").unwrap();
+        assert_eq!(loaded.public_key().to_hex(), original_pubkey);
+
+        assert!(matches!(
+            Wallet::load_with_passphrase(&path, "wrong passphrase"),
+            Err(WalletError::WrongPassphrase)
+        ));
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_unseal_accepts_legacy_chacha20poly1305_blob() {
+        // A wallet sealed before `KdfParams::cipher` existed has a 12-byte
+        // nonce and no `cipher` field at all; `unseal` must still open it
+        // under the cipher it was actually sealed with.
+        let passphrase = "legacy passphrase";
+        let mut salt = [0u8; SALT_LEN];
+        rand::thread_rng().fill_bytes(&mut salt);
+        let mut nonce_bytes = [0u8; 12];
+        rand::thread_rng().fill_bytes(&mut nonce_bytes);
+
+        let key = derive_key(
+            passphrase,
+            &salt,
+            ARGON2_MEM_COST_KIB,
+            ARGON2_TIME_COST,
+            ARGON2_PARALLELISM,
+        )
+        .unwrap();
+        let payload = SealedSecret {
+            secret_key: "deadbeef".to_string(),
+            mnemonic: None,
+        };
+        let plaintext = serde_json::to_vec(&payload).unwrap();
+        let cipher = ChaCha20Poly1305::new(key.as_slice().into());
+        let nonce = Nonce::from_slice(&nonce_bytes);
+        let ciphertext = cipher.encrypt(nonce, plaintext.as_slice()).unwrap();
+
+        let kdf = KdfParams {
+            salt: hex::encode(salt),
+            nonce: hex::encode(nonce_bytes),
+            mem_cost_kib: ARGON2_MEM_COST_KIB,
+            time_cost: ARGON2_TIME_COST,
+            parallelism: ARGON2_PARALLELISM,
+            cipher: default_cipher(),
+        };
+
+        let sealed = unseal(passphrase, &hex::encode(ciphertext), &kdf).unwrap();
+        assert_eq!(sealed.secret_key, "deadbeef");
+    }
+
+    #[test]
+    fn test_derive_account_differs_by_index_but_reproducible() {
+        let mnemonic = crate::crypto::generate_mnemonic();
+
+        let account0 = Wallet::derive_account(&mnemonic, "", 0);
+        let account1 = Wallet::derive_account(&mnemonic, "", 1);
+        let account0_again = Wallet::derive_account(&mnemonic, "", 0);
+
+        assert_ne!(
+            account0.public_key().to_hex(),
+            account1.public_key().to_hex()
+        );
+        assert_eq!(
+            account0.public_key().to_hex(),
+            account0_again.public_key().to_hex()
+        );
+        assert_eq!(account0.account_index, Some(0));
+        assert_eq!(account1.account_index, Some(1));
+    }
+
+    #[test]
+    fn test_derive_account_index_round_trips_through_save_load() {
+        let mnemonic = crate::crypto::generate_mnemonic();
+        let mut wallet = Wallet::derive_account(&mnemonic, "", 7);
+
+        let path = temp_dir().join("hardclaw_test_wallet_account_index.json");
+        wallet.save(&path).unwrap();
+
+        let loaded = Wallet::load(&path).unwrap();
+        assert_eq!(loaded.account_index, Some(7));
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_generate_with_prefix_finds_matching_address() {
+        // One nibble keeps this test fast (expected ~16 attempts).
+        let attempts = Arc::new(AtomicU64::new(0));
+        let wallet = Wallet::generate_with_prefix("a", 4, false, &attempts).unwrap();
+        assert!(attempts.load(Ordering::Relaxed) >= 1);
+        assert!(wallet
+            .address()
+            .to_hex()
+            .trim_start_matches("0x")
+            .to_ascii_lowercase()
+            .starts_with('a'));
+    }
+
+    #[test]
+    fn test_generate_with_prefix_rejects_non_hex() {
+        assert!(matches!(
+            Wallet::generate_with_prefix("zz", 1, false, &Arc::new(AtomicU64::new(0))),
+            Err(WalletError::InvalidVanityPrefix(_))
+        ));
+    }
+
+    #[test]
+    fn test_generate_with_prefix_rejects_infeasible_length() {
+        assert!(matches!(
+            Wallet::generate_with_prefix("123456789", 1, false, &Arc::new(AtomicU64::new(0))),
+            Err(WalletError::InvalidVanityPrefix(_))
+        ));
+    }
 }